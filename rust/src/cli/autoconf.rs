@@ -9,10 +9,11 @@ use nmstate::{
     NetworkState, VlanConfig, VlanInterface,
 };
 
-use crate::error::CliError;
+use crate::{error::CliError, query::parse_lldp_listen_time};
 
 const APP_NAME: &str = "nmstatectl-autoconf";
 const BOND_PREFIX: &str = "bond";
+const DEFAULT_LLDP_LISTEN_TIME: u32 = 30;
 
 pub(crate) fn autoconf(argv: &[String]) -> Result<String, CliError> {
     let matches = clap::Command::new(APP_NAME)
@@ -34,6 +35,26 @@ pub(crate) fn autoconf(argv: &[String]) -> Result<String, CliError> {
                 .index(1)
                 .help("Use only the specified NICs (comma-separated)"),
         )
+        .arg(
+            clap::Arg::new("KERNEL")
+                .short('k')
+                .long("kernel")
+                .takes_value(false)
+                .help(
+                    "Listen LLDP frames natively and apply to kernel only \
+                    without NetworkManager",
+                ),
+        )
+        .arg(
+            clap::Arg::new("LLDP_LISTEN_TIME")
+                .long("lldp-listen-time")
+                .takes_value(true)
+                .requires("KERNEL")
+                .help(
+                    "Seconds to listen LLDP frames in kernel mode, \
+                    default is 30",
+                ),
+        )
         .get_matches_from(argv);
     let mut log_builder = env_logger::Builder::new();
 
@@ -42,13 +63,24 @@ pub(crate) fn autoconf(argv: &[String]) -> Result<String, CliError> {
     log_builder.filter(Some("nm_dbus"), log::LevelFilter::Info);
     log_builder.init();
 
+    let kernel_only = matches.is_present("KERNEL");
     let mut cur_state = NetworkState::new();
+    if kernel_only {
+        cur_state.set_kernel_only(true);
+        cur_state.set_lldp_listen_time(
+            match matches.value_of("LLDP_LISTEN_TIME") {
+                Some(t) => parse_lldp_listen_time(t)?,
+                None => DEFAULT_LLDP_LISTEN_TIME,
+            },
+        );
+    }
     cur_state.retrieve()?;
     filter_net_state(&mut cur_state, matches.value_of("ONLY"))?;
 
     let vlan_to_iface = get_lldp_vlans(&cur_state);
 
-    let desire_state = gen_desire_state(&vlan_to_iface);
+    let mut desire_state = gen_desire_state(&vlan_to_iface);
+    desire_state.set_kernel_only(kernel_only);
 
     if !matches.is_present("DRY_RUN") {
         eprintln!("This is a experimental function!");
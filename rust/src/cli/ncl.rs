@@ -135,6 +135,16 @@ fn main() {
                         .takes_value(false)
                         .help("Show secrets(hide by default)"),
                 )
+                .arg(
+                    clap::Arg::new("LLDP_LISTEN_TIME")
+                        .long("lldp-listen-time")
+                        .takes_value(true)
+                        .requires("KERNEL")
+                        .help(
+                            "Listen LLDP frames for specified seconds to \
+                            show LLDP neighbors in kernel mode",
+                        ),
+                )
         )
         .subcommand(
             clap::Command::new(SUB_CMD_APPLY)
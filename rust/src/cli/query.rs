@@ -1,5 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use std::str::FromStr;
+
 use nmstate::{
     DnsState, HostNameState, NetworkState, OvnConfiguration, OvsDbGlobalConfig,
     RouteRules, Routes,
@@ -39,6 +41,9 @@ pub(crate) fn show(matches: &clap::ArgMatches) -> Result<String, CliError> {
         net_state.set_running_config_only(true);
     }
     net_state.set_include_secrets(matches.is_present("SHOW_SECRETS"));
+    if let Some(listen_time) = matches.value_of("LLDP_LISTEN_TIME") {
+        net_state.set_lldp_listen_time(parse_lldp_listen_time(listen_time)?);
+    }
     net_state.retrieve()?;
     Ok(if let Some(ifname) = matches.value_of("IFNAME") {
         let mut new_net_state = filter_net_state_with_iface(&net_state, ifname);
@@ -55,6 +60,13 @@ pub(crate) fn show(matches: &clap::ArgMatches) -> Result<String, CliError> {
     })
 }
 
+pub(crate) fn parse_lldp_listen_time(value: &str) -> Result<u32, CliError> {
+    u32::from_str(value).map_err(|e| CliError {
+        code: crate::error::EX_DATAERR,
+        error_msg: format!("Invalid LLDP listen time {value}: {e}"),
+    })
+}
+
 pub(crate) fn sort_netstate(
     net_state: NetworkState,
 ) -> Result<SortedNetworkState, CliError> {
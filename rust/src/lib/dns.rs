@@ -1,13 +1,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use std::net::{Ipv4Addr, Ipv6Addr};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    ip::is_ipv6_addr, ErrorKind, MergedInterface, MergedNetworkState,
-    NmstateError,
+    ip::{is_ipv6_addr, is_ipv6_unicast_link_local},
+    ErrorKind, MergedInterface, MergedNetworkState, NmstateError,
 };
 
 const SUPPORTED_DNS_OPTS_NO_VALUE: [&str; 15] = [
@@ -31,6 +31,8 @@ const SUPPORTED_DNS_OPTS_NO_VALUE: [&str; 15] = [
 const SUPPORTED_DNS_OPTS_WITH_VALUE: [&str; 3] =
     ["ndots", "timeout", "attempts"];
 
+pub(crate) const DNS_TLS_URI_PREFIX: &str = "dns+tls://";
+
 #[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
 #[non_exhaustive]
 #[serde(deny_unknown_fields)]
@@ -57,6 +59,17 @@ const SUPPORTED_DNS_OPTS_WITH_VALUE: [&str; 3] =
 ///      - trust-ad
 ///      - rotate
 /// ```
+/// Example partial yaml of [NetworkState] with DNS-over-TLS and DNSSEC:
+/// ```yaml
+/// ---
+/// dns-resolver:
+///   config:
+///      server:
+///      - dns+tls://192.0.2.250#dns.example.org
+///      - dns+tls://[2001:db8:1::250]:853#dns.example.org
+///      dns-over-tls: "yes"
+///      dnssec: allow-downgrade
+/// ```
 /// To purge all static DNS configuration:
 /// ```yml
 /// ---
@@ -115,6 +128,20 @@ pub struct DnsClientState {
     /// To remove all existing search, please use `Some(Vec::new())`.
     /// If undefined(set to `None`), will preserve current config.
     pub options: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "dns-over-tls")]
+    /// DNS-over-TLS policy.
+    /// Applied to `connection.dns-over-tls` of the NetworkManager connection
+    /// profiles holding DNS configuration, or to the systemd-resolved global
+    /// configuration in kernel only mode.
+    /// Serialize and deserialize to/from `dns-over-tls`.
+    /// If undefined(set to `None`), will preserve current config.
+    pub dns_over_tls: Option<DnsOverTls>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// DNSSEC validation policy.
+    /// Applied to the systemd-resolved global configuration, hence not
+    /// supported in memory only mode.
+    /// If undefined(set to `None`), will preserve current config.
+    pub dnssec: Option<Dnssec>,
     #[serde(skip)]
     // Lower is better
     pub(crate) priority: Option<i32>,
@@ -126,7 +153,11 @@ impl DnsClientState {
     }
 
     pub fn is_empty(&self) -> bool {
-        self.server.is_none() && self.search.is_none() && self.options.is_none()
+        self.server.is_none()
+            && self.search.is_none()
+            && self.options.is_none()
+            && self.dns_over_tls.is_none()
+            && self.dnssec.is_none()
     }
 
     pub(crate) fn is_null(&self) -> bool {
@@ -138,33 +169,7 @@ impl DnsClientState {
     // sanitize the IP addresses.
     pub(crate) fn sanitize(&mut self) -> Result<(), NmstateError> {
         if let Some(srvs) = self.server.as_mut() {
-            let mut sanitized_srvs = Vec::new();
-            for srv in srvs {
-                if is_ipv6_addr(srv.as_str()) {
-                    let splits: Vec<&str> = srv.split('%').collect();
-                    if splits.len() == 2 {
-                        if let Ok(ip_addr) = splits[0].parse::<Ipv6Addr>() {
-                            sanitized_srvs
-                                .push(format!("{}%{}", ip_addr, splits[1]));
-                        }
-                    } else if let Ok(ip_addr) = srv.parse::<Ipv6Addr>() {
-                        sanitized_srvs.push(ip_addr.to_string());
-                    } else {
-                        return Err(NmstateError::new(
-                            ErrorKind::InvalidArgument,
-                            format!("Invalid DNS server string {srv}",),
-                        ));
-                    }
-                } else if let Ok(ip_addr) = srv.parse::<Ipv4Addr>() {
-                    sanitized_srvs.push(ip_addr.to_string());
-                } else {
-                    return Err(NmstateError::new(
-                        ErrorKind::InvalidArgument,
-                        format!("Invalid DNS server string {srv}",),
-                    ));
-                }
-            }
-            self.server = Some(sanitized_srvs);
+            sanitize_dns_servers(srvs)?;
         }
         if let Some(opts) = self.options.as_ref() {
             for opt in opts {
@@ -206,6 +211,369 @@ impl DnsClientState {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+/// DNS-over-TLS policy
+pub enum DnsOverTls {
+    /// Do not use DNS-over-TLS.
+    /// Serialize and deserialize to/from `no`.
+    No,
+    /// Try DNS-over-TLS first and fallback to plain DNS when name server does
+    /// not support DNS-over-TLS.
+    /// Serialize and deserialize to/from `opportunistic`.
+    Opportunistic,
+    /// Always use DNS-over-TLS and fail if name server does not support it.
+    /// Serialize and deserialize to/from `yes`.
+    Yes,
+}
+
+impl std::fmt::Display for DnsOverTls {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::No => "no",
+                Self::Opportunistic => "opportunistic",
+                Self::Yes => "yes",
+            }
+        )
+    }
+}
+
+impl FromStr for DnsOverTls {
+    type Err = NmstateError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "no" | "false" | "0" => Ok(Self::No),
+            "opportunistic" => Ok(Self::Opportunistic),
+            "yes" | "true" | "1" => Ok(Self::Yes),
+            _ => Err(NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!("Invalid DNS-over-TLS policy {s}"),
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+/// DNSSEC validation policy
+pub enum Dnssec {
+    /// Do not validate DNS response.
+    /// Serialize and deserialize to/from `no`.
+    No,
+    /// Validate DNS response if name server supports DNSSEC, otherwise
+    /// accept unsigned response.
+    /// Serialize and deserialize to/from `allow-downgrade`.
+    AllowDowngrade,
+    /// Always validate DNS response and fail on unsigned response.
+    /// Serialize and deserialize to/from `yes`.
+    Yes,
+}
+
+impl std::fmt::Display for Dnssec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::No => "no",
+                Self::AllowDowngrade => "allow-downgrade",
+                Self::Yes => "yes",
+            }
+        )
+    }
+}
+
+impl FromStr for Dnssec {
+    type Err = NmstateError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "no" | "false" | "0" => Ok(Self::No),
+            "allow-downgrade" => Ok(Self::AllowDowngrade),
+            "yes" | "true" | "1" => Ok(Self::Yes),
+            _ => Err(NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!("Invalid DNSSEC policy {s}"),
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(deny_unknown_fields)]
+/// Interface level DNS configuration, also known as split DNS.
+/// Unlike the global [DnsState], name servers defined here are only used for
+/// resolving domains routed to this interface. Example partial yaml output of
+/// [crate::Interface] with VPN DNS config:
+/// ```yaml
+/// ---
+/// interfaces:
+/// - name: tun0
+///   type: unknown
+///   dns:
+///     server:
+///     - 192.0.2.53
+///     search:
+///     - ~corp.example.com
+///     - example.org
+///     priority: 50
+/// ```
+/// To purge interface level DNS configuration:
+/// ```yml
+/// ---
+/// interfaces:
+/// - name: tun0
+///   type: unknown
+///   dns: {}
+/// ```
+/// Interface holding interface level DNS configuration will not be chosen to
+/// store the global DNS configuration defined in [DnsState].
+pub struct InterfaceDnsConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Name server IP address list.
+    /// IPv4 and IPv6 name servers are stored to the IPv4 and IPv6 stack
+    /// respectively, hence the order between IPv4 and IPv6 name servers is
+    /// not preserved.
+    /// If undefined(set to `None`), will preserve current config.
+    pub server: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Search domain list. Domain prefixed with `~` is routing-only domain
+    /// which is only used for deciding which interface should resolve the
+    /// query but not used as search domain, `~.` means this interface is
+    /// preferred for all domains.
+    /// If undefined(set to `None`), will preserve current config.
+    pub search: Option<Vec<String>>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_i32_or_string"
+    )]
+    /// DNS priority of this interface, lower value means higher priority.
+    /// Negative value will exclude DNS config of other interfaces with
+    /// bigger priority value.
+    /// If undefined(set to `None`), will preserve current config.
+    pub priority: Option<i32>,
+}
+
+impl InterfaceDnsConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether all properties are undefined, which means purging interface
+    /// level DNS configuration when applying.
+    pub fn is_empty(&self) -> bool {
+        self.server.is_none()
+            && self.search.is_none()
+            && self.priority.is_none()
+    }
+
+    pub(crate) fn sanitize(
+        &mut self,
+        iface_name: &str,
+    ) -> Result<(), NmstateError> {
+        if let Some(srvs) = self.server.as_mut() {
+            sanitize_dns_servers(srvs)?;
+            for srv in srvs.iter_mut() {
+                if let Some((_, scope)) = parse_dns_ipv6_link_local_srv(srv)? {
+                    if scope != iface_name {
+                        let e = NmstateError::new(
+                            ErrorKind::InvalidArgument,
+                            format!(
+                                "Interface level DNS server {srv} of \
+                                interface {iface_name} is pointing to \
+                                other interface {scope}"
+                            ),
+                        );
+                        log::error!("{}", e);
+                        return Err(e);
+                    }
+                } else if let Ok(ip) = srv.parse::<Ipv6Addr>() {
+                    if is_ipv6_unicast_link_local(&ip) {
+                        srv.push('%');
+                        srv.push_str(iface_name);
+                    }
+                }
+            }
+        }
+        if let Some(schs) = self.search.as_ref() {
+            for sch in schs {
+                if sch.is_empty() || sch == "~" || sch.contains(' ') {
+                    let e = NmstateError::new(
+                        ErrorKind::InvalidArgument,
+                        format!(
+                            "Invalid DNS search domain '{sch}' for \
+                            interface {iface_name}"
+                        ),
+                    );
+                    log::error!("{}", e);
+                    return Err(e);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn ipv4_servers(&self) -> Vec<String> {
+        self.server
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .filter(|s| !is_ipv6_dns_srv(s))
+            .cloned()
+            .collect()
+    }
+
+    // The IPv6 link local scope is removed
+    pub(crate) fn ipv6_servers(&self) -> Vec<String> {
+        self.server
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .filter(|s| is_ipv6_dns_srv(s))
+            .map(|s| s.split('%').next().unwrap_or(s).to_string())
+            .collect()
+    }
+}
+
+fn sanitize_dns_servers(srvs: &mut Vec<String>) -> Result<(), NmstateError> {
+    let mut sanitized_srvs = Vec::new();
+    for srv in srvs.iter() {
+        if let Some((ip, port, server_name)) = parse_dns_tls_srv(srv)? {
+            sanitized_srvs.push(gen_dns_tls_srv(ip, port, server_name));
+        } else if srv.contains("://") {
+            let e = NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "Unsupported DNS server URI {srv}, only \
+                    '{DNS_TLS_URI_PREFIX}' is supported"
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        } else if is_ipv6_addr(srv.as_str()) {
+            let splits: Vec<&str> = srv.split('%').collect();
+            if splits.len() == 2 {
+                if let Ok(ip_addr) = splits[0].parse::<Ipv6Addr>() {
+                    sanitized_srvs.push(format!("{}%{}", ip_addr, splits[1]));
+                }
+            } else if let Ok(ip_addr) = srv.parse::<Ipv6Addr>() {
+                sanitized_srvs.push(ip_addr.to_string());
+            } else {
+                return Err(NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!("Invalid DNS server string {srv}",),
+                ));
+            }
+        } else if let Ok(ip_addr) = srv.parse::<Ipv4Addr>() {
+            sanitized_srvs.push(ip_addr.to_string());
+        } else {
+            return Err(NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!("Invalid DNS server string {srv}",),
+            ));
+        }
+    }
+    *srvs = sanitized_srvs;
+    Ok(())
+}
+
+// IP address, port and server name of DNS-over-TLS name server
+type DnsTlsSrv<'a> = (IpAddr, Option<u16>, Option<&'a str>);
+
+// Parse DNS-over-TLS name server URI in the format of
+// `dns+tls://ADDRESS[:PORT][#SERVER_NAME]`, IPv6 address with port should be
+// enclosed by square brackets. Return None if not DNS-over-TLS URI.
+pub(crate) fn parse_dns_tls_srv(
+    srv: &str,
+) -> Result<Option<DnsTlsSrv<'_>>, NmstateError> {
+    let uri = if let Some(u) = srv.strip_prefix(DNS_TLS_URI_PREFIX) {
+        u
+    } else {
+        return Ok(None);
+    };
+    let (addr_port, server_name) = match uri.split_once('#') {
+        Some((a, n)) => (a, Some(n)),
+        None => (uri, None),
+    };
+    if server_name.map(|n| n.is_empty()) == Some(true) {
+        return Err(invalid_dns_tls_srv(srv));
+    }
+    let (addr, port) = if let Some(a) = addr_port.strip_prefix('[') {
+        match a.split_once(']') {
+            Some((addr, "")) => (addr, None),
+            Some((addr, port)) => match port.strip_prefix(':') {
+                Some(p) => (addr, Some(p)),
+                None => return Err(invalid_dns_tls_srv(srv)),
+            },
+            None => return Err(invalid_dns_tls_srv(srv)),
+        }
+    } else if addr_port.matches(':').count() == 1 {
+        match addr_port.split_once(':') {
+            Some((addr, port)) => (addr, Some(port)),
+            None => (addr_port, None),
+        }
+    } else {
+        (addr_port, None)
+    };
+    let ip = addr
+        .parse::<IpAddr>()
+        .map_err(|_| invalid_dns_tls_srv(srv))?;
+    let port = match port {
+        Some(p) => match p.parse::<u16>() {
+            Ok(p) if p != 0 => Some(p),
+            _ => return Err(invalid_dns_tls_srv(srv)),
+        },
+        None => None,
+    };
+    Ok(Some((ip, port, server_name)))
+}
+
+fn invalid_dns_tls_srv(srv: &str) -> NmstateError {
+    let e = NmstateError::new(
+        ErrorKind::InvalidArgument,
+        format!(
+            "Invalid DNS-over-TLS name server {srv}, the correct format \
+            should be 'dns+tls://192.0.2.1:853#dns.example.org' or \
+            'dns+tls://[2001:db8::1]:853#dns.example.org'"
+        ),
+    );
+    log::error!("{}", e);
+    e
+}
+
+fn gen_dns_tls_srv(
+    ip: IpAddr,
+    port: Option<u16>,
+    server_name: Option<&str>,
+) -> String {
+    let mut ret = match (ip, port) {
+        (IpAddr::V6(ip), Some(port)) => {
+            format!("{DNS_TLS_URI_PREFIX}[{ip}]:{port}")
+        }
+        (ip, Some(port)) => format!("{DNS_TLS_URI_PREFIX}{ip}:{port}"),
+        (ip, None) => format!("{DNS_TLS_URI_PREFIX}{ip}"),
+    };
+    if let Some(server_name) = server_name {
+        ret.push('#');
+        ret.push_str(server_name);
+    }
+    ret
+}
+
+// Whether specified name server(could be DNS-over-TLS URI) is IPv6
+pub(crate) fn is_ipv6_dns_srv(srv: &str) -> bool {
+    match parse_dns_tls_srv(srv) {
+        Ok(Some((ip, _, _))) => ip.is_ipv6(),
+        _ => is_ipv6_addr(srv),
+    }
+}
+
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub(crate) struct MergedDnsState {
     pub(crate) desired: Option<DnsState>,
@@ -213,6 +581,8 @@ pub(crate) struct MergedDnsState {
     pub(crate) servers: Vec<String>,
     pub(crate) searches: Vec<String>,
     pub(crate) options: Vec<String>,
+    pub(crate) dns_over_tls: Option<DnsOverTls>,
+    pub(crate) dnssec: Option<Dnssec>,
 }
 
 impl MergedDnsState {
@@ -237,6 +607,9 @@ impl MergedDnsState {
             .as_ref()
             .and_then(|c| c.options.clone())
             .unwrap_or_default();
+        let mut dns_over_tls =
+            current.config.as_ref().and_then(|c| c.dns_over_tls);
+        let mut dnssec = current.config.as_ref().and_then(|c| c.dnssec);
 
         let mut desired = match desired {
             Some(d) => d,
@@ -247,6 +620,8 @@ impl MergedDnsState {
                     servers,
                     searches,
                     options,
+                    dns_over_tls,
+                    dnssec,
                 });
             }
         };
@@ -254,15 +629,14 @@ impl MergedDnsState {
         desired.sanitize()?;
 
         if let Some(conf) = desired.config.as_ref() {
-            //  * `server`, `search` and `options` are None. Equal to desire
-            //  state `config: {}`, means purging
-            if conf.server.is_none()
-                && conf.search.is_none()
-                && conf.options.is_none()
-            {
+            //  * All properties are None. Equal to desire state `config: {}`,
+            //  means purging
+            if conf.is_empty() {
                 servers.clear();
                 searches.clear();
                 options.clear();
+                dns_over_tls = None;
+                dnssec = None;
             } else {
                 if let Some(des_srvs) = conf.server.as_ref() {
                     servers.clear();
@@ -276,6 +650,12 @@ impl MergedDnsState {
                     options.clear();
                     options.extend_from_slice(des_opts);
                 }
+                if conf.dns_over_tls.is_some() {
+                    dns_over_tls = conf.dns_over_tls;
+                }
+                if conf.dnssec.is_some() {
+                    dnssec = conf.dnssec;
+                }
             }
         }
 
@@ -285,12 +665,35 @@ impl MergedDnsState {
             servers,
             searches,
             options,
+            dns_over_tls,
+            dnssec,
         })
     }
 
+    // The DNSSEC policy is stored to systemd-resolved config file which
+    // cannot be memory only.
+    pub(crate) fn validate_memory_only(&self) -> Result<(), NmstateError> {
+        let cur_dnssec = self.current.config.as_ref().and_then(|c| c.dnssec);
+        if self.dnssec != cur_dnssec {
+            let e = NmstateError::new(
+                ErrorKind::NotSupportedError,
+                "Changing DNSSEC policy is not supported in memory only mode"
+                    .to_string(),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    // The DNS-over-TLS policy without name server is also stored to
+    // interface, so it could be applied to name servers learned from
+    // DHCP/autoconf.
     pub(crate) fn is_search_or_option_only(&self) -> bool {
         self.servers.is_empty()
-            && (!self.searches.is_empty() || !self.options.is_empty())
+            && (!self.searches.is_empty()
+                || !self.options.is_empty()
+                || self.dns_over_tls.is_some())
     }
 }
 
@@ -317,6 +720,17 @@ impl MergedNetworkState {
                         ),
                     ));
                 };
+                if iface.has_iface_dns() {
+                    return Err(NmstateError::new(
+                        ErrorKind::InvalidArgument,
+                        format!(
+                            "Desired IPv6 link local DNS server {srv} is \
+                            pointing to interface {iface_name} which is \
+                            holding interface level DNS config, please \
+                            define it in the DNS config of that interface"
+                        ),
+                    ));
+                }
                 if iface.is_iface_valid_for_dns(true) {
                     iface_names.push(iface.merged.name());
                 } else {
@@ -382,8 +796,12 @@ pub(crate) fn parse_dns_ipv6_link_local_srv(
 
 impl MergedInterface {
     // IP stack is merged with current at this point.
+    // Interface holding interface level DNS config is not valid for storing
+    // global DNS config.
     pub(crate) fn is_iface_valid_for_dns(&self, is_ipv6: bool) -> bool {
-        if is_ipv6 {
+        if self.has_iface_dns() {
+            false
+        } else if is_ipv6 {
             self.merged.base_iface().ipv6.as_ref().map(|ip_conf| {
                 ip_conf.enabled && (ip_conf.is_static() || (ip_conf.is_auto()))
             }) == Some(true)
@@ -393,4 +811,79 @@ impl MergedInterface {
             }) == Some(true)
         }
     }
+
+    pub(crate) fn has_iface_dns(&self) -> bool {
+        self.merged.base_iface().dns.as_ref().map(|d| !d.is_empty())
+            == Some(true)
+    }
+
+    // * Interface level DNS config requires IP stack enabled.
+    // * Include full interface level DNS config and IP stacks in `for_apply`
+    //   as backend need them to store the DNS config.
+    pub(crate) fn post_inter_ifaces_process_dns(
+        &mut self,
+    ) -> Result<(), NmstateError> {
+        if !self.is_desired()
+            || !self.merged.is_up()
+            || self
+                .desired
+                .as_ref()
+                .and_then(|i| i.base_iface().dns.as_ref())
+                .is_none()
+        {
+            return Ok(());
+        }
+        let dns_conf = if let Some(d) = self.merged.base_iface().dns.as_ref() {
+            d.clone()
+        } else {
+            return Ok(());
+        };
+        let base_iface = self.merged.base_iface();
+        if !dns_conf.is_empty() {
+            let iface_name = base_iface.name.as_str();
+            if !base_iface.can_have_ip() {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "Interface {iface_name} cannot hold interface level \
+                        DNS config as it is attached to a controller where \
+                        IP is not allowed"
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+            let has_v4_srv = !dns_conf.ipv4_servers().is_empty();
+            let has_v6_srv = !dns_conf.ipv6_servers().is_empty();
+            if (has_v4_srv && !base_iface.is_ipv4_enabled())
+                || (has_v6_srv && !base_iface.is_ipv6_enabled())
+                || (!base_iface.is_ipv4_enabled()
+                    && !base_iface.is_ipv6_enabled())
+            {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "Interface {iface_name} has IP disabled, hence \
+                        cannot hold desired interface level DNS config \
+                        {dns_conf:?}"
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
+        let merged_ipv4 = base_iface.ipv4.clone();
+        let merged_ipv6 = base_iface.ipv6.clone();
+        if let Some(apply_iface) = self.for_apply.as_mut() {
+            let apply_base_iface = apply_iface.base_iface_mut();
+            apply_base_iface.dns = Some(dns_conf);
+            if apply_base_iface.ipv4.is_none() {
+                apply_base_iface.ipv4 = merged_ipv4;
+            }
+            if apply_base_iface.ipv6.is_none() {
+                apply_base_iface.ipv6 = merged_ipv6;
+            }
+        }
+        Ok(())
+    }
 }
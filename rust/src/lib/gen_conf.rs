@@ -8,13 +8,15 @@ use crate::{
 
 impl NetworkState {
     /// Generate offline network configurations.
-    /// Currently only support generate NetworkManager key file out of
-    /// NetworkState.
+    /// Currently only support generate NetworkManager key file and static
+    /// hostname file out of NetworkState.
     ///
     /// The output is a [HashMap] with backend name as key and
     /// `Vec<(config_file_name, config_content>)>` as value.
     ///
     /// The backend name for NetworkManager is `NetworkManager`.
+    /// The backend name for static hostname is `hostname` holding the
+    /// `/etc/hostname` file.
     pub fn gen_conf(
         &self,
     ) -> Result<HashMap<String, Vec<(String, String)>>, NmstateError> {
@@ -33,13 +35,50 @@ impl NetworkState {
             false, // memory only
         )?;
         ret.insert("NetworkManager".to_string(), nm_gen_conf(&merged_state)?);
+        if let Some(hostname_confs) = gen_hostname_conf(&merged_state) {
+            ret.insert("hostname".to_string(), hostname_confs);
+        }
         Ok(ret)
     }
 }
 
+const HOSTNAME_CONFIG_PATH: &str = "/etc/hostname";
+
+fn gen_hostname_conf(
+    merged_state: &MergedNetworkState,
+) -> Option<Vec<(String, String)>> {
+    let desired = merged_state.hostname.desired.as_ref()?;
+    if desired.running.is_some() {
+        log::warn!(
+            "Ignoring runtime hostname as it cannot be stored to \
+            configuration file"
+        );
+    }
+    if desired.pretty.is_some() {
+        log::warn!(
+            "Cannot store pretty hostname to configuration file, please use \
+            `hostnamectl --pretty` instead"
+        );
+    }
+    match desired.config.as_deref() {
+        Some("") => {
+            log::warn!(
+                "Cannot remove static hostname via configuration file, \
+                please remove {HOSTNAME_CONFIG_PATH} manually"
+            );
+            None
+        }
+        Some(config) => Some(vec![(
+            HOSTNAME_CONFIG_PATH.to_string(),
+            format!("{config}\n"),
+        )]),
+        None => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{Interface, InterfaceType, Interfaces};
+    use crate::{Interface, InterfaceType, Interfaces, NetworkState};
 
     #[test]
     fn test_gen_conf_change_unknown_to_eth() {
@@ -65,4 +104,41 @@ mod tests {
             panic!("Expecting ethernet interface");
         }
     }
+
+    #[test]
+    fn test_gen_conf_static_hostname() {
+        let net_state: NetworkState = serde_yaml::from_str(
+            r"---
+hostname:
+  config: host.example.org
+  pretty: Lennart's Laptop
+",
+        )
+        .unwrap();
+
+        let confs = net_state.gen_conf().unwrap();
+
+        assert_eq!(
+            confs["hostname"],
+            vec![(
+                "/etc/hostname".to_string(),
+                "host.example.org\n".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_gen_conf_purge_static_hostname() {
+        let net_state: NetworkState = serde_yaml::from_str(
+            r"---
+hostname:
+  config: ''
+",
+        )
+        .unwrap();
+
+        let confs = net_state.gen_conf().unwrap();
+
+        assert!(!confs.contains_key("hostname"));
+    }
 }
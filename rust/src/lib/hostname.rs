@@ -1,14 +1,34 @@
 // SPDX-License-Identifier: Apache-2.0
 use serde::{Deserialize, Serialize};
 
+use crate::{ErrorKind, NmstateError};
+
 #[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
 #[non_exhaustive]
 #[serde(deny_unknown_fields)]
+/// Host name state
+///
+/// Example yaml output of [crate::NetworkState] with host name:
+/// ```yaml
+/// hostname:
+///   running: host-a.example.org
+///   config: host.example.org
+///   pretty: Lennart's Laptop
+/// ```
 pub struct HostNameState {
     #[serde(skip_serializing_if = "Option::is_none")]
+    /// Transient host name used by kernel currently.
     pub running: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    /// Static host name stored in `/etc/hostname`.
+    /// Set to empty string to remove static host name.
     pub config: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Free-form UTF-8 host name for presentation to the user, managed by
+    /// systemd-hostnamed.
+    /// Set to empty string to remove pretty host name.
+    /// Changing this is not supported in memory only mode.
+    pub pretty: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
@@ -24,4 +44,31 @@ impl MergedHostNameState {
     ) -> Self {
         Self { desired, current }
     }
+
+    // The pretty host name is stored by systemd-hostnamed to
+    // `/etc/machine-info` which cannot be memory only.
+    pub(crate) fn validate_memory_only(&self) -> Result<(), NmstateError> {
+        let des_pretty = if let Some(p) =
+            self.desired.as_ref().and_then(|d| d.pretty.as_deref())
+        {
+            p
+        } else {
+            return Ok(());
+        };
+        let cur_pretty = self
+            .current
+            .as_ref()
+            .and_then(|c| c.pretty.as_deref())
+            .unwrap_or_default();
+        if des_pretty != cur_pretty {
+            let e = NmstateError::new(
+                ErrorKind::NotSupportedError,
+                "Changing pretty hostname is not supported in memory only mode"
+                    .to_string(),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+        Ok(())
+    }
 }
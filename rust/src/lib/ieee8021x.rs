@@ -24,6 +24,34 @@ use crate::NetworkState;
 ///       private-key: /etc/pki/802-1x-test/client.example.org.key
 ///       private-key-password: password
 /// ```
+///
+/// The TTLS authentication with server domain matching, TLS 1.0 and TLS 1.1
+/// disabled and inline PEM CA certificate would be:
+/// ```yml
+/// ---
+/// interfaces:
+///   - name: eth1
+///     type: ethernet
+///     state: up
+///     802.1x:
+///       eap-methods:
+///         - ttls
+///       identity: user@example.org
+///       anonymous-identity: anonymous@example.org
+///       domain-suffix-match: radius.example.org
+///       phase1-auth-flags: 3
+///       phase2-auth: mschapv2
+///       password: password
+///       ca-cert: |
+///         -----BEGIN CERTIFICATE-----
+///         MIIDdzCCAl+gAwIBAgIUB2fQ...
+///         -----END CERTIFICATE-----
+/// ```
+///
+/// Besides file path, the certificate and private key properties also accept
+/// PKCS#11 URI (e.g. `pkcs11:token=mytoken;object=mykey`) or inline PEM
+/// content started with `-----BEGIN`. The inline private keys are
+/// considered as secrets.
 pub struct Ieee8021XConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub identity: Option<String>,
@@ -50,19 +78,190 @@ pub struct Ieee8021XConfig {
     /// Deserialize and serialize from/to `password`.
     /// Replaced to `<_password_hid_by_nmstate>` when querying.
     pub password: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Identity used in the unencrypted phase of tunneled EAP methods like
+    /// TTLS and PEAP.
+    /// Deserialize and serialize from/to `anonymous-identity`.
+    pub anonymous_identity: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Only accept server certificate whose subject or alternative names
+    /// ending with this domain. Multiple domains can be separated by
+    /// semicolon.
+    /// Deserialize and serialize from/to `domain-suffix-match`.
+    pub domain_suffix_match: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Only accept server certificate whose alternative subject names
+    /// matching one of the entries, for example `DNS:radius.example.org`.
+    /// Deserialize and serialize from/to `altsubject-matches`.
+    pub altsubject_matches: Option<Vec<String>>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    /// Bit flags controlling TLS versions and time checks of phase 1:
+    ///  * 0x1: Disable TLS 1.0
+    ///  * 0x2: Disable TLS 1.1
+    ///  * 0x4: Disable TLS 1.2
+    ///  * 0x8: Disable certificate time checks
+    ///  * 0x10: Disable TLS 1.3
+    ///  * 0x20: Enable TLS 1.0
+    ///  * 0x40: Enable TLS 1.1
+    ///  * 0x80: Enable TLS 1.2
+    ///  * 0x100: Enable TLS 1.3
+    ///
+    /// Deserialize and serialize from/to `phase1-auth-flags`.
+    pub phase1_auth_flags: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// CA certificate used by inner TLS authentication of phase 2.
+    /// Deserialize and serialize from/to `phase2-ca-cert`.
+    pub phase2_ca_cert: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Client certificate used by inner TLS authentication of phase 2.
+    /// Deserialize and serialize from/to `phase2-client-cert`.
+    pub phase2_client_cert: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Private key used by inner TLS authentication of phase 2.
+    /// Deserialize and serialize from/to `phase2-private-key`.
+    pub phase2_private_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Deserialize and serialize from/to `phase2-private-key-password`.
+    /// Replaced to `<_password_hid_by_nmstate>` when querying.
+    pub phase2_private_key_password: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The 802.1X authentication state reported by NetworkManager. Only
+    /// included when [crate::NetworkState::set_include_status_data()] set to
+    /// true. Ignored when applying.
+    /// Deserialize and serialize from/to `auth-state`.
+    pub auth_state: Option<Ieee8021XAuthState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The reason of 802.1X authentication failure. Only included when
+    /// [crate::NetworkState::set_include_status_data()] set to true and
+    /// `auth-state` is `failed`. Ignored when applying.
+    /// Deserialize and serialize from/to `auth-failure-reason`.
+    pub auth_failure_reason: Option<Ieee8021XAuthFailureReason>,
 }
 
 impl Ieee8021XConfig {
+    pub(crate) const PEM_PREFIX: &'static str = "-----BEGIN";
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether specified certificate or private key property is inline PEM
+    /// content instead of file path or PKCS#11 URI.
+    pub(crate) fn is_inline_blob(value: &str) -> bool {
+        value.trim_start().starts_with(Self::PEM_PREFIX)
+    }
+
     pub(crate) fn hide_secrets(&mut self) {
-        if self.private_key_password.is_some() {
-            self.private_key_password =
-                Some(NetworkState::PASSWORD_HID_BY_NMSTATE.to_string());
+        for secret in [
+            &mut self.private_key_password,
+            &mut self.password,
+            &mut self.phase2_private_key_password,
+        ] {
+            if secret.is_some() {
+                *secret =
+                    Some(NetworkState::PASSWORD_HID_BY_NMSTATE.to_string());
+            }
+        }
+        for private_key in [&mut self.private_key, &mut self.phase2_private_key]
+        {
+            if private_key.as_deref().map(Self::is_inline_blob) == Some(true) {
+                *private_key =
+                    Some(NetworkState::PASSWORD_HID_BY_NMSTATE.to_string());
+            }
+        }
+    }
+}
+
+impl Ieee8021XConfig {
+    pub(crate) fn sanitize(&mut self, is_desired: bool) {
+        if is_desired
+            && (self.auth_state.is_some() || self.auth_failure_reason.is_some())
+        {
+            log::info!(
+                "Ignoring 802.1X auth-state and auth-failure-reason as they \
+                are query only"
+            );
         }
-        if self.password.is_some() {
-            self.password =
-                Some(NetworkState::PASSWORD_HID_BY_NMSTATE.to_string());
+        if is_desired {
+            self.remove_status_data();
         }
     }
+
+    pub(crate) fn remove_status_data(&mut self) {
+        self.auth_state = None;
+        self.auth_failure_reason = None;
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum Ieee8021XAuthState {
+    /// Authentication succeeded.
+    Authenticated,
+    /// Authentication is in progress.
+    Connecting,
+    /// Authentication failed, check `auth-failure-reason` for detail.
+    Failed,
+}
+
+impl std::fmt::Display for Ieee8021XAuthState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Authenticated => "authenticated",
+                Self::Connecting => "connecting",
+                Self::Failed => "failed",
+            }
+        )
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum Ieee8021XAuthFailureReason {
+    /// Required secrets like password were not provided.
+    NoSecrets,
+    /// The 802.1X supplicant disconnected.
+    SupplicantDisconnect,
+    /// The 802.1X supplicant configuration failed.
+    SupplicantConfigFailed,
+    /// The 802.1X supplicant failed, commonly due to authentication been
+    /// rejected by authenticator.
+    SupplicantFailed,
+    /// The 802.1X supplicant took too long to authenticate.
+    SupplicantTimeout,
+}
+
+impl std::fmt::Display for Ieee8021XAuthFailureReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::NoSecrets => "no-secrets",
+                Self::SupplicantDisconnect => "supplicant-disconnect",
+                Self::SupplicantConfigFailed => "supplicant-config-failed",
+                Self::SupplicantFailed => "supplicant-failed",
+                Self::SupplicantTimeout => "supplicant-timeout",
+            }
+        )
+    }
+}
+
+fn hide_inline_private_key(value: &Option<String>) -> Option<String> {
+    if value.as_deref().map(Ieee8021XConfig::is_inline_blob) == Some(true) {
+        Some(NetworkState::PASSWORD_HID_BY_NMSTATE.to_string())
+    } else {
+        value.clone()
+    }
 }
 
 impl std::fmt::Debug for Ieee8021XConfig {
@@ -70,7 +269,7 @@ impl std::fmt::Debug for Ieee8021XConfig {
         f.debug_struct("Ieee8021XConfig")
             .field("identity", &self.identity)
             .field("eap", &self.eap)
-            .field("private_key", &self.private_key)
+            .field("private_key", &hide_inline_private_key(&self.private_key))
             .field("client_cert", &self.client_cert)
             .field("ca_cert", &self.ca_cert)
             .field(
@@ -82,6 +281,22 @@ impl std::fmt::Debug for Ieee8021XConfig {
                 "password",
                 &Some(NetworkState::PASSWORD_HID_BY_NMSTATE.to_string()),
             )
+            .field("anonymous_identity", &self.anonymous_identity)
+            .field("domain_suffix_match", &self.domain_suffix_match)
+            .field("altsubject_matches", &self.altsubject_matches)
+            .field("phase1_auth_flags", &self.phase1_auth_flags)
+            .field("phase2_ca_cert", &self.phase2_ca_cert)
+            .field("phase2_client_cert", &self.phase2_client_cert)
+            .field(
+                "phase2_private_key",
+                &hide_inline_private_key(&self.phase2_private_key),
+            )
+            .field(
+                "phase2_private_key_password",
+                &Some(NetworkState::PASSWORD_HID_BY_NMSTATE.to_string()),
+            )
+            .field("auth_state", &self.auth_state)
+            .field("auth_failure_reason", &self.auth_failure_reason)
             .finish()
     }
 }
@@ -4,9 +4,9 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     DispatchConfig, ErrorKind, EthtoolConfig, Ieee8021XConfig,
-    InterfaceIdentifier, InterfaceIpv4, InterfaceIpv6, InterfaceState,
-    InterfaceType, LldpConfig, MergedInterface, MptcpConfig, NmstateError,
-    OvsDbIfaceConfig, RouteEntry, WaitIp,
+    InterfaceDnsConfig, InterfaceIdentifier, InterfaceIpv4, InterfaceIpv6,
+    InterfaceState, InterfaceType, LldpConfig, MergedInterface, MptcpConfig,
+    NmstateError, OvsDbIfaceConfig, RouteEntry, WaitIp,
 };
 
 const MINIMUM_IPV6_MTU: u64 = 1280;
@@ -91,6 +91,10 @@ pub struct BaseInterface {
     /// and dynamic).
     pub mptcp: Option<MptcpConfig>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    /// Interface level DNS configuration.
+    /// Hided if interface is not holding interface level DNS configuration.
+    pub dns: Option<InterfaceDnsConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     /// Controller of the specified interface.
     /// Only valid for applying, `None` means no change, empty string means
     /// detach from current controller, please be advise, an error will trigger
@@ -165,6 +169,10 @@ impl BaseInterface {
             self.permanent_mac_address
                 .clone_from(&current.permanent_mac_address);
         }
+        // Empty interface level DNS config means purge
+        if desired.dns.as_ref().map(|d| d.is_empty()) == Some(true) {
+            self.dns = Some(InterfaceDnsConfig::new());
+        }
         self.copy_mac_from.clone_from(&desired.copy_mac_from);
     }
 
@@ -214,6 +222,23 @@ impl BaseInterface {
         }
     }
 
+    pub(crate) fn remove_status_data(&mut self) {
+        if let Some(conf) = self.ieee8021x.as_mut() {
+            conf.remove_status_data();
+            // The 802.1X config might only holding authentication failure
+            // for NetworkManager failed device
+            if conf == &Ieee8021XConfig::default() {
+                self.ieee8021x = None;
+            }
+        }
+        if let Some(ipv4) = self.ipv4.as_mut() {
+            ipv4.remove_status_data();
+        }
+        if let Some(ipv6) = self.ipv6.as_mut() {
+            ipv6.remove_status_data();
+        }
+    }
+
     pub(crate) fn is_ipv4_enabled(&self) -> bool {
         self.ipv4.as_ref().map(|i| i.enabled) == Some(true)
     }
@@ -257,9 +282,18 @@ impl BaseInterface {
                 }
             }
         }
+        if let Some(dns_conf) = self.dns.as_mut() {
+            dns_conf.sanitize(self.name.as_str())?;
+        }
         if let Some(lldp_conf) = self.lldp.as_mut() {
             lldp_conf.sanitize();
         }
+        if let Some(ieee8021x_conf) = self.ieee8021x.as_mut() {
+            ieee8021x_conf.sanitize(is_desired);
+        }
+        if let Some(ethtool_conf) = self.ethtool.as_mut() {
+            ethtool_conf.sanitize(is_desired)?;
+        }
 
         if !self.can_have_ip() {
             self.wait_ip = None;
@@ -304,6 +338,7 @@ impl MergedInterface {
         self.post_inter_ifaces_process_ip()?;
         self.post_inter_ifaces_process_mptcp()?;
         self.post_inter_ifaces_process_ethtool();
+        self.post_inter_ifaces_process_dns()?;
         self.validate_mtu()?;
         self.validate_can_have_ip()?;
         Ok(())
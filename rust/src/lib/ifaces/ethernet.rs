@@ -2,9 +2,10 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::parse_hex_bytes;
 use crate::{
     BaseInterface, ErrorKind, Interface, InterfaceType, Interfaces,
-    MergedInterfaces, NmstateError, SrIovConfig,
+    MergedInterfaces, NetworkState, NmstateError, SrIovConfig,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -60,6 +61,11 @@ use crate::{
 ///       tx-max: 256
 ///   ethernet:
 ///     auto-negotiation: false
+///     wake-on-lan:
+///       modes:
+///       - magic
+///       - secureon
+///       secureon-password: 00:11:22:33:44:55
 /// ```
 pub struct EthernetInterface {
     #[serde(flatten)]
@@ -94,6 +100,11 @@ impl EthernetInterface {
         {
             sriov_conf.sanitize()?
         }
+        if let Some(wol_conf) =
+            self.ethernet.as_mut().and_then(|e| e.wake_on_lan.as_mut())
+        {
+            wol_conf.sanitize()?
+        }
 
         Ok(())
     }
@@ -150,12 +161,166 @@ pub struct EthernetConfig {
     pub speed: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub duplex: Option<EthernetDuplex>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Wake-on-LAN configuration.
+    /// Deserialize and serialize from/to `wake-on-lan`.
+    pub wake_on_lan: Option<EthernetWakeOnLanConfig>,
 }
 
 impl EthernetConfig {
     pub fn new() -> Self {
         Self::default()
     }
+
+    pub(crate) fn hide_secrets(&mut self) {
+        if let Some(wol_conf) = self.wake_on_lan.as_mut() {
+            wol_conf.hide_secrets();
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, PartialOrd, Ord,
+)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum EthernetWakeOnLanMode {
+    /// Wake on PHY activity.
+    /// Deserialize and serialize from/to `phy`.
+    Phy,
+    /// Wake on unicast messages.
+    /// Deserialize and serialize from/to `unicast`.
+    Unicast,
+    /// Wake on multicast messages.
+    /// Deserialize and serialize from/to `multicast`.
+    Multicast,
+    /// Wake on broadcast messages.
+    /// Deserialize and serialize from/to `broadcast`.
+    Broadcast,
+    /// Wake on ARP.
+    /// Deserialize and serialize from/to `arp`.
+    Arp,
+    /// Wake on magic packet.
+    /// Deserialize and serialize from/to `magic`.
+    Magic,
+    /// Wake on magic packet with SecureOn password. Implies `magic`.
+    /// Deserialize and serialize from/to `secureon`.
+    Secureon,
+}
+
+impl std::fmt::Display for EthernetWakeOnLanMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Phy => "phy",
+                Self::Unicast => "unicast",
+                Self::Multicast => "multicast",
+                Self::Broadcast => "broadcast",
+                Self::Arp => "arp",
+                Self::Magic => "magic",
+                Self::Secureon => "secureon",
+            }
+        )
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+pub struct EthernetWakeOnLanConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Wake-on-LAN modes. Empty list means disable Wake-on-LAN.
+    pub modes: Option<Vec<EthernetWakeOnLanMode>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The SecureOn password in the format of MAC address, example:
+    /// `00:11:22:33:44:55`. Only valid when `secureon` mode is enabled.
+    /// Deserialize and serialize from/to `secureon-password`.
+    pub secureon_password: Option<String>,
+}
+
+impl EthernetWakeOnLanConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn hide_secrets(&mut self) {
+        if self.secureon_password.is_some() {
+            self.secureon_password =
+                Some(NetworkState::PASSWORD_HID_BY_NMSTATE.to_string());
+        }
+    }
+
+    pub(crate) fn is_secureon(&self) -> bool {
+        self.modes
+            .as_ref()
+            .map(|m| m.contains(&EthernetWakeOnLanMode::Secureon))
+            .unwrap_or_default()
+    }
+
+    fn sanitize(&mut self) -> Result<(), NmstateError> {
+        if let Some(modes) = self.modes.as_mut() {
+            // SecureOn is extension of magic packet
+            if modes.contains(&EthernetWakeOnLanMode::Secureon) {
+                modes.push(EthernetWakeOnLanMode::Magic);
+            }
+            modes.sort_unstable();
+            modes.dedup();
+        }
+        if let Some(password) = self.secureon_password.as_mut() {
+            if password.as_str() == NetworkState::PASSWORD_HID_BY_NMSTATE {
+                return Ok(());
+            }
+            password.make_ascii_uppercase();
+            if parse_secureon_password(password.as_str()).is_none() {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    "Invalid Wake-on-LAN secureon-password, should be in \
+                    the format of MAC address, example: 00:11:22:33:44:55"
+                        .to_string(),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+            if self.modes.is_some() && !self.is_secureon() {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    "Wake-on-LAN secureon-password is only valid when \
+                    secureon mode is enabled"
+                        .to_string(),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for EthernetWakeOnLanConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EthernetWakeOnLanConfig")
+            .field("modes", &self.modes)
+            .field(
+                "secureon_password",
+                &self
+                    .secureon_password
+                    .as_ref()
+                    .map(|_| NetworkState::PASSWORD_HID_BY_NMSTATE),
+            )
+            .finish()
+    }
+}
+
+pub(crate) fn parse_secureon_password(password: &str) -> Option<[u8; 6]> {
+    let mut ret = [0u8; 6];
+    let bytes = parse_hex_bytes(password)?;
+    if bytes.len() != ret.len() {
+        return None;
+    }
+    ret.copy_from_slice(bytes.as_slice());
+    Some(ret)
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
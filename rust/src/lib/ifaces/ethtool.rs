@@ -9,7 +9,7 @@ use serde::{
     Serializer,
 };
 
-use crate::MergedInterface;
+use crate::{ErrorKind, MergedInterface, NmstateError};
 
 const ETHTOOL_FEATURE_CLI_ALIAS: [(&str, &str); 17] = [
     ("rx", "rx-checksum"),
@@ -115,6 +115,30 @@ impl Serialize for EthtoolFeatureConfig {
 ///       rx-max: 256
 ///       tx: 256
 ///       tx-max: 256
+///     channels:
+///       combined: 4
+///       combined-max: 8
+///     rss:
+///       hash-function: toeplitz
+///       hash-key: "6d:5a:56:da:25:5b:0e:c2:41:67:25:3d:43:a3:8f:b0:d0:ca:2b:cb"
+///       indirection-table: [0, 1, 2, 3, 0, 1, 2, 3]
+///     link-modes:
+///       supported:
+///       - 10000baseT/Full
+///       - 25000baseCR/Full
+///       advertised:
+///       - 25000baseCR/Full
+///     fec:
+///       mode: rs
+///       active: rs
+///     eee:
+///       enabled: true
+///       active: false
+///       tx-lpi-enabled: true
+///       tx-lpi-timer: 0
+///     private-flags:
+///       disable-fw-lldp: true
+///       link-down-on-close: false
 /// ```
 pub struct EthtoolConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -134,6 +158,43 @@ pub struct EthtoolConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     /// The rx/tx ring parameters of the specified network device.
     pub ring: Option<EthtoolRingConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The number of rx/tx/other/combined queues of the specified network
+    /// device.
+    pub channels: Option<EthtoolChannelsConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The receive side scaling(RSS) settings of the specified network
+    /// device.
+    /// NetworkManager cannot persist RSS settings, hence in NetworkManager
+    /// mode they are applied to kernel directly and not persistent.
+    pub rss: Option<EthtoolRssConfig>,
+    #[serde(rename = "link-modes", skip_serializing_if = "Option::is_none")]
+    /// The link modes supported and advertised by the specified network
+    /// device.
+    /// NetworkManager cannot persist advertised link modes, hence in
+    /// NetworkManager mode they are applied to kernel directly and not
+    /// persistent.
+    /// Deserialize and serialize from/to `link-modes`.
+    pub link_modes: Option<EthtoolLinkModesConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The forward error correction(FEC) settings of the specified network
+    /// device.
+    pub fec: Option<EthtoolFecConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The Energy-Efficient Ethernet(EEE) settings of the specified network
+    /// device.
+    pub eee: Option<EthtoolEeeConfig>,
+    #[serde(
+        rename = "private-flags",
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "parse_ethtool_feature"
+    )]
+    /// The driver specific private flags of the specified network device.
+    /// NetworkManager cannot persist private flags, hence in NetworkManager
+    /// mode they are applied to kernel directly and not persistent.
+    /// Deserialize and serialize from/to `private-flags`.
+    pub private_flags: Option<EthtoolFeatureConfig>,
 }
 
 impl EthtoolConfig {
@@ -151,6 +212,19 @@ impl EthtoolConfig {
             }
         }
     }
+
+    pub(crate) fn sanitize(
+        &mut self,
+        is_desired: bool,
+    ) -> Result<(), NmstateError> {
+        if let Some(rss_conf) = self.rss.as_mut() {
+            rss_conf.sanitize(is_desired)?;
+        }
+        if let Some(link_modes_conf) = self.link_modes.as_mut() {
+            link_modes_conf.sanitize();
+        }
+        Ok(())
+    }
 }
 
 #[derive(
@@ -419,6 +493,329 @@ impl EthtoolRingConfig {
     }
 }
 
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+pub struct EthtoolChannelsConfig {
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    pub rx: Option<u32>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    /// Deserialize and serialize from/to `rx-max`.
+    pub rx_max: Option<u32>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    pub tx: Option<u32>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    /// Deserialize and serialize from/to `tx-max`.
+    pub tx_max: Option<u32>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    pub other: Option<u32>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    /// Deserialize and serialize from/to `other-max`.
+    pub other_max: Option<u32>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    pub combined: Option<u32>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    /// Deserialize and serialize from/to `combined-max`.
+    pub combined_max: Option<u32>,
+}
+
+impl EthtoolChannelsConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+/// The RSS hash function.
+pub enum EthtoolRssHashFunction {
+    /// Deserialize and serialize from/to `toeplitz`.
+    Toeplitz,
+    /// Deserialize and serialize from/to `xor`.
+    Xor,
+    /// Deserialize and serialize from/to `crc32`.
+    Crc32,
+}
+
+impl std::fmt::Display for EthtoolRssHashFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Toeplitz => "toeplitz",
+                Self::Xor => "xor",
+                Self::Crc32 => "crc32",
+            }
+        )
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+pub struct EthtoolRssConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Deserialize and serialize from/to `hash-function`.
+    pub hash_function: Option<EthtoolRssHashFunction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The RSS hash key in the format of hex string separated by `:` on
+    /// every two characters, case insensitive when applying.
+    /// Deserialize and serialize from/to `hash-key`.
+    pub hash_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The RX flow hash indirection table, each entry is the RX queue index.
+    /// When applying, the size of this list should be equal to the size of
+    /// indirection table supported by the network device.
+    /// Cannot be used with `equal`.
+    /// Deserialize and serialize from/to `indirection-table`.
+    pub indirection_table: Option<Vec<u32>>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    /// Spread the RX flow hash indirection table evenly over the first
+    /// specified number of RX queues. Only valid for applying, query will
+    /// show the expanded `indirection-table` instead.
+    pub equal: Option<u32>,
+}
+
+impl EthtoolRssConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn sanitize(&mut self, is_desired: bool) -> Result<(), NmstateError> {
+        if let Some(hash_key) = self.hash_key.as_mut() {
+            hash_key.make_ascii_lowercase();
+            if is_desired && parse_hex_bytes(hash_key.as_str()).is_none() {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "Invalid ethtool RSS hash-key {hash_key}, should be \
+                        hex string separated by colon, \
+                        example: 6d:5a:56:da"
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
+        if is_desired {
+            if self.equal == Some(0) {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    "The ethtool RSS `equal` should be bigger than 0".into(),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+            if self.equal.is_some() && self.indirection_table.is_some() {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    "The ethtool RSS `equal` cannot be used with \
+                    `indirection-table`"
+                        .into(),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
+    /// Expand `equal` to `indirection-table` using specified table size.
+    pub(crate) fn expand_equal(&mut self, table_size: usize) {
+        if let Some(equal) = self.equal.take() {
+            self.indirection_table =
+                Some((0..table_size as u32).map(|i| i % equal).collect());
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+pub struct EthtoolLinkModesConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The link modes supported by the network device in the format of
+    /// kernel ethtool link mode name, example: `25000baseCR/Full`.
+    /// Only valid for query, ignored when applying.
+    pub supported: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The link modes advertised during auto-negotiation. Link modes not
+    /// included will not be advertised. When applying, every link mode should
+    /// be supported by the network device.
+    pub advertised: Option<Vec<String>>,
+}
+
+impl EthtoolLinkModesConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn sanitize(&mut self) {
+        for modes in [self.supported.as_mut(), self.advertised.as_mut()]
+            .into_iter()
+            .flatten()
+        {
+            modes.sort_unstable();
+            modes.dedup();
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+/// The forward error correction(FEC) mode.
+pub enum EthtoolFecMode {
+    /// Disable FEC.
+    /// Deserialize and serialize from/to `off`.
+    Off,
+    /// Reed-Solomon FEC.
+    /// Deserialize and serialize from/to `rs`.
+    Rs,
+    /// BASE-R(Fire Code) FEC.
+    /// Deserialize and serialize from/to `baser`.
+    Baser,
+    /// Low Latency Reed-Solomon FEC.
+    /// Deserialize and serialize from/to `llrs`.
+    Llrs,
+    /// Let the network device choose FEC mode based on link mode.
+    /// Deserialize and serialize from/to `auto`.
+    Auto,
+}
+
+impl std::fmt::Display for EthtoolFecMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Off => "off",
+                Self::Rs => "rs",
+                Self::Baser => "baser",
+                Self::Llrs => "llrs",
+                Self::Auto => "auto",
+            }
+        )
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+pub struct EthtoolFecConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The configured FEC mode.
+    pub mode: Option<EthtoolFecMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The FEC mode currently in use. Only valid for query, ignored when
+    /// applying.
+    pub active: Option<EthtoolFecMode>,
+}
+
+impl EthtoolFecConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(
+    Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Default, Copy,
+)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+pub struct EthtoolEeeConfig {
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_bool_or_string"
+    )]
+    /// Whether to enable Energy-Efficient Ethernet.
+    pub enabled: Option<bool>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_bool_or_string"
+    )]
+    /// Whether EEE is negotiated with link partner and in use.
+    /// Only valid for query, ignored when applying.
+    pub active: Option<bool>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_bool_or_string"
+    )]
+    /// Whether to enable transmit low power idle(LPI).
+    /// NetworkManager cannot persist this, hence in NetworkManager mode it
+    /// is applied to kernel directly and not persistent.
+    /// Deserialize and serialize from/to `tx-lpi-enabled`.
+    pub tx_lpi_enabled: Option<bool>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    /// The delay in microseconds before entering low power idle.
+    /// NetworkManager cannot persist this, hence in NetworkManager mode it
+    /// is applied to kernel directly and not persistent.
+    /// Deserialize and serialize from/to `tx-lpi-timer`.
+    pub tx_lpi_timer: Option<u32>,
+}
+
+impl EthtoolEeeConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+pub(crate) fn parse_hex_bytes(hex_str: &str) -> Option<Vec<u8>> {
+    let mut ret = Vec::new();
+    for byte_str in hex_str.split(':') {
+        if byte_str.len() != 2 {
+            return None;
+        }
+        ret.push(u8::from_str_radix(byte_str, 16).ok()?);
+    }
+    Some(ret)
+}
+
 fn parse_ethtool_feature<'de, D>(
     deserializer: D,
 ) -> Result<Option<EthtoolFeatureConfig>, D::Error>
@@ -499,5 +896,44 @@ impl MergedInterface {
         {
             ethtool_conf.apply_feature_alias();
         }
+        // Query only properties cannot be verified
+        if let Some(ethtool_conf) = self
+            .for_verify
+            .as_mut()
+            .map(|i| i.base_iface_mut())
+            .and_then(|b| b.ethtool.as_mut())
+        {
+            if let Some(link_modes) = ethtool_conf.link_modes.as_mut() {
+                link_modes.supported = None;
+            }
+            if let Some(fec) = ethtool_conf.fec.as_mut() {
+                fec.active = None;
+            }
+            if let Some(eee) = ethtool_conf.eee.as_mut() {
+                eee.active = None;
+            }
+        }
+        // Cannot verify `equal` directly, expand it to indirection table
+        // using the size of current indirection table.
+        let cur_indir_size = self
+            .current
+            .as_ref()
+            .and_then(|i| i.base_iface().ethtool.as_ref())
+            .and_then(|e| e.rss.as_ref())
+            .and_then(|r| r.indirection_table.as_ref())
+            .map(|t| t.len());
+        if let Some(rss_conf) = self
+            .for_verify
+            .as_mut()
+            .map(|i| i.base_iface_mut())
+            .and_then(|b| b.ethtool.as_mut())
+            .and_then(|e| e.rss.as_mut())
+        {
+            if let Some(table_size) = cur_indir_size {
+                rss_conf.expand_equal(table_size);
+            } else {
+                rss_conf.equal = None;
+            }
+        }
     }
 }
@@ -2,7 +2,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::{BaseInterface, InterfaceType, NmstateError};
+use crate::{BaseInterface, ErrorKind, InterfaceType, NmstateError};
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 #[serde(rename_all = "kebab-case")]
@@ -21,6 +21,22 @@ use crate::{BaseInterface, InterfaceType, NmstateError};
 ///       multicast-spec: 40
 ///       protocol: prp
 /// ```
+///
+/// The HSR RedBox with SAN (Singly Attached Node) connected via interlink
+/// port would be:
+/// ```yaml
+/// ---
+/// interfaces:
+///   - name: hsr0
+///     type: hsr
+///     state: up
+///     hsr:
+///       port1: eth1
+///       port2: eth2
+///       interlink: eth3
+///       multicast-spec: 40
+///       protocol: hsr
+/// ```
 pub struct HsrInterface {
     #[serde(flatten)]
     pub base: BaseInterface,
@@ -56,6 +72,7 @@ impl HsrInterface {
                     address.as_mut().make_ascii_uppercase();
                     log::warn!("The supervision-address is read-only, ignoring it on desired state.");
                 }
+                conf.validate(self.base.name.as_str())?;
             }
         }
         Ok(())
@@ -79,12 +96,72 @@ pub struct HsrConfig {
     pub multicast_spec: u8,
     /// Protocol to be used.
     pub protocol: HsrProtocol,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The interlink interface name connecting HSR RedBox to non-HSR
+    /// network. Only supported by HSR protocol. Setting to empty string
+    /// means no interlink port.
+    pub interlink: Option<String>,
 }
 
 impl HsrConfig {
     pub fn new() -> Self {
         Self::default()
     }
+
+    fn validate(&self, iface_name: &str) -> Result<(), NmstateError> {
+        if self.port1.is_empty() || self.port2.is_empty() {
+            let e = NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "HSR interface {iface_name} requires both port1 and \
+                    port2 defined"
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+        if self.port1 == self.port2 {
+            let e = NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "HSR interface {iface_name} cannot use the same \
+                    interface {} for port1 and port2",
+                    self.port1
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+        if let Some(interlink) =
+            self.interlink.as_deref().filter(|i| !i.is_empty())
+        {
+            if interlink == self.port1 || interlink == self.port2 {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "HSR interface {iface_name} cannot use port \
+                        {interlink} as both interlink and port1/port2"
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+            // Linux kernel only support RedBox for HSR protocol, PRP
+            // nodes are always doubly attached without interlink.
+            if self.protocol == HsrProtocol::Prp {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "HSR interface {iface_name} is using PRP protocol \
+                        which does not support interlink port"
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
@@ -161,6 +161,11 @@ impl Interfaces {
             .chain(self.user_ifaces.values_mut())
         {
             iface.base_iface_mut().hide_secrets();
+            if let Interface::Ethernet(eth_iface) = iface {
+                if let Some(eth_conf) = eth_iface.ethernet.as_mut() {
+                    eth_conf.hide_secrets();
+                }
+            }
             if let Interface::MacSec(macsec_iface) = iface {
                 if let Some(macsec_conf) = macsec_iface.macsec.as_mut() {
                     macsec_conf.hide_secrets();
@@ -172,6 +177,25 @@ impl Interfaces {
         }
     }
 
+    // Remove query-only status data like counters
+    pub(crate) fn remove_status_data(&mut self) {
+        for iface in self.kernel_ifaces.values_mut() {
+            iface.base_iface_mut().remove_status_data();
+            if let Interface::Ethernet(eth_iface) = iface {
+                if let Some(vfs) = eth_iface
+                    .ethernet
+                    .as_mut()
+                    .and_then(|e| e.sr_iov.as_mut())
+                    .and_then(|s| s.vfs.as_mut())
+                {
+                    for vf in vfs.iter_mut() {
+                        vf.stats = None;
+                    }
+                }
+            }
+        }
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = &Interface> {
         self.user_ifaces.values().chain(self.kernel_ifaces.values())
     }
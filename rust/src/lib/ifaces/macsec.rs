@@ -3,7 +3,8 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    BaseInterface, ErrorKind, InterfaceType, NetworkState, NmstateError,
+    BaseInterface, ErrorKind, Interface, InterfaceType, MergedInterface,
+    NetworkState, NmstateError,
 };
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
@@ -26,6 +27,35 @@ use crate::{
 ///       validation: strict
 ///       send-sci: true
 /// ```
+/// The example YAML of MACsec interface using static SAK(Secure Association
+/// Key) would be:
+/// ```yaml
+/// ---
+/// interfaces:
+///   - name: macsec0
+///     type: macsec
+///     state: up
+///     macsec:
+///       encrypt: true
+///       base-iface: eth1
+///       port: 1
+///       validation: strict
+///       send-sci: true
+///       static-sak:
+///         tx-sa:
+///           an: 0
+///           pn: 1
+///           key-id: 01000000000000000000000000000000
+///           key: 81a6bd5b5e0bbc4e63da2cfd1b9b3eda
+///         rx-sc:
+///           - address: 52:54:00:AA:BB:CC
+///             port: 1
+///             sa:
+///               - an: 0
+///                 pn: 1
+///                 key-id: 02000000000000000000000000000000
+///                 key: 3dd7a7e2b6c1c2b97c3a1e1e1d2f7f40
+/// ```
 pub struct MacSecInterface {
     #[serde(flatten)]
     pub base: BaseInterface,
@@ -52,9 +82,14 @@ impl MacSecInterface {
     }
 
     pub(crate) fn sanitize(
-        &self,
+        &mut self,
         is_desired: bool,
     ) -> Result<(), NmstateError> {
+        if let Some(static_sak) =
+            self.macsec.as_mut().and_then(|c| c.static_sak.as_mut())
+        {
+            static_sak.sanitize(is_desired)?;
+        }
         if is_desired {
             if let Some(conf) = &self.macsec {
                 if conf.mka_cak.is_none() ^ conf.mka_ckn.is_none() {
@@ -76,6 +111,18 @@ impl MacSecInterface {
                         return Err(e);
                     }
                 }
+                if conf.static_sak.is_some()
+                    && (conf.mka_cak.is_some() || conf.mka_ckn.is_some())
+                {
+                    let e = NmstateError::new(
+                        ErrorKind::InvalidArgument,
+                        "The static-sak cannot be used along with mka_cak \
+                        and mka_ckn"
+                            .to_string(),
+                    );
+                    log::error!("{}", e);
+                    return Err(e);
+                }
                 if let Some(mka_ckn) = &conf.mka_ckn {
                     if mka_ckn.len() > 64
                         || mka_ckn.len() < 2
@@ -127,6 +174,12 @@ pub struct MacSecConfig {
     pub send_sci: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub offload: Option<MacSecOffload>,
+    /// Static SAK(Secure Association Key) configuration for TX and RX secure
+    /// associations. Cannot be used along with `mka_cak` and `mka_ckn`.
+    /// Only supported in kernel mode.
+    /// Deserialize and serialize from/to `static-sak`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub static_sak: Option<MacSecStaticSakConfig>,
 }
 
 impl MacSecConfig {
@@ -139,6 +192,9 @@ impl MacSecConfig {
             self.mka_cak =
                 Some(NetworkState::PASSWORD_HID_BY_NMSTATE.to_string());
         }
+        if let Some(static_sak) = self.static_sak.as_mut() {
+            static_sak.hide_secrets();
+        }
     }
 }
 
@@ -156,6 +212,7 @@ impl std::fmt::Debug for MacSecConfig {
             .field("validation", &self.validation)
             .field("send_sci", &self.send_sci)
             .field("offload", &self.offload)
+            .field("static_sak", &self.static_sak)
             .finish()
     }
 }
@@ -204,3 +261,290 @@ pub enum MacSecOffload {
     Phy,
     Mac,
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+pub struct MacSecStaticSakConfig {
+    /// The secure association used for transmitting. It will be used as
+    /// encoding SA of the MACsec interface.
+    /// Deserialize and serialize from/to `tx-sa`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tx_sa: Option<MacSecSaConfig>,
+    /// The receive secure channels of peers. Setting to empty list will
+    /// remove all receive secure channels.
+    /// Deserialize and serialize from/to `rx-sc`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rx_sc: Option<Vec<MacSecRxScConfig>>,
+}
+
+impl MacSecStaticSakConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // * Validate and normalize secure associations
+    // * Convert peer MAC address to upper case
+    // * Sort RX SC by address and port, sort SA by association number
+    fn sanitize(&mut self, is_desired: bool) -> Result<(), NmstateError> {
+        if let Some(tx_sa) = self.tx_sa.as_mut() {
+            tx_sa.sanitize(is_desired)?;
+        }
+        if let Some(rx_scs) = self.rx_sc.as_mut() {
+            for rx_sc in rx_scs.iter_mut() {
+                rx_sc.address.make_ascii_uppercase();
+                if let Some(sas) = rx_sc.sa.as_mut() {
+                    for sa in sas.iter_mut() {
+                        sa.sanitize(is_desired)?;
+                    }
+                    sas.sort_unstable_by_key(|sa| sa.an);
+                    if is_desired && sas.windows(2).any(|w| w[0].an == w[1].an)
+                    {
+                        let e = NmstateError::new(
+                            ErrorKind::InvalidArgument,
+                            format!(
+                                "Duplicate MACsec association number in RX \
+                                SC {} port {}",
+                                rx_sc.address, rx_sc.port
+                            ),
+                        );
+                        log::error!("{}", e);
+                        return Err(e);
+                    }
+                }
+            }
+            rx_scs.sort_unstable_by(|a, b| {
+                (a.address.as_str(), a.port).cmp(&(b.address.as_str(), b.port))
+            });
+        }
+        Ok(())
+    }
+
+    pub(crate) fn hide_secrets(&mut self) {
+        if let Some(tx_sa) = self.tx_sa.as_mut() {
+            tx_sa.hide_secrets();
+        }
+        for sa in self
+            .rx_sc
+            .as_deref_mut()
+            .unwrap_or_default()
+            .iter_mut()
+            .filter_map(|sc| sc.sa.as_mut())
+            .flatten()
+        {
+            sa.hide_secrets();
+        }
+    }
+
+    // Packet number is increasing with traffic, hence cannot be verified.
+    // The kernel never reports the key.
+    pub(crate) fn remove_unverifiable(&mut self) {
+        if let Some(tx_sa) = self.tx_sa.as_mut() {
+            tx_sa.pn = None;
+            tx_sa.key = None;
+        }
+        for sa in self
+            .rx_sc
+            .as_deref_mut()
+            .unwrap_or_default()
+            .iter_mut()
+            .filter_map(|sc| sc.sa.as_mut())
+            .flatten()
+        {
+            sa.pn = None;
+            sa.key = None;
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+pub struct MacSecRxScConfig {
+    /// The MAC address of peer, used as address component of the SCI
+    /// (Secure Channel Identifier).
+    pub address: String,
+    /// The port component of the SCI of peer.
+    #[serde(deserialize_with = "crate::deserializer::u16_or_string")]
+    pub port: u16,
+    /// The secure associations of this receive secure channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sa: Option<Vec<MacSecSaConfig>>,
+}
+
+impl MacSecRxScConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+pub struct MacSecSaConfig {
+    /// The association number, between 0 and 3.
+    #[serde(deserialize_with = "crate::deserializer::u8_or_string")]
+    pub an: u8,
+    /// The initial packet number, must not be 0. When querying, it is the
+    /// next packet number. Default to 1 when creating new SA. Ignored in
+    /// verification.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    pub pn: Option<u32>,
+    /// The key identifier in hexadecimal string of up to 32 characters. Will
+    /// be padded by trailing zeros to 32 characters.
+    /// Deserialize and serialize from/to `key-id`.
+    pub key_id: String,
+    /// The secure association key in hexadecimal string of 32 characters.
+    /// Only GCM-AES-128 cipher suite is supported.
+    /// Kernel never reports the key, hence querying always shows
+    /// `<_password_hid_by_nmstate>`. When applying with
+    /// `<_password_hid_by_nmstate>`, the existing key will be preserved.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+}
+
+impl MacSecSaConfig {
+    pub(crate) const KEY_ID_LEN: usize = 16;
+    // Key length of GCM-AES-128, the default cipher suite of kernel
+    pub(crate) const KEY_LEN: usize = 16;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn sanitize(&mut self, is_desired: bool) -> Result<(), NmstateError> {
+        self.key_id.make_ascii_lowercase();
+        if self.key_id.len() < Self::KEY_ID_LEN * 2 {
+            self.key_id = format!("{:0<32}", self.key_id);
+        }
+        if !is_desired {
+            return Ok(());
+        }
+        if self.an > 3 {
+            let e = NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "Invalid MACsec association number {}, should be \
+                    between 0 and 3",
+                    self.an
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+        if self.pn == Some(0) {
+            let e = NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "Invalid packet number 0 for MACsec association number {}",
+                    self.an
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+        if parse_hex_str(&self.key_id).map(|k| k.len())
+            != Some(Self::KEY_ID_LEN)
+        {
+            let e = NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "Invalid MACsec key-id {} for association number {}, \
+                    should be hexadecimal string of up to 32 characters",
+                    self.key_id, self.an
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+        match self.key.as_mut() {
+            Some(key) => {
+                if key.as_str() == NetworkState::PASSWORD_HID_BY_NMSTATE {
+                    return Ok(());
+                }
+                key.make_ascii_lowercase();
+                if parse_hex_str(key).map(|k| k.len()) != Some(Self::KEY_LEN) {
+                    let e = NmstateError::new(
+                        ErrorKind::InvalidArgument,
+                        format!(
+                            "Invalid MACsec key for association number {}, \
+                            should be hexadecimal string of 32 characters",
+                            self.an
+                        ),
+                    );
+                    log::error!("{}", e);
+                    return Err(e);
+                }
+            }
+            None => {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "MACsec key is required for association number {}",
+                        self.an
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn hide_secrets(&mut self) {
+        if self.key.is_some() {
+            self.key = Some(NetworkState::PASSWORD_HID_BY_NMSTATE.to_string());
+        }
+    }
+}
+
+impl std::fmt::Debug for MacSecSaConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MacSecSaConfig")
+            .field("an", &self.an)
+            .field("pn", &self.pn)
+            .field("key_id", &self.key_id)
+            .field(
+                "key",
+                &self
+                    .key
+                    .as_ref()
+                    .map(|_| NetworkState::PASSWORD_HID_BY_NMSTATE),
+            )
+            .finish()
+    }
+}
+
+// Parse hexadecimal string without separator, e.g. `0a1b2c`
+pub(crate) fn parse_hex_str(hex_str: &str) -> Option<Vec<u8>> {
+    if hex_str.len() % 2 == 1 {
+        return None;
+    }
+    (0..hex_str.len())
+        .step_by(2)
+        .map(|i| {
+            hex_str
+                .get(i..i + 2)
+                .and_then(|b| u8::from_str_radix(b, 16).ok())
+        })
+        .collect()
+}
+
+impl MergedInterface {
+    pub(crate) fn post_inter_ifaces_process_macsec(&mut self) {
+        if let Some(Interface::MacSec(verify_iface)) = self.for_verify.as_mut()
+        {
+            if let Some(static_sak) = verify_iface
+                .macsec
+                .as_mut()
+                .and_then(|c| c.static_sak.as_mut())
+            {
+                static_sak.remove_unverifiable();
+            }
+        }
+    }
+}
@@ -37,12 +37,18 @@ pub use bridge_vlan::{
     BridgePortVlanRange,
 };
 pub use dummy::DummyInterface;
+#[cfg(feature = "query_apply")]
+pub(crate) use ethernet::parse_secureon_password;
 pub use ethernet::{
-    EthernetConfig, EthernetDuplex, EthernetInterface, VethConfig,
+    EthernetConfig, EthernetDuplex, EthernetInterface, EthernetWakeOnLanConfig,
+    EthernetWakeOnLanMode, VethConfig,
 };
+pub(crate) use ethtool::parse_hex_bytes;
 pub use ethtool::{
-    EthtoolCoalesceConfig, EthtoolConfig, EthtoolFeatureConfig,
-    EthtoolPauseConfig, EthtoolRingConfig,
+    EthtoolChannelsConfig, EthtoolCoalesceConfig, EthtoolConfig,
+    EthtoolEeeConfig, EthtoolFeatureConfig, EthtoolFecConfig, EthtoolFecMode,
+    EthtoolLinkModesConfig, EthtoolPauseConfig, EthtoolRingConfig,
+    EthtoolRssConfig, EthtoolRssHashFunction,
 };
 pub use hsr::{HsrConfig, HsrInterface, HsrProtocol};
 pub use infiniband::{InfiniBandConfig, InfiniBandInterface, InfiniBandMode};
@@ -60,15 +66,22 @@ pub use linux_bridge::{
 pub use loopback::LoopbackInterface;
 pub use mac_vlan::{MacVlanConfig, MacVlanInterface, MacVlanMode};
 pub use mac_vtap::{MacVtapConfig, MacVtapInterface, MacVtapMode};
+#[cfg(feature = "query_apply")]
+pub(crate) use macsec::parse_hex_str;
 pub use macsec::{
-    MacSecConfig, MacSecInterface, MacSecOffload, MacSecValidate,
+    MacSecConfig, MacSecInterface, MacSecOffload, MacSecRxScConfig,
+    MacSecSaConfig, MacSecStaticSakConfig, MacSecValidate,
 };
 pub use ovs::{
     OvsBridgeBondConfig, OvsBridgeBondMode, OvsBridgeBondPortConfig,
     OvsBridgeConfig, OvsBridgeInterface, OvsBridgeOptions, OvsBridgePortConfig,
     OvsBridgeStpOptions, OvsDpdkConfig, OvsInterface, OvsPatchConfig,
 };
-pub use sriov::{SrIovConfig, SrIovVfConfig};
+pub use sriov::{
+    SrIovConfig, SrIovEswitchConfig, SrIovEswitchEncapMode,
+    SrIovEswitchInlineMode, SrIovEswitchMode, SrIovVfConfig, SrIovVfLinkState,
+    SrIovVfStats,
+};
 pub use vlan::{
     VlanConfig, VlanInterface, VlanProtocol, VlanRegistrationProtocol,
 };
@@ -34,6 +34,9 @@ use crate::{
 ///         max-tx-rate: 0
 ///         vlan-id: 0
 ///         qos: 0
+///         vlan-proto: 802.1q
+///         link-state: auto
+///         query-rss-en: false
 ///       - id: 1
 ///         mac-address: 00:11:22:33:00:ef
 ///         spoof-check: true
@@ -42,6 +45,27 @@ use crate::{
 ///         max-tx-rate: 0
 ///         vlan-id: 0
 ///         qos: 0
+///         vlan-proto: 802.1q
+///         link-state: auto
+///         query-rss-en: false
+/// ```
+/// The example yaml output of SR-IOV PF in switchdev mode would be:
+/// ```yml
+/// interfaces:
+/// - name: ens1f1
+///   type: ethernet
+///   state: up
+///   ethernet:
+///     sr-iov:
+///       total-vfs: 1
+///       eswitch:
+///         mode: switchdev
+///         inline-mode: none
+///         encap-mode: basic
+///       vfs:
+///       - id: 0
+///         iface-name: ens1f1v0
+///         representor: ens1f1_0
 /// ```
 pub struct SrIovConfig {
     #[serde(
@@ -68,10 +92,14 @@ pub struct SrIovConfig {
     ///   to defaults.
     /// * If not empty, missing [SrIovVfConfig] will use current configuration.
     pub vfs: Option<Vec<SrIovVfConfig>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The embedded switch configuration of PF.
+    pub eswitch: Option<SrIovEswitchConfig>,
 }
 
 impl SrIovConfig {
     pub(crate) const VF_NAMING_PREFIX: &'static str = "sriov:";
+    pub(crate) const VF_REPRESENTOR_NAMING_PREFIX: &'static str = "sriov-rep:";
     pub(crate) const VF_NAMING_SEPERATOR: char = ':';
 
     pub fn new() -> Self {
@@ -80,9 +108,17 @@ impl SrIovConfig {
 
     // * Convert VF MAC address to upper case
     // * Sort by VF ID
+    // * Remove VF statistics
     pub(crate) fn sanitize(&mut self) -> Result<(), NmstateError> {
         if let Some(vfs) = self.vfs.as_mut() {
             for vf in vfs.iter_mut() {
+                if vf.stats.is_some() {
+                    log::info!(
+                        "Ignoring SR-IOV VF {} stats as it is query only",
+                        vf.id
+                    );
+                    vf.stats = None;
+                }
                 if let Some(address) = vf.mac_address.as_mut() {
                     address.make_ascii_uppercase()
                 }
@@ -157,6 +193,12 @@ pub struct SrIovVfConfig {
     /// when applying network state.
     #[serde(default, skip_serializing_if = "String::is_empty")]
     pub iface_name: String,
+    /// Interface name of the representor of this VF when PF is in
+    /// `switchdev` eswitch mode, only for querying, will be ignored when
+    /// applying network state. The representor could be referred as
+    /// `sriov-rep:<pf_name>:<vf_id>` in interface name or port name.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub representor: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     /// Deserialize and serialize from/to `mac-address`.
     pub mac_address: Option<String>,
@@ -203,6 +245,27 @@ pub struct SrIovVfConfig {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub vlan_proto: Option<VlanProtocol>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Administrative link state of VF.
+    /// NetworkManager cannot store this, hence it is applied to kernel
+    /// directly and not persistent in NetworkManager mode.
+    /// Deserialize and serialize from/to `link-state`.
+    pub link_state: Option<SrIovVfLinkState>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_bool_or_string"
+    )]
+    /// Allow VF to query the RSS configuration of PF.
+    /// NetworkManager cannot store this, hence it is applied to kernel
+    /// directly and not persistent in NetworkManager mode.
+    /// Deserialize and serialize from/to `query-rss-en`.
+    pub query_rss_en: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Traffic statistics of VF, only for querying when
+    /// [crate::NetworkState::set_include_status_data()] set to true, will be
+    /// ignored when applying network state.
+    pub stats: Option<SrIovVfStats>,
 }
 
 impl SrIovVfConfig {
@@ -211,6 +274,132 @@ impl SrIovVfConfig {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum SrIovVfLinkState {
+    /// Follow the link state of PF.
+    /// Deserialize and serialize from/to `auto`.
+    Auto,
+    /// Force VF link up regardless the link state of PF.
+    /// Deserialize and serialize from/to `enable`.
+    Enable,
+    /// Force VF link down.
+    /// Deserialize and serialize from/to `disable`.
+    Disable,
+}
+
+impl std::fmt::Display for SrIovVfLinkState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Auto => "auto",
+                Self::Enable => "enable",
+                Self::Disable => "disable",
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+pub struct SrIovVfStats {
+    /// Deserialize and serialize from/to `rx-packets`.
+    pub rx_packets: u64,
+    /// Deserialize and serialize from/to `tx-packets`.
+    pub tx_packets: u64,
+    /// Deserialize and serialize from/to `rx-bytes`.
+    pub rx_bytes: u64,
+    /// Deserialize and serialize from/to `tx-bytes`.
+    pub tx_bytes: u64,
+    pub broadcast: u64,
+    pub multicast: u64,
+    /// Deserialize and serialize from/to `rx-dropped`.
+    pub rx_dropped: u64,
+    /// Deserialize and serialize from/to `tx-dropped`.
+    pub tx_dropped: u64,
+}
+
+impl SrIovVfStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum SrIovEswitchMode {
+    /// Deserialize and serialize from/to `legacy`.
+    Legacy,
+    /// Deserialize and serialize from/to `switchdev`.
+    Switchdev,
+}
+
+impl std::fmt::Display for SrIovEswitchMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Legacy => "legacy",
+                Self::Switchdev => "switchdev",
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+/// The minimum packet header required by eswitch to match packets sent by VF.
+pub enum SrIovEswitchInlineMode {
+    /// Deserialize and serialize from/to `none`.
+    None,
+    /// Deserialize and serialize from/to `link`.
+    Link,
+    /// Deserialize and serialize from/to `network`.
+    Network,
+    /// Deserialize and serialize from/to `transport`.
+    Transport,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+/// Whether eswitch offloads tunnel encapsulation and decapsulation.
+pub enum SrIovEswitchEncapMode {
+    /// Deserialize and serialize from/to `none`.
+    None,
+    /// Deserialize and serialize from/to `basic`.
+    Basic,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+pub struct SrIovEswitchConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The eswitch mode. The `switchdev` mode is required for hardware
+    /// offload of Open vSwitch.
+    pub mode: Option<SrIovEswitchMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Deserialize and serialize from/to `inline-mode`.
+    pub inline_mode: Option<SrIovEswitchInlineMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Deserialize and serialize from/to `encap-mode`.
+    pub encap_mode: Option<SrIovEswitchEncapMode>,
+}
+
+impl SrIovEswitchConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
 impl Interfaces {
     pub(crate) fn resolve_sriov_reference(
         &mut self,
@@ -227,10 +416,11 @@ impl Interfaces {
     ) -> Result<(), NmstateError> {
         let mut changed_iface_names: Vec<String> = Vec::new();
         for iface in self.kernel_ifaces.values_mut() {
-            if let Some((pf_name, vf_id)) = parse_sriov_vf_naming(iface.name())?
+            if let Some((naming, pf_name, vf_id)) =
+                parse_sriov_vf_naming(iface.name())?
             {
                 if let Some(vf_iface_name) =
-                    get_sriov_vf_iface_name(current, pf_name, vf_id)
+                    get_sriov_vf_iface_name(current, naming, pf_name, vf_id)
                 {
                     changed_iface_names.push(iface.name().to_string());
                     log::info!(
@@ -292,9 +482,11 @@ impl Interfaces {
                 None => continue,
             };
             for port in ports {
-                if let Some((pf_name, vf_id)) = parse_sriov_vf_naming(port)? {
+                if let Some((naming, pf_name, vf_id)) =
+                    parse_sriov_vf_naming(port)?
+                {
                     if let Some(vf_iface_name) =
-                        get_sriov_vf_iface_name(current, pf_name, vf_id)
+                        get_sriov_vf_iface_name(current, naming, pf_name, vf_id)
                     {
                         log::info!(
                             "SR-IOV VF {} resolved to interface name {}",
@@ -329,45 +521,68 @@ impl Interfaces {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SrIovVfNaming {
+    /// `sriov:<pf_name>:<vf_id>` referring to the VF interface
+    Vf,
+    /// `sriov-rep:<pf_name>:<vf_id>` referring to the VF representor
+    Representor,
+}
+
+impl SrIovVfNaming {
+    fn prefix(&self) -> &'static str {
+        match self {
+            Self::Vf => SrIovConfig::VF_NAMING_PREFIX,
+            Self::Representor => SrIovConfig::VF_REPRESENTOR_NAMING_PREFIX,
+        }
+    }
+}
+
 fn parse_sriov_vf_naming(
     iface_name: &str,
-) -> Result<Option<(&str, u32)>, NmstateError> {
-    if iface_name.starts_with(SrIovConfig::VF_NAMING_PREFIX) {
-        let names: Vec<&str> =
-            iface_name.split(SrIovConfig::VF_NAMING_SEPERATOR).collect();
-        if names.len() == 3 {
-            match names[2].parse::<u32>() {
-                Ok(vf_id) => Ok(Some((names[1], vf_id))),
-                Err(e) => {
-                    let e = NmstateError::new(
-                        ErrorKind::InvalidArgument,
-                        format!(
-                            "Invalid SR-IOV VF ID in {iface_name}, correct format \
-                            is 'sriov:<pf_name>:<vf_id>', error: {e}"
-                        ),
-                    );
-                    log::error!("{}", e);
-                    Err(e)
-                }
+) -> Result<Option<(SrIovVfNaming, &str, u32)>, NmstateError> {
+    let naming = if iface_name.starts_with(SrIovConfig::VF_NAMING_PREFIX) {
+        SrIovVfNaming::Vf
+    } else if iface_name.starts_with(SrIovConfig::VF_REPRESENTOR_NAMING_PREFIX)
+    {
+        SrIovVfNaming::Representor
+    } else {
+        return Ok(None);
+    };
+    let prefix = naming.prefix();
+    let names: Vec<&str> =
+        iface_name.split(SrIovConfig::VF_NAMING_SEPERATOR).collect();
+    if names.len() == 3 {
+        match names[2].parse::<u32>() {
+            Ok(vf_id) => Ok(Some((naming, names[1], vf_id))),
+            Err(e) => {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "Invalid SR-IOV VF ID in {iface_name}, correct format \
+                        is '{prefix}<pf_name>:<vf_id>', error: {e}"
+                    ),
+                );
+                log::error!("{}", e);
+                Err(e)
             }
-        } else {
-            let e = NmstateError::new(
-                ErrorKind::InvalidArgument,
-                format!(
-                    "Invalid SR-IOV VF name {iface_name}, correct format is \
-                    'sriov:<pf_name>:<vf_id>'",
-                ),
-            );
-            log::error!("{}", e);
-            Err(e)
         }
     } else {
-        Ok(None)
+        let e = NmstateError::new(
+            ErrorKind::InvalidArgument,
+            format!(
+                "Invalid SR-IOV VF name {iface_name}, correct format is \
+                '{prefix}<pf_name>:<vf_id>'",
+            ),
+        );
+        log::error!("{}", e);
+        Err(e)
     }
 }
 
 fn get_sriov_vf_iface_name(
     current: &Interfaces,
+    naming: SrIovVfNaming,
     pf_name: &str,
     vf_id: u32,
 ) -> Option<String> {
@@ -382,8 +597,12 @@ fn get_sriov_vf_iface_name(
         {
             for vf in vfs {
                 if vf.id == vf_id {
-                    if !vf.iface_name.is_empty() {
-                        return Some(vf.iface_name.clone());
+                    let name = match naming {
+                        SrIovVfNaming::Vf => &vf.iface_name,
+                        SrIovVfNaming::Representor => &vf.representor,
+                    };
+                    if !name.is_empty() {
+                        return Some(name.clone());
                     }
                     break;
                 }
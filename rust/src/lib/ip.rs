@@ -7,8 +7,9 @@ use std::str::FromStr;
 use serde::{Deserialize, Deserializer, Serialize};
 
 use crate::{
-    BaseInterface, DnsClientState, ErrorKind, MergedInterface,
-    MptcpAddressFlag, NmstateError, RouteRuleEntry,
+    BaseInterface, DnsClientState, ErrorKind, Ipv4Sysctl, Ipv6Sysctl,
+    Ipv6TempAddr, MergedInterface, MptcpAddressFlag, NmstateError,
+    RouteRuleEntry,
 };
 
 const AF_INET: u8 = 2;
@@ -16,6 +17,7 @@ const AF_INET6: u8 = 10;
 const IPV4_ADDR_LEN: usize = 32;
 const IPV6_ADDR_LEN: usize = 128;
 const FOREVER: &str = "forever";
+const IP_TIMEOUT_MAX: u32 = i32::MAX as u32;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[non_exhaustive]
@@ -102,6 +104,73 @@ struct InterfaceIp {
         rename = "dhcp-custom-hostname"
     )]
     pub dhcp_custom_hostname: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "dhcp-vendor-class-identifier"
+    )]
+    pub dhcp_vendor_class_identifier: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "dhcp-user-class"
+    )]
+    pub dhcp_user_class: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "dhcp-iaid")]
+    pub dhcp_iaid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "dhcp-fqdn")]
+    pub dhcp_fqdn: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "dhcp-reject-servers"
+    )]
+    pub dhcp_reject_servers: Option<Vec<String>>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "dhcp-request-options"
+    )]
+    pub dhcp_request_options: Option<Vec<u8>>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "dhcp-send-release",
+        default,
+        deserialize_with = "crate::deserializer::option_bool_or_string"
+    )]
+    pub dhcp_send_release: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ip6-privacy")]
+    pub ip6_privacy: Option<Ipv6TempAddr>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "dad-transmits",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    pub dad_transmits: Option<u32>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "ra-timeout",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    pub ra_timeout: Option<u32>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "dhcp-timeout",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    pub dhcp_timeout: Option<u32>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "mtu-from-ra",
+        default,
+        deserialize_with = "crate::deserializer::option_bool_or_string"
+    )]
+    pub mtu_from_ra: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "dhcp-lease")]
+    pub dhcp_lease: Option<DhcpLease>,
+    // Deserialized by InterfaceIpv4 and InterfaceIpv6 as they are holding
+    // different sysctl types.
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub sysctl: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Default, Serialize)]
@@ -190,8 +259,45 @@ pub struct InterfaceIpv4 {
     /// If not defined, current non-dynamic hostname will be used.
     /// Deserialize from `dhcp-custom-hostname`
     pub dhcp_custom_hostname: Option<String>,
+    /// Vendor class identifier sent in DHCP option 60.
+    /// Serialize and deserialize to/from `dhcp-vendor-class-identifier`.
+    pub dhcp_vendor_class_identifier: Option<String>,
+    /// User class identifiers sent in DHCP option 77 defined in RFC 3004.
+    /// Not supported by NetworkManager backend yet.
+    /// Serialize and deserialize to/from `dhcp-user-class`.
+    pub dhcp_user_class: Option<Vec<String>>,
+    /// Identity Association Identifier(IAID) used by DHCP client. Could be
+    /// `mac`, `perm-mac`, `ifname`, `stable` or a 32 bits integer.
+    /// Serialize and deserialize to/from `dhcp-iaid`.
+    pub dhcp_iaid: Option<String>,
+    /// FQDN sent in `Fully Qualified Domain Name (FQDN)` option(81) defined
+    /// in RFC 4702. Should not conflict with `dhcp-custom-hostname`.
+    /// Serialize and deserialize to/from `dhcp-fqdn`.
+    pub dhcp_fqdn: Option<String>,
+    /// Timeout in seconds to wait for DHCP lease. The maximum value
+    /// 2147483647 means wait forever, which is also the default of nmstate.
+    /// Serialize and deserialize to/from `dhcp-timeout`.
+    pub dhcp_timeout: Option<u32>,
+    /// IP addresses or networks(e.g. `192.0.2.0/24`) of DHCP servers to
+    /// reject lease from.
+    /// Serialize and deserialize to/from `dhcp-reject-servers`.
+    pub dhcp_reject_servers: Option<Vec<String>>,
+    /// Extra DHCP option codes to request from DHCP server.
+    /// Not supported by NetworkManager backend yet.
+    /// Serialize and deserialize to/from `dhcp-request-options`.
+    pub dhcp_request_options: Option<Vec<u8>>,
+    /// DHCPv4 lease retrieved from DHCP client. Query only, ignored when
+    /// applying.
+    /// Only shown when `include_status_data` is enabled.
+    /// Serialize to `dhcp-lease`.
+    pub dhcp_lease: Option<DhcpLease>,
+    /// Per-interface IPv4 kernel parameters.
+    pub sysctl: Option<Ipv4Sysctl>,
     pub(crate) dns: Option<DnsClientState>,
     pub(crate) rules: Option<Vec<RouteRuleEntry>>,
+    // Kernel default values of `sysctl`, used to resolve the effective
+    // values as query only shows non-default ones.
+    pub(crate) sysctl_default: Option<Ipv4Sysctl>,
 }
 
 impl InterfaceIpv4 {
@@ -210,6 +316,10 @@ impl InterfaceIpv4 {
             && !self.addresses.as_deref().unwrap_or_default().is_empty()
     }
 
+    pub(crate) fn remove_status_data(&mut self) {
+        self.dhcp_lease = None;
+    }
+
     pub(crate) fn merge_ip(&mut self, current: &Self) {
         if !self.enabled_defined {
             self.enabled = current.enabled;
@@ -261,6 +371,7 @@ impl InterfaceIpv4 {
 
         // The rules is `pub(crate)`, it will not merged by `merge_json_value()`
         self.rules.clone_from(&current.rules);
+        self.sysctl_default.clone_from(&current.sysctl_default);
 
         self.sanitize(false).ok();
     }
@@ -272,6 +383,7 @@ impl InterfaceIpv4 {
     // * Remove auto IP address.
     // * Set DHCP options to None if DHCP is false
     // * Remove mptcp_flags is they are for query only
+    // * Remove dhcp_lease as it is for query only
     pub(crate) fn sanitize(
         &mut self,
         is_desired: bool,
@@ -334,6 +446,9 @@ impl InterfaceIpv4 {
                 a.valid_life_time = None;
                 a.preferred_life_time = None
             });
+            for addr in addrs.iter_mut() {
+                addr.sanitize_peer_and_flags(is_desired)?;
+            }
         }
 
         if !self.enabled {
@@ -356,6 +471,13 @@ impl InterfaceIpv4 {
             self.dhcp_client_id = None;
             self.dhcp_send_hostname = None;
             self.dhcp_custom_hostname = None;
+            self.dhcp_vendor_class_identifier = None;
+            self.dhcp_user_class = None;
+            self.dhcp_iaid = None;
+            self.dhcp_fqdn = None;
+            self.dhcp_timeout = None;
+            self.dhcp_reject_servers = None;
+            self.dhcp_request_options = None;
         }
         if self.dhcp_send_hostname == Some(false) {
             if is_desired {
@@ -370,14 +492,76 @@ impl InterfaceIpv4 {
                         );
                     }
                 }
+                if let Some(fqdn) = self.dhcp_fqdn.as_deref() {
+                    if !fqdn.is_empty() {
+                        log::warn!(
+                            "Ignoring `dhcp-fqdn: {fqdn}` as \
+                            `dhcp-send-hostname` is disabled"
+                        );
+                    }
+                }
             }
             self.dhcp_custom_hostname = None;
+            self.dhcp_fqdn = None;
         }
         if let Some(addrs) = self.addresses.as_mut() {
             for addr in addrs.iter_mut() {
                 addr.mptcp_flags = None;
             }
         }
+        if is_desired && self.dhcp_lease.is_some() {
+            log::info!("Ignoring `dhcp-lease` as it is query only");
+        }
+        self.remove_status_data();
+        if is_desired {
+            if let (Some(custom_hostname), Some(fqdn)) = (
+                self.dhcp_custom_hostname.as_deref(),
+                self.dhcp_fqdn.as_deref(),
+            ) {
+                if !custom_hostname.is_empty()
+                    && !fqdn.is_empty()
+                    && custom_hostname != fqdn
+                {
+                    let e = NmstateError::new(
+                        ErrorKind::InvalidArgument,
+                        format!(
+                            "The `dhcp-custom-hostname: {custom_hostname}` \
+                            is conflicting with `dhcp-fqdn: {fqdn}`"
+                        ),
+                    );
+                    log::error!("{}", e);
+                    return Err(e);
+                }
+            }
+            validate_ip_timeout("dhcp-timeout", self.dhcp_timeout)?;
+        }
+        if let Some(iaid) = self.dhcp_iaid.as_deref() {
+            if is_desired {
+                validate_dhcp_iaid(iaid)?;
+            }
+        }
+        if let Some(servers) = self.dhcp_reject_servers.as_mut() {
+            for server in servers.iter_mut() {
+                let ip_net = sanitize_ip_network(server)?;
+                if is_ipv6_addr(&ip_net) {
+                    let e = NmstateError::new(
+                        ErrorKind::InvalidArgument,
+                        format!(
+                            "Got IPv6 network {server} in \
+                            dhcp-reject-servers of ipv4 config section"
+                        ),
+                    );
+                    log::error!("{}", e);
+                    return Err(e);
+                }
+                *server = ip_net;
+            }
+        }
+        if let Some(sysctl) = self.sysctl.as_ref() {
+            if is_desired {
+                sysctl.sanitize()?;
+            }
+        }
         Ok(())
     }
 }
@@ -387,7 +571,17 @@ impl<'de> Deserialize<'de> for InterfaceIpv4 {
     where
         D: Deserializer<'de>,
     {
-        let v = serde_json::Value::deserialize(deserializer)?;
+        let mut v = serde_json::Value::deserialize(deserializer)?;
+
+        let sysctl: Option<Ipv4Sysctl> =
+            match v.as_object_mut().and_then(|v_map| v_map.remove("sysctl")) {
+                Some(sysctl_value) => {
+                    Some(serde_json::from_value(sysctl_value).map_err(|e| {
+                        serde::de::Error::custom(format!("sysctl: {e}"))
+                    })?)
+                }
+                None => None,
+            };
 
         if let Some(v_map) = v.as_object() {
             if v_map.contains_key("autoconf") {
@@ -400,6 +594,19 @@ impl<'de> Deserialize<'de> for InterfaceIpv4 {
                     "dhcp-duid is not allowed for IPv4",
                 ));
             }
+            for key in [
+                "ip6-privacy",
+                "dad-transmits",
+                "ra-timeout",
+                "mtu-from-ra",
+                "dhcp-send-release",
+            ] {
+                if v_map.contains_key(key) {
+                    return Err(serde::de::Error::custom(format!(
+                        "{key} is not allowed for IPv4"
+                    )));
+                }
+            }
         }
 
         let ip: InterfaceIp = match serde_json::from_value(v) {
@@ -408,7 +615,8 @@ impl<'de> Deserialize<'de> for InterfaceIpv4 {
                 return Err(serde::de::Error::custom(format!("{e}")));
             }
         };
-        let ret = Self::from(ip);
+        let mut ret = Self::from(ip);
+        ret.sysctl = sysctl;
         Ok(ret)
     }
 }
@@ -429,6 +637,14 @@ impl From<InterfaceIp> for InterfaceIpv4 {
             auto_route_metric: ip.auto_route_metric,
             dhcp_send_hostname: ip.dhcp_send_hostname,
             dhcp_custom_hostname: ip.dhcp_custom_hostname,
+            dhcp_vendor_class_identifier: ip.dhcp_vendor_class_identifier,
+            dhcp_user_class: ip.dhcp_user_class,
+            dhcp_iaid: ip.dhcp_iaid,
+            dhcp_fqdn: ip.dhcp_fqdn,
+            dhcp_timeout: ip.dhcp_timeout,
+            dhcp_reject_servers: ip.dhcp_reject_servers,
+            dhcp_request_options: ip.dhcp_request_options,
+            dhcp_lease: ip.dhcp_lease,
             ..Default::default()
         }
     }
@@ -454,6 +670,19 @@ impl From<InterfaceIpv4> for InterfaceIp {
             auto_route_metric: ip.auto_route_metric,
             dhcp_send_hostname: ip.dhcp_send_hostname,
             dhcp_custom_hostname: ip.dhcp_custom_hostname,
+            dhcp_vendor_class_identifier: ip.dhcp_vendor_class_identifier,
+            dhcp_user_class: ip.dhcp_user_class,
+            dhcp_iaid: ip.dhcp_iaid,
+            dhcp_fqdn: ip.dhcp_fqdn,
+            dhcp_timeout: ip.dhcp_timeout,
+            dhcp_reject_servers: ip.dhcp_reject_servers,
+            dhcp_request_options: ip.dhcp_request_options,
+            dhcp_lease: ip.dhcp_lease,
+            sysctl: ip
+                .sysctl
+                .as_ref()
+                .filter(|s| !s.is_empty())
+                .and_then(|s| serde_json::to_value(s).ok()),
             ..Default::default()
         }
     }
@@ -548,9 +777,50 @@ pub struct InterfaceIpv6 {
     /// If not defined, current non-dynamic hostname will be used.
     /// Deserialize from `dhcp-custom-hostname`
     pub dhcp_custom_hostname: Option<String>,
+    /// Identity Association Identifier(IAID) used by DHCPv6 client. Could be
+    /// `mac`, `perm-mac`, `ifname`, `stable` or a 32 bits integer.
+    /// If not defined, `mac` will be used.
+    /// Serialize and deserialize to/from `dhcp-iaid`.
+    pub dhcp_iaid: Option<String>,
+    /// Whether to send DHCPv6 RELEASE message when deactivating.
+    /// Serialize and deserialize to/from `dhcp-send-release`.
+    pub dhcp_send_release: Option<bool>,
+    /// IPv6 privacy extensions(RFC 8981) for temporary addresses.
+    /// Should not conflict with `use-tempaddr` of [Ipv6Sysctl].
+    /// Serialize and deserialize to/from `ip6-privacy`.
+    pub ip6_privacy: Option<Ipv6TempAddr>,
+    /// Number of Duplicate Address Detection probes to send. Setting to 0
+    /// disables DAD.
+    /// Serialize and deserialize to/from `dad-transmits`.
+    pub dad_transmits: Option<u32>,
+    /// Timeout in seconds to wait for IPv6 router advertisement.
+    /// Only available for autoconf enabled interface. The maximum value
+    /// 2147483647 means wait forever, which is also the default of nmstate.
+    /// Ignored in kernel only mode.
+    /// Serialize and deserialize to/from `ra-timeout`.
+    pub ra_timeout: Option<u32>,
+    /// Timeout in seconds to wait for DHCPv6 lease.
+    /// Only available for DHCPv6 enabled interface. The maximum value
+    /// 2147483647 means wait forever, which is also the default of nmstate.
+    /// Ignored in kernel only mode.
+    /// Serialize and deserialize to/from `dhcp-timeout`.
+    pub dhcp_timeout: Option<u32>,
+    /// Whether to apply MTU from IPv6 router advertisement.
+    /// Serialize and deserialize to/from `mtu-from-ra`.
+    pub mtu_from_ra: Option<bool>,
+    /// DHCPv6 lease retrieved from DHCP client. Query only, ignored when
+    /// applying.
+    /// Only shown when `include_status_data` is enabled.
+    /// Serialize to `dhcp-lease`.
+    pub dhcp_lease: Option<DhcpLease>,
+    /// Per-interface IPv6 kernel parameters.
+    pub sysctl: Option<Ipv6Sysctl>,
 
     pub(crate) dns: Option<DnsClientState>,
     pub(crate) rules: Option<Vec<RouteRuleEntry>>,
+    // Kernel default values of `sysctl`, used to resolve the effective
+    // values as query only shows non-default ones.
+    pub(crate) sysctl_default: Option<Ipv6Sysctl>,
 }
 
 impl InterfaceIpv6 {
@@ -569,11 +839,16 @@ impl InterfaceIpv6 {
             && !self.addresses.as_deref().unwrap_or_default().is_empty()
     }
 
+    pub(crate) fn remove_status_data(&mut self) {
+        self.dhcp_lease = None;
+    }
+
     // * Set auto_dns, auto_gateway and auto_routes to true if DHCP enabled and
     //   those options is None
     // * Disable DHCP and remove address if enabled: false
     // * Set DHCP options to None if DHCP is false
     // * Remove `mptcp_flags` as they are for query only
+    // * Remove `dhcp_lease` as it is for query only
     pub(crate) fn sanitize(
         &mut self,
         is_desired: bool,
@@ -611,6 +886,9 @@ impl InterfaceIpv6 {
                 a.valid_life_time = None;
                 a.preferred_life_time = None
             });
+            for addr in addrs.iter_mut() {
+                addr.sanitize_peer_and_flags(is_desired)?;
+            }
         }
 
         if self.is_auto() {
@@ -661,6 +939,9 @@ impl InterfaceIpv6 {
             self.dhcp = None;
             self.autoconf = None;
             self.addresses = None;
+            self.ip6_privacy = None;
+            self.dad_transmits = None;
+            self.mtu_from_ra = None;
         }
 
         if !self.is_auto() {
@@ -672,11 +953,49 @@ impl InterfaceIpv6 {
             self.dhcp_send_hostname = None;
             self.dhcp_custom_hostname = None;
         }
+        if self.autoconf != Some(true) {
+            self.ra_timeout = None;
+        }
+        if self.dhcp != Some(true) {
+            self.dhcp_timeout = None;
+            self.dhcp_iaid = None;
+            self.dhcp_send_release = None;
+        }
+        if let Some(iaid) = self.dhcp_iaid.as_deref() {
+            if is_desired {
+                validate_dhcp_iaid(iaid)?;
+            }
+        }
+        if is_desired {
+            validate_ip_timeout("ra-timeout", self.ra_timeout)?;
+            validate_ip_timeout("dhcp-timeout", self.dhcp_timeout)?;
+            if let (Some(privacy), Some(use_tempaddr)) = (
+                self.ip6_privacy,
+                self.sysctl.as_ref().and_then(|s| s.use_tempaddr),
+            ) {
+                if privacy != use_tempaddr {
+                    let e = NmstateError::new(
+                        ErrorKind::InvalidArgument,
+                        format!(
+                            "Desired IPv6 ip6-privacy {privacy} is \
+                            conflicting with sysctl use-tempaddr \
+                            {use_tempaddr}"
+                        ),
+                    );
+                    log::error!("{}", e);
+                    return Err(e);
+                }
+            }
+        }
         if let Some(addrs) = self.addresses.as_mut() {
             for addr in addrs.iter_mut() {
                 addr.mptcp_flags = None;
             }
         }
+        if is_desired && self.dhcp_lease.is_some() {
+            log::info!("Ignoring `dhcp-lease` as it is query only");
+        }
+        self.remove_status_data();
         if let Some(token) = self.token.as_mut() {
             if is_desired
                 && self.autoconf == Some(false)
@@ -710,6 +1029,11 @@ impl InterfaceIpv6 {
             }
             self.dhcp_custom_hostname = None;
         }
+        if let Some(sysctl) = self.sysctl.as_ref() {
+            if is_desired {
+                sysctl.sanitize()?;
+            }
+        }
         Ok(())
     }
 
@@ -739,6 +1063,7 @@ impl InterfaceIpv6 {
 
         // The rules is `pub(crate)`, it will not merged by `merge_json_value()`
         self.rules.clone_from(&current.rules);
+        self.sysctl_default.clone_from(&current.sysctl_default);
 
         self.sanitize(false).ok();
     }
@@ -779,7 +1104,17 @@ impl<'de> Deserialize<'de> for InterfaceIpv6 {
     where
         D: Deserializer<'de>,
     {
-        let v = serde_json::Value::deserialize(deserializer)?;
+        let mut v = serde_json::Value::deserialize(deserializer)?;
+
+        let sysctl: Option<Ipv6Sysctl> =
+            match v.as_object_mut().and_then(|v_map| v_map.remove("sysctl")) {
+                Some(sysctl_value) => {
+                    Some(serde_json::from_value(sysctl_value).map_err(|e| {
+                        serde::de::Error::custom(format!("sysctl: {e}"))
+                    })?)
+                }
+                None => None,
+            };
 
         if let Some(v_map) = v.as_object() {
             if v_map.contains_key("dhcp_client_id") {
@@ -787,6 +1122,19 @@ impl<'de> Deserialize<'de> for InterfaceIpv6 {
                     "dhcp-client-id is not allowed for IPv6",
                 ));
             }
+            for key in [
+                "dhcp-vendor-class-identifier",
+                "dhcp-user-class",
+                "dhcp-fqdn",
+                "dhcp-reject-servers",
+                "dhcp-request-options",
+            ] {
+                if v_map.contains_key(key) {
+                    return Err(serde::de::Error::custom(format!(
+                        "{key} is not allowed for IPv6"
+                    )));
+                }
+            }
         }
         let ip: InterfaceIp = match serde_json::from_value(v) {
             Ok(i) => i,
@@ -794,7 +1142,8 @@ impl<'de> Deserialize<'de> for InterfaceIpv6 {
                 return Err(serde::de::Error::custom(format!("{e}")));
             }
         };
-        let ret = Self::from(ip);
+        let mut ret = Self::from(ip);
+        ret.sysctl = sysctl;
         Ok(ret)
     }
 }
@@ -818,6 +1167,14 @@ impl From<InterfaceIp> for InterfaceIpv6 {
             token: ip.token,
             dhcp_send_hostname: ip.dhcp_send_hostname,
             dhcp_custom_hostname: ip.dhcp_custom_hostname,
+            dhcp_iaid: ip.dhcp_iaid,
+            dhcp_send_release: ip.dhcp_send_release,
+            ip6_privacy: ip.ip6_privacy,
+            dad_transmits: ip.dad_transmits,
+            ra_timeout: ip.ra_timeout,
+            dhcp_timeout: ip.dhcp_timeout,
+            mtu_from_ra: ip.mtu_from_ra,
+            dhcp_lease: ip.dhcp_lease,
             ..Default::default()
         }
     }
@@ -846,6 +1203,19 @@ impl From<InterfaceIpv6> for InterfaceIp {
             token: ip.token,
             dhcp_send_hostname: ip.dhcp_send_hostname,
             dhcp_custom_hostname: ip.dhcp_custom_hostname,
+            dhcp_iaid: ip.dhcp_iaid,
+            dhcp_send_release: ip.dhcp_send_release,
+            ip6_privacy: ip.ip6_privacy,
+            dad_transmits: ip.dad_transmits,
+            ra_timeout: ip.ra_timeout,
+            dhcp_timeout: ip.dhcp_timeout,
+            mtu_from_ra: ip.mtu_from_ra,
+            dhcp_lease: ip.dhcp_lease,
+            sysctl: ip
+                .sysctl
+                .as_ref()
+                .filter(|s| !s.is_empty())
+                .and_then(|s| serde_json::to_value(s).ok()),
             ..Default::default()
         }
     }
@@ -863,6 +1233,16 @@ pub struct InterfaceIpAddr {
     /// Prefix length.
     /// Serialize and deserialize to/from `prefix-length`.
     pub prefix_length: u8,
+    /// Peer address of point-to-point link. When defined, the
+    /// `prefix-length` applies to the peer address instead of `ip`.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub peer: Option<IpAddr>,
+    /// Flags of this IP address. When applying with `None`, the verification
+    /// process ignores the flags of current IP address.
+    /// When applying with `Some(Vec::new())`, IP address will be configured
+    /// without any flag.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub flags: Option<Vec<InterfaceIpAddrFlag>>,
     #[serde(skip_serializing_if = "is_none_or_empty_mptcp_flags", default)]
     /// MPTCP flag on this IP address.
     /// Ignored when applying as nmstate does not support support IP address
@@ -899,6 +1279,8 @@ impl Default for InterfaceIpAddr {
         Self {
             ip: IpAddr::V6(std::net::Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 1)),
             prefix_length: 128,
+            peer: None,
+            flags: None,
             mptcp_flags: None,
             valid_life_time: None,
             preferred_life_time: None,
@@ -917,6 +1299,8 @@ impl std::fmt::Display for InterfaceIpAddr {
                 self.valid_life_time.as_deref().unwrap_or(FOREVER),
                 self.preferred_life_time.as_deref().unwrap_or(FOREVER)
             )
+        } else if let Some(peer) = self.peer.as_ref() {
+            write!(f, "{} peer {}/{}", self.ip, peer, self.prefix_length)
         } else {
             write!(f, "{}/{}", self.ip, self.prefix_length)
         }
@@ -928,6 +1312,102 @@ impl InterfaceIpAddr {
         self.valid_life_time.is_some()
             && self.valid_life_time.as_deref() != Some(FOREVER)
     }
+
+    fn sanitize_peer_and_flags(
+        &mut self,
+        is_desired: bool,
+    ) -> Result<(), NmstateError> {
+        if let Some(flags) = self.flags.as_mut() {
+            flags.sort_unstable();
+            flags.dedup();
+        }
+        if !is_desired {
+            return Ok(());
+        }
+        if let Some(peer) = self.peer.as_ref() {
+            if peer.is_ipv6() != self.ip.is_ipv6() {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "The peer address {peer} is not in the same IP \
+                        family of IP address {}",
+                        self.ip
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
+        if self.ip.is_ipv4() {
+            if let Some(flag) = self
+                .flags
+                .as_deref()
+                .unwrap_or_default()
+                .iter()
+                .find(|f| f.is_ipv6_only())
+            {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "The IP address flag {flag} of {} is only \
+                        supported by IPv6",
+                        self.ip
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+/// Flags of IP address.
+pub enum InterfaceIpAddrFlag {
+    /// Do not create prefix route for this IP address.
+    Noprefixroute,
+    /// Do not perform Duplicate Address Detection. IPv6 only.
+    Nodad,
+    /// Mobile IPv6 home address defined in RFC 6275. IPv6 only.
+    Home,
+    /// Optimistic Duplicate Address Detection defined in RFC 4429.
+    /// IPv6 only.
+    Optimistic,
+}
+
+impl InterfaceIpAddrFlag {
+    pub(crate) fn is_ipv6_only(&self) -> bool {
+        !matches!(self, Self::Noprefixroute)
+    }
+}
+
+impl std::fmt::Display for InterfaceIpAddrFlag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Noprefixroute => "noprefixroute",
+                Self::Nodad => "nodad",
+                Self::Home => "home",
+                Self::Optimistic => "optimistic",
+            }
+        )
+    }
 }
 
 pub(crate) fn is_ipv6_addr(addr: &str) -> bool {
@@ -973,6 +1453,8 @@ impl std::convert::TryFrom<&str> for InterfaceIpAddr {
         Ok(Self {
             ip,
             prefix_length,
+            peer: None,
+            flags: None,
             mptcp_flags: None,
             valid_life_time: None,
             preferred_life_time: None,
@@ -1053,6 +1535,63 @@ impl std::fmt::Display for Dhcpv6Duid {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+/// DHCP lease currently held by DHCP client. Query only.
+/// Example YAML output of DHCPv4 lease:
+/// ```yaml
+/// ---
+/// dhcp-lease:
+///   server: 192.0.2.1
+///   lease-time: 3600
+///   expiry: 1700000000
+///   routers:
+///   - 192.0.2.1
+///   dns-servers:
+///   - 192.0.2.53
+///   domain-name: example.org
+///   mtu: 1500
+/// ```
+pub struct DhcpLease {
+    /// IP address of DHCPv4 server or DUID of DHCPv6 server.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server: Option<String>,
+    /// Lease time in seconds.
+    /// Serialize and deserialize to/from `lease-time`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lease_time: Option<u64>,
+    /// Lease expiry time in seconds since UNIX epoch.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiry: Option<u64>,
+    /// Routers offered by DHCPv4 server.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub routers: Option<Vec<String>>,
+    /// DNS name servers offered by DHCP server.
+    /// Serialize and deserialize to/from `dns-servers`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dns_servers: Option<Vec<String>>,
+    /// Domain name offered by DHCPv4 server.
+    /// Serialize and deserialize to/from `domain-name`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain_name: Option<String>,
+    /// DNS search domains offered by DHCP server.
+    /// Serialize and deserialize to/from `domain-search`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain_search: Option<Vec<String>>,
+    /// NTP servers offered by DHCP server.
+    /// Serialize and deserialize to/from `ntp-servers`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ntp_servers: Option<Vec<String>>,
+    /// Interface MTU offered by DHCPv4 server.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mtu: Option<u32>,
+    /// Prefixes delegated by DHCPv6 server.
+    /// Serialize and deserialize to/from `prefix-delegation`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prefix_delegation: Option<Vec<String>>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 /// IPv6 address generation mode
 pub enum Ipv6AddrGenMode {
@@ -1164,6 +1703,44 @@ fn validate_wait_ip(base_iface: &BaseInterface) -> Result<(), NmstateError> {
     Ok(())
 }
 
+fn validate_ip_timeout(
+    prop_name: &str,
+    timeout: Option<u32>,
+) -> Result<(), NmstateError> {
+    if let Some(t) = timeout {
+        if t == 0 || t > IP_TIMEOUT_MAX {
+            let e = NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "Invalid {prop_name} {t}, should be in the range of 1 \
+                    to {IP_TIMEOUT_MAX}"
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+    }
+    Ok(())
+}
+
+fn validate_dhcp_iaid(iaid: &str) -> Result<(), NmstateError> {
+    if ["mac", "perm-mac", "ifname", "stable"].contains(&iaid)
+        || iaid.parse::<u32>().is_ok()
+    {
+        Ok(())
+    } else {
+        let e = NmstateError::new(
+            ErrorKind::InvalidArgument,
+            format!(
+                "Invalid dhcp-iaid {iaid}, should be `mac`, `perm-mac`, \
+                `ifname`, `stable` or a 32 bits unsigned integer"
+            ),
+        );
+        log::error!("{}", e);
+        Err(e)
+    }
+}
+
 pub(crate) fn sanitize_ip_network(
     ip_net: &str,
 ) -> Result<String, NmstateError> {
@@ -96,7 +96,9 @@ mod ifaces;
 mod ip;
 mod lldp;
 mod mptcp;
+mod neighbor;
 mod net_state;
+mod nexthop;
 #[cfg(feature = "query_apply")]
 mod nispor;
 mod nm;
@@ -117,15 +119,20 @@ mod serializer;
 mod state;
 #[cfg(feature = "query_apply")]
 mod statistic;
+mod sysctl;
 mod unit_tests;
 
 pub use crate::dispatch::DispatchConfig;
 pub(crate) use crate::dns::MergedDnsState;
-pub use crate::dns::{DnsClientState, DnsState};
+pub use crate::dns::{
+    DnsClientState, DnsOverTls, DnsState, Dnssec, InterfaceDnsConfig,
+};
 pub use crate::error::{ErrorKind, NmstateError};
 pub use crate::hostname::HostNameState;
 pub(crate) use crate::hostname::MergedHostNameState;
-pub use crate::ieee8021x::Ieee8021XConfig;
+pub use crate::ieee8021x::{
+    Ieee8021XAuthFailureReason, Ieee8021XAuthState, Ieee8021XConfig,
+};
 pub(crate) use crate::iface::MergedInterface;
 pub use crate::iface::{
     Interface, InterfaceIdentifier, InterfaceState, InterfaceType,
@@ -138,37 +145,51 @@ pub use crate::ifaces::{
     BondMode, BondOptions, BondPortConfig, BondPrimaryReselect,
     BondXmitHashPolicy, BridgePortTrunkTag, BridgePortVlanConfig,
     BridgePortVlanMode, BridgePortVlanRange, DummyInterface, EthernetConfig,
-    EthernetDuplex, EthernetInterface, EthtoolCoalesceConfig, EthtoolConfig,
-    EthtoolFeatureConfig, EthtoolPauseConfig, EthtoolRingConfig, HsrConfig,
+    EthernetDuplex, EthernetInterface, EthernetWakeOnLanConfig,
+    EthernetWakeOnLanMode, EthtoolChannelsConfig, EthtoolCoalesceConfig,
+    EthtoolConfig, EthtoolEeeConfig, EthtoolFeatureConfig, EthtoolFecConfig,
+    EthtoolFecMode, EthtoolLinkModesConfig, EthtoolPauseConfig,
+    EthtoolRingConfig, EthtoolRssConfig, EthtoolRssHashFunction, HsrConfig,
     HsrInterface, HsrProtocol, InfiniBandConfig, InfiniBandInterface,
     InfiniBandMode, Interfaces, IpVlanConfig, IpVlanInterface, IpVlanMode,
     IpsecInterface, LibreswanAddressFamily, LibreswanConfig,
     LibreswanConnectionType, LinuxBridgeConfig, LinuxBridgeInterface,
     LinuxBridgeMulticastRouterType, LinuxBridgeOptions, LinuxBridgePortConfig,
     LinuxBridgeStpOptions, LoopbackInterface, MacSecConfig, MacSecInterface,
-    MacSecOffload, MacSecValidate, MacVlanConfig, MacVlanInterface,
-    MacVlanMode, MacVtapConfig, MacVtapInterface, MacVtapMode,
-    OvsBridgeBondConfig, OvsBridgeBondMode, OvsBridgeBondPortConfig,
-    OvsBridgeConfig, OvsBridgeInterface, OvsBridgeOptions, OvsBridgePortConfig,
+    MacSecOffload, MacSecRxScConfig, MacSecSaConfig, MacSecStaticSakConfig,
+    MacSecValidate, MacVlanConfig, MacVlanInterface, MacVlanMode,
+    MacVtapConfig, MacVtapInterface, MacVtapMode, OvsBridgeBondConfig,
+    OvsBridgeBondMode, OvsBridgeBondPortConfig, OvsBridgeConfig,
+    OvsBridgeInterface, OvsBridgeOptions, OvsBridgePortConfig,
     OvsBridgeStpOptions, OvsDpdkConfig, OvsInterface, OvsPatchConfig,
-    SrIovConfig, SrIovVfConfig, VethConfig, VlanConfig, VlanInterface,
-    VlanProtocol, VlanRegistrationProtocol, VrfConfig, VrfInterface,
-    VxlanConfig, VxlanInterface, XfrmInterface,
+    SrIovConfig, SrIovEswitchConfig, SrIovEswitchEncapMode,
+    SrIovEswitchInlineMode, SrIovEswitchMode, SrIovVfConfig, SrIovVfLinkState,
+    SrIovVfStats, VethConfig, VlanConfig, VlanInterface, VlanProtocol,
+    VlanRegistrationProtocol, VrfConfig, VrfInterface, VxlanConfig,
+    VxlanInterface, XfrmInterface,
 };
 pub use crate::ip::{
-    AddressFamily, Dhcpv4ClientId, Dhcpv6Duid, InterfaceIpAddr, InterfaceIpv4,
-    InterfaceIpv6, Ipv6AddrGenMode, WaitIp,
+    AddressFamily, DhcpLease, Dhcpv4ClientId, Dhcpv6Duid, InterfaceIpAddr,
+    InterfaceIpAddrFlag, InterfaceIpv4, InterfaceIpv6, Ipv6AddrGenMode, WaitIp,
 };
 pub use crate::lldp::{
     LldpAddressFamily, LldpChassisId, LldpChassisIdType, LldpConfig,
-    LldpMacPhy, LldpMaxFrameSize, LldpMgmtAddr, LldpMgmtAddrs, LldpNeighborTlv,
-    LldpPortId, LldpPortIdType, LldpPpvids, LldpSystemCapabilities,
-    LldpSystemCapability, LldpSystemDescription, LldpSystemName, LldpVlan,
-    LldpVlans,
+    LldpMacPhy, LldpMaxFrameSize, LldpMedAppType, LldpMedNetworkPolicies,
+    LldpMedNetworkPolicy, LldpMedSubtype, LldpMgmtAddr, LldpMgmtAddrs,
+    LldpNeighborTlv, LldpPortId, LldpPortIdType, LldpPpvids,
+    LldpSystemCapabilities, LldpSystemCapability, LldpSystemDescription,
+    LldpSystemName, LldpVlan, LldpVlans,
 };
 pub use crate::mptcp::{MptcpAddressFlag, MptcpConfig};
+pub(crate) use crate::neighbor::MergedNeighbors;
+pub use crate::neighbor::{NeighborEntry, NeighborState, Neighbors};
 pub(crate) use crate::net_state::MergedNetworkState;
 pub use crate::net_state::NetworkState;
+pub(crate) use crate::nexthop::MergedNexthops;
+pub use crate::nexthop::{
+    NexthopEntry, NexthopGroupMember, NexthopGroupType, NexthopResilientConfig,
+    NexthopState, Nexthops,
+};
 pub(crate) use crate::ovn::MergedOvnConfiguration;
 pub use crate::ovn::{
     OvnBridgeMapping, OvnBridgeMappingState, OvnConfiguration,
@@ -180,10 +201,18 @@ pub use crate::policy::{
     NetworkCaptureRules, NetworkPolicy, NetworkStateTemplate,
 };
 pub(crate) use crate::route::MergedRoutes;
-pub use crate::route::{RouteEntry, RouteState, RouteType, Routes};
+pub use crate::route::{
+    RouteEntry, RouteMetricLock, RoutePreference, RouteProtocol, RouteScope,
+    RouteState, RouteType, Routes,
+};
 pub(crate) use crate::route_rule::MergedRouteRules;
 pub use crate::route_rule::{
-    RouteRuleAction, RouteRuleEntry, RouteRuleState, RouteRules,
+    RouteRuleAction, RouteRuleEntry, RouteRulePortRange, RouteRuleState,
+    RouteRuleUidRange, RouteRules,
 };
 #[cfg(feature = "query_apply")]
 pub use crate::statistic::{NmstateFeature, NmstateStatistic};
+pub(crate) use crate::sysctl::MergedIpForwarding;
+pub use crate::sysctl::{
+    IpForwarding, Ipv4Sysctl, Ipv6Sysctl, Ipv6TempAddr, RpFilter,
+};
@@ -49,6 +49,19 @@ impl From<LldpOrgSubtype> for u8 {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[repr(u8)]
+#[serde(into = "u8")]
+pub enum LldpMedSubtype {
+    NetworkPolicy = 2,
+}
+
+impl From<LldpMedSubtype> for u8 {
+    fn from(value: LldpMedSubtype) -> Self {
+        value as Self
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub enum LldpOrgOiu {
     #[serde(rename = "00:80:c2")]
@@ -59,6 +72,8 @@ pub enum LldpOrgOiu {
     Ppvids,
     #[serde(rename = "00:12:0f")]
     MaxFrameSize,
+    #[serde(rename = "00:12:bb")]
+    Med,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize, Serialize)]
@@ -108,6 +123,7 @@ pub enum LldpNeighborTlv {
     Ieee8021Ppvids(LldpPpvids),
     ManagementAddresses(LldpMgmtAddrs),
     Ieee8023MaxFrameSize(LldpMaxFrameSize),
+    MedNetworkPolicies(LldpMedNetworkPolicies),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
@@ -434,6 +450,12 @@ pub struct LldpVlan {
     pub vid: u32,
 }
 
+impl LldpVlan {
+    pub fn new(vid: u32, name: String) -> Self {
+        Self { name, vid }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 #[non_exhaustive]
 #[serde(rename_all = "kebab-case")]
@@ -577,3 +599,118 @@ impl LldpMaxFrameSize {
         }
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[non_exhaustive]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct LldpMedNetworkPolicies {
+    #[serde(rename = "type")]
+    pub ty: LldpNeighborTlvType,
+    pub lldp_med_network_policies: Vec<LldpMedNetworkPolicy>,
+    pub oui: LldpOrgOiu,
+    pub subtype: LldpMedSubtype,
+}
+
+impl LldpMedNetworkPolicies {
+    pub fn new(lldp_med_network_policies: Vec<LldpMedNetworkPolicy>) -> Self {
+        Self {
+            lldp_med_network_policies,
+            oui: LldpOrgOiu::Med,
+            subtype: LldpMedSubtype::NetworkPolicy,
+            ty: LldpNeighborTlvType::OrganizationSpecific,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Default)]
+#[non_exhaustive]
+#[serde(rename_all = "kebab-case")]
+/// ANSI/TIA-1057 LLDP-MED network policy, commonly used by switches to
+/// announce the voice VLAN to IP phones.
+pub struct LldpMedNetworkPolicy {
+    pub application_type: LldpMedAppType,
+    #[serde(rename = "_description")]
+    pub description: String,
+    /// Whether the network policy for this application type is unknown.
+    pub unknown_policy: bool,
+    pub tagged: bool,
+    pub vid: u16,
+    pub priority: u8,
+    pub dscp: u8,
+}
+
+impl LldpMedNetworkPolicy {
+    pub fn new(
+        application_type: LldpMedAppType,
+        unknown_policy: bool,
+        tagged: bool,
+        vid: u16,
+        priority: u8,
+        dscp: u8,
+    ) -> Self {
+        Self {
+            application_type: application_type.clone(),
+            description: application_type.into(),
+            unknown_policy,
+            tagged,
+            vid,
+            priority,
+            dscp,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Default)]
+#[repr(u8)]
+#[serde(into = "u8")]
+pub enum LldpMedAppType {
+    #[default]
+    Reserved = 0,
+    Voice = 1,
+    VoiceSignaling = 2,
+    GuestVoice = 3,
+    GuestVoiceSignaling = 4,
+    SoftphoneVoice = 5,
+    VideoConferencing = 6,
+    StreamingVideo = 7,
+    VideoSignaling = 8,
+}
+
+impl From<LldpMedAppType> for u8 {
+    fn from(value: LldpMedAppType) -> Self {
+        value as Self
+    }
+}
+
+impl From<u8> for LldpMedAppType {
+    fn from(v: u8) -> LldpMedAppType {
+        match v {
+            1 => Self::Voice,
+            2 => Self::VoiceSignaling,
+            3 => Self::GuestVoice,
+            4 => Self::GuestVoiceSignaling,
+            5 => Self::SoftphoneVoice,
+            6 => Self::VideoConferencing,
+            7 => Self::StreamingVideo,
+            8 => Self::VideoSignaling,
+            _ => Self::Reserved,
+        }
+    }
+}
+
+impl From<LldpMedAppType> for String {
+    fn from(v: LldpMedAppType) -> String {
+        match v {
+            LldpMedAppType::Reserved => "Reserved",
+            LldpMedAppType::Voice => "Voice",
+            LldpMedAppType::VoiceSignaling => "Voice signaling",
+            LldpMedAppType::GuestVoice => "Guest voice",
+            LldpMedAppType::GuestVoiceSignaling => "Guest voice signaling",
+            LldpMedAppType::SoftphoneVoice => "Softphone voice",
+            LldpMedAppType::VideoConferencing => "Video conferencing",
+            LldpMedAppType::StreamingVideo => "Streaming video",
+            LldpMedAppType::VideoSignaling => "Video signaling",
+        }
+        .to_string()
+    }
+}
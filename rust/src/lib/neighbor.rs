@@ -0,0 +1,325 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use crate::{ErrorKind, NmstateError};
+
+// InfiniBand hardware address is the longest one with 20 bytes.
+const LINK_LAYER_ADDRESS_MAX_LEN: usize = 20;
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(deny_unknown_fields)]
+/// IP neighbor(ARP for IPv4 and NDP for IPv6) status
+pub struct Neighbors {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Running effected neighbors with link layer address resolved or
+    /// statically defined, including proxy neighbors.
+    ///
+    /// Ignored when applying.
+    pub running: Option<Vec<NeighborEntry>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Permanent neighbors and proxy neighbors.
+    ///
+    /// When applying, `None` means preserve current neighbors.
+    /// This property is not overriding but adding specified neighbors to
+    /// existing ones, neighbor with the same IP, interface and proxy
+    /// setting will be replaced. To delete a neighbor entry, please set
+    /// [NeighborEntry.state] as [NeighborState::Absent]. Any property of
+    /// absent [NeighborEntry] set to `None` means wildcard. For example,
+    /// this [crate::NetworkState] will add a permanent neighbor and a proxy
+    /// neighbor to eth1, and remove all other permanent neighbors of eth2:
+    /// ```yaml
+    /// neighbors:
+    ///   config:
+    ///   - ip: 192.0.2.1
+    ///     interface: eth1
+    ///     link-layer-address: 00:23:45:67:89:1A
+    ///   - ip: 2001:db8:1::1
+    ///     interface: eth1
+    ///     proxy: true
+    ///   - interface: eth2
+    ///     state: absent
+    /// ```
+    pub config: Option<Vec<NeighborEntry>>,
+}
+
+impl Neighbors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.running.is_none() && self.config.is_none()
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum NeighborState {
+    /// Used for delete neighbor
+    #[default]
+    Absent,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+#[serde(deny_unknown_fields)]
+pub struct NeighborEntry {
+    /// Only used for delete neighbor when applying.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<NeighborState>,
+    /// IPv4 or IPv6 address of the neighbor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+    /// Interface holding this neighbor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interface: Option<String>,
+    /// Link layer address(MAC address for ethernet) of the neighbor.
+    /// Mandatory for non-proxy neighbor.
+    /// Serialize and deserialize to/from `link-layer-address`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link_layer_address: Option<String>,
+    /// Proxy ARP(IPv4) or proxy NDP(IPv6) entry, the host will reply ARP
+    /// request or neighbor solicitation of this IP address on specified
+    /// interface. Cannot be used with `link-layer-address`.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_bool_or_string"
+    )]
+    pub proxy: Option<bool>,
+}
+
+impl NeighborEntry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn is_absent(&self) -> bool {
+        matches!(self.state, Some(NeighborState::Absent))
+    }
+
+    pub(crate) fn is_proxy(&self) -> bool {
+        self.proxy == Some(true)
+    }
+
+    /// Whether the desired neighbor (self) matches with another
+    pub(crate) fn is_match(&self, other: &Self) -> bool {
+        if self.ip.is_some() && self.ip != other.ip {
+            return false;
+        }
+        if self.interface.is_some() && self.interface != other.interface {
+            return false;
+        }
+        if self.link_layer_address.is_some()
+            && self.link_layer_address != other.link_layer_address
+        {
+            return false;
+        }
+        if self.proxy.is_some() && self.is_proxy() != other.is_proxy() {
+            return false;
+        }
+        true
+    }
+
+    // Neighbor sharing the same IP, interface and proxy setting will be
+    // replaced by kernel.
+    pub(crate) fn is_same_key(&self, other: &Self) -> bool {
+        self.ip == other.ip
+            && self.interface == other.interface
+            && self.is_proxy() == other.is_proxy()
+    }
+
+    pub(crate) fn sanitize(&mut self) -> Result<(), NmstateError> {
+        if let Some(ip) = self.ip.as_ref() {
+            let new_ip = format!(
+                "{}",
+                ip.parse::<std::net::IpAddr>().map_err(|e| {
+                    NmstateError::new(
+                        ErrorKind::InvalidArgument,
+                        format!("Invalid neighbor IP address {ip}: {e}"),
+                    )
+                })?
+            );
+            if ip != &new_ip {
+                log::warn!("Neighbor IP {} sanitized to {}", ip, new_ip);
+                self.ip = Some(new_ip);
+            }
+        }
+        if let Some(addr) = self.link_layer_address.as_ref() {
+            let new_addr = sanitize_link_layer_address(addr)?;
+            if addr != &new_addr {
+                log::info!(
+                    "Neighbor link-layer-address {} sanitized to {}",
+                    addr,
+                    new_addr
+                );
+                self.link_layer_address = Some(new_addr);
+            }
+        }
+        if self.is_absent() {
+            return Ok(());
+        }
+        if self.ip.is_none() || self.interface.is_none() {
+            let e = NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "Both ip and interface are mandatory for neighbor {self}"
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+        if self.is_proxy() {
+            if self.link_layer_address.is_some() {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "Proxy neighbor cannot have link-layer-address: \
+                        {self}"
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        } else if self.link_layer_address.is_none() {
+            let e = NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "The link-layer-address is mandatory for non-proxy \
+                    neighbor {self}"
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for NeighborEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut props = Vec::new();
+        if self.is_absent() {
+            props.push("state: absent".to_string());
+        }
+        if let Some(v) = self.ip.as_ref() {
+            props.push(format!("ip: {v}"));
+        }
+        if let Some(v) = self.interface.as_ref() {
+            props.push(format!("interface: {v}"));
+        }
+        if let Some(v) = self.link_layer_address.as_ref() {
+            props.push(format!("link-layer-address: {v}"));
+        }
+        if let Some(v) = self.proxy {
+            props.push(format!("proxy: {v}"));
+        }
+
+        write!(f, "{}", props.join(" "))
+    }
+}
+
+fn sanitize_link_layer_address(addr: &str) -> Result<String, NmstateError> {
+    let bytes: Vec<&str> = addr.split(':').collect();
+    if bytes.len() > LINK_LAYER_ADDRESS_MAX_LEN
+        || bytes.iter().any(|b| {
+            b.is_empty() || b.len() > 2 || u8::from_str_radix(b, 16).is_err()
+        })
+    {
+        let e = NmstateError::new(
+            ErrorKind::InvalidArgument,
+            format!(
+                "Invalid neighbor link-layer-address {addr}, should be \
+                colon separated hex bytes like 00:23:45:67:89:1A"
+            ),
+        );
+        log::error!("{}", e);
+        return Err(e);
+    }
+    Ok(bytes
+        .iter()
+        .map(|b| format!("{:0>2}", b.to_uppercase()))
+        .collect::<Vec<String>>()
+        .join(":"))
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct MergedNeighbors {
+    pub(crate) desired: Neighbors,
+    pub(crate) current: Neighbors,
+    // The `for_apply` will hold two type of neighbor:
+    //  * Desired neighbors
+    //  * Current neighbors been marked as absent
+    pub(crate) for_apply: Vec<NeighborEntry>,
+}
+
+impl MergedNeighbors {
+    pub(crate) fn new(
+        desired: Neighbors,
+        current: Neighbors,
+    ) -> Result<Self, NmstateError> {
+        let mut for_apply: Vec<NeighborEntry> = Vec::new();
+
+        let mut des_neighs: Vec<NeighborEntry> = Vec::new();
+        for neigh in desired.config.as_deref().unwrap_or_default() {
+            let mut neigh = neigh.clone();
+            neigh.sanitize()?;
+            des_neighs.push(neigh);
+        }
+
+        for (i, neigh) in des_neighs
+            .iter()
+            .enumerate()
+            .filter(|(_, n)| !n.is_absent())
+        {
+            if des_neighs[i + 1..]
+                .iter()
+                .any(|n| !n.is_absent() && n.is_same_key(neigh))
+            {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!("Duplicate desired neighbor {neigh}"),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
+
+        for neigh in current.config.as_deref().unwrap_or_default() {
+            if des_neighs
+                .iter()
+                .filter(|n| n.is_absent())
+                .any(|absent_neigh| absent_neigh.is_match(neigh))
+                && !des_neighs
+                    .iter()
+                    .any(|n| !n.is_absent() && n.is_same_key(neigh))
+            {
+                let mut new_neigh = neigh.clone();
+                new_neigh.state = Some(NeighborState::Absent);
+                for_apply.push(new_neigh);
+            }
+        }
+
+        for_apply.extend(des_neighs.into_iter().filter(|n| !n.is_absent()));
+
+        Ok(Self {
+            desired,
+            current,
+            for_apply,
+        })
+    }
+
+    pub(crate) fn is_changed(&self) -> bool {
+        let cur_neighs = self.current.config.as_deref().unwrap_or_default();
+        self.for_apply.iter().any(|neigh| {
+            neigh.is_absent()
+                || !cur_neighs.iter().any(|cur_neigh| neigh.is_match(cur_neigh))
+        })
+    }
+}
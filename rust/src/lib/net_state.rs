@@ -6,10 +6,12 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    DnsState, ErrorKind, HostNameState, Interface, Interfaces, MergedDnsState,
-    MergedHostNameState, MergedInterfaces, MergedOvnConfiguration,
-    MergedOvsDbGlobalConfig, MergedRouteRules, MergedRoutes, NmstateError,
-    OvnConfiguration, OvsDbGlobalConfig, RouteRules, Routes,
+    DnsState, ErrorKind, HostNameState, Interface, Interfaces, IpForwarding,
+    MergedDnsState, MergedHostNameState, MergedInterfaces, MergedIpForwarding,
+    MergedNeighbors, MergedNexthops, MergedOvnConfiguration,
+    MergedOvsDbGlobalConfig, MergedRouteRules, MergedRoutes, Neighbors,
+    Nexthops, NmstateError, OvnConfiguration, OvsDbGlobalConfig, RouteRules,
+    Routes,
 };
 
 /// The [NetworkState] represents the whole network state including both
@@ -89,6 +91,10 @@ pub struct NetworkState {
     /// DNS resolver status, deserialize and serialize from/to `dns-resolver`.
     #[serde(rename = "dns-resolver", skip_serializing_if = "Option::is_none")]
     pub dns: Option<DnsState>,
+    /// Global IP forwarding, deserialize and serialize from/to
+    /// `ip-forwarding`.
+    #[serde(rename = "ip-forwarding", skip_serializing_if = "Option::is_none")]
+    pub ip_forwarding: Option<IpForwarding>,
     /// Route rule, deserialize and serialize from/to `route-rules`.
     #[serde(
         rename = "route-rules",
@@ -96,9 +102,15 @@ pub struct NetworkState {
         skip_serializing_if = "RouteRules::is_empty"
     )]
     pub rules: RouteRules,
+    /// Kernel nexthop objects
+    #[serde(default, skip_serializing_if = "Nexthops::is_empty")]
+    pub nexthops: Nexthops,
     /// Route
     #[serde(default, skip_serializing_if = "Routes::is_empty")]
     pub routes: Routes,
+    /// Static IP neighbors(ARP/NDP entries)
+    #[serde(default, skip_serializing_if = "Neighbors::is_empty")]
+    pub neighbors: Neighbors,
     /// Network interfaces
     #[serde(default)]
     pub interfaces: Interfaces,
@@ -124,15 +136,20 @@ pub struct NetworkState {
     pub(crate) running_config_only: bool,
     #[serde(skip)]
     pub(crate) memory_only: bool,
+    #[serde(skip)]
+    pub(crate) lldp_listen_time: u32,
 }
 
 impl NetworkState {
     pub fn is_empty(&self) -> bool {
         self.hostname.is_none()
             && self.dns.is_none()
+            && self.ip_forwarding.is_none()
             && self.ovsdb.is_none()
             && self.rules.is_empty()
+            && self.nexthops.is_empty()
             && self.routes.is_empty()
+            && self.neighbors.is_empty()
             && self.interfaces.is_empty()
             && self.ovn.is_none()
     }
@@ -189,7 +206,9 @@ impl NetworkState {
         self
     }
 
-    /// Deprecated. No use at all.
+    /// Whether to include status data(like SR-IOV VF traffic counters and
+    /// 802.1X authentication state) in [NetworkState::retrieve()].
+    /// Default is false.
     pub fn set_include_status_data(&mut self, value: bool) -> &mut Self {
         self.include_status_data = value;
         self
@@ -212,6 +231,16 @@ impl NetworkState {
         self
     }
 
+    /// Only available when [NetworkState::set_kernel_only()] set to true.
+    /// Listen LLDP frames on all interfaces for specified seconds during
+    /// [NetworkState::retrieve()] to populate LLDP neighbors. The LLDP
+    /// transmit interval of most switches is 30 seconds.
+    /// Default is 0 which means not listening and reporting LLDP as disabled.
+    pub fn set_lldp_listen_time(&mut self, value: u32) -> &mut Self {
+        self.lldp_listen_time = value;
+        self
+    }
+
     /// Create empty [NetworkState]
     pub fn new() -> Self {
         Default::default()
@@ -321,10 +350,13 @@ pub(crate) struct MergedNetworkState {
     pub(crate) interfaces: MergedInterfaces,
     pub(crate) hostname: MergedHostNameState,
     pub(crate) dns: MergedDnsState,
+    pub(crate) ip_forwarding: MergedIpForwarding,
     pub(crate) ovn: MergedOvnConfiguration,
     pub(crate) ovsdb: MergedOvsDbGlobalConfig,
     pub(crate) routes: MergedRoutes,
     pub(crate) rules: MergedRouteRules,
+    pub(crate) nexthops: MergedNexthops,
+    pub(crate) neighbors: MergedNeighbors,
     pub(crate) memory_only: bool,
 }
 
@@ -350,9 +382,19 @@ impl MergedNetworkState {
         let mut rules = MergedRouteRules::new(desired.rules, current.rules)?;
         rules.remove_rules_to_ignored_ifaces(ignored_ifaces);
 
+        let nexthops = MergedNexthops::new(desired.nexthops, current.nexthops)?;
+
+        let neighbors =
+            MergedNeighbors::new(desired.neighbors, current.neighbors)?;
+
         let hostname =
             MergedHostNameState::new(desired.hostname, current.hostname);
 
+        let ip_forwarding = MergedIpForwarding::new(
+            desired.ip_forwarding,
+            current.ip_forwarding,
+        );
+
         let ovn = MergedOvnConfiguration::new(desired.ovn, current.ovn)?;
 
         let ovsdb = MergedOvsDbGlobalConfig::new(
@@ -365,6 +407,8 @@ impl MergedNetworkState {
             interfaces,
             routes,
             rules,
+            nexthops,
+            neighbors,
             dns: MergedDnsState::new(
                 desired.dns,
                 current.dns.unwrap_or_default(),
@@ -372,9 +416,14 @@ impl MergedNetworkState {
             ovn,
             ovsdb,
             hostname,
+            ip_forwarding,
             memory_only,
         };
         ret.validate_ipv6_link_local_address_dns_srv()?;
+        if memory_only {
+            ret.dns.validate_memory_only()?;
+            ret.hostname.validate_memory_only()?;
+        }
 
         Ok(ret)
     }
@@ -0,0 +1,622 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{ip::is_ipv6_addr, AddressFamily, ErrorKind, NmstateError};
+
+const NEXTHOP_GROUP_WEIGHT_DEFAULT: u16 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(deny_unknown_fields)]
+/// Kernel nexthop objects
+pub struct Nexthops {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// When applying, `None` means preserve existing nexthops.
+    /// Nmstate is using partial editing for nexthop, which means desired
+    /// nexthops only append to or replace existing ones with the same ID.
+    /// To delete a nexthop, please set [NexthopEntry.state] to
+    /// [NexthopState::Absent]. Any property set to None in absent nexthop
+    /// means wildcard. For example, this [crate::NetworkState] will create
+    /// two nexthops and a weighted group of them, then use the group in a
+    /// route:
+    /// ```yml
+    /// ---
+    /// nexthops:
+    ///   config:
+    ///     - id: 1
+    ///       next-hop-interface: eth1
+    ///       next-hop-address: 192.0.2.1
+    ///     - id: 2
+    ///       next-hop-interface: eth2
+    ///       next-hop-address: 198.51.100.1
+    ///     - id: 10
+    ///       group:
+    ///         - id: 1
+    ///           weight: 2
+    ///         - id: 2
+    /// routes:
+    ///   config:
+    ///     - destination: 203.0.113.0/24
+    ///       nexthop-id: 10
+    /// ```
+    pub config: Option<Vec<NexthopEntry>>,
+}
+
+impl Nexthops {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.config.is_none()
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum NexthopState {
+    /// Used for delete nexthop
+    #[default]
+    Absent,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+#[serde(deny_unknown_fields)]
+pub struct NexthopEntry {
+    /// Indicate this is normal nexthop or absent nexthop.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<NexthopState>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    /// Unique ID of this nexthop, referred by
+    /// [crate::RouteEntry.nexthop_id] and group members.
+    /// Mandatory for every non-absent nexthop.
+    pub id: Option<u32>,
+    /// Address family of the nexthop. Only required for nexthop without
+    /// next hop address, default to [AddressFamily::IPv4]. Ignored for
+    /// nexthop group.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub family: Option<AddressFamily>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "next-hop-interface"
+    )]
+    /// Nexthop interface name.
+    /// Serialize and deserialize to/from `next-hop-interface`.
+    /// Mandatory for every non-absent nexthop except for group and blackhole
+    /// nexthop.
+    pub next_hop_iface: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "next-hop-address"
+    )]
+    /// Gateway IP address.
+    /// Serialize and deserialize to/from `next-hop-address`.
+    pub next_hop_addr: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_bool_or_string"
+    )]
+    /// Pretend that the gateway is directly attached to the interface, even
+    /// if it does not match any interface prefix.
+    pub onlink: Option<bool>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_bool_or_string"
+    )]
+    /// Discard the packets matching this nexthop silently.
+    pub blackhole: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Members of this nexthop group. Member cannot be another nexthop
+    /// group.
+    pub group: Option<Vec<NexthopGroupMember>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Type of nexthop group, default to [NexthopGroupType::Multipath].
+    /// Only valid for nexthop group.
+    pub group_type: Option<NexthopGroupType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Bucket settings of resilient nexthop group.
+    /// Only valid when `group-type` is [NexthopGroupType::Resilient].
+    pub resilient: Option<NexthopResilientConfig>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+#[serde(deny_unknown_fields)]
+pub struct NexthopGroupMember {
+    #[serde(deserialize_with = "crate::deserializer::u32_or_string")]
+    /// ID of the member nexthop.
+    pub id: u32,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u16_or_string"
+    )]
+    /// Weight of this member in the group. The valid range of this property
+    /// is 1-256, default to 1.
+    pub weight: Option<u16>,
+}
+
+impl NexthopGroupMember {
+    pub fn new(id: u32, weight: Option<u16>) -> Self {
+        Self { id, weight }
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum NexthopGroupType {
+    /// Hash-threshold multipath group.
+    /// Deserialize and serialize from/to `mpath`.
+    #[default]
+    #[serde(rename = "mpath", alias = "multipath")]
+    Multipath,
+    /// Resilient group which keeps flows on their nexthop when the group
+    /// changes.
+    Resilient,
+}
+
+impl std::fmt::Display for NexthopGroupType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Multipath => "mpath",
+                Self::Resilient => "resilient",
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+#[serde(deny_unknown_fields)]
+pub struct NexthopResilientConfig {
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u16_or_string"
+    )]
+    /// Number of nexthop buckets, default to 128 by kernel.
+    pub buckets: Option<u16>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    /// Seconds of idle time after which a bucket could be migrated to
+    /// another nexthop, default to 120 by kernel.
+    pub idle_timer: Option<u32>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    /// Seconds after which an unbalanced group is forcefully balanced even
+    /// if buckets are not idle. 0 means disabled, default to 0 by kernel.
+    pub unbalanced_timer: Option<u32>,
+}
+
+impl NexthopResilientConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Whether desired resilient config (self) matches with another
+    fn is_match(&self, other: &Self) -> bool {
+        (self.buckets.is_none() || self.buckets == other.buckets)
+            && (self.idle_timer.is_none()
+                || self.idle_timer == other.idle_timer)
+            && (self.unbalanced_timer.is_none()
+                || self.unbalanced_timer == other.unbalanced_timer)
+    }
+}
+
+impl NexthopEntry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn is_absent(&self) -> bool {
+        matches!(self.state, Some(NexthopState::Absent))
+    }
+
+    pub(crate) fn is_group(&self) -> bool {
+        self.group.is_some()
+    }
+
+    /// Whether the desired nexthop (self) matches with another
+    pub(crate) fn is_match(&self, other: &Self) -> bool {
+        if self.id.is_some() && self.id != other.id {
+            return false;
+        }
+        if self.family.is_some()
+            && !self.is_group()
+            && self.family != other.family
+        {
+            return false;
+        }
+        if self.next_hop_iface.is_some()
+            && self.next_hop_iface != other.next_hop_iface
+        {
+            return false;
+        }
+        if self.next_hop_addr.is_some()
+            && self.next_hop_addr != other.next_hop_addr
+        {
+            return false;
+        }
+        if self.onlink.is_some()
+            && self.onlink.unwrap_or_default()
+                != other.onlink.unwrap_or_default()
+        {
+            return false;
+        }
+        if self.blackhole.is_some()
+            && self.blackhole.unwrap_or_default()
+                != other.blackhole.unwrap_or_default()
+        {
+            return false;
+        }
+        if let Some(members) = self.group.as_deref() {
+            let other_members = other.group.as_deref().unwrap_or_default();
+            if members.len() != other_members.len()
+                || !members.iter().all(|m| {
+                    other_members.iter().any(|o| {
+                        m.id == o.id
+                            && m.weight.unwrap_or(NEXTHOP_GROUP_WEIGHT_DEFAULT)
+                                == o.weight
+                                    .unwrap_or(NEXTHOP_GROUP_WEIGHT_DEFAULT)
+                    })
+                })
+            {
+                return false;
+            }
+        }
+        if self.group_type.is_some()
+            && self.group_type.unwrap_or_default()
+                != other.group_type.unwrap_or_default()
+        {
+            return false;
+        }
+        if let Some(res_conf) = self.resilient.as_ref() {
+            if !res_conf.is_match(&other.resilient.clone().unwrap_or_default())
+            {
+                return false;
+            }
+        }
+        true
+    }
+
+    pub(crate) fn sanitize(&mut self) -> Result<(), NmstateError> {
+        if let Some(via) = self.next_hop_addr.as_ref() {
+            let new_via = format!(
+                "{}",
+                via.parse::<std::net::IpAddr>().map_err(|e| {
+                    NmstateError::new(
+                        ErrorKind::InvalidArgument,
+                        format!("Failed to parse IP address '{via}': {e}"),
+                    )
+                })?
+            );
+            if via != &new_via {
+                log::warn!(
+                    "Nexthop next-hop-address {} sanitized to {}",
+                    via,
+                    new_via
+                );
+                self.next_hop_addr = Some(new_via);
+            }
+        }
+        if let Some(members) = self.group.as_mut() {
+            members.sort_unstable_by_key(|m| m.id);
+        }
+        if self.is_absent() {
+            return Ok(());
+        }
+        let invalid_nexthop = |msg: String| {
+            let e = NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!("{msg}: {self}"),
+            );
+            log::error!("{}", e);
+            e
+        };
+        match self.id {
+            None => {
+                return Err(invalid_nexthop(
+                    "Nexthop ID is mandatory for non-absent nexthop".into(),
+                ));
+            }
+            Some(0) => {
+                return Err(invalid_nexthop("Nexthop ID 0 is reserved".into()));
+            }
+            _ => (),
+        }
+        if let Some(family) = self.family {
+            if family == AddressFamily::Unknown {
+                return Err(invalid_nexthop(
+                    "Nexthop family should be ipv4 or ipv6".into(),
+                ));
+            }
+            if let Some(via) = self.next_hop_addr.as_deref() {
+                if is_ipv6_addr(via) != (family == AddressFamily::IPv6) {
+                    return Err(invalid_nexthop(
+                        "The next-hop-address mismatches with the family"
+                            .into(),
+                    ));
+                }
+            }
+        }
+        if let Some(members) = self.group.as_deref() {
+            if self.next_hop_iface.is_some()
+                || self.next_hop_addr.is_some()
+                || self.onlink == Some(true)
+                || self.blackhole == Some(true)
+            {
+                return Err(invalid_nexthop(
+                    "Nexthop group cannot have next-hop-interface, \
+                    next-hop-address, onlink or blackhole"
+                        .into(),
+                ));
+            }
+            if members.is_empty() {
+                return Err(invalid_nexthop(
+                    "Nexthop group should have at least one member".into(),
+                ));
+            }
+            let mut member_ids = HashSet::new();
+            for member in members {
+                if !member_ids.insert(member.id) {
+                    return Err(invalid_nexthop(format!(
+                        "Duplicate nexthop group member {}",
+                        member.id
+                    )));
+                }
+                if member.id == self.id.unwrap_or_default() {
+                    return Err(invalid_nexthop(
+                        "Nexthop group cannot contain itself".into(),
+                    ));
+                }
+                if let Some(weight) = member.weight {
+                    if !(1..=256).contains(&weight) {
+                        return Err(invalid_nexthop(format!(
+                            "Invalid nexthop group member weight {weight}, \
+                            should be in the range of 1 to 256"
+                        )));
+                    }
+                }
+            }
+        } else {
+            if self.group_type.is_some() || self.resilient.is_some() {
+                return Err(invalid_nexthop(
+                    "The group-type and resilient are only valid for \
+                    nexthop group"
+                        .into(),
+                ));
+            }
+            if self.blackhole == Some(true) {
+                if self.next_hop_iface.is_some()
+                    || self.next_hop_addr.is_some()
+                    || self.onlink == Some(true)
+                {
+                    return Err(invalid_nexthop(
+                        "Blackhole nexthop cannot have next-hop-interface, \
+                        next-hop-address or onlink"
+                            .into(),
+                    ));
+                }
+            } else if self.next_hop_iface.is_none() {
+                return Err(invalid_nexthop(
+                    "The next-hop-interface is mandatory for nexthop \
+                    which is neither group nor blackhole"
+                        .into(),
+                ));
+            }
+            if self.onlink == Some(true) && self.next_hop_addr.is_none() {
+                return Err(invalid_nexthop(
+                    "The onlink nexthop should have next-hop-address".into(),
+                ));
+            }
+        }
+        if self.resilient.is_some()
+            && self.group_type != Some(NexthopGroupType::Resilient)
+        {
+            return Err(invalid_nexthop(
+                "The resilient is only valid for nexthop group of \
+                resilient group-type"
+                    .into(),
+            ));
+        }
+        if self.resilient.as_ref().and_then(|r| r.buckets) == Some(0) {
+            return Err(invalid_nexthop(
+                "The resilient nexthop group should have at least one bucket"
+                    .into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for NexthopEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut props = Vec::new();
+        if self.is_absent() {
+            props.push("state: absent".to_string());
+        }
+        if let Some(v) = self.id {
+            props.push(format!("id: {v}"));
+        }
+        if let Some(v) = self.family {
+            props.push(format!("family: {v}"));
+        }
+        if let Some(v) = self.next_hop_iface.as_ref() {
+            props.push(format!("next-hop-interface: {v}"));
+        }
+        if let Some(v) = self.next_hop_addr.as_ref() {
+            props.push(format!("next-hop-address: {v}"));
+        }
+        if let Some(v) = self.onlink {
+            props.push(format!("onlink: {v}"));
+        }
+        if let Some(v) = self.blackhole {
+            props.push(format!("blackhole: {v}"));
+        }
+        if let Some(v) = self.group.as_ref() {
+            props.push(format!(
+                "group: [{}]",
+                v.iter()
+                    .map(|m| match m.weight {
+                        Some(w) => format!("{}/{}", m.id, w),
+                        None => m.id.to_string(),
+                    })
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ));
+        }
+        if let Some(v) = self.group_type {
+            props.push(format!("group-type: {v}"));
+        }
+        if let Some(v) = self.resilient.as_ref() {
+            if let Some(v) = v.buckets {
+                props.push(format!("buckets: {v}"));
+            }
+            if let Some(v) = v.idle_timer {
+                props.push(format!("idle-timer: {v}"));
+            }
+            if let Some(v) = v.unbalanced_timer {
+                props.push(format!("unbalanced-timer: {v}"));
+            }
+        }
+
+        write!(f, "{}", props.join(" "))
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct MergedNexthops {
+    pub(crate) desired: Nexthops,
+    pub(crate) current: Nexthops,
+    // The `for_apply` will hold two type of nexthop:
+    //  * Desired nexthops
+    //  * Current nexthops been marked as absent
+    pub(crate) for_apply: Vec<NexthopEntry>,
+}
+
+impl MergedNexthops {
+    pub(crate) fn new(
+        desired: Nexthops,
+        current: Nexthops,
+    ) -> Result<Self, NmstateError> {
+        let mut for_apply: Vec<NexthopEntry> = Vec::new();
+        let mut merged_nhs: Vec<NexthopEntry> = Vec::new();
+
+        let mut des_nhs: Vec<NexthopEntry> = Vec::new();
+        for nh in desired.config.as_deref().unwrap_or_default() {
+            let mut nh = nh.clone();
+            nh.sanitize()?;
+            des_nhs.push(nh);
+        }
+
+        let mut des_ids: HashSet<u32> = HashSet::new();
+        for nh in des_nhs.iter().filter(|n| !n.is_absent()) {
+            if let Some(id) = nh.id {
+                if !des_ids.insert(id) {
+                    let e = NmstateError::new(
+                        ErrorKind::InvalidArgument,
+                        format!("Duplicate desired nexthop ID {id}"),
+                    );
+                    log::error!("{}", e);
+                    return Err(e);
+                }
+            }
+        }
+
+        for nh in current.config.as_deref().unwrap_or_default() {
+            if des_nhs
+                .iter()
+                .filter(|n| n.is_absent())
+                .any(|absent_nh| absent_nh.is_match(nh))
+            {
+                if !nh.id.map(|id| des_ids.contains(&id)).unwrap_or_default() {
+                    let mut new_nh = nh.clone();
+                    new_nh.state = Some(NexthopState::Absent);
+                    for_apply.push(new_nh);
+                }
+            } else if !nh.id.map(|id| des_ids.contains(&id)).unwrap_or_default()
+            {
+                merged_nhs.push(nh.clone());
+            }
+        }
+
+        for nh in des_nhs.into_iter().filter(|n| !n.is_absent()) {
+            merged_nhs.push(nh.clone());
+            for_apply.push(nh);
+        }
+
+        for nh in for_apply.iter().filter(|n| !n.is_absent()) {
+            for member in nh.group.as_deref().unwrap_or_default() {
+                match merged_nhs.iter().find(|n| n.id == Some(member.id)) {
+                    Some(member_nh) if member_nh.is_group() => {
+                        let e = NmstateError::new(
+                            ErrorKind::InvalidArgument,
+                            format!(
+                                "Nexthop group member {} of nexthop {nh} \
+                                is also a nexthop group, nested nexthop \
+                                group is not supported",
+                                member.id
+                            ),
+                        );
+                        log::error!("{}", e);
+                        return Err(e);
+                    }
+                    Some(_) => (),
+                    None => {
+                        let e = NmstateError::new(
+                            ErrorKind::InvalidArgument,
+                            format!(
+                                "Nexthop group member {} of nexthop {nh} \
+                                does not exist",
+                                member.id
+                            ),
+                        );
+                        log::error!("{}", e);
+                        return Err(e);
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            desired,
+            current,
+            for_apply,
+        })
+    }
+
+    pub(crate) fn is_changed(&self) -> bool {
+        let cur_nhs = self.current.config.as_deref().unwrap_or_default();
+        self.for_apply.iter().any(|nh| {
+            nh.is_absent() || !cur_nhs.iter().any(|cur_nh| nh.is_match(cur_nh))
+        })
+    }
+}
@@ -2,10 +2,23 @@
 
 use crate::{
     nispor::{
-        dns::apply_dns_conf_to_etc,
-        hostname::set_running_hostname,
-        ip::{nmstate_ipv4_to_np, nmstate_ipv6_to_np},
-        route::gen_nispor_route_confs,
+        dns::{apply_dns_conf_to_etc, validate_dns_srvs, validate_iface_dns},
+        ethernet::{apply_sriov_eswitch, apply_sriov_vf_link_conf},
+        ethtool::apply_ethtool_netlink_conf,
+        hostname::apply_hostname,
+        hsr::create_hsr_ifaces,
+        ip::{
+            apply_ip_addr_peer_and_flags, nmstate_ipv4_to_np,
+            nmstate_ipv6_to_np,
+        },
+        macsec::{apply_macsec_static_sak, create_macsec_ifaces},
+        neighbor::apply_neighbors,
+        nexthop::{apply_nexthops, delete_nexthops},
+        resolved::apply_resolved_dns_policy,
+        route::{apply_routes_with_metrics, gen_nispor_route_confs},
+        sysctl::{
+            apply_ip_forwarding, apply_ip_sysctl, apply_ipv6_dad_and_ra_mtu,
+        },
         veth::nms_veth_conf_to_np,
         vlan::nms_vlan_conf_to_np,
     },
@@ -16,6 +29,8 @@ use crate::{
 pub(crate) async fn nispor_apply(
     merged_state: &MergedNetworkState,
 ) -> Result<(), NmstateError> {
+    validate_iface_dns(&merged_state.interfaces)?;
+    validate_dns_srvs(&merged_state.dns)?;
     delete_ifaces(&merged_state.interfaces).await?;
 
     let mut ifaces: Vec<&MergedInterface> = merged_state
@@ -36,14 +51,25 @@ pub(crate) async fn nispor_apply(
     });
 
     let mut np_ifaces: Vec<nispor::IfaceConf> = Vec::new();
+    // Nispor cannot create MACsec interface, they are created after their
+    // base interfaces been created by nispor.
+    let mut np_new_macsec_ifaces: Vec<nispor::IfaceConf> = Vec::new();
     for merged_iface in ifaces.iter().filter(|i| {
         i.merged.iface_type() != InterfaceType::Unknown && !i.merged.is_absent()
     }) {
         if let Some(iface) = merged_iface.for_apply.as_ref() {
-            np_ifaces.push(nmstate_iface_to_np(iface)?);
+            if iface.iface_type() == InterfaceType::MacSec
+                && merged_iface.current.is_none()
+            {
+                np_new_macsec_ifaces.push(nmstate_iface_to_np(iface)?);
+            } else {
+                np_ifaces.push(nmstate_iface_to_np(iface)?);
+            }
         }
     }
 
+    create_hsr_ifaces(&merged_state.interfaces).await?;
+
     let mut net_conf = nispor::NetConf::default();
     net_conf.ifaces = Some(np_ifaces);
 
@@ -51,25 +77,54 @@ pub(crate) async fn nispor_apply(
         net_conf.routes = Some(gen_nispor_route_confs(&merged_state.routes)?);
     }
 
-    if let Err(e) = net_conf.apply_async().await {
-        return Err(NmstateError::new(
-            ErrorKind::PluginFailure,
-            format!("Unknown error from nipsor plugin: {}, {}", e.kind, e.msg),
-        ));
+    np_net_conf_apply(net_conf).await?;
+
+    if !np_new_macsec_ifaces.is_empty() {
+        create_macsec_ifaces(&merged_state.interfaces).await?;
+        let mut net_conf = nispor::NetConf::default();
+        net_conf.ifaces = Some(np_new_macsec_ifaces);
+        np_net_conf_apply(net_conf).await?;
     }
 
-    if let Some(running_hostname) = merged_state
-        .hostname
-        .desired
-        .as_ref()
-        .and_then(|c| c.running.as_ref())
-    {
-        set_running_hostname(running_hostname)?;
+    apply_ip_addr_peer_and_flags(&merged_state.interfaces).await?;
+
+    // Nexthop should be created after its next hop interface and before
+    // routes using it
+    if merged_state.nexthops.is_changed() {
+        apply_nexthops(&merged_state.nexthops).await?;
+    }
+
+    if merged_state.routes.is_changed() {
+        apply_routes_with_metrics(&merged_state.routes).await?;
+    }
+
+    if merged_state.nexthops.is_changed() {
+        delete_nexthops(&merged_state.nexthops).await?;
     }
 
+    if merged_state.neighbors.is_changed() {
+        apply_neighbors(&merged_state.neighbors).await?;
+    }
+
+    // Global IPv4 forwarding will override forwarding of all interfaces,
+    // hence should be applied before interface sysctl.
+    apply_ip_forwarding(&merged_state.ip_forwarding)?;
+    apply_ip_sysctl(&merged_state.interfaces)?;
+    apply_ipv6_dad_and_ra_mtu(&merged_state.interfaces)?;
+
+    apply_sriov_eswitch(&merged_state.interfaces).await?;
+    apply_sriov_vf_link_conf(&merged_state.interfaces).await?;
+    apply_macsec_static_sak(&merged_state.interfaces).await?;
+    apply_ethtool_netlink_conf(&merged_state.interfaces).await?;
+
+    apply_hostname(&merged_state.hostname, merged_state.memory_only)?;
+
     if merged_state.dns.is_changed() {
         apply_dns_conf_to_etc(&merged_state.dns)?;
     }
+    if merged_state.dns.is_dns_policy_changed() {
+        apply_resolved_dns_policy(&merged_state.dns, true)?;
+    }
 
     Ok(())
 }
@@ -87,6 +142,18 @@ fn nmstate_iface_type_to_np(
     }
 }
 
+async fn np_net_conf_apply(
+    net_conf: nispor::NetConf,
+) -> Result<(), NmstateError> {
+    if let Err(e) = net_conf.apply_async().await {
+        return Err(NmstateError::new(
+            ErrorKind::PluginFailure,
+            format!("Unknown error from nipsor plugin: {}, {}", e.kind, e.msg),
+        ));
+    }
+    Ok(())
+}
+
 fn nmstate_iface_to_np(
     nms_iface: &Interface,
 ) -> Result<nispor::IfaceConf, NmstateError> {
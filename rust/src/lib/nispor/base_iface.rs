@@ -137,3 +137,9 @@ fn get_permanent_mac_address(iface: &nispor::Iface) -> Option<String> {
         Some(iface.permanent_mac_address.as_str().to_uppercase())
     }
 }
+
+pub(crate) fn get_iface_index(iface_name: &str) -> Option<u32> {
+    std::fs::read_to_string(format!("/sys/class/net/{iface_name}/ifindex"))
+        .ok()
+        .and_then(|i| i.trim().parse::<u32>().ok())
+}
@@ -0,0 +1,257 @@
+// SPDX-License-Identifier: Apache-2.0
+
+// Devlink netlink commands for SR-IOV eswitch which is not supported by
+// nispor yet.
+
+use std::collections::HashMap;
+
+use genetlink::GenetlinkHandle;
+
+use super::genl::{
+    find_nl_attr, genl_request, NlAttr, NlAttrValue, NmGenlFamily,
+};
+use crate::{
+    NmstateError, SrIovEswitchConfig, SrIovEswitchEncapMode,
+    SrIovEswitchInlineMode, SrIovEswitchMode,
+};
+
+const DEVLINK_CMD_PORT_GET: u8 = 5;
+const DEVLINK_CMD_ESWITCH_GET: u8 = 29;
+const DEVLINK_CMD_ESWITCH_SET: u8 = 30;
+
+const DEVLINK_ATTR_BUS_NAME: u16 = 1;
+const DEVLINK_ATTR_DEV_NAME: u16 = 2;
+const DEVLINK_ATTR_PORT_NETDEV_NAME: u16 = 7;
+const DEVLINK_ATTR_ESWITCH_MODE: u16 = 25;
+const DEVLINK_ATTR_ESWITCH_INLINE_MODE: u16 = 26;
+const DEVLINK_ATTR_ESWITCH_ENCAP_MODE: u16 = 62;
+const DEVLINK_ATTR_PORT_FLAVOUR: u16 = 77;
+const DEVLINK_ATTR_PORT_PCI_VF_NUMBER: u16 = 128;
+const DEVLINK_ATTR_PORT_EXTERNAL: u16 = 149;
+
+const DEVLINK_ESWITCH_MODE_LEGACY: u16 = 0;
+const DEVLINK_ESWITCH_MODE_SWITCHDEV: u16 = 1;
+
+const DEVLINK_ESWITCH_INLINE_MODE_NONE: u8 = 0;
+const DEVLINK_ESWITCH_INLINE_MODE_LINK: u8 = 1;
+const DEVLINK_ESWITCH_INLINE_MODE_NETWORK: u8 = 2;
+const DEVLINK_ESWITCH_INLINE_MODE_TRANSPORT: u8 = 3;
+
+const DEVLINK_ESWITCH_ENCAP_MODE_NONE: u8 = 0;
+const DEVLINK_ESWITCH_ENCAP_MODE_BASIC: u8 = 1;
+
+const DEVLINK_PORT_FLAVOUR_PCI_VF: u16 = 4;
+
+const PCI_BUS_NAME: &str = "pci";
+
+#[derive(Debug)]
+pub(crate) struct DevlinkFamily;
+
+impl NmGenlFamily for DevlinkFamily {
+    const NAME: &'static str = "devlink";
+    const VERSION: u8 = 1;
+}
+
+fn dev_handle_attrs(pci_addr: &str) -> Vec<NlAttr> {
+    vec![
+        NlAttr::new(
+            DEVLINK_ATTR_BUS_NAME,
+            NlAttrValue::String(PCI_BUS_NAME.to_string()),
+        ),
+        NlAttr::new(
+            DEVLINK_ATTR_DEV_NAME,
+            NlAttrValue::String(pci_addr.to_string()),
+        ),
+    ]
+}
+
+/// Get PCI address of specified interface, return None if not PCI device.
+pub(crate) fn get_pci_address(iface_name: &str) -> Option<String> {
+    let dev_path =
+        std::fs::read_link(format!("/sys/class/net/{iface_name}/device"))
+            .ok()?;
+    let subsystem = std::fs::read_link(format!(
+        "/sys/class/net/{iface_name}/device/subsystem"
+    ))
+    .ok()?;
+    if subsystem.file_name().and_then(|n| n.to_str()) != Some(PCI_BUS_NAME) {
+        return None;
+    }
+    dev_path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .map(|n| n.to_string())
+}
+
+pub(crate) async fn get_eswitch(
+    handle: &mut GenetlinkHandle,
+    pci_addr: &str,
+) -> Result<Option<SrIovEswitchConfig>, NmstateError> {
+    let attrs = match genl_request::<DevlinkFamily>(
+        handle,
+        DEVLINK_CMD_ESWITCH_GET,
+        dev_handle_attrs(pci_addr),
+        false,
+    )
+    .await?
+    .pop()
+    {
+        Some(a) => a,
+        None => return Ok(None),
+    };
+    let mut ret = SrIovEswitchConfig::new();
+    ret.mode = match find_nl_attr(&attrs, DEVLINK_ATTR_ESWITCH_MODE)
+        .and_then(|a| a.as_u16())
+    {
+        Some(DEVLINK_ESWITCH_MODE_LEGACY) => Some(SrIovEswitchMode::Legacy),
+        Some(DEVLINK_ESWITCH_MODE_SWITCHDEV) => {
+            Some(SrIovEswitchMode::Switchdev)
+        }
+        _ => None,
+    };
+    ret.inline_mode =
+        match find_nl_attr(&attrs, DEVLINK_ATTR_ESWITCH_INLINE_MODE)
+            .and_then(|a| a.as_u8())
+        {
+            Some(DEVLINK_ESWITCH_INLINE_MODE_NONE) => {
+                Some(SrIovEswitchInlineMode::None)
+            }
+            Some(DEVLINK_ESWITCH_INLINE_MODE_LINK) => {
+                Some(SrIovEswitchInlineMode::Link)
+            }
+            Some(DEVLINK_ESWITCH_INLINE_MODE_NETWORK) => {
+                Some(SrIovEswitchInlineMode::Network)
+            }
+            Some(DEVLINK_ESWITCH_INLINE_MODE_TRANSPORT) => {
+                Some(SrIovEswitchInlineMode::Transport)
+            }
+            _ => None,
+        };
+    ret.encap_mode = match find_nl_attr(&attrs, DEVLINK_ATTR_ESWITCH_ENCAP_MODE)
+        .and_then(|a| a.as_u8())
+    {
+        Some(DEVLINK_ESWITCH_ENCAP_MODE_NONE) => {
+            Some(SrIovEswitchEncapMode::None)
+        }
+        Some(DEVLINK_ESWITCH_ENCAP_MODE_BASIC) => {
+            Some(SrIovEswitchEncapMode::Basic)
+        }
+        _ => None,
+    };
+    if ret == SrIovEswitchConfig::default() {
+        Ok(None)
+    } else {
+        Ok(Some(ret))
+    }
+}
+
+pub(crate) async fn set_eswitch(
+    handle: &mut GenetlinkHandle,
+    iface_name: &str,
+    pci_addr: &str,
+    eswitch: &SrIovEswitchConfig,
+) -> Result<(), NmstateError> {
+    let mut attrs = dev_handle_attrs(pci_addr);
+    if let Some(mode) = eswitch.mode {
+        attrs.push(NlAttr::new(
+            DEVLINK_ATTR_ESWITCH_MODE,
+            NlAttrValue::U16(match mode {
+                SrIovEswitchMode::Legacy => DEVLINK_ESWITCH_MODE_LEGACY,
+                SrIovEswitchMode::Switchdev => DEVLINK_ESWITCH_MODE_SWITCHDEV,
+            }),
+        ));
+    }
+    if let Some(inline_mode) = eswitch.inline_mode {
+        attrs.push(NlAttr::new(
+            DEVLINK_ATTR_ESWITCH_INLINE_MODE,
+            NlAttrValue::U8(match inline_mode {
+                SrIovEswitchInlineMode::None => {
+                    DEVLINK_ESWITCH_INLINE_MODE_NONE
+                }
+                SrIovEswitchInlineMode::Link => {
+                    DEVLINK_ESWITCH_INLINE_MODE_LINK
+                }
+                SrIovEswitchInlineMode::Network => {
+                    DEVLINK_ESWITCH_INLINE_MODE_NETWORK
+                }
+                SrIovEswitchInlineMode::Transport => {
+                    DEVLINK_ESWITCH_INLINE_MODE_TRANSPORT
+                }
+            }),
+        ));
+    }
+    if let Some(encap_mode) = eswitch.encap_mode {
+        attrs.push(NlAttr::new(
+            DEVLINK_ATTR_ESWITCH_ENCAP_MODE,
+            NlAttrValue::U8(match encap_mode {
+                SrIovEswitchEncapMode::None => DEVLINK_ESWITCH_ENCAP_MODE_NONE,
+                SrIovEswitchEncapMode::Basic => {
+                    DEVLINK_ESWITCH_ENCAP_MODE_BASIC
+                }
+            }),
+        ));
+    }
+    if attrs.len() == 2 {
+        return Ok(());
+    }
+    log::info!("Setting SR-IOV eswitch of interface {iface_name}");
+    genl_request::<DevlinkFamily>(
+        handle,
+        DEVLINK_CMD_ESWITCH_SET,
+        attrs,
+        false,
+    )
+    .await
+    .map_err(|e| {
+        NmstateError::new(
+            e.kind(),
+            format!(
+                "Failed to set SR-IOV eswitch of {iface_name}: {}",
+                e.msg()
+            ),
+        )
+    })?;
+    Ok(())
+}
+
+/// Dump devlink ports to get VF representor interface names indexed by
+/// PF PCI address and VF ID.
+pub(crate) async fn get_vf_representors(
+    handle: &mut GenetlinkHandle,
+) -> Result<HashMap<(String, u32), String>, NmstateError> {
+    let mut ret = HashMap::new();
+    for attrs in genl_request::<DevlinkFamily>(
+        handle,
+        DEVLINK_CMD_PORT_GET,
+        Vec::new(),
+        true,
+    )
+    .await?
+    {
+        if find_nl_attr(&attrs, DEVLINK_ATTR_PORT_FLAVOUR)
+            .and_then(|a| a.as_u16())
+            != Some(DEVLINK_PORT_FLAVOUR_PCI_VF)
+        {
+            continue;
+        }
+        // Ignore VF of external host controller (SmartNIC)
+        if find_nl_attr(&attrs, DEVLINK_ATTR_PORT_EXTERNAL)
+            .and_then(|a| a.as_u8())
+            .unwrap_or_default()
+            > 0
+        {
+            continue;
+        }
+        if let (Some(pci_addr), Some(vf_id), Some(netdev)) = (
+            find_nl_attr(&attrs, DEVLINK_ATTR_DEV_NAME)
+                .and_then(|a| a.as_string()),
+            find_nl_attr(&attrs, DEVLINK_ATTR_PORT_PCI_VF_NUMBER)
+                .and_then(|a| a.as_u16()),
+            find_nl_attr(&attrs, DEVLINK_ATTR_PORT_NETDEV_NAME)
+                .and_then(|a| a.as_string()),
+        ) {
+            ret.insert((pci_addr, vf_id.into()), netdev);
+        }
+    }
+    Ok(ret)
+}
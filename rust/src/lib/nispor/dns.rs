@@ -5,7 +5,8 @@ use std::io::{Read, Write};
 use std::os::unix::fs::OpenOptionsExt;
 
 use crate::{
-    DnsClientState, DnsState, ErrorKind, MergedDnsState, NmstateError,
+    dns::DNS_TLS_URI_PREFIX, DnsClientState, DnsState, ErrorKind,
+    MergedDnsState, MergedInterfaces, NmstateError,
 };
 
 const ETC_RESOLV_CONF_PATH: &str = "/etc/resolv.conf";
@@ -108,3 +109,51 @@ pub(crate) fn apply_dns_conf_to_etc(
 
     Ok(())
 }
+
+// The /etc/resolv.conf cannot hold interface level DNS config
+pub(crate) fn validate_iface_dns(
+    merged_ifaces: &MergedInterfaces,
+) -> Result<(), NmstateError> {
+    for iface in merged_ifaces
+        .kernel_ifaces
+        .values()
+        .filter_map(|i| i.for_apply.as_ref())
+    {
+        if iface.base_iface().dns.as_ref().map(|d| !d.is_empty()) == Some(true)
+        {
+            let e = NmstateError::new(
+                ErrorKind::NotSupportedError,
+                format!(
+                    "Interface level DNS config of interface {} is not \
+                    supported in kernel only mode",
+                    iface.name()
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+    }
+    Ok(())
+}
+
+// The /etc/resolv.conf cannot hold DNS-over-TLS name server
+pub(crate) fn validate_dns_srvs(
+    merged_dns: &MergedDnsState,
+) -> Result<(), NmstateError> {
+    if let Some(srv) = merged_dns
+        .servers
+        .iter()
+        .find(|s| s.starts_with(DNS_TLS_URI_PREFIX))
+    {
+        let e = NmstateError::new(
+            ErrorKind::NotSupportedError,
+            format!(
+                "DNS-over-TLS name server {srv} is not supported in kernel \
+                only mode"
+            ),
+        );
+        log::error!("{}", e);
+        return Err(e);
+    }
+    Ok(())
+}
@@ -1,10 +1,25 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use std::{fs, path};
+
+use rtnetlink::{
+    packet_route::link::{
+        LinkAttribute, LinkVfInfo, VfInfo, VfInfoLinkState, VfInfoRssQueryEn,
+        VfLinkState,
+    },
+    LinkUnspec,
+};
+
+use super::{
+    devlink::{get_eswitch, get_pci_address, get_vf_representors, set_eswitch},
+    genl::new_genl_handle,
+};
 use crate::{
-    BaseInterface, EthernetConfig, EthernetDuplex, EthernetInterface,
-    SrIovConfig, SrIovVfConfig, VlanProtocol,
+    BaseInterface, ErrorKind, EthernetConfig, EthernetDuplex,
+    EthernetInterface, Interface, Interfaces, MergedInterfaces, NmstateError,
+    SrIovConfig, SrIovEswitchMode, SrIovVfConfig, SrIovVfLinkState,
+    SrIovVfStats, VlanProtocol,
 };
-use std::{fs, path};
 
 pub(crate) fn np_ethernet_to_nmstate(
     np_iface: &nispor::Iface,
@@ -91,9 +106,239 @@ fn gen_sriov_conf(sriov_info: &nispor::SriovInfo) -> SrIovConfig {
                 None
             }
         };
+        vf.link_state = match &vf_info.link_state {
+            nispor::VfLinkState::Auto => Some(SrIovVfLinkState::Auto),
+            nispor::VfLinkState::Enable => Some(SrIovVfLinkState::Enable),
+            nispor::VfLinkState::Disable => Some(SrIovVfLinkState::Disable),
+            s => {
+                log::debug!(
+                    "Got unknown link state {s:?} on SR-IOV VF {}",
+                    vf_info.id
+                );
+                None
+            }
+        };
+        vf.query_rss_en = Some(vf_info.query_rss);
+        let mut stats = SrIovVfStats::new();
+        stats.rx_packets = vf_info.state.rx_packets;
+        stats.tx_packets = vf_info.state.tx_packets;
+        stats.rx_bytes = vf_info.state.rx_bytes;
+        stats.tx_bytes = vf_info.state.tx_bytes;
+        stats.broadcast = vf_info.state.broadcast;
+        stats.multicast = vf_info.state.multicast;
+        stats.rx_dropped = vf_info.state.rx_dropped;
+        stats.tx_dropped = vf_info.state.tx_dropped;
+        vf.stats = Some(stats);
         vfs.push(vf);
     }
     ret.total_vfs = Some(vfs.len() as u32);
     ret.vfs = Some(vfs);
     ret
 }
+
+// Nispor does not support SR-IOV eswitch yet, use devlink netlink directly
+// for SR-IOV PF.
+pub(crate) async fn append_sriov_eswitch_info(ifaces: &mut Interfaces) {
+    let mut handle = None;
+    let mut representors = None;
+    for iface in ifaces.kernel_ifaces.values_mut() {
+        let iface_name = iface.name().to_string();
+        let sriov_conf = match iface {
+            Interface::Ethernet(eth_iface) => match eth_iface
+                .ethernet
+                .as_mut()
+                .and_then(|e| e.sr_iov.as_mut())
+            {
+                Some(s) => s,
+                None => continue,
+            },
+            _ => continue,
+        };
+        let pci_addr = match get_pci_address(&iface_name) {
+            Some(p) => p,
+            None => continue,
+        };
+        if handle.is_none() {
+            match new_genl_handle() {
+                Ok(h) => handle = Some(h),
+                Err(e) => {
+                    log::warn!("{e}");
+                    return;
+                }
+            }
+        }
+        let handle = match handle.as_mut() {
+            Some(h) => h,
+            None => return,
+        };
+        match get_eswitch(handle, &pci_addr).await {
+            Ok(e) => sriov_conf.eswitch = e,
+            Err(e) => {
+                log::debug!(
+                    "Failed to query SR-IOV eswitch of {iface_name}: {e}"
+                );
+            }
+        }
+        if sriov_conf.eswitch.as_ref().and_then(|e| e.mode)
+            != Some(SrIovEswitchMode::Switchdev)
+        {
+            continue;
+        }
+        if representors.is_none() {
+            match get_vf_representors(handle).await {
+                Ok(r) => representors = Some(r),
+                Err(e) => {
+                    log::debug!("Failed to query SR-IOV VF representors: {e}");
+                    representors = Some(Default::default());
+                }
+            }
+        }
+        if let (Some(vfs), Some(representors)) =
+            (sriov_conf.vfs.as_mut(), representors.as_ref())
+        {
+            for vf in vfs.iter_mut() {
+                if let Some(rep_name) =
+                    representors.get(&(pci_addr.clone(), vf.id))
+                {
+                    vf.representor = rep_name.to_string();
+                }
+            }
+        }
+    }
+}
+
+pub(crate) async fn apply_sriov_eswitch(
+    merged_ifaces: &MergedInterfaces,
+) -> Result<(), NmstateError> {
+    let mut handle = None;
+    for apply_iface in merged_ifaces
+        .kernel_ifaces
+        .values()
+        .filter(|i| i.is_changed())
+        .filter_map(|i| i.for_apply.as_ref())
+        .filter(|i| !i.is_absent())
+    {
+        let eswitch_conf = match apply_iface {
+            Interface::Ethernet(eth_iface) => match eth_iface
+                .ethernet
+                .as_ref()
+                .and_then(|e| e.sr_iov.as_ref())
+                .and_then(|s| s.eswitch.as_ref())
+            {
+                Some(e) => e,
+                None => continue,
+            },
+            _ => continue,
+        };
+        let pci_addr = match get_pci_address(apply_iface.name()) {
+            Some(p) => p,
+            None => {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "Interface {} is not PCI device, cannot set SR-IOV \
+                        eswitch",
+                        apply_iface.name()
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        };
+        if handle.is_none() {
+            handle = Some(new_genl_handle()?);
+        }
+        if let Some(handle) = handle.as_mut() {
+            set_eswitch(handle, apply_iface.name(), &pci_addr, eswitch_conf)
+                .await?;
+        }
+    }
+    Ok(())
+}
+
+// Neither nispor nor NetworkManager support VF link state and RSS query
+// yet, use rtnetlink directly.
+pub(crate) async fn apply_sriov_vf_link_conf(
+    merged_ifaces: &MergedInterfaces,
+) -> Result<(), NmstateError> {
+    let mut handle = None;
+    for apply_iface in merged_ifaces
+        .kernel_ifaces
+        .values()
+        .filter(|i| i.is_changed())
+        .filter_map(|i| i.for_apply.as_ref())
+        .filter(|i| !i.is_absent())
+    {
+        let vfs = match apply_iface {
+            Interface::Ethernet(eth_iface) => match eth_iface
+                .ethernet
+                .as_ref()
+                .and_then(|e| e.sr_iov.as_ref())
+                .and_then(|s| s.vfs.as_ref())
+            {
+                Some(v) => v,
+                None => continue,
+            },
+            _ => continue,
+        };
+        let vf_infos: Vec<LinkVfInfo> =
+            vfs.iter().filter_map(gen_vf_link_info).collect();
+        if vf_infos.is_empty() {
+            continue;
+        }
+        if handle.is_none() {
+            let (conn, h, _) = rtnetlink::new_connection().map_err(|e| {
+                NmstateError::new(
+                    ErrorKind::Bug,
+                    format!("Failed to create route netlink connection: {e}"),
+                )
+            })?;
+            tokio::spawn(conn);
+            handle = Some(h);
+        }
+        if let Some(handle) = handle.as_ref() {
+            log::info!(
+                "Setting SR-IOV VF link state and RSS query of {}",
+                apply_iface.name()
+            );
+            let message = LinkUnspec::new_with_name(apply_iface.name())
+                .append_extra_attribute(LinkAttribute::VfInfoList(vf_infos))
+                .build();
+            handle.link().set(message).execute().await.map_err(|e| {
+                let e = NmstateError::new(
+                    ErrorKind::PluginFailure,
+                    format!(
+                        "Failed to set SR-IOV VF link state and RSS query \
+                        of {}: {e}",
+                        apply_iface.name()
+                    ),
+                );
+                log::error!("{}", e);
+                e
+            })?;
+        }
+    }
+    Ok(())
+}
+
+fn gen_vf_link_info(vf: &SrIovVfConfig) -> Option<LinkVfInfo> {
+    let mut vf_info = Vec::new();
+    if let Some(link_state) = vf.link_state {
+        vf_info.push(VfInfo::LinkState(VfInfoLinkState::new(
+            vf.id,
+            match link_state {
+                SrIovVfLinkState::Auto => VfLinkState::Auto,
+                SrIovVfLinkState::Enable => VfLinkState::Enable,
+                SrIovVfLinkState::Disable => VfLinkState::Disable,
+            },
+        )));
+    }
+    if let Some(enabled) = vf.query_rss_en {
+        vf_info.push(VfInfo::RssQueryEn(VfInfoRssQueryEn::new(vf.id, enabled)));
+    }
+    if vf_info.is_empty() {
+        None
+    } else {
+        Some(LinkVfInfo(vf_info))
+    }
+}
@@ -1,7 +1,16 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use super::{
+    ethtool_netlink::{
+        get_channels, get_eee, get_fec, get_link_modes, get_private_flags,
+        get_rss, get_wol, set_channels, set_eee, set_fec, set_link_modes,
+        set_private_flags, set_rss, set_wol,
+    },
+    genl::new_genl_handle,
+};
 use crate::{
-    EthtoolCoalesceConfig, EthtoolConfig, EthtoolPauseConfig, EthtoolRingConfig,
+    EthtoolCoalesceConfig, EthtoolConfig, EthtoolPauseConfig,
+    EthtoolRingConfig, Interface, Interfaces, MergedInterfaces, NmstateError,
 };
 
 pub(crate) fn np_ethtool_to_nmstate(
@@ -63,3 +72,183 @@ fn gen_ethtool_config(ethtool_info: &nispor::EthtoolInfo) -> EthtoolConfig {
     }
     ret
 }
+
+// Nispor does not support querying ethtool channels, RSS, link modes, FEC,
+// EEE, private flags and Wake-on-LAN yet, use ethtool netlink directly for
+// interfaces supporting ethtool.
+pub(crate) async fn append_ethtool_netlink_info(ifaces: &mut Interfaces) {
+    let mut handle = match new_genl_handle() {
+        Ok(h) => h,
+        Err(e) => {
+            log::warn!("{e}");
+            return;
+        }
+    };
+    for iface in ifaces.kernel_ifaces.values_mut() {
+        let iface_name = iface.name().to_string();
+        if let Some(ethtool_conf) = iface.base_iface_mut().ethtool.as_mut() {
+            match get_channels(&mut handle, &iface_name).await {
+                Ok(c) => ethtool_conf.channels = c,
+                Err(e) => log::debug!(
+                    "Failed to query ethtool channels of {iface_name}: {e}"
+                ),
+            }
+            match get_rss(&mut handle, &iface_name).await {
+                Ok(r) => ethtool_conf.rss = r,
+                Err(e) => log::debug!(
+                    "Failed to query ethtool RSS of {iface_name}: {e}"
+                ),
+            }
+            match get_link_modes(&mut handle, &iface_name).await {
+                Ok(l) => ethtool_conf.link_modes = l,
+                Err(e) => log::debug!(
+                    "Failed to query ethtool link modes of {iface_name}: {e}"
+                ),
+            }
+            match get_fec(&mut handle, &iface_name).await {
+                Ok(f) => ethtool_conf.fec = f,
+                Err(e) => log::debug!(
+                    "Failed to query ethtool FEC of {iface_name}: {e}"
+                ),
+            }
+            match get_eee(&mut handle, &iface_name).await {
+                Ok(e) => ethtool_conf.eee = e,
+                Err(e) => log::debug!(
+                    "Failed to query ethtool EEE of {iface_name}: {e}"
+                ),
+            }
+            match get_private_flags(&mut handle, &iface_name).await {
+                Ok(p) => ethtool_conf.private_flags = p,
+                Err(e) => log::debug!(
+                    "Failed to query ethtool private flags of \
+                    {iface_name}: {e}"
+                ),
+            }
+        }
+        if let Interface::Ethernet(eth_iface) = iface {
+            if let Some(eth_conf) = eth_iface.ethernet.as_mut() {
+                match get_wol(&mut handle, &iface_name).await {
+                    Ok(w) => eth_conf.wake_on_lan = w,
+                    Err(e) => log::debug!(
+                        "Failed to query Wake-on-LAN of {iface_name}: {e}"
+                    ),
+                }
+            }
+        }
+    }
+}
+
+/// Apply ethtool and Wake-on-LAN settings not supported by nispor.
+pub(crate) async fn apply_ethtool_netlink_conf(
+    merged_ifaces: &MergedInterfaces,
+) -> Result<(), NmstateError> {
+    let mut handle = None;
+    for apply_iface in merged_ifaces
+        .kernel_ifaces
+        .values()
+        .filter(|i| i.is_changed())
+        .filter_map(|i| i.for_apply.as_ref())
+        .filter(|i| !i.is_absent())
+    {
+        let wol_conf = if let Interface::Ethernet(eth_iface) = apply_iface {
+            eth_iface
+                .ethernet
+                .as_ref()
+                .and_then(|e| e.wake_on_lan.as_ref())
+        } else {
+            None
+        };
+        if let Some(wol_conf) = wol_conf {
+            if handle.is_none() {
+                handle = Some(new_genl_handle()?);
+            }
+            if let Some(handle) = handle.as_mut() {
+                set_wol(handle, apply_iface.name(), wol_conf).await?;
+            }
+        }
+        let ethtool_conf = match apply_iface.base_iface().ethtool.as_ref() {
+            Some(e) => e,
+            None => continue,
+        };
+        if handle.is_none() {
+            handle = Some(new_genl_handle()?);
+        }
+        let iface_name = apply_iface.name();
+        if let Some(handle) = handle.as_mut() {
+            if let Some(channels) = ethtool_conf.channels.as_ref() {
+                set_channels(handle, iface_name, channels).await?;
+            }
+            if let Some(fec) = ethtool_conf.fec.as_ref() {
+                set_fec(handle, iface_name, fec).await?;
+            }
+            if let Some(rss) = ethtool_conf.rss.as_ref() {
+                set_rss(handle, iface_name, rss).await?;
+            }
+            if let Some(link_modes) = ethtool_conf.link_modes.as_ref() {
+                set_link_modes(handle, iface_name, link_modes).await?;
+            }
+            if let Some(eee) = ethtool_conf.eee.as_ref() {
+                set_eee(handle, iface_name, eee).await?;
+            }
+            if let Some(private_flags) = ethtool_conf.private_flags.as_ref() {
+                set_private_flags(handle, iface_name, private_flags).await?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// NetworkManager does not support ethtool RSS, advertised link modes, EEE
+/// low power idle settings and private flags, hence apply them to kernel
+/// directly after NetworkManager activation.
+pub(crate) async fn apply_nm_unsupported_ethtool_conf(
+    merged_ifaces: &MergedInterfaces,
+) -> Result<(), NmstateError> {
+    let mut handle = None;
+    for apply_iface in merged_ifaces
+        .kernel_ifaces
+        .values()
+        .filter(|i| i.is_changed())
+        .filter_map(|i| i.for_apply.as_ref())
+        .filter(|i| !i.is_absent())
+    {
+        let ethtool_conf = match apply_iface.base_iface().ethtool.as_ref() {
+            Some(e) => e,
+            None => continue,
+        };
+        // The EEE `enabled` is supported by NetworkManager
+        let eee_lpi_conf = ethtool_conf
+            .eee
+            .filter(|e| e.tx_lpi_enabled.is_some() || e.tx_lpi_timer.is_some())
+            .map(|mut eee| {
+                eee.enabled = None;
+                eee
+            });
+        if ethtool_conf.rss.is_none()
+            && ethtool_conf.link_modes.is_none()
+            && eee_lpi_conf.is_none()
+            && ethtool_conf.private_flags.is_none()
+        {
+            continue;
+        }
+        if handle.is_none() {
+            handle = Some(new_genl_handle()?);
+        }
+        let iface_name = apply_iface.name();
+        if let Some(handle) = handle.as_mut() {
+            if let Some(rss) = ethtool_conf.rss.as_ref() {
+                set_rss(handle, iface_name, rss).await?;
+            }
+            if let Some(link_modes) = ethtool_conf.link_modes.as_ref() {
+                set_link_modes(handle, iface_name, link_modes).await?;
+            }
+            if let Some(eee) = eee_lpi_conf.as_ref() {
+                set_eee(handle, iface_name, eee).await?;
+            }
+            if let Some(private_flags) = ethtool_conf.private_flags.as_ref() {
+                set_private_flags(handle, iface_name, private_flags).await?;
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,765 @@
+// SPDX-License-Identifier: Apache-2.0
+
+// Ethtool netlink commands not supported by nispor yet.
+
+use std::collections::HashMap;
+
+use genetlink::GenetlinkHandle;
+
+use super::genl::{
+    find_nl_attr, genl_request, parse_nl_attrs, NlAttr, NlAttrValue,
+    NmGenlFamily,
+};
+use crate::{
+    ifaces::{parse_hex_bytes, parse_secureon_password},
+    ErrorKind, EthernetWakeOnLanConfig, EthernetWakeOnLanMode,
+    EthtoolChannelsConfig, EthtoolEeeConfig, EthtoolFeatureConfig,
+    EthtoolFecConfig, EthtoolFecMode, EthtoolLinkModesConfig, EthtoolRssConfig,
+    EthtoolRssHashFunction, NmstateError,
+};
+
+pub(crate) const ETHTOOL_MSG_LINKMODES_GET: u8 = 4;
+pub(crate) const ETHTOOL_MSG_LINKMODES_SET: u8 = 5;
+pub(crate) const ETHTOOL_MSG_WOL_GET: u8 = 9;
+pub(crate) const ETHTOOL_MSG_WOL_SET: u8 = 10;
+pub(crate) const ETHTOOL_MSG_PRIVFLAGS_GET: u8 = 13;
+pub(crate) const ETHTOOL_MSG_PRIVFLAGS_SET: u8 = 14;
+pub(crate) const ETHTOOL_MSG_CHANNELS_GET: u8 = 17;
+pub(crate) const ETHTOOL_MSG_CHANNELS_SET: u8 = 18;
+pub(crate) const ETHTOOL_MSG_EEE_GET: u8 = 23;
+pub(crate) const ETHTOOL_MSG_EEE_SET: u8 = 24;
+pub(crate) const ETHTOOL_MSG_FEC_GET: u8 = 29;
+pub(crate) const ETHTOOL_MSG_FEC_SET: u8 = 30;
+pub(crate) const ETHTOOL_MSG_RSS_GET: u8 = 38;
+pub(crate) const ETHTOOL_MSG_RSS_SET: u8 = 48;
+
+const ETHTOOL_A_HEADER_DEV_NAME: u16 = 2;
+
+const ETHTOOL_A_BITSET_NOMASK: u16 = 1;
+const ETHTOOL_A_BITSET_BITS: u16 = 3;
+const ETHTOOL_A_BITSET_BITS_BIT: u16 = 1;
+const ETHTOOL_A_BITSET_BIT_NAME: u16 = 2;
+const ETHTOOL_A_BITSET_BIT_VALUE: u16 = 3;
+
+const ETHTOOL_A_LINKMODES_HEADER: u16 = 1;
+const ETHTOOL_A_LINKMODES_OURS: u16 = 3;
+
+const ETHTOOL_A_WOL_HEADER: u16 = 1;
+const ETHTOOL_A_WOL_MODES: u16 = 2;
+const ETHTOOL_A_WOL_SOPASS: u16 = 3;
+
+const ETHTOOL_A_PRIVFLAGS_HEADER: u16 = 1;
+const ETHTOOL_A_PRIVFLAGS_FLAGS: u16 = 2;
+
+const ETHTOOL_A_EEE_HEADER: u16 = 1;
+const ETHTOOL_A_EEE_ACTIVE: u16 = 4;
+const ETHTOOL_A_EEE_ENABLED: u16 = 5;
+const ETHTOOL_A_EEE_TX_LPI_ENABLED: u16 = 6;
+const ETHTOOL_A_EEE_TX_LPI_TIMER: u16 = 7;
+
+const ETHTOOL_A_FEC_HEADER: u16 = 1;
+const ETHTOOL_A_FEC_MODES: u16 = 2;
+const ETHTOOL_A_FEC_AUTO: u16 = 3;
+const ETHTOOL_A_FEC_ACTIVE: u16 = 4;
+
+// Index of FEC bits in ethtool link mode bitset
+const ETHTOOL_LINK_MODE_FEC_NONE_BIT: u32 = 49;
+const ETHTOOL_LINK_MODE_FEC_RS_BIT: u32 = 50;
+const ETHTOOL_LINK_MODE_FEC_BASER_BIT: u32 = 51;
+const ETHTOOL_LINK_MODE_FEC_LLRS_BIT: u32 = 74;
+
+// Name of bits in ethtool Wake-on-LAN bitset
+const WOL_NAMES: [(&str, EthernetWakeOnLanMode); 7] = [
+    ("phy", EthernetWakeOnLanMode::Phy),
+    ("ucast", EthernetWakeOnLanMode::Unicast),
+    ("mcast", EthernetWakeOnLanMode::Multicast),
+    ("bcast", EthernetWakeOnLanMode::Broadcast),
+    ("arp", EthernetWakeOnLanMode::Arp),
+    ("magic", EthernetWakeOnLanMode::Magic),
+    ("magicsecure", EthernetWakeOnLanMode::Secureon),
+];
+
+// Name of FEC bits in ethtool link mode bitset
+const FEC_NAMES: [(&str, EthtoolFecMode); 4] = [
+    ("None", EthtoolFecMode::Off),
+    ("RS", EthtoolFecMode::Rs),
+    ("BASER", EthtoolFecMode::Baser),
+    ("LLRS", EthtoolFecMode::Llrs),
+];
+
+const ETHTOOL_A_CHANNELS_HEADER: u16 = 1;
+const ETHTOOL_A_CHANNELS_RX_MAX: u16 = 2;
+const ETHTOOL_A_CHANNELS_TX_MAX: u16 = 3;
+const ETHTOOL_A_CHANNELS_OTHER_MAX: u16 = 4;
+const ETHTOOL_A_CHANNELS_COMBINED_MAX: u16 = 5;
+const ETHTOOL_A_CHANNELS_RX_COUNT: u16 = 6;
+const ETHTOOL_A_CHANNELS_TX_COUNT: u16 = 7;
+const ETHTOOL_A_CHANNELS_OTHER_COUNT: u16 = 8;
+const ETHTOOL_A_CHANNELS_COMBINED_COUNT: u16 = 9;
+
+const ETHTOOL_A_RSS_HEADER: u16 = 1;
+const ETHTOOL_A_RSS_HFUNC: u16 = 3;
+const ETHTOOL_A_RSS_INDIR: u16 = 4;
+const ETHTOOL_A_RSS_HKEY: u16 = 5;
+
+const ETH_RSS_HASH_TOP: u32 = 1 << 0;
+const ETH_RSS_HASH_XOR: u32 = 1 << 1;
+const ETH_RSS_HASH_CRC32: u32 = 1 << 2;
+
+#[derive(Debug)]
+pub(crate) struct EthtoolFamily;
+
+impl NmGenlFamily for EthtoolFamily {
+    const NAME: &'static str = "ethtool";
+    const VERSION: u8 = 1;
+}
+
+fn header_attr(kind: u16, iface_name: &str) -> NlAttr {
+    NlAttr::new(
+        kind,
+        NlAttrValue::Nested(vec![NlAttr::new(
+            ETHTOOL_A_HEADER_DEV_NAME,
+            NlAttrValue::String(iface_name.to_string()),
+        )]),
+    )
+}
+
+async fn ethtool_get(
+    handle: &mut GenetlinkHandle,
+    cmd: u8,
+    header_kind: u16,
+    iface_name: &str,
+) -> Result<Option<Vec<NlAttr>>, NmstateError> {
+    let mut replies = genl_request::<EthtoolFamily>(
+        handle,
+        cmd,
+        vec![header_attr(header_kind, iface_name)],
+        false,
+    )
+    .await?;
+    Ok(replies.pop())
+}
+
+async fn ethtool_set(
+    handle: &mut GenetlinkHandle,
+    cmd: u8,
+    attrs: Vec<NlAttr>,
+    iface_name: &str,
+) -> Result<(), NmstateError> {
+    genl_request::<EthtoolFamily>(handle, cmd, attrs, false)
+        .await
+        .map_err(|e| {
+            NmstateError::new(
+                e.kind(),
+                format!("Failed to set ethtool of {iface_name}: {}", e.msg()),
+            )
+        })?;
+    Ok(())
+}
+
+pub(crate) async fn get_channels(
+    handle: &mut GenetlinkHandle,
+    iface_name: &str,
+) -> Result<Option<EthtoolChannelsConfig>, NmstateError> {
+    let attrs = match ethtool_get(
+        handle,
+        ETHTOOL_MSG_CHANNELS_GET,
+        ETHTOOL_A_CHANNELS_HEADER,
+        iface_name,
+    )
+    .await?
+    {
+        Some(a) => a,
+        None => return Ok(None),
+    };
+    // Kernel use 0 maximum to indicate channel type not supported
+    let get_pair = |max_kind: u16, count_kind: u16| -> (u32, u32) {
+        let max = find_nl_attr(&attrs, max_kind)
+            .and_then(|a| a.as_u32())
+            .unwrap_or_default();
+        let count = find_nl_attr(&attrs, count_kind)
+            .and_then(|a| a.as_u32())
+            .unwrap_or_default();
+        (max, count)
+    };
+
+    let mut ret = EthtoolChannelsConfig::new();
+    let (max, count) =
+        get_pair(ETHTOOL_A_CHANNELS_RX_MAX, ETHTOOL_A_CHANNELS_RX_COUNT);
+    if max > 0 {
+        ret.rx_max = Some(max);
+        ret.rx = Some(count);
+    }
+    let (max, count) =
+        get_pair(ETHTOOL_A_CHANNELS_TX_MAX, ETHTOOL_A_CHANNELS_TX_COUNT);
+    if max > 0 {
+        ret.tx_max = Some(max);
+        ret.tx = Some(count);
+    }
+    let (max, count) =
+        get_pair(ETHTOOL_A_CHANNELS_OTHER_MAX, ETHTOOL_A_CHANNELS_OTHER_COUNT);
+    if max > 0 {
+        ret.other_max = Some(max);
+        ret.other = Some(count);
+    }
+    let (max, count) = get_pair(
+        ETHTOOL_A_CHANNELS_COMBINED_MAX,
+        ETHTOOL_A_CHANNELS_COMBINED_COUNT,
+    );
+    if max > 0 {
+        ret.combined_max = Some(max);
+        ret.combined = Some(count);
+    }
+    if ret == EthtoolChannelsConfig::default() {
+        Ok(None)
+    } else {
+        Ok(Some(ret))
+    }
+}
+
+pub(crate) async fn set_channels(
+    handle: &mut GenetlinkHandle,
+    iface_name: &str,
+    channels: &EthtoolChannelsConfig,
+) -> Result<(), NmstateError> {
+    let mut attrs = vec![header_attr(ETHTOOL_A_CHANNELS_HEADER, iface_name)];
+    for (kind, value) in [
+        (ETHTOOL_A_CHANNELS_RX_COUNT, channels.rx),
+        (ETHTOOL_A_CHANNELS_TX_COUNT, channels.tx),
+        (ETHTOOL_A_CHANNELS_OTHER_COUNT, channels.other),
+        (ETHTOOL_A_CHANNELS_COMBINED_COUNT, channels.combined),
+    ] {
+        if let Some(v) = value {
+            attrs.push(NlAttr::new(kind, NlAttrValue::U32(v)));
+        }
+    }
+    if attrs.len() == 1 {
+        return Ok(());
+    }
+    log::info!("Setting ethtool channels of interface {iface_name}");
+    ethtool_set(handle, ETHTOOL_MSG_CHANNELS_SET, attrs, iface_name).await
+}
+
+fn hfunc_to_nmstate(hfunc: u32) -> Option<EthtoolRssHashFunction> {
+    match hfunc {
+        ETH_RSS_HASH_TOP => Some(EthtoolRssHashFunction::Toeplitz),
+        ETH_RSS_HASH_XOR => Some(EthtoolRssHashFunction::Xor),
+        ETH_RSS_HASH_CRC32 => Some(EthtoolRssHashFunction::Crc32),
+        _ => None,
+    }
+}
+
+fn nmstate_hfunc_to_kernel(hfunc: EthtoolRssHashFunction) -> u32 {
+    match hfunc {
+        EthtoolRssHashFunction::Toeplitz => ETH_RSS_HASH_TOP,
+        EthtoolRssHashFunction::Xor => ETH_RSS_HASH_XOR,
+        EthtoolRssHashFunction::Crc32 => ETH_RSS_HASH_CRC32,
+    }
+}
+
+pub(crate) async fn get_rss(
+    handle: &mut GenetlinkHandle,
+    iface_name: &str,
+) -> Result<Option<EthtoolRssConfig>, NmstateError> {
+    let attrs = match ethtool_get(
+        handle,
+        ETHTOOL_MSG_RSS_GET,
+        ETHTOOL_A_RSS_HEADER,
+        iface_name,
+    )
+    .await?
+    {
+        Some(a) => a,
+        None => return Ok(None),
+    };
+    let mut ret = EthtoolRssConfig::new();
+    ret.hash_function = find_nl_attr(&attrs, ETHTOOL_A_RSS_HFUNC)
+        .and_then(|a| a.as_u32())
+        .and_then(hfunc_to_nmstate);
+    ret.hash_key = find_nl_attr(&attrs, ETHTOOL_A_RSS_HKEY)
+        .map(|a| a.as_bytes())
+        .filter(|k| !k.is_empty())
+        .map(|k| {
+            k.iter()
+                .map(|b| format!("{b:02x}"))
+                .collect::<Vec<String>>()
+                .join(":")
+        });
+    ret.indirection_table = find_nl_attr(&attrs, ETHTOOL_A_RSS_INDIR)
+        .map(|a| a.as_bytes())
+        .filter(|t| !t.is_empty())
+        .map(|t| {
+            t.chunks_exact(4)
+                .map(|b| u32::from_ne_bytes([b[0], b[1], b[2], b[3]]))
+                .collect()
+        });
+    if ret == EthtoolRssConfig::default() {
+        Ok(None)
+    } else {
+        Ok(Some(ret))
+    }
+}
+
+pub(crate) async fn set_rss(
+    handle: &mut GenetlinkHandle,
+    iface_name: &str,
+    rss: &EthtoolRssConfig,
+) -> Result<(), NmstateError> {
+    let mut rss = rss.clone();
+    if rss.equal.is_some() {
+        let table_size = get_rss(handle, iface_name)
+            .await?
+            .and_then(|r| r.indirection_table)
+            .map(|t| t.len())
+            .unwrap_or_default();
+        if table_size == 0 {
+            return Err(NmstateError::new(
+                ErrorKind::NotSupportedError,
+                format!(
+                    "Interface {iface_name} does not support RSS \
+                    indirection table"
+                ),
+            ));
+        }
+        rss.expand_equal(table_size);
+    }
+
+    let mut attrs = vec![header_attr(ETHTOOL_A_RSS_HEADER, iface_name)];
+    if let Some(hfunc) = rss.hash_function {
+        attrs.push(NlAttr::new(
+            ETHTOOL_A_RSS_HFUNC,
+            NlAttrValue::U32(nmstate_hfunc_to_kernel(hfunc)),
+        ));
+    }
+    if let Some(key) = rss.hash_key.as_deref().and_then(parse_hex_bytes) {
+        attrs.push(NlAttr::new(ETHTOOL_A_RSS_HKEY, NlAttrValue::Binary(key)));
+    }
+    if let Some(table) = rss.indirection_table.as_ref() {
+        attrs.push(NlAttr::new(
+            ETHTOOL_A_RSS_INDIR,
+            NlAttrValue::Binary(
+                table.iter().flat_map(|i| i.to_ne_bytes()).collect(),
+            ),
+        ));
+    }
+    if attrs.len() == 1 {
+        return Ok(());
+    }
+    log::info!("Setting ethtool RSS of interface {iface_name}");
+    ethtool_set(handle, ETHTOOL_MSG_RSS_SET, attrs, iface_name).await
+}
+
+// Parse the verbose ethtool bitset into list of bit name and whether it is
+// set. When bitset has no mask, only set bits are included.
+fn parse_bitset(attr: &NlAttr) -> Vec<(String, bool)> {
+    let mut ret = Vec::new();
+    let attrs = match parse_nl_attrs(attr.as_bytes()) {
+        Ok(a) => a,
+        Err(e) => {
+            log::debug!("Failed to parse ethtool bitset: {e}");
+            return ret;
+        }
+    };
+    let no_mask = find_nl_attr(&attrs, ETHTOOL_A_BITSET_NOMASK).is_some();
+    let bits = match find_nl_attr(&attrs, ETHTOOL_A_BITSET_BITS)
+        .and_then(|a| parse_nl_attrs(a.as_bytes()).ok())
+    {
+        Some(b) => b,
+        None => return ret,
+    };
+    for bit in bits
+        .iter()
+        .filter(|b| b.kind == ETHTOOL_A_BITSET_BITS_BIT)
+        .filter_map(|b| parse_nl_attrs(b.as_bytes()).ok())
+    {
+        if let Some(name) = find_nl_attr(&bit, ETHTOOL_A_BITSET_BIT_NAME)
+            .and_then(|a| a.as_string())
+        {
+            let is_set = no_mask
+                || find_nl_attr(&bit, ETHTOOL_A_BITSET_BIT_VALUE).is_some();
+            ret.push((name, is_set));
+        }
+    }
+    ret
+}
+
+// Generate the verbose ethtool bitset using bit names. When `no_mask` is
+// true, bits not included will be cleared by kernel.
+fn gen_bitset(kind: u16, bits: &[(&str, bool)], no_mask: bool) -> NlAttr {
+    let mut attrs = Vec::new();
+    if no_mask {
+        attrs.push(NlAttr::new(ETHTOOL_A_BITSET_NOMASK, NlAttrValue::Flag));
+    }
+    let mut bit_attrs = Vec::new();
+    for (name, is_set) in bits {
+        let mut bit = vec![NlAttr::new(
+            ETHTOOL_A_BITSET_BIT_NAME,
+            NlAttrValue::String(name.to_string()),
+        )];
+        if *is_set {
+            bit.push(NlAttr::new(
+                ETHTOOL_A_BITSET_BIT_VALUE,
+                NlAttrValue::Flag,
+            ));
+        }
+        bit_attrs.push(NlAttr::new(
+            ETHTOOL_A_BITSET_BITS_BIT,
+            NlAttrValue::Nested(bit),
+        ));
+    }
+    attrs.push(NlAttr::new(
+        ETHTOOL_A_BITSET_BITS,
+        NlAttrValue::Nested(bit_attrs),
+    ));
+    NlAttr::new(kind, NlAttrValue::Nested(attrs))
+}
+
+// The link mode bitset also contains port types, pause and FEC bits, only
+// keep real link modes like `25000baseCR/Full`.
+fn is_link_mode_name(name: &str) -> bool {
+    name.contains("base")
+}
+
+pub(crate) async fn get_link_modes(
+    handle: &mut GenetlinkHandle,
+    iface_name: &str,
+) -> Result<Option<EthtoolLinkModesConfig>, NmstateError> {
+    let attrs = match ethtool_get(
+        handle,
+        ETHTOOL_MSG_LINKMODES_GET,
+        ETHTOOL_A_LINKMODES_HEADER,
+        iface_name,
+    )
+    .await?
+    {
+        Some(a) => a,
+        None => return Ok(None),
+    };
+    let bits = find_nl_attr(&attrs, ETHTOOL_A_LINKMODES_OURS)
+        .map(parse_bitset)
+        .unwrap_or_default();
+    let mut supported = Vec::new();
+    let mut advertised = Vec::new();
+    for (name, is_set) in bits {
+        if is_link_mode_name(name.as_str()) {
+            if is_set {
+                advertised.push(name.clone());
+            }
+            supported.push(name);
+        }
+    }
+    if supported.is_empty() {
+        return Ok(None);
+    }
+    let mut ret = EthtoolLinkModesConfig::new();
+    ret.supported = Some(supported);
+    ret.advertised = Some(advertised);
+    Ok(Some(ret))
+}
+
+pub(crate) async fn set_link_modes(
+    handle: &mut GenetlinkHandle,
+    iface_name: &str,
+    link_modes: &EthtoolLinkModesConfig,
+) -> Result<(), NmstateError> {
+    let advertised = match link_modes.advertised.as_ref() {
+        Some(a) => a,
+        None => return Ok(()),
+    };
+    let supported = get_link_modes(handle, iface_name)
+        .await?
+        .and_then(|l| l.supported)
+        .unwrap_or_default();
+    for mode in advertised {
+        if !supported.contains(mode) {
+            let e = NmstateError::new(
+                ErrorKind::NotSupportedError,
+                format!(
+                    "Interface {iface_name} does not support link mode \
+                    {mode}, supported link modes are: {}",
+                    supported.join(", ")
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+    }
+    // Only touch the link mode bits, leaving pause and port bits untouched.
+    let bits: Vec<(&str, bool)> = supported
+        .iter()
+        .map(|m| (m.as_str(), advertised.contains(m)))
+        .collect();
+    log::info!("Setting ethtool advertised link modes of {iface_name}");
+    ethtool_set(
+        handle,
+        ETHTOOL_MSG_LINKMODES_SET,
+        vec![
+            header_attr(ETHTOOL_A_LINKMODES_HEADER, iface_name),
+            gen_bitset(ETHTOOL_A_LINKMODES_OURS, bits.as_slice(), false),
+        ],
+        iface_name,
+    )
+    .await
+}
+
+pub(crate) async fn get_fec(
+    handle: &mut GenetlinkHandle,
+    iface_name: &str,
+) -> Result<Option<EthtoolFecConfig>, NmstateError> {
+    let attrs = match ethtool_get(
+        handle,
+        ETHTOOL_MSG_FEC_GET,
+        ETHTOOL_A_FEC_HEADER,
+        iface_name,
+    )
+    .await?
+    {
+        Some(a) => a,
+        None => return Ok(None),
+    };
+    let mut ret = EthtoolFecConfig::new();
+    if find_nl_attr(&attrs, ETHTOOL_A_FEC_AUTO).and_then(|a| a.as_u8())
+        == Some(1)
+    {
+        ret.mode = Some(EthtoolFecMode::Auto);
+    } else if let Some(bits) =
+        find_nl_attr(&attrs, ETHTOOL_A_FEC_MODES).map(parse_bitset)
+    {
+        ret.mode =
+            bits.iter()
+                .filter(|(_, is_set)| *is_set)
+                .find_map(|(name, _)| {
+                    FEC_NAMES
+                        .iter()
+                        .find(|(n, _)| n == name)
+                        .map(|(_, mode)| *mode)
+                });
+    }
+    ret.active = match find_nl_attr(&attrs, ETHTOOL_A_FEC_ACTIVE)
+        .and_then(|a| a.as_u32())
+    {
+        Some(ETHTOOL_LINK_MODE_FEC_NONE_BIT) => Some(EthtoolFecMode::Off),
+        Some(ETHTOOL_LINK_MODE_FEC_RS_BIT) => Some(EthtoolFecMode::Rs),
+        Some(ETHTOOL_LINK_MODE_FEC_BASER_BIT) => Some(EthtoolFecMode::Baser),
+        Some(ETHTOOL_LINK_MODE_FEC_LLRS_BIT) => Some(EthtoolFecMode::Llrs),
+        _ => None,
+    };
+    if ret == EthtoolFecConfig::default() {
+        Ok(None)
+    } else {
+        Ok(Some(ret))
+    }
+}
+
+pub(crate) async fn set_fec(
+    handle: &mut GenetlinkHandle,
+    iface_name: &str,
+    fec: &EthtoolFecConfig,
+) -> Result<(), NmstateError> {
+    let mode = match fec.mode {
+        Some(m) => m,
+        None => return Ok(()),
+    };
+    let mut attrs = vec![header_attr(ETHTOOL_A_FEC_HEADER, iface_name)];
+    if mode == EthtoolFecMode::Auto {
+        attrs.push(NlAttr::new(ETHTOOL_A_FEC_AUTO, NlAttrValue::U8(1)));
+    } else {
+        attrs.push(NlAttr::new(ETHTOOL_A_FEC_AUTO, NlAttrValue::U8(0)));
+        if let Some((name, _)) = FEC_NAMES.iter().find(|(_, m)| *m == mode) {
+            attrs.push(gen_bitset(ETHTOOL_A_FEC_MODES, &[(name, true)], true));
+        }
+    }
+    log::info!("Setting ethtool FEC mode of {iface_name} to {mode}");
+    ethtool_set(handle, ETHTOOL_MSG_FEC_SET, attrs, iface_name).await
+}
+
+pub(crate) async fn get_eee(
+    handle: &mut GenetlinkHandle,
+    iface_name: &str,
+) -> Result<Option<EthtoolEeeConfig>, NmstateError> {
+    let attrs = match ethtool_get(
+        handle,
+        ETHTOOL_MSG_EEE_GET,
+        ETHTOOL_A_EEE_HEADER,
+        iface_name,
+    )
+    .await?
+    {
+        Some(a) => a,
+        None => return Ok(None),
+    };
+    let get_bool = |kind: u16| -> Option<bool> {
+        find_nl_attr(&attrs, kind)
+            .and_then(|a| a.as_u8())
+            .map(|v| v > 0)
+    };
+    let mut ret = EthtoolEeeConfig::new();
+    ret.enabled = get_bool(ETHTOOL_A_EEE_ENABLED);
+    ret.active = get_bool(ETHTOOL_A_EEE_ACTIVE);
+    ret.tx_lpi_enabled = get_bool(ETHTOOL_A_EEE_TX_LPI_ENABLED);
+    ret.tx_lpi_timer = find_nl_attr(&attrs, ETHTOOL_A_EEE_TX_LPI_TIMER)
+        .and_then(|a| a.as_u32());
+    if ret == EthtoolEeeConfig::default() {
+        Ok(None)
+    } else {
+        Ok(Some(ret))
+    }
+}
+
+pub(crate) async fn set_eee(
+    handle: &mut GenetlinkHandle,
+    iface_name: &str,
+    eee: &EthtoolEeeConfig,
+) -> Result<(), NmstateError> {
+    let mut attrs = vec![header_attr(ETHTOOL_A_EEE_HEADER, iface_name)];
+    for (kind, value) in [
+        (ETHTOOL_A_EEE_ENABLED, eee.enabled),
+        (ETHTOOL_A_EEE_TX_LPI_ENABLED, eee.tx_lpi_enabled),
+    ] {
+        if let Some(v) = value {
+            attrs.push(NlAttr::new(kind, NlAttrValue::U8(v.into())));
+        }
+    }
+    if let Some(v) = eee.tx_lpi_timer {
+        attrs
+            .push(NlAttr::new(ETHTOOL_A_EEE_TX_LPI_TIMER, NlAttrValue::U32(v)));
+    }
+    if attrs.len() == 1 {
+        return Ok(());
+    }
+    log::info!("Setting ethtool EEE of interface {iface_name}");
+    ethtool_set(handle, ETHTOOL_MSG_EEE_SET, attrs, iface_name).await
+}
+
+pub(crate) async fn get_wol(
+    handle: &mut GenetlinkHandle,
+    iface_name: &str,
+) -> Result<Option<EthernetWakeOnLanConfig>, NmstateError> {
+    let attrs = match ethtool_get(
+        handle,
+        ETHTOOL_MSG_WOL_GET,
+        ETHTOOL_A_WOL_HEADER,
+        iface_name,
+    )
+    .await?
+    {
+        Some(a) => a,
+        None => return Ok(None),
+    };
+    let bits = match find_nl_attr(&attrs, ETHTOOL_A_WOL_MODES) {
+        Some(a) => parse_bitset(a),
+        None => return Ok(None),
+    };
+    let mut ret = EthernetWakeOnLanConfig::new();
+    let mut modes: Vec<EthernetWakeOnLanMode> = bits
+        .iter()
+        .filter(|(_, is_set)| *is_set)
+        .filter_map(|(name, _)| {
+            WOL_NAMES
+                .iter()
+                .find(|(n, _)| n == name)
+                .map(|(_, mode)| *mode)
+        })
+        .collect();
+    modes.sort_unstable();
+    if modes.contains(&EthernetWakeOnLanMode::Secureon) {
+        // Kernel only include SecureOn password when caller has
+        // CAP_NET_ADMIN
+        ret.secureon_password = find_nl_attr(&attrs, ETHTOOL_A_WOL_SOPASS)
+            .map(|a| a.as_bytes())
+            .filter(|p| !p.is_empty())
+            .map(|p| {
+                p.iter()
+                    .map(|b| format!("{b:02X}"))
+                    .collect::<Vec<String>>()
+                    .join(":")
+            });
+    }
+    ret.modes = Some(modes);
+    Ok(Some(ret))
+}
+
+pub(crate) async fn set_wol(
+    handle: &mut GenetlinkHandle,
+    iface_name: &str,
+    wol: &EthernetWakeOnLanConfig,
+) -> Result<(), NmstateError> {
+    let mut attrs = vec![header_attr(ETHTOOL_A_WOL_HEADER, iface_name)];
+    if let Some(modes) = wol.modes.as_ref() {
+        let bits: Vec<(&str, bool)> = WOL_NAMES
+            .iter()
+            .filter(|(_, m)| modes.contains(m))
+            .map(|(n, _)| (*n, true))
+            .collect();
+        attrs.push(gen_bitset(ETHTOOL_A_WOL_MODES, bits.as_slice(), true));
+    }
+    // The password hid by nmstate cannot be parsed, hence kernel will keep
+    // using the current one.
+    if let Some(password) = wol
+        .secureon_password
+        .as_deref()
+        .and_then(parse_secureon_password)
+    {
+        attrs.push(NlAttr::new(
+            ETHTOOL_A_WOL_SOPASS,
+            NlAttrValue::Binary(password.to_vec()),
+        ));
+    }
+    if attrs.len() == 1 {
+        return Ok(());
+    }
+    log::info!("Setting Wake-on-LAN of interface {iface_name}");
+    ethtool_set(handle, ETHTOOL_MSG_WOL_SET, attrs, iface_name).await
+}
+
+pub(crate) async fn get_private_flags(
+    handle: &mut GenetlinkHandle,
+    iface_name: &str,
+) -> Result<Option<EthtoolFeatureConfig>, NmstateError> {
+    let attrs = match ethtool_get(
+        handle,
+        ETHTOOL_MSG_PRIVFLAGS_GET,
+        ETHTOOL_A_PRIVFLAGS_HEADER,
+        iface_name,
+    )
+    .await?
+    {
+        Some(a) => a,
+        None => return Ok(None),
+    };
+    let bits = find_nl_attr(&attrs, ETHTOOL_A_PRIVFLAGS_FLAGS)
+        .map(parse_bitset)
+        .unwrap_or_default();
+    if bits.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(
+            bits.into_iter().collect::<HashMap<String, bool>>().into(),
+        ))
+    }
+}
+
+pub(crate) async fn set_private_flags(
+    handle: &mut GenetlinkHandle,
+    iface_name: &str,
+    private_flags: &EthtoolFeatureConfig,
+) -> Result<(), NmstateError> {
+    let bits: Vec<(&str, bool)> = private_flags
+        .into_iter()
+        .map(|(name, value)| (name.as_str(), *value))
+        .collect();
+    if bits.is_empty() {
+        return Ok(());
+    }
+    log::info!("Setting ethtool private flags of interface {iface_name}");
+    ethtool_set(
+        handle,
+        ETHTOOL_MSG_PRIVFLAGS_SET,
+        vec![
+            header_attr(ETHTOOL_A_PRIVFLAGS_HEADER, iface_name),
+            gen_bitset(ETHTOOL_A_PRIVFLAGS_FLAGS, bits.as_slice(), false),
+        ],
+        iface_name,
+    )
+    .await
+}
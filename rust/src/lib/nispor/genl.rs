@@ -0,0 +1,259 @@
+// SPDX-License-Identifier: Apache-2.0
+
+// Minimum generic netlink support for kernel features not covered by nispor
+// yet. The replies are stored as untyped attributes, each user is responsible
+// for decoding the attributes it cares about.
+
+use std::marker::PhantomData;
+
+use futures::StreamExt;
+use genetlink::GenetlinkHandle;
+use netlink_packet_core::{
+    DecodeError, Emitable, NetlinkMessage, NetlinkPayload, Nla, NlaBuffer,
+    NlasIterator, ParseableParametrized, NLA_F_NESTED, NLA_TYPE_MASK,
+    NLM_F_ACK, NLM_F_DUMP, NLM_F_REQUEST,
+};
+use netlink_packet_generic::{GenlFamily, GenlHeader, GenlMessage};
+
+use crate::{ErrorKind, NmstateError};
+
+pub(crate) trait NmGenlFamily: std::fmt::Debug {
+    const NAME: &'static str;
+    const VERSION: u8;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum NlAttrValue {
+    Flag,
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    String(String),
+    Binary(Vec<u8>),
+    Nested(Vec<NlAttr>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct NlAttr {
+    pub(crate) kind: u16,
+    pub(crate) value: NlAttrValue,
+}
+
+impl NlAttr {
+    pub(crate) fn new(kind: u16, value: NlAttrValue) -> Self {
+        Self { kind, value }
+    }
+
+    pub(crate) fn as_bytes(&self) -> &[u8] {
+        match &self.value {
+            NlAttrValue::Binary(v) => v.as_slice(),
+            _ => &[],
+        }
+    }
+
+    pub(crate) fn as_u8(&self) -> Option<u8> {
+        match self.value {
+            NlAttrValue::U8(v) => Some(v),
+            _ => self.as_bytes().first().copied(),
+        }
+    }
+
+    pub(crate) fn as_u16(&self) -> Option<u16> {
+        match self.value {
+            NlAttrValue::U16(v) => Some(v),
+            _ => self
+                .as_bytes()
+                .get(..2)
+                .map(|b| u16::from_ne_bytes([b[0], b[1]])),
+        }
+    }
+
+    pub(crate) fn as_string(&self) -> Option<String> {
+        match &self.value {
+            NlAttrValue::String(s) => Some(s.clone()),
+            _ => std::str::from_utf8(self.as_bytes())
+                .ok()
+                .map(|s| s.trim_end_matches('\0').to_string()),
+        }
+    }
+
+    pub(crate) fn as_u32(&self) -> Option<u32> {
+        match self.value {
+            NlAttrValue::U32(v) => Some(v),
+            _ => self
+                .as_bytes()
+                .get(..4)
+                .map(|b| u32::from_ne_bytes([b[0], b[1], b[2], b[3]])),
+        }
+    }
+}
+
+impl Nla for NlAttr {
+    fn value_len(&self) -> usize {
+        match &self.value {
+            NlAttrValue::Flag => 0,
+            NlAttrValue::U8(_) => 1,
+            NlAttrValue::U16(_) => 2,
+            NlAttrValue::U32(_) => 4,
+            NlAttrValue::String(s) => s.len() + 1,
+            NlAttrValue::Binary(v) => v.len(),
+            NlAttrValue::Nested(v) => v.as_slice().buffer_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        if let NlAttrValue::Nested(_) = self.value {
+            self.kind | NLA_F_NESTED
+        } else {
+            self.kind
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match &self.value {
+            NlAttrValue::Flag => (),
+            NlAttrValue::U8(v) => buffer[0] = *v,
+            NlAttrValue::U16(v) => buffer.copy_from_slice(&v.to_ne_bytes()),
+            NlAttrValue::U32(v) => buffer.copy_from_slice(&v.to_ne_bytes()),
+            NlAttrValue::String(s) => {
+                buffer[..s.len()].copy_from_slice(s.as_bytes());
+                buffer[s.len()] = 0;
+            }
+            NlAttrValue::Binary(v) => buffer.copy_from_slice(v.as_slice()),
+            NlAttrValue::Nested(v) => v.as_slice().emit(buffer),
+        }
+    }
+}
+
+pub(crate) fn parse_nl_attrs(buf: &[u8]) -> Result<Vec<NlAttr>, DecodeError> {
+    let mut ret = Vec::new();
+    for nla in NlasIterator::new(buf) {
+        let nla: NlaBuffer<&[u8]> = nla?;
+        ret.push(NlAttr {
+            kind: nla.kind() & NLA_TYPE_MASK,
+            value: NlAttrValue::Binary(nla.value().to_vec()),
+        });
+    }
+    Ok(ret)
+}
+
+pub(crate) fn find_nl_attr(attrs: &[NlAttr], kind: u16) -> Option<&NlAttr> {
+    attrs.iter().find(|a| a.kind == kind)
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct NmGenlMessage<F> {
+    pub(crate) cmd: u8,
+    pub(crate) attrs: Vec<NlAttr>,
+    _family: PhantomData<F>,
+}
+
+impl<F> NmGenlMessage<F> {
+    pub(crate) fn new(cmd: u8, attrs: Vec<NlAttr>) -> Self {
+        Self {
+            cmd,
+            attrs,
+            _family: PhantomData,
+        }
+    }
+}
+
+impl<F: NmGenlFamily> GenlFamily for NmGenlMessage<F> {
+    fn family_name() -> &'static str {
+        F::NAME
+    }
+
+    fn version(&self) -> u8 {
+        F::VERSION
+    }
+
+    fn command(&self) -> u8 {
+        self.cmd
+    }
+}
+
+impl<F> Emitable for NmGenlMessage<F> {
+    fn buffer_len(&self) -> usize {
+        self.attrs.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.attrs.as_slice().emit(buffer)
+    }
+}
+
+impl<F> ParseableParametrized<[u8], GenlHeader> for NmGenlMessage<F> {
+    fn parse_with_param(
+        buf: &[u8],
+        header: GenlHeader,
+    ) -> Result<Self, DecodeError> {
+        Ok(Self::new(header.cmd, parse_nl_attrs(buf)?))
+    }
+}
+
+pub(crate) fn new_genl_handle() -> Result<GenetlinkHandle, NmstateError> {
+    let (conn, handle, _) = genetlink::new_connection().map_err(|e| {
+        NmstateError::new(
+            ErrorKind::Bug,
+            format!("Failed to create generic netlink connection: {e}"),
+        )
+    })?;
+    tokio::spawn(conn);
+    Ok(handle)
+}
+
+/// Send generic netlink request and return the attributes of each reply.
+/// Set `is_dump` to true for dumping, otherwise the request will be
+/// acknowledged by kernel.
+pub(crate) async fn genl_request<F>(
+    handle: &mut GenetlinkHandle,
+    cmd: u8,
+    attrs: Vec<NlAttr>,
+    is_dump: bool,
+) -> Result<Vec<Vec<NlAttr>>, NmstateError>
+where
+    F: NmGenlFamily,
+{
+    let mut nl_msg = NetlinkMessage::from(GenlMessage::from_payload(
+        NmGenlMessage::<F>::new(cmd, attrs),
+    ));
+    nl_msg.header.flags = if is_dump {
+        NLM_F_REQUEST | NLM_F_DUMP | NLM_F_ACK
+    } else {
+        NLM_F_REQUEST | NLM_F_ACK
+    };
+
+    let mut replies = handle.request(nl_msg).await.map_err(|e| {
+        NmstateError::new(
+            ErrorKind::PluginFailure,
+            format!("Failed to send {} netlink request: {e}", F::NAME),
+        )
+    })?;
+
+    let mut ret = Vec::new();
+    while let Some(reply) = replies.next().await {
+        let reply = reply.map_err(|e| {
+            NmstateError::new(
+                ErrorKind::PluginFailure,
+                format!("Failed to parse {} netlink reply: {e}", F::NAME),
+            )
+        })?;
+        match reply.payload {
+            NetlinkPayload::InnerMessage(msg) => {
+                ret.push(msg.payload.attrs);
+            }
+            NetlinkPayload::Error(e) if e.code.is_some() => {
+                return Err(NmstateError::new(
+                    ErrorKind::PluginFailure,
+                    format!(
+                        "{} netlink command {cmd} failed: {}",
+                        F::NAME,
+                        e.to_io()
+                    ),
+                ));
+            }
+            _ => (),
+        }
+    }
+    Ok(ret)
+}
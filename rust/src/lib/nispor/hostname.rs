@@ -2,10 +2,14 @@
 
 use std::io::Read;
 
-use crate::{ErrorKind, HostNameState, NmstateError};
+use crate::{ErrorKind, HostNameState, MergedHostNameState, NmstateError};
 
 const HOST_NAME_MAX: usize = 64;
 
+const HOSTNAMED_DBUS_NAME: &str = "org.freedesktop.hostname1";
+const HOSTNAMED_DBUS_PATH: &str = "/org/freedesktop/hostname1";
+const HOSTNAMED_DBUS_IFACE: &str = "org.freedesktop.hostname1";
+
 pub(crate) fn get_hostname_state() -> Option<HostNameState> {
     let running = match nix::unistd::gethostname() {
         Ok(hostname_cstr) => match hostname_cstr.into_string() {
@@ -24,6 +28,7 @@ pub(crate) fn get_hostname_state() -> Option<HostNameState> {
         Some(HostNameState {
             running,
             config: get_config_hostname(),
+            pretty: get_pretty_hostname(),
         })
     } else {
         None
@@ -58,7 +63,180 @@ fn get_config_hostname() -> Option<String> {
     }
 }
 
-pub(crate) fn set_running_hostname(hostname: &str) -> Result<(), NmstateError> {
+// Return None if pretty hostname is not set or systemd-hostnamed is not
+// available.
+fn get_pretty_hostname() -> Option<String> {
+    let proxy = match hostnamed_proxy() {
+        Ok(p) => p,
+        Err(e) => {
+            log::debug!("Failed to connect to systemd-hostnamed: {e}");
+            return None;
+        }
+    };
+    match proxy.get_property::<String>("PrettyHostname") {
+        Ok(h) if !h.is_empty() => Some(h),
+        Ok(_) => None,
+        Err(e) => {
+            log::debug!("Failed to query pretty hostname: {e}");
+            None
+        }
+    }
+}
+
+fn hostnamed_proxy() -> Result<zbus::Proxy<'static>, zbus::Error> {
+    let connection = zbus::Connection::new_system()?;
+    zbus::Proxy::new(
+        &connection,
+        HOSTNAMED_DBUS_NAME,
+        HOSTNAMED_DBUS_PATH,
+        HOSTNAMED_DBUS_IFACE,
+    )
+}
+
+fn hostnamed_set(
+    proxy: &zbus::Proxy,
+    method: &str,
+    hostname: &str,
+) -> Result<(), NmstateError> {
+    // The second argument is `interactive` for polkit authorization
+    if let Err(e) = proxy.call::<_, ()>(method, &(hostname, false)) {
+        let e = NmstateError::new(
+            ErrorKind::PluginFailure,
+            format!(
+                "Failed to invoke {method}('{hostname}') of \
+                systemd-hostnamed: {e}"
+            ),
+        );
+        log::error!("{}", e);
+        return Err(e);
+    }
+    Ok(())
+}
+
+// Apply static, transient and pretty hostname via systemd-hostnamed.
+// When systemd-hostnamed is not available, fallback to set kernel hostname
+// and /etc/hostname directly.
+pub(crate) fn apply_hostname(
+    merged_hostname: &MergedHostNameState,
+    memory_only: bool,
+) -> Result<(), NmstateError> {
+    let desired = if let Some(d) = merged_hostname.desired.as_ref() {
+        d
+    } else {
+        return Ok(());
+    };
+    let (config, pretty) = if memory_only {
+        if desired.config.is_some() || desired.pretty.is_some() {
+            log::debug!(
+                "Cannot change static or pretty hostname in memory only \
+                mode, ignoring"
+            );
+        }
+        (None, None)
+    } else {
+        (desired.config.as_deref(), desired.pretty.as_deref())
+    };
+    if let Some(running) = desired.running.as_deref() {
+        validate_running_hostname(running)?;
+    }
+
+    match hostnamed_proxy() {
+        Ok(proxy) => {
+            // Static hostname should be set before transient hostname
+            // as systemd-hostnamed will also change transient hostname
+            // when static hostname changed.
+            if let Some(config) = config {
+                hostnamed_set(&proxy, "SetStaticHostname", config)?;
+            }
+            if let Some(running) = desired.running.as_deref() {
+                hostnamed_set(&proxy, "SetHostname", running)?;
+            }
+            if let Some(pretty) = pretty {
+                hostnamed_set(&proxy, "SetPrettyHostname", pretty)?;
+            }
+        }
+        Err(e) => {
+            log::info!(
+                "Failed to connect to systemd-hostnamed: {e}, setting \
+                hostname directly"
+            );
+            if pretty.is_some() {
+                let e = NmstateError::new(
+                    ErrorKind::DependencyError,
+                    "Pretty hostname requires systemd-hostnamed".to_string(),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+            if let Some(config) = config {
+                set_config_hostname(config)?;
+            }
+            if let Some(running) = desired.running.as_deref() {
+                set_running_hostname(running)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+// Pretty hostname is not supported by NetworkManager
+pub(crate) fn apply_pretty_hostname(
+    merged_hostname: &MergedHostNameState,
+    memory_only: bool,
+) -> Result<(), NmstateError> {
+    let pretty = if let Some(p) = merged_hostname
+        .desired
+        .as_ref()
+        .and_then(|d| d.pretty.as_deref())
+    {
+        p
+    } else {
+        return Ok(());
+    };
+    if memory_only {
+        log::debug!(
+            "Cannot change pretty hostname in memory only mode, ignoring"
+        );
+        return Ok(());
+    }
+    match hostnamed_proxy() {
+        Ok(proxy) => hostnamed_set(&proxy, "SetPrettyHostname", pretty),
+        Err(e) => {
+            let e = NmstateError::new(
+                ErrorKind::DependencyError,
+                format!(
+                    "Pretty hostname requires systemd-hostnamed, but failed \
+                    to connect to it: {e}"
+                ),
+            );
+            log::error!("{}", e);
+            Err(e)
+        }
+    }
+}
+
+fn set_config_hostname(hostname: &str) -> Result<(), NmstateError> {
+    let result = if hostname.is_empty() {
+        if std::path::Path::new(HOSTNAME_CONFIG_PATH).exists() {
+            std::fs::remove_file(HOSTNAME_CONFIG_PATH)
+        } else {
+            Ok(())
+        }
+    } else {
+        std::fs::write(HOSTNAME_CONFIG_PATH, format!("{hostname}\n"))
+    };
+    if let Err(e) = result {
+        let e = NmstateError::new(
+            ErrorKind::PermissionError,
+            format!("Failed to store {HOSTNAME_CONFIG_PATH}: {e}"),
+        );
+        log::error!("{}", e);
+        return Err(e);
+    }
+    Ok(())
+}
+
+fn validate_running_hostname(hostname: &str) -> Result<(), NmstateError> {
     if hostname.is_empty() {
         let e = NmstateError::new(
             ErrorKind::InvalidArgument,
@@ -75,6 +253,11 @@ pub(crate) fn set_running_hostname(hostname: &str) -> Result<(), NmstateError> {
         log::error!("{}", e);
         return Err(e);
     }
+    Ok(())
+}
+
+pub(crate) fn set_running_hostname(hostname: &str) -> Result<(), NmstateError> {
+    validate_running_hostname(hostname)?;
 
     let os_str = std::ffi::OsStr::new(hostname);
     if nix::unistd::sethostname(os_str).is_err() {
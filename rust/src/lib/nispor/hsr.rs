@@ -1,6 +1,22 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{BaseInterface, HsrConfig, HsrInterface, HsrProtocol};
+use futures::TryStreamExt;
+use netlink_packet_core::{DefaultNla, Nla};
+use rtnetlink::{
+    packet_route::link::{
+        self, InfoData, InfoHsr, InfoKind, LinkAttribute, LinkInfo,
+    },
+    Handle, LinkMessageBuilder, LinkUnspec,
+};
+
+use super::base_iface::get_iface_index;
+use crate::{
+    BaseInterface, ErrorKind, HsrConfig, HsrInterface, HsrProtocol, Interface,
+    Interfaces, MergedInterfaces, NmstateError,
+};
+
+// The netlink-packet-route crate does not support IFLA_HSR_INTERLINK yet.
+const IFLA_HSR_INTERLINK: u16 = 8;
 
 impl From<nispor::HsrProtocol> for HsrProtocol {
     fn from(v: nispor::HsrProtocol) -> Self {
@@ -43,6 +59,7 @@ pub(crate) fn np_hsr_to_nmstate(
         // use the last byte of supervision_address instead.
         multicast_spec: mutlicast_value,
         protocol: np_hsr_info.protocol.into(),
+        interlink: None,
     });
 
     HsrInterface {
@@ -50,3 +67,214 @@ pub(crate) fn np_hsr_to_nmstate(
         hsr: hsr_conf,
     }
 }
+
+fn new_rtnl_handle() -> Result<Handle, NmstateError> {
+    let (conn, handle, _) = rtnetlink::new_connection().map_err(|e| {
+        NmstateError::new(
+            ErrorKind::Bug,
+            format!("Failed to create route netlink connection: {e}"),
+        )
+    })?;
+    tokio::spawn(conn);
+    Ok(handle)
+}
+
+async fn get_iface_name_by_index(
+    handle: &Handle,
+    index: u32,
+) -> Option<String> {
+    let mut links = handle.link().get().match_index(index).execute();
+    if let Ok(Some(nl_msg)) = links.try_next().await {
+        nl_msg.attributes.into_iter().find_map(|attr| {
+            if let LinkAttribute::IfName(name) = attr {
+                Some(name)
+            } else {
+                None
+            }
+        })
+    } else {
+        None
+    }
+}
+
+async fn get_hsr_interlink(
+    handle: &Handle,
+    iface_name: &str,
+) -> Option<String> {
+    let mut links = handle
+        .link()
+        .get()
+        .match_name(iface_name.to_string())
+        .execute();
+    let nl_msg = links.try_next().await.ok().flatten()?;
+    let mut interlink_index = None;
+    for attr in nl_msg.attributes.iter() {
+        if let LinkAttribute::LinkInfo(infos) = attr {
+            for info in infos {
+                if let LinkInfo::Data(InfoData::Hsr(hsr_infos)) = info {
+                    for hsr_info in hsr_infos {
+                        if let InfoHsr::Other(nla) = hsr_info {
+                            if nla.kind() == IFLA_HSR_INTERLINK
+                                && nla.value_len() == 4
+                            {
+                                let mut buf = [0u8; 4];
+                                nla.emit_value(&mut buf);
+                                interlink_index = Some(u32::from_ne_bytes(buf));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    get_iface_name_by_index(handle, interlink_index?).await
+}
+
+// Nispor does not support HSR interlink port yet, use rtnetlink directly.
+pub(crate) async fn append_hsr_interlink_info(ifaces: &mut Interfaces) {
+    if !ifaces
+        .kernel_ifaces
+        .values()
+        .any(|i| matches!(i, Interface::Hsr(_)))
+    {
+        return;
+    }
+    let handle = match new_rtnl_handle() {
+        Ok(h) => h,
+        Err(e) => {
+            log::warn!("Failed to query HSR interlink: {e}");
+            return;
+        }
+    };
+    for iface in ifaces.kernel_ifaces.values_mut() {
+        if let Interface::Hsr(hsr_iface) = iface {
+            if let Some(hsr_conf) = hsr_iface.hsr.as_mut() {
+                hsr_conf.interlink =
+                    get_hsr_interlink(&handle, hsr_iface.base.name.as_str())
+                        .await;
+            }
+        }
+    }
+}
+
+fn get_port_index(
+    iface_name: &str,
+    port_name: &str,
+) -> Result<u32, NmstateError> {
+    if let Some(i) = get_iface_index(port_name) {
+        Ok(i)
+    } else {
+        let e = NmstateError::new(
+            ErrorKind::InvalidArgument,
+            format!(
+                "Port {port_name} of HSR interface {iface_name} does not exist"
+            ),
+        );
+        log::error!("{}", e);
+        Err(e)
+    }
+}
+
+fn is_hsr_conf_changed(desired: &HsrConfig, current: &HsrConfig) -> bool {
+    desired.port1 != current.port1
+        || desired.port2 != current.port2
+        || desired.protocol != current.protocol
+        || desired.multicast_spec != current.multicast_spec
+        || desired.interlink.as_deref().unwrap_or_default()
+            != current.interlink.as_deref().unwrap_or_default()
+}
+
+// Nispor cannot create HSR interface yet, use rtnetlink directly.
+// Linux kernel does not support changing HSR ports or protocol of existing
+// HSR interface, hence we recreate the interface on these changes.
+pub(crate) async fn create_hsr_ifaces(
+    merged_ifaces: &MergedInterfaces,
+) -> Result<(), NmstateError> {
+    let mut handle = None;
+    for (merged_iface, hsr_conf) in merged_ifaces
+        .kernel_ifaces
+        .values()
+        .filter(|i| i.is_changed() && !i.merged.is_absent())
+        .filter_map(|i| {
+            if let Some(Interface::Hsr(hsr_iface)) = i.for_apply.as_ref() {
+                hsr_iface.hsr.as_ref().map(|c| (i, c))
+            } else {
+                None
+            }
+        })
+    {
+        let iface_name = merged_iface.merged.name();
+        let cur_index = if let Some(Interface::Hsr(cur_iface)) =
+            merged_iface.current.as_ref()
+        {
+            if let Some(cur_conf) = cur_iface.hsr.as_ref() {
+                if !is_hsr_conf_changed(hsr_conf, cur_conf) {
+                    continue;
+                }
+            }
+            get_iface_index(iface_name)
+        } else {
+            None
+        };
+
+        let port1_index = get_port_index(iface_name, hsr_conf.port1.as_str())?;
+        let port2_index = get_port_index(iface_name, hsr_conf.port2.as_str())?;
+        let mut infos = vec![
+            InfoHsr::Port1(port1_index),
+            InfoHsr::Port2(port2_index),
+            InfoHsr::MulticastSpec(hsr_conf.multicast_spec),
+            InfoHsr::Protocol(match hsr_conf.protocol {
+                HsrProtocol::Hsr => link::HsrProtocol::Hsr,
+                HsrProtocol::Prp => link::HsrProtocol::Prp,
+            }),
+        ];
+        if let Some(interlink) =
+            hsr_conf.interlink.as_deref().filter(|i| !i.is_empty())
+        {
+            infos.push(InfoHsr::Other(DefaultNla::new(
+                IFLA_HSR_INTERLINK,
+                get_port_index(iface_name, interlink)?
+                    .to_ne_bytes()
+                    .to_vec(),
+            )));
+        }
+
+        if handle.is_none() {
+            handle = Some(new_rtnl_handle()?);
+        }
+        if let Some(handle) = handle.as_ref() {
+            if let Some(cur_index) = cur_index {
+                log::info!(
+                    "Deleting HSR interface {iface_name} for recreation as \
+                    kernel does not support changing HSR configuration"
+                );
+                handle.link().del(cur_index).execute().await.map_err(|e| {
+                    let e = NmstateError::new(
+                        ErrorKind::PluginFailure,
+                        format!(
+                            "Failed to delete HSR interface {iface_name}: {e}"
+                        ),
+                    );
+                    log::error!("{}", e);
+                    e
+                })?;
+            }
+            log::info!("Creating HSR interface {iface_name}");
+            let nl_msg = LinkMessageBuilder::<LinkUnspec>::new_with_info_kind(
+                InfoKind::Hsr,
+            )
+            .name(iface_name.to_string())
+            .set_info_data(InfoData::Hsr(infos))
+            .build();
+            handle.link().add(nl_msg).execute().await.map_err(|e| {
+                let e = NmstateError::new(
+                    ErrorKind::PluginFailure,
+                    format!("Failed to create HSR interface {iface_name}: {e}"),
+                );
+                log::error!("{}", e);
+                e
+            })?;
+        }
+    }
+    Ok(())
+}
@@ -1,10 +1,19 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::HashMap;
+use std::net::IpAddr;
 use std::str::FromStr;
 
+use futures::stream::TryStreamExt;
+use rtnetlink::packet_route::{
+    address::{AddressAttribute, AddressFlags, AddressMessage},
+    AddressFamily,
+};
+
 use crate::{
-    nispor::mptcp::get_mptcp_flags, InterfaceIpAddr, InterfaceIpv4,
-    InterfaceIpv6,
+    nispor::{base_iface::get_iface_index, mptcp::get_mptcp_flags},
+    ErrorKind, InterfaceIpAddr, InterfaceIpAddrFlag, InterfaceIpv4,
+    InterfaceIpv6, Interfaces, MergedInterfaces, NmstateError,
 };
 
 pub(crate) fn np_ipv4_to_nmstate(
@@ -32,6 +41,10 @@ pub(crate) fn np_ipv4_to_nmstate(
                 Ok(i) => addresses.push(InterfaceIpAddr {
                     ip: i,
                     prefix_length: np_addr.prefix_len,
+                    peer: np_addr
+                        .peer
+                        .as_deref()
+                        .and_then(|p| IpAddr::from_str(p).ok()),
                     mptcp_flags: Some(get_mptcp_flags(
                         np_iface,
                         np_addr.address.as_str(),
@@ -99,6 +112,8 @@ pub(crate) fn np_ipv6_to_nmstate(
                 Ok(i) => addresses.push(InterfaceIpAddr {
                     ip: i,
                     prefix_length: np_addr.prefix_len,
+                    peer: np_addr.peer.map(IpAddr::V6),
+                    flags: np_ipv6_addr_flags_to_nmstate(&np_addr.flags),
                     mptcp_flags: Some(get_mptcp_flags(
                         np_iface,
                         np_addr.address.as_str(),
@@ -141,7 +156,15 @@ pub(crate) fn nmstate_ipv4_to_np(
 ) -> nispor::IpConf {
     let mut np_ip_conf = nispor::IpConf::default();
     if let Some(nms_ipv4) = nms_ipv4 {
-        for nms_addr in nms_ipv4.addresses.as_deref().unwrap_or_default() {
+        // IP address with peer or flags is applied by
+        // `apply_ip_addr_peer_and_flags()`
+        for nms_addr in nms_ipv4
+            .addresses
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .filter(|a| !a.has_peer_or_flags())
+        {
             np_ip_conf.addresses.push({
                 let mut ip_conf = nispor::IpAddrConf::default();
                 ip_conf.address = nms_addr.ip.to_string();
@@ -158,7 +181,15 @@ pub(crate) fn nmstate_ipv6_to_np(
 ) -> nispor::IpConf {
     let mut np_ip_conf = nispor::IpConf::default();
     if let Some(nms_ipv6) = nms_ipv6 {
-        for nms_addr in nms_ipv6.addresses.as_deref().unwrap_or_default() {
+        // IP address with peer or flags is applied by
+        // `apply_ip_addr_peer_and_flags()`
+        for nms_addr in nms_ipv6
+            .addresses
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .filter(|a| !a.has_peer_or_flags())
+        {
             np_ip_conf.addresses.push({
                 let mut ip_conf = nispor::IpAddrConf::default();
                 ip_conf.address = nms_addr.ip.to_string();
@@ -169,3 +200,207 @@ pub(crate) fn nmstate_ipv6_to_np(
     }
     np_ip_conf
 }
+
+fn np_ipv6_addr_flags_to_nmstate(
+    np_flags: &[nispor::Ipv6AddrFlag],
+) -> Option<Vec<InterfaceIpAddrFlag>> {
+    let mut ret = Vec::new();
+    for np_flag in np_flags {
+        match np_flag {
+            nispor::Ipv6AddrFlag::Noprefixroute => {
+                ret.push(InterfaceIpAddrFlag::Noprefixroute)
+            }
+            nispor::Ipv6AddrFlag::Nodad => ret.push(InterfaceIpAddrFlag::Nodad),
+            nispor::Ipv6AddrFlag::Homeaddress => {
+                ret.push(InterfaceIpAddrFlag::Home)
+            }
+            nispor::Ipv6AddrFlag::Optimistic => {
+                ret.push(InterfaceIpAddrFlag::Optimistic)
+            }
+            _ => (),
+        }
+    }
+    if ret.is_empty() {
+        None
+    } else {
+        ret.sort_unstable();
+        Some(ret)
+    }
+}
+
+// Nispor does not provide flags of IPv4 address, query them from kernel
+// directly.
+pub(crate) async fn append_ipv4_addr_flags(
+    ifaces: &mut Interfaces,
+    np_ifaces: &HashMap<String, nispor::Iface>,
+) {
+    let index_to_name: HashMap<u32, &str> = np_ifaces
+        .values()
+        .map(|i| (i.index, i.name.as_str()))
+        .collect();
+
+    let (conn, handle, _) = match rtnetlink::new_connection() {
+        Ok(c) => c,
+        Err(e) => {
+            log::warn!("Failed to create route netlink connection: {e}");
+            return;
+        }
+    };
+    tokio::spawn(conn);
+
+    let mut request = handle.address().get();
+    request.message_mut().header.family = AddressFamily::Inet;
+    let mut nl_msgs = request.execute();
+    loop {
+        let nl_msg = match nl_msgs.try_next().await {
+            Ok(Some(m)) => m,
+            Ok(None) => break,
+            Err(e) => {
+                log::warn!("Failed to retrieve IPv4 addresses: {e}");
+                break;
+            }
+        };
+        let mut ip: Option<IpAddr> = None;
+        let mut flags = AddressFlags::empty();
+        for attr in nl_msg.attributes.as_slice() {
+            match attr {
+                AddressAttribute::Local(i) => ip = Some(*i),
+                AddressAttribute::Flags(f) => flags = *f,
+                _ => (),
+            }
+        }
+        if !flags.contains(AddressFlags::Noprefixroute) {
+            continue;
+        }
+        let (ip, iface_name) =
+            match (ip, index_to_name.get(&nl_msg.header.index)) {
+                (Some(i), Some(n)) => (i, n),
+                _ => continue,
+            };
+        if let Some(addr) = ifaces
+            .kernel_ifaces
+            .get_mut(*iface_name)
+            .and_then(|i| i.base_iface_mut().ipv4.as_mut())
+            .and_then(|i| i.addresses.as_mut())
+            .and_then(|addrs| {
+                addrs.iter_mut().find(|a| {
+                    a.ip == ip && a.prefix_length == nl_msg.header.prefix_len
+                })
+            })
+        {
+            addr.flags = Some(vec![InterfaceIpAddrFlag::Noprefixroute]);
+        }
+    }
+}
+
+pub(crate) async fn apply_ip_addr_peer_and_flags(
+    merged_ifaces: &MergedInterfaces,
+) -> Result<(), NmstateError> {
+    let mut pending: Vec<(&str, InterfaceIpAddr)> = Vec::new();
+    for merged_iface in merged_ifaces.kernel_ifaces.values() {
+        let apply_iface = match merged_iface.for_apply.as_ref() {
+            Some(i) if !i.is_absent() && i.base_iface().can_have_ip() => i,
+            _ => continue,
+        };
+        let cur_iface = merged_iface.current.as_ref().map(|i| i.base_iface());
+        let apply_iface = apply_iface.base_iface();
+        for (addrs, cur_addrs) in [
+            (
+                apply_iface.ipv4.as_ref().and_then(|i| i.addresses.as_ref()),
+                cur_iface
+                    .and_then(|i| i.ipv4.as_ref())
+                    .and_then(|i| i.addresses.as_ref()),
+            ),
+            (
+                apply_iface.ipv6.as_ref().and_then(|i| i.addresses.as_ref()),
+                cur_iface
+                    .and_then(|i| i.ipv6.as_ref())
+                    .and_then(|i| i.addresses.as_ref()),
+            ),
+        ] {
+            for addr in addrs.map(Vec::as_slice).unwrap_or_default() {
+                let mut addr = addr.clone();
+                // Preserve flags of current IP address if not desired
+                if addr.flags.is_none() {
+                    addr.flags = cur_addrs
+                        .and_then(|c| c.iter().find(|c| c.is_same_addr(&addr)))
+                        .and_then(|c| c.flags.clone());
+                }
+                if addr.has_peer_or_flags() {
+                    pending.push((apply_iface.name.as_str(), addr));
+                }
+            }
+        }
+    }
+    if pending.is_empty() {
+        return Ok(());
+    }
+
+    let (conn, handle, _) = rtnetlink::new_connection().map_err(|e| {
+        NmstateError::new(
+            ErrorKind::Bug,
+            format!("Failed to create route netlink connection: {e}"),
+        )
+    })?;
+    tokio::spawn(conn);
+
+    for (iface_name, addr) in pending {
+        let index = match get_iface_index(iface_name) {
+            Some(i) => i,
+            None => {
+                let e = NmstateError::new(
+                    ErrorKind::Bug,
+                    format!(
+                        "Failed to find interface {iface_name} for applying \
+                        IP address {addr}"
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        };
+        let mut request = handle
+            .address()
+            .add(index, addr.ip, addr.prefix_length)
+            .replace();
+        set_peer_and_flags(request.message_mut(), &addr);
+        if let Err(e) = request.execute().await {
+            let e = NmstateError::new(
+                ErrorKind::PluginFailure,
+                format!(
+                    "Failed to apply IP address {addr} to interface \
+                    {iface_name}: {e}"
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+    }
+    Ok(())
+}
+
+fn set_peer_and_flags(nl_msg: &mut AddressMessage, addr: &InterfaceIpAddr) {
+    if let Some(peer) = addr.peer {
+        // The IFA_ADDRESS holds peer address while IFA_LOCAL holding local
+        // address. Broadcast address is meaningless for point-to-point link.
+        nl_msg.attributes.retain(|a| {
+            !matches!(
+                a,
+                AddressAttribute::Address(_) | AddressAttribute::Broadcast(_)
+            )
+        });
+        nl_msg.attributes.push(AddressAttribute::Address(peer));
+    }
+    let mut flags = AddressFlags::empty();
+    for flag in addr.flags.as_deref().unwrap_or_default() {
+        flags |= match flag {
+            InterfaceIpAddrFlag::Noprefixroute => AddressFlags::Noprefixroute,
+            InterfaceIpAddrFlag::Nodad => AddressFlags::Nodad,
+            InterfaceIpAddrFlag::Home => AddressFlags::Homeaddress,
+            InterfaceIpAddrFlag::Optimistic => AddressFlags::Optimistic,
+        };
+    }
+    if !flags.is_empty() {
+        nl_msg.attributes.push(AddressAttribute::Flags(flags));
+    }
+}
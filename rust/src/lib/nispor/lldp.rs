@@ -0,0 +1,258 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+use std::time::{Duration, Instant};
+
+use super::base_iface::get_iface_index;
+use crate::{
+    query_apply::{
+        get_lldp_frame_ttl, lldp_frame_to_nmstate, LLDP_ETHER_TYPE,
+        LLDP_MULTICAST_ADDR,
+    },
+    ErrorKind, InterfaceType, Interfaces, LldpConfig, LldpNeighborTlv,
+    NmstateError,
+};
+
+const LLDP_MAX_FRAME_SIZE: usize = 9216;
+
+// Kernel mode has no LLDP daemon, when `listen_time` is not zero, we listen
+// LLDP frames on all interfaces for specified seconds and only report LLDP
+// on interfaces with neighbors found.
+pub(crate) async fn append_lldp_info(
+    ifaces: &mut Interfaces,
+    listen_time: u32,
+) {
+    if listen_time == 0 {
+        return;
+    }
+    let iface_indexes: HashMap<u32, String> = ifaces
+        .kernel_ifaces
+        .values()
+        .filter(|i| {
+            i.iface_type() != InterfaceType::Loopback
+                && i.is_up()
+                && i.base_iface().lldp.is_none()
+        })
+        .filter_map(|i| {
+            get_iface_index(i.name()).map(|index| (index, i.name().to_string()))
+        })
+        .collect();
+    if iface_indexes.is_empty() {
+        return;
+    }
+
+    // The listening is blocking on socket for `listen_time` seconds, run it
+    // in dedicated thread to not stall the async runtime.
+    let neighbors = match tokio::task::spawn_blocking(move || {
+        listen_lldp(&iface_indexes, listen_time)
+    })
+    .await
+    {
+        Ok(Ok(n)) => n,
+        Ok(Err(e)) => {
+            log::warn!("Failed to listen LLDP frames: {e}");
+            return;
+        }
+        Err(e) => {
+            log::warn!("Failed to join LLDP listening thread: {e}");
+            return;
+        }
+    };
+
+    for (iface_name, iface_neighbors) in neighbors {
+        if let Some(iface) = ifaces.kernel_ifaces.get_mut(&iface_name) {
+            iface.base_iface_mut().lldp = Some(LldpConfig {
+                enabled: true,
+                neighbors: iface_neighbors,
+            });
+        }
+    }
+}
+
+// Return neighbors indexed by interface name
+fn listen_lldp(
+    iface_indexes: &HashMap<u32, String>,
+    listen_time: u32,
+) -> Result<HashMap<String, Vec<Vec<LldpNeighborTlv>>>, NmstateError> {
+    let fd = new_lldp_socket(libc::SOCK_NONBLOCK).map_err(|e| {
+        NmstateError::new(
+            ErrorKind::PluginFailure,
+            format!("Failed to create LLDP socket: {e}"),
+        )
+    })?;
+    for (iface_index, iface_name) in iface_indexes.iter() {
+        if let Err(e) = add_lldp_multicast_membership(&fd, *iface_index) {
+            log::debug!(
+                "Failed to add LLDP multicast membership on interface \
+                {iface_name}: {e}"
+            );
+        }
+    }
+    log::info!("Listening LLDP frames for {listen_time} seconds");
+    // Frames indexed by interface index and source MAC address, newer frame
+    // from the same neighbor overrides older one.
+    let mut frames: HashMap<(u32, [u8; 6]), Vec<u8>> = HashMap::new();
+    let mut buffer = vec![0u8; LLDP_MAX_FRAME_SIZE];
+    let deadline = Instant::now() + Duration::from_secs(listen_time.into());
+    while let Some(remain) = deadline.checked_duration_since(Instant::now()) {
+        if !wait_readable(&fd, remain).map_err(|e| {
+            NmstateError::new(
+                ErrorKind::PluginFailure,
+                format!("Failed to wait on LLDP socket: {e}"),
+            )
+        })? {
+            break;
+        }
+        let (len, addr) = match recv_lldp_frame(&fd, &mut buffer) {
+            Ok(r) => r,
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => continue,
+            Err(e) => {
+                return Err(NmstateError::new(
+                    ErrorKind::PluginFailure,
+                    format!("Failed to receive LLDP frame: {e}"),
+                ));
+            }
+        };
+        // Ignore frames sent by ourselves
+        if addr.sll_pkttype == libc::PACKET_OUTGOING {
+            continue;
+        }
+        let iface_index = addr.sll_ifindex as u32;
+        if let (Some(iface_name), Some(src_mac)) = (
+            iface_indexes.get(&iface_index),
+            buffer.get(6..12).and_then(|m| <[u8; 6]>::try_from(m).ok()),
+        ) {
+            log::debug!("Got LLDP frame on interface {iface_name}");
+            frames.insert((iface_index, src_mac), buffer[..len].to_vec());
+        }
+    }
+
+    let mut ret: HashMap<String, Vec<Vec<LldpNeighborTlv>>> = HashMap::new();
+    let mut keys: Vec<&(u32, [u8; 6])> = frames.keys().collect();
+    keys.sort_unstable();
+    for key in keys {
+        let frame = &frames[key];
+        // Neighbor is shutting down its LLDP agent
+        if get_lldp_frame_ttl(frame) == Some(0) {
+            continue;
+        }
+        let tlvs = lldp_frame_to_nmstate(frame);
+        if let Some(iface_name) = iface_indexes.get(&key.0) {
+            if !tlvs.is_empty() {
+                ret.entry(iface_name.to_string()).or_default().push(tlvs);
+            }
+        }
+    }
+    Ok(ret)
+}
+
+// Return false on timeout
+fn wait_readable(
+    fd: &OwnedFd,
+    timeout: Duration,
+) -> Result<bool, std::io::Error> {
+    let mut pfd = libc::pollfd {
+        fd: fd.as_raw_fd(),
+        events: libc::POLLIN,
+        revents: 0,
+    };
+    let timeout_ms =
+        libc::c_int::try_from(timeout.as_millis()).unwrap_or(libc::c_int::MAX);
+    loop {
+        // SAFETY: `pfd` is valid for the whole call and the count of it is
+        // passed along.
+        let rc = unsafe { libc::poll(&mut pfd, 1, timeout_ms) };
+        if rc < 0 {
+            let e = std::io::Error::last_os_error();
+            if e.kind() == std::io::ErrorKind::Interrupted {
+                continue;
+            }
+            return Err(e);
+        }
+        return Ok(rc > 0);
+    }
+}
+
+fn recv_lldp_frame(
+    fd: &OwnedFd,
+    buffer: &mut [u8],
+) -> Result<(usize, libc::sockaddr_ll), std::io::Error> {
+    let mut addr = new_sockaddr_ll(0);
+    let mut addr_len =
+        std::mem::size_of::<libc::sockaddr_ll>() as libc::socklen_t;
+    // SAFETY: `buffer` and `addr` are valid for the whole call and their
+    // lengths are passed along.
+    let rc = unsafe {
+        libc::recvfrom(
+            fd.as_raw_fd(),
+            buffer.as_mut_ptr() as *mut libc::c_void,
+            buffer.len(),
+            0,
+            &mut addr as *mut libc::sockaddr_ll as *mut libc::sockaddr,
+            &mut addr_len,
+        )
+    };
+    if rc < 0 {
+        Err(std::io::Error::last_os_error())
+    } else {
+        Ok((rc as usize, addr))
+    }
+}
+
+// Without this, the NIC might drop the LLDP multicast frames.
+fn add_lldp_multicast_membership(
+    fd: &OwnedFd,
+    iface_index: u32,
+) -> Result<(), std::io::Error> {
+    let mut mreq = libc::packet_mreq {
+        mr_ifindex: iface_index as libc::c_int,
+        mr_type: libc::PACKET_MR_MULTICAST as libc::c_ushort,
+        mr_alen: LLDP_MULTICAST_ADDR.len() as libc::c_ushort,
+        mr_address: [0u8; 8],
+    };
+    mreq.mr_address[..LLDP_MULTICAST_ADDR.len()]
+        .copy_from_slice(&LLDP_MULTICAST_ADDR);
+    // SAFETY: `mreq` is valid for the whole call and its length is passed
+    // along.
+    let rc = unsafe {
+        libc::setsockopt(
+            fd.as_raw_fd(),
+            libc::SOL_PACKET,
+            libc::PACKET_ADD_MEMBERSHIP,
+            &mreq as *const libc::packet_mreq as *const libc::c_void,
+            std::mem::size_of::<libc::packet_mreq>() as libc::socklen_t,
+        )
+    };
+    if rc < 0 {
+        Err(std::io::Error::last_os_error())
+    } else {
+        Ok(())
+    }
+}
+
+fn new_lldp_socket(flags: libc::c_int) -> Result<OwnedFd, std::io::Error> {
+    // SAFETY: No pointer involved, the returned file descriptor is checked
+    // and owned by `OwnedFd` afterwards.
+    let fd = unsafe {
+        libc::socket(
+            libc::AF_PACKET,
+            libc::SOCK_RAW | libc::SOCK_CLOEXEC | flags,
+            LLDP_ETHER_TYPE.to_be() as libc::c_int,
+        )
+    };
+    if fd < 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    // SAFETY: `fd` is a newly created valid file descriptor owned by nobody.
+    Ok(unsafe { OwnedFd::from_raw_fd(fd) })
+}
+
+fn new_sockaddr_ll(iface_index: u32) -> libc::sockaddr_ll {
+    // SAFETY: `sockaddr_ll` is plain old data which is valid when zeroed.
+    let mut addr: libc::sockaddr_ll = unsafe { std::mem::zeroed() };
+    addr.sll_family = libc::AF_PACKET as libc::c_ushort;
+    addr.sll_protocol = LLDP_ETHER_TYPE.to_be();
+    addr.sll_ifindex = iface_index as libc::c_int;
+    addr
+}
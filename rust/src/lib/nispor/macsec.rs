@@ -1,7 +1,16 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use rtnetlink::{packet_route::link, LinkMacSec};
+
+use super::{
+    base_iface::get_iface_index,
+    genl::new_genl_handle,
+    macsec_netlink::{get_macsec_static_saks, set_macsec_static_sak},
+};
 use crate::{
-    BaseInterface, MacSecConfig, MacSecInterface, MacSecOffload, MacSecValidate,
+    BaseInterface, ErrorKind, Interface, Interfaces, MacSecConfig,
+    MacSecInterface, MacSecOffload, MacSecValidate, MergedInterfaces,
+    NmstateError,
 };
 
 impl From<nispor::MacSecValidate> for MacSecValidate {
@@ -46,6 +55,7 @@ pub(crate) fn np_macsec_to_nmstate(
             mka_cak: None,
             mka_ckn: None,
             offload: Some(np_macsec_info.offload.into()),
+            static_sak: None,
         });
 
     MacSecInterface {
@@ -53,3 +63,225 @@ pub(crate) fn np_macsec_to_nmstate(
         macsec: macsec_conf,
     }
 }
+
+// Nispor does not support MACsec static SAK yet, use MACsec generic netlink
+// directly.
+pub(crate) async fn append_macsec_static_sak_info(ifaces: &mut Interfaces) {
+    if !ifaces
+        .kernel_ifaces
+        .values()
+        .any(|i| matches!(i, Interface::MacSec(_)))
+    {
+        return;
+    }
+    let static_saks = match new_genl_handle() {
+        Ok(mut handle) => match get_macsec_static_saks(&mut handle).await {
+            Ok(s) => s,
+            Err(e) => {
+                log::debug!("Failed to query MACsec static SAK: {e}");
+                return;
+            }
+        },
+        Err(e) => {
+            log::warn!("{e}");
+            return;
+        }
+    };
+    for iface in ifaces.kernel_ifaces.values_mut() {
+        if let Interface::MacSec(macsec_iface) = iface {
+            if let (Some(macsec_conf), Some(index)) = (
+                macsec_iface.macsec.as_mut(),
+                get_iface_index(macsec_iface.base.name.as_str()),
+            ) {
+                macsec_conf.static_sak = static_saks.get(&index).cloned();
+            }
+        }
+    }
+}
+
+// Nispor cannot create MACsec interface yet, use rtnetlink directly.
+pub(crate) async fn create_macsec_ifaces(
+    merged_ifaces: &MergedInterfaces,
+) -> Result<(), NmstateError> {
+    let mut handle = None;
+    for (apply_iface, macsec_conf) in merged_ifaces
+        .kernel_ifaces
+        .values()
+        .filter(|i| i.is_changed() && i.current.is_none())
+        .filter_map(|i| i.for_apply.as_ref())
+        .filter(|i| !i.is_absent())
+        .filter_map(|i| {
+            if let Interface::MacSec(macsec_iface) = i {
+                macsec_iface.macsec.as_ref().map(|c| (i, c))
+            } else {
+                None
+            }
+        })
+    {
+        let base_index = match get_iface_index(macsec_conf.base_iface.as_str())
+        {
+            Some(i) => i,
+            None => {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "Base interface {} of MACsec interface {} does not \
+                        exist",
+                        macsec_conf.base_iface,
+                        apply_iface.name()
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        };
+        if handle.is_none() {
+            handle = Some(new_rtnl_handle()?);
+        }
+        if let Some(handle) = handle.as_ref() {
+            log::info!("Creating MACsec interface {}", apply_iface.name());
+            let mut builder = LinkMacSec::new(apply_iface.name(), base_index)
+                .encrypt(macsec_conf.encrypt)
+                .inc_sci(macsec_conf.send_sci)
+                .validation(match macsec_conf.validation {
+                    MacSecValidate::Disabled => link::MacSecValidate::Disabled,
+                    MacSecValidate::Check => link::MacSecValidate::Check,
+                    MacSecValidate::Strict => link::MacSecValidate::Strict,
+                });
+            if macsec_conf.port > 0 {
+                builder = builder.port(macsec_conf.port as u16);
+            }
+            if let Some(offload) = macsec_conf.offload.as_ref() {
+                builder = builder.offload(match offload {
+                    MacSecOffload::Off => link::MacSecOffload::Off,
+                    MacSecOffload::Phy => link::MacSecOffload::Phy,
+                    MacSecOffload::Mac => link::MacSecOffload::Mac,
+                });
+            }
+            handle.link().add(builder.build()).execute().await.map_err(
+                |e| {
+                    let e = NmstateError::new(
+                        ErrorKind::PluginFailure,
+                        format!(
+                            "Failed to create MACsec interface {}: {e}",
+                            apply_iface.name()
+                        ),
+                    );
+                    log::error!("{}", e);
+                    e
+                },
+            )?;
+        }
+    }
+    Ok(())
+}
+
+pub(crate) async fn apply_macsec_static_sak(
+    merged_ifaces: &MergedInterfaces,
+) -> Result<(), NmstateError> {
+    let mut genl_handle = None;
+    let mut rtnl_handle = None;
+    let mut cur_static_saks = None;
+    for (apply_iface, static_sak) in merged_ifaces
+        .kernel_ifaces
+        .values()
+        .filter(|i| i.is_changed())
+        .filter_map(|i| i.for_apply.as_ref())
+        .filter(|i| !i.is_absent())
+        .filter_map(|i| {
+            if let Interface::MacSec(macsec_iface) = i {
+                macsec_iface
+                    .macsec
+                    .as_ref()
+                    .and_then(|c| c.static_sak.as_ref())
+                    .map(|s| (i, s))
+            } else {
+                None
+            }
+        })
+    {
+        let index = match get_iface_index(apply_iface.name()) {
+            Some(i) => i,
+            None => {
+                let e = NmstateError::new(
+                    ErrorKind::Bug,
+                    format!(
+                        "Failed to find interface index of MACsec interface {}",
+                        apply_iface.name()
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        };
+        if genl_handle.is_none() {
+            genl_handle = Some(new_genl_handle()?);
+        }
+        let handle = match genl_handle.as_mut() {
+            Some(h) => h,
+            None => continue,
+        };
+        if cur_static_saks.is_none() {
+            cur_static_saks = Some(get_macsec_static_saks(handle).await?);
+        }
+        set_macsec_static_sak(
+            handle,
+            apply_iface.name(),
+            index,
+            static_sak,
+            cur_static_saks.as_ref().and_then(|c| c.get(&index)),
+        )
+        .await?;
+        if let Some(tx_sa) = static_sak.tx_sa.as_ref() {
+            if rtnl_handle.is_none() {
+                rtnl_handle = Some(new_rtnl_handle()?);
+            }
+            if let Some(rtnl_handle) = rtnl_handle.as_ref() {
+                set_macsec_encoding_sa(
+                    rtnl_handle,
+                    apply_iface.name(),
+                    tx_sa.an,
+                )
+                .await?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn new_rtnl_handle() -> Result<rtnetlink::Handle, NmstateError> {
+    let (conn, handle, _) = rtnetlink::new_connection().map_err(|e| {
+        NmstateError::new(
+            ErrorKind::Bug,
+            format!("Failed to create route netlink connection: {e}"),
+        )
+    })?;
+    tokio::spawn(conn);
+    Ok(handle)
+}
+
+async fn set_macsec_encoding_sa(
+    handle: &rtnetlink::Handle,
+    iface_name: &str,
+    an: u8,
+) -> Result<(), NmstateError> {
+    let message = rtnetlink::LinkMessageBuilder::<LinkMacSec>::new(iface_name)
+        .encoding_sa(an)
+        .build();
+    // RTM_NEWLINK without NLM_F_CREATE is required for changing link info
+    handle
+        .link()
+        .set_port(message)
+        .execute()
+        .await
+        .map_err(|e| {
+            let e = NmstateError::new(
+                ErrorKind::PluginFailure,
+                format!(
+                "Failed to set MACsec encoding SA {an} of {iface_name}: {e}"
+            ),
+            );
+            log::error!("{}", e);
+            e
+        })
+}
@@ -0,0 +1,403 @@
+// SPDX-License-Identifier: Apache-2.0
+
+// MACsec generic netlink commands for secure channels and associations
+// which is not supported by nispor yet.
+
+use std::collections::HashMap;
+
+use genetlink::GenetlinkHandle;
+
+use super::genl::{
+    find_nl_attr, genl_request, parse_nl_attrs, NlAttr, NlAttrValue,
+    NmGenlFamily,
+};
+use crate::{
+    ifaces::parse_hex_str, MacSecRxScConfig, MacSecSaConfig,
+    MacSecStaticSakConfig, NetworkState, NmstateError,
+};
+
+const MACSEC_CMD_GET_TXSC: u8 = 0;
+const MACSEC_CMD_ADD_RXSC: u8 = 1;
+const MACSEC_CMD_DEL_RXSC: u8 = 2;
+const MACSEC_CMD_ADD_TXSA: u8 = 4;
+const MACSEC_CMD_DEL_TXSA: u8 = 5;
+const MACSEC_CMD_UPD_TXSA: u8 = 6;
+const MACSEC_CMD_ADD_RXSA: u8 = 7;
+const MACSEC_CMD_DEL_RXSA: u8 = 8;
+const MACSEC_CMD_UPD_RXSA: u8 = 9;
+
+const MACSEC_ATTR_IFINDEX: u16 = 1;
+const MACSEC_ATTR_RXSC_CONFIG: u16 = 2;
+const MACSEC_ATTR_SA_CONFIG: u16 = 3;
+const MACSEC_ATTR_TXSA_LIST: u16 = 5;
+const MACSEC_ATTR_RXSC_LIST: u16 = 6;
+
+const MACSEC_RXSC_ATTR_SCI: u16 = 1;
+const MACSEC_RXSC_ATTR_ACTIVE: u16 = 2;
+const MACSEC_RXSC_ATTR_SA_LIST: u16 = 3;
+
+const MACSEC_SA_ATTR_AN: u16 = 1;
+const MACSEC_SA_ATTR_ACTIVE: u16 = 2;
+const MACSEC_SA_ATTR_PN: u16 = 3;
+const MACSEC_SA_ATTR_KEY: u16 = 4;
+const MACSEC_SA_ATTR_KEYID: u16 = 5;
+
+const DEFAULT_PN: u32 = 1;
+
+#[derive(Debug)]
+pub(crate) struct MacSecFamily;
+
+impl NmGenlFamily for MacSecFamily {
+    const NAME: &'static str = "macsec";
+    const VERSION: u8 = 1;
+}
+
+/// Get the static SAK configuration of all MACsec interfaces indexed by
+/// interface index. The keys are always hidden as kernel never reports it.
+pub(crate) async fn get_macsec_static_saks(
+    handle: &mut GenetlinkHandle,
+) -> Result<HashMap<u32, MacSecStaticSakConfig>, NmstateError> {
+    let mut ret = HashMap::new();
+    for attrs in
+        genl_request::<MacSecFamily>(handle, MACSEC_CMD_GET_TXSC, vec![], true)
+            .await?
+    {
+        let index = match find_nl_attr(&attrs, MACSEC_ATTR_IFINDEX)
+            .and_then(|a| a.as_u32())
+        {
+            Some(i) => i,
+            None => continue,
+        };
+        let mut conf = MacSecStaticSakConfig::new();
+        if let Some(list_attr) = find_nl_attr(&attrs, MACSEC_ATTR_TXSA_LIST) {
+            // Only the first TX SA is shown as nmstate only support single
+            // TX SA
+            conf.tx_sa = parse_nested_list(list_attr)
+                .iter()
+                .filter_map(|a| parse_sa(a))
+                .next();
+        }
+        if let Some(list_attr) = find_nl_attr(&attrs, MACSEC_ATTR_RXSC_LIST) {
+            let mut rx_scs = Vec::new();
+            for sc_attrs in parse_nested_list(list_attr) {
+                if let Some(rx_sc) = parse_rx_sc(&sc_attrs) {
+                    rx_scs.push(rx_sc);
+                }
+            }
+            conf.rx_sc = Some(rx_scs);
+        }
+        if conf.tx_sa.is_some()
+            || conf.rx_sc.as_ref().map(|s| !s.is_empty()) == Some(true)
+        {
+            ret.insert(index, conf);
+        }
+    }
+    Ok(ret)
+}
+
+/// Apply TX SA and RX SCs to existing MACsec interface:
+///  * SA with key hid by nmstate will be preserved with packet number
+///    updated if defined.
+///  * SA with new key will be recreated.
+///  * Undesired RX SCs and SAs will be removed.
+pub(crate) async fn set_macsec_static_sak(
+    handle: &mut GenetlinkHandle,
+    iface_name: &str,
+    index: u32,
+    conf: &MacSecStaticSakConfig,
+    cur_conf: Option<&MacSecStaticSakConfig>,
+) -> Result<(), NmstateError> {
+    if let Some(tx_sa) = conf.tx_sa.as_ref() {
+        let cur_tx_sa = cur_conf.and_then(|c| c.tx_sa.as_ref());
+        if let Some(cur_tx_sa) = cur_tx_sa.filter(|c| c.an != tx_sa.an) {
+            log::info!(
+                "Removing MACsec TX SA {} of {iface_name}",
+                cur_tx_sa.an
+            );
+            del_sa(
+                handle,
+                vec![ifindex_attr(index)],
+                cur_tx_sa.an,
+                (MACSEC_CMD_DEL_TXSA, MACSEC_CMD_UPD_TXSA),
+            )
+            .await?;
+        }
+        let cur_tx_sa = cur_tx_sa.filter(|c| c.an == tx_sa.an);
+        apply_sa(
+            handle,
+            iface_name,
+            index,
+            None,
+            tx_sa,
+            cur_tx_sa,
+            (
+                MACSEC_CMD_ADD_TXSA,
+                MACSEC_CMD_DEL_TXSA,
+                MACSEC_CMD_UPD_TXSA,
+            ),
+        )
+        .await?;
+    }
+
+    if let Some(rx_scs) = conf.rx_sc.as_ref() {
+        let cur_rx_scs = cur_conf
+            .and_then(|c| c.rx_sc.as_deref())
+            .unwrap_or_default();
+        for cur_rx_sc in cur_rx_scs.iter().filter(|c| {
+            !rx_scs
+                .iter()
+                .any(|r| r.address == c.address && r.port == c.port)
+        }) {
+            log::info!(
+                "Removing MACsec RX SC {} port {} of {iface_name}",
+                cur_rx_sc.address,
+                cur_rx_sc.port
+            );
+            genl_request::<MacSecFamily>(
+                handle,
+                MACSEC_CMD_DEL_RXSC,
+                vec![ifindex_attr(index), rx_sc_config_attr(cur_rx_sc)?],
+                false,
+            )
+            .await?;
+        }
+        for rx_sc in rx_scs {
+            let cur_rx_sc = cur_rx_scs
+                .iter()
+                .find(|c| c.address == rx_sc.address && c.port == rx_sc.port);
+            if cur_rx_sc.is_none() {
+                log::info!(
+                    "Adding MACsec RX SC {} port {} to {iface_name}",
+                    rx_sc.address,
+                    rx_sc.port
+                );
+                genl_request::<MacSecFamily>(
+                    handle,
+                    MACSEC_CMD_ADD_RXSC,
+                    vec![ifindex_attr(index), rx_sc_config_attr(rx_sc)?],
+                    false,
+                )
+                .await?;
+            }
+            let sas = rx_sc.sa.as_deref().unwrap_or_default();
+            let cur_sas =
+                cur_rx_sc.and_then(|c| c.sa.as_deref()).unwrap_or_default();
+            for cur_sa in
+                cur_sas.iter().filter(|c| !sas.iter().any(|s| s.an == c.an))
+            {
+                log::info!(
+                    "Removing MACsec RX SA {} of RX SC {} port {} of \
+                    {iface_name}",
+                    cur_sa.an,
+                    rx_sc.address,
+                    rx_sc.port
+                );
+                del_sa(
+                    handle,
+                    vec![ifindex_attr(index), rx_sc_config_attr(rx_sc)?],
+                    cur_sa.an,
+                    (MACSEC_CMD_DEL_RXSA, MACSEC_CMD_UPD_RXSA),
+                )
+                .await?;
+            }
+            for sa in sas {
+                apply_sa(
+                    handle,
+                    iface_name,
+                    index,
+                    Some(rx_sc),
+                    sa,
+                    cur_sas.iter().find(|c| c.an == sa.an),
+                    (
+                        MACSEC_CMD_ADD_RXSA,
+                        MACSEC_CMD_DEL_RXSA,
+                        MACSEC_CMD_UPD_RXSA,
+                    ),
+                )
+                .await?;
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn apply_sa(
+    handle: &mut GenetlinkHandle,
+    iface_name: &str,
+    index: u32,
+    rx_sc: Option<&MacSecRxScConfig>,
+    sa: &MacSecSaConfig,
+    cur_sa: Option<&MacSecSaConfig>,
+    (add_cmd, del_cmd, upd_cmd): (u8, u8, u8),
+) -> Result<(), NmstateError> {
+    let mut attrs = vec![ifindex_attr(index)];
+    if let Some(rx_sc) = rx_sc {
+        attrs.push(rx_sc_config_attr(rx_sc)?);
+    }
+    if sa.is_key_hidden() {
+        if cur_sa.is_none() {
+            return Err(NmstateError::new(
+                crate::ErrorKind::InvalidArgument,
+                format!(
+                    "MACsec key of association number {} on {iface_name} \
+                    is required as it does not exist yet",
+                    sa.an
+                ),
+            ));
+        }
+        if let Some(pn) = sa.pn {
+            attrs.push(NlAttr::new(
+                MACSEC_ATTR_SA_CONFIG,
+                NlAttrValue::Nested(vec![
+                    NlAttr::new(MACSEC_SA_ATTR_AN, NlAttrValue::U8(sa.an)),
+                    NlAttr::new(MACSEC_SA_ATTR_PN, NlAttrValue::U32(pn)),
+                ]),
+            ));
+            genl_request::<MacSecFamily>(handle, upd_cmd, attrs, false).await?;
+        }
+        return Ok(());
+    }
+    if cur_sa.is_some() {
+        del_sa(handle, attrs.clone(), sa.an, (del_cmd, upd_cmd)).await?;
+    }
+    log::info!(
+        "Adding MACsec {} SA {} to {iface_name}",
+        if rx_sc.is_some() { "RX" } else { "TX" },
+        sa.an
+    );
+    attrs.push(new_sa_config_attr(sa));
+    genl_request::<MacSecFamily>(handle, add_cmd, attrs, false).await?;
+    Ok(())
+}
+
+// Kernel refuses to delete active SA, deactivate it first.
+async fn del_sa(
+    handle: &mut GenetlinkHandle,
+    attrs: Vec<NlAttr>,
+    an: u8,
+    (del_cmd, upd_cmd): (u8, u8),
+) -> Result<(), NmstateError> {
+    let mut upd_attrs = attrs.clone();
+    upd_attrs.push(NlAttr::new(
+        MACSEC_ATTR_SA_CONFIG,
+        NlAttrValue::Nested(vec![
+            NlAttr::new(MACSEC_SA_ATTR_AN, NlAttrValue::U8(an)),
+            NlAttr::new(MACSEC_SA_ATTR_ACTIVE, NlAttrValue::U8(0)),
+        ]),
+    ));
+    genl_request::<MacSecFamily>(handle, upd_cmd, upd_attrs, false).await?;
+    let mut del_attrs = attrs;
+    del_attrs.push(NlAttr::new(
+        MACSEC_ATTR_SA_CONFIG,
+        NlAttrValue::Nested(vec![NlAttr::new(
+            MACSEC_SA_ATTR_AN,
+            NlAttrValue::U8(an),
+        )]),
+    ));
+    genl_request::<MacSecFamily>(handle, del_cmd, del_attrs, false).await?;
+    Ok(())
+}
+
+fn ifindex_attr(index: u32) -> NlAttr {
+    NlAttr::new(MACSEC_ATTR_IFINDEX, NlAttrValue::U32(index))
+}
+
+fn rx_sc_config_attr(rx_sc: &MacSecRxScConfig) -> Result<NlAttr, NmstateError> {
+    Ok(NlAttr::new(
+        MACSEC_ATTR_RXSC_CONFIG,
+        NlAttrValue::Nested(vec![
+            NlAttr::new(
+                MACSEC_RXSC_ATTR_SCI,
+                NlAttrValue::Binary(gen_sci(&rx_sc.address, rx_sc.port)?),
+            ),
+            NlAttr::new(MACSEC_RXSC_ATTR_ACTIVE, NlAttrValue::U8(1)),
+        ]),
+    ))
+}
+
+fn new_sa_config_attr(sa: &MacSecSaConfig) -> NlAttr {
+    NlAttr::new(
+        MACSEC_ATTR_SA_CONFIG,
+        NlAttrValue::Nested(vec![
+            NlAttr::new(MACSEC_SA_ATTR_AN, NlAttrValue::U8(sa.an)),
+            NlAttr::new(
+                MACSEC_SA_ATTR_PN,
+                NlAttrValue::U32(sa.pn.unwrap_or(DEFAULT_PN)),
+            ),
+            NlAttr::new(MACSEC_SA_ATTR_ACTIVE, NlAttrValue::U8(1)),
+            NlAttr::new(
+                MACSEC_SA_ATTR_KEYID,
+                NlAttrValue::Binary(
+                    parse_hex_str(&sa.key_id).unwrap_or_default(),
+                ),
+            ),
+            NlAttr::new(
+                MACSEC_SA_ATTR_KEY,
+                NlAttrValue::Binary(
+                    sa.key
+                        .as_deref()
+                        .and_then(parse_hex_str)
+                        .unwrap_or_default(),
+                ),
+            ),
+        ]),
+    )
+}
+
+// The SCI is 6 bytes MAC address followed by 2 bytes port in network order
+fn gen_sci(address: &str, port: u16) -> Result<Vec<u8>, NmstateError> {
+    let mut sci = parse_hex_str(&address.replace(':', ""))
+        .filter(|m| m.len() == 6)
+        .ok_or_else(|| {
+            let e = NmstateError::new(
+                crate::ErrorKind::InvalidArgument,
+                format!("Invalid MACsec RX SC MAC address {address}"),
+            );
+            log::error!("{}", e);
+            e
+        })?;
+    sci.extend_from_slice(&port.to_be_bytes());
+    Ok(sci)
+}
+
+fn parse_nested_list(attr: &NlAttr) -> Vec<Vec<NlAttr>> {
+    parse_nl_attrs(attr.as_bytes())
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|a| parse_nl_attrs(a.as_bytes()).ok())
+        .collect()
+}
+
+fn parse_sa(attrs: &[NlAttr]) -> Option<MacSecSaConfig> {
+    let mut sa = MacSecSaConfig::new();
+    sa.an = find_nl_attr(attrs, MACSEC_SA_ATTR_AN)?.as_u8()?;
+    sa.pn = find_nl_attr(attrs, MACSEC_SA_ATTR_PN).and_then(|a| a.as_u32());
+    sa.key_id = find_nl_attr(attrs, MACSEC_SA_ATTR_KEYID)
+        .map(|a| a.as_bytes().iter().map(|b| format!("{b:02x}")).collect())
+        .unwrap_or_default();
+    sa.key = Some(NetworkState::PASSWORD_HID_BY_NMSTATE.to_string());
+    Some(sa)
+}
+
+fn parse_rx_sc(attrs: &[NlAttr]) -> Option<MacSecRxScConfig> {
+    let sci = find_nl_attr(attrs, MACSEC_RXSC_ATTR_SCI)?.as_bytes();
+    if sci.len() != 8 {
+        return None;
+    }
+    let mut rx_sc = MacSecRxScConfig::new();
+    rx_sc.address = sci[..6]
+        .iter()
+        .map(|b| format!("{b:02X}"))
+        .collect::<Vec<String>>()
+        .join(":");
+    rx_sc.port = u16::from_be_bytes([sci[6], sci[7]]);
+    if let Some(list_attr) = find_nl_attr(attrs, MACSEC_RXSC_ATTR_SA_LIST) {
+        rx_sc.sa = Some(
+            parse_nested_list(list_attr)
+                .iter()
+                .filter_map(|a| parse_sa(a))
+                .collect(),
+        );
+    }
+    Some(rx_sc)
+}
@@ -3,10 +3,13 @@
 mod apply;
 mod base_iface;
 mod bond;
+mod devlink;
 mod dns;
 mod error;
 mod ethernet;
 mod ethtool;
+pub(crate) mod ethtool_netlink;
+mod genl;
 mod hostname;
 mod hsr;
 mod infiniband;
@@ -14,17 +17,33 @@ mod ip;
 mod ipvlan;
 mod linux_bridge;
 mod linux_bridge_port_vlan;
+mod lldp;
 mod mac_vlan;
 mod macsec;
+mod macsec_netlink;
 mod mptcp;
+mod neighbor;
+mod nexthop;
+mod resolved;
 mod route;
 mod route_rule;
 mod show;
+mod sysctl;
 mod veth;
 mod vlan;
 mod vrf;
 mod vxlan;
 
 pub(crate) use apply::nispor_apply;
-pub(crate) use hostname::set_running_hostname;
+pub(crate) use ethernet::apply_sriov_vf_link_conf;
+pub(crate) use ethtool::apply_nm_unsupported_ethtool_conf;
+pub(crate) use hostname::{apply_pretty_hostname, set_running_hostname};
+pub(crate) use resolved::{
+    append_resolved_dns_policy, apply_resolved_dns_policy, get_resolved_conf,
+    restore_resolved_conf,
+};
 pub(crate) use show::nispor_retrieve;
+pub(crate) use sysctl::apply_ipv6_dad_and_ra_mtu;
+
+#[cfg(test)]
+pub(crate) use route_rule::nl_rule_to_nmstate;
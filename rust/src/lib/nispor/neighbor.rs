@@ -0,0 +1,254 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::net::IpAddr;
+
+use futures::stream::TryStreamExt;
+use rtnetlink::{
+    packet_route::{
+        neighbour::{
+            NeighbourAddress, NeighbourAttribute, NeighbourFlags,
+            NeighbourMessage, NeighbourState,
+        },
+        AddressFamily,
+    },
+    Handle, IpVersion,
+};
+
+use super::base_iface::get_iface_index;
+use crate::{
+    ErrorKind, MergedNeighbors, NeighborEntry, Neighbors, NmstateError,
+};
+
+const ENOENT: i32 = 2;
+
+pub(crate) async fn get_neighbors(
+    np_ifaces: &HashMap<String, nispor::Iface>,
+    running_config_only: bool,
+) -> Neighbors {
+    let mut ret = Neighbors::new();
+    let index_to_name: HashMap<u32, &str> = np_ifaces
+        .values()
+        .map(|i| (i.index, i.name.as_str()))
+        .collect();
+
+    let (conn, handle, _) = match rtnetlink::new_connection() {
+        Ok(c) => c,
+        Err(e) => {
+            log::warn!("Failed to create route netlink connection: {e}");
+            return ret;
+        }
+    };
+    tokio::spawn(conn);
+
+    let mut running: Vec<NeighborEntry> = Vec::new();
+    let mut config: Vec<NeighborEntry> = Vec::new();
+    for ip_version in [IpVersion::V4, IpVersion::V6] {
+        for proxy in [false, true] {
+            let mut request =
+                handle.neighbours().get().set_family(ip_version.clone());
+            if proxy {
+                request = request.proxies();
+            }
+            let mut neighs = request.execute();
+            loop {
+                match neighs.try_next().await {
+                    Ok(Some(nl_msg)) => {
+                        if let Some(neigh) =
+                            nl_msg_to_nmstate(&nl_msg, &index_to_name)
+                        {
+                            if neigh.is_proxy()
+                                || nl_msg.header.state
+                                    == NeighbourState::Permanent
+                            {
+                                config.push(neigh.clone());
+                            }
+                            if neigh.is_proxy()
+                                || neigh.link_layer_address.is_some()
+                                    && matches!(
+                                        nl_msg.header.state,
+                                        NeighbourState::Reachable
+                                            | NeighbourState::Stale
+                                            | NeighbourState::Delay
+                                            | NeighbourState::Probe
+                                            | NeighbourState::Permanent
+                                    )
+                            {
+                                running.push(neigh);
+                            }
+                        }
+                    }
+                    Ok(None) => break,
+                    Err(e) => {
+                        log::warn!("Failed to retrieve neighbors: {e}");
+                        break;
+                    }
+                }
+            }
+        }
+    }
+    if !running_config_only {
+        ret.running = Some(running);
+    }
+    ret.config = Some(config);
+    ret
+}
+
+fn nl_msg_to_nmstate(
+    nl_msg: &NeighbourMessage,
+    index_to_name: &HashMap<u32, &str>,
+) -> Option<NeighborEntry> {
+    let mut ret = NeighborEntry::new();
+    ret.interface =
+        Some(index_to_name.get(&nl_msg.header.ifindex)?.to_string());
+    if nl_msg.header.flags.contains(NeighbourFlags::Proxy) {
+        ret.proxy = Some(true);
+    }
+    for attr in nl_msg.attributes.as_slice() {
+        match attr {
+            NeighbourAttribute::Destination(NeighbourAddress::Inet(i)) => {
+                ret.ip = Some(i.to_string());
+            }
+            NeighbourAttribute::Destination(NeighbourAddress::Inet6(i)) => {
+                ret.ip = Some(i.to_string());
+            }
+            NeighbourAttribute::LinkLocalAddress(addr) if !addr.is_empty() => {
+                ret.link_layer_address = Some(
+                    addr.iter()
+                        .map(|b| format!("{b:02X}"))
+                        .collect::<Vec<String>>()
+                        .join(":"),
+                );
+            }
+            _ => (),
+        }
+    }
+    ret.ip.as_ref()?;
+    Some(ret)
+}
+
+pub(crate) async fn apply_neighbors(
+    merged_neighs: &MergedNeighbors,
+) -> Result<(), NmstateError> {
+    if merged_neighs.for_apply.is_empty() {
+        return Ok(());
+    }
+    let (conn, handle, _) = rtnetlink::new_connection().map_err(|e| {
+        NmstateError::new(
+            ErrorKind::Bug,
+            format!("Failed to create route netlink connection: {e}"),
+        )
+    })?;
+    tokio::spawn(conn);
+
+    // Kernel will remove the neighbors of deleted interface, hence
+    // neighbor not found is ignored.
+    for neigh in merged_neighs.for_apply.iter().filter(|n| n.is_absent()) {
+        if neigh
+            .interface
+            .as_deref()
+            .and_then(get_iface_index)
+            .is_none()
+        {
+            continue;
+        }
+        let (index, ip) = get_index_and_ip(neigh)?;
+        let mut message = NeighbourMessage::default();
+        message.header.family = if ip.is_ipv6() {
+            AddressFamily::Inet6
+        } else {
+            AddressFamily::Inet
+        };
+        message.header.ifindex = index;
+        if neigh.is_proxy() {
+            message.header.flags = NeighbourFlags::Proxy;
+        }
+        message
+            .attributes
+            .push(NeighbourAttribute::Destination(match ip {
+                IpAddr::V4(i) => NeighbourAddress::Inet(i),
+                IpAddr::V6(i) => NeighbourAddress::Inet6(i),
+            }));
+        if let Err(e) = handle.neighbours().del(message).execute().await {
+            if let rtnetlink::Error::NetlinkError(ref m) = e {
+                if m.raw_code() == -ENOENT {
+                    continue;
+                }
+            }
+            let e = NmstateError::new(
+                ErrorKind::PluginFailure,
+                format!("Failed to remove neighbor {neigh}: {e}"),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+    }
+
+    for neigh in merged_neighs.for_apply.iter().filter(|n| !n.is_absent()) {
+        add_neighbor(&handle, neigh).await?;
+    }
+    Ok(())
+}
+
+async fn add_neighbor(
+    handle: &Handle,
+    neigh: &NeighborEntry,
+) -> Result<(), NmstateError> {
+    let (index, ip) = get_index_and_ip(neigh)?;
+    let mut request = handle.neighbours().add(index, ip).replace();
+    if neigh.is_proxy() {
+        request = request
+            .flags(NeighbourFlags::Proxy)
+            .state(NeighbourState::None);
+    } else if let Some(addr) = neigh.link_layer_address.as_deref() {
+        request = request.link_local_address(
+            addr.split(':')
+                .filter_map(|b| u8::from_str_radix(b, 16).ok())
+                .collect::<Vec<u8>>()
+                .as_slice(),
+        );
+    }
+    if let Err(e) = request.execute().await {
+        let e = NmstateError::new(
+            ErrorKind::PluginFailure,
+            format!("Failed to add neighbor {neigh}: {e}"),
+        );
+        log::error!("{}", e);
+        return Err(e);
+    }
+    Ok(())
+}
+
+fn get_index_and_ip(
+    neigh: &NeighborEntry,
+) -> Result<(u32, IpAddr), NmstateError> {
+    let iface_name = neigh.interface.as_deref().unwrap_or_default();
+    let index = match get_iface_index(iface_name) {
+        Some(i) => i,
+        None => {
+            let e = NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "Interface {iface_name} of neighbor {neigh} does not \
+                    exist"
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+    };
+    let ip = neigh
+        .ip
+        .as_deref()
+        .unwrap_or_default()
+        .parse::<IpAddr>()
+        .map_err(|e| {
+            let e = NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!("Invalid IP address of neighbor {neigh}: {e}"),
+            );
+            log::error!("{}", e);
+            e
+        })?;
+    Ok((index, ip))
+}
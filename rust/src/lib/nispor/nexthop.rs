@@ -0,0 +1,625 @@
+// SPDX-License-Identifier: Apache-2.0
+
+// Nispor and netlink-packet-route do not support kernel nexthop objects yet,
+// use minimum route netlink message of `struct nhmsg` directly.
+
+use std::collections::HashMap;
+use std::net::IpAddr;
+
+use futures::{StreamExt, TryStreamExt};
+use netlink_packet_core::{
+    DecodeError, Emitable, NetlinkDeserializable, NetlinkHeader,
+    NetlinkMessage, NetlinkPayload, NetlinkSerializable, Nla, NLM_F_ACK,
+    NLM_F_CREATE, NLM_F_DUMP, NLM_F_REPLACE, NLM_F_REQUEST,
+};
+use rtnetlink::{
+    packet_route::{
+        route::{
+            self as rt, RouteAddress, RouteAttribute, RouteMessage, RouteType,
+        },
+        AddressFamily as RtAddressFamily,
+    },
+    proto::ConnectionHandle,
+    sys::{protocols::NETLINK_ROUTE, AsyncSocket, SocketAddr},
+    RouteMessageBuilder,
+};
+
+use super::{
+    base_iface::get_iface_index,
+    genl::{find_nl_attr, parse_nl_attrs, NlAttr, NlAttrValue},
+    route::np_route_protocol_to_nmstate,
+};
+use crate::{
+    AddressFamily, ErrorKind, MergedNexthops, NexthopEntry, NexthopGroupMember,
+    NexthopGroupType, NexthopResilientConfig, Nexthops, NmstateError,
+    RouteEntry, RouteScope,
+};
+
+// kernel values
+const RTM_NEWNEXTHOP: u16 = 104;
+const RTM_DELNEXTHOP: u16 = 105;
+const RTM_GETNEXTHOP: u16 = 106;
+
+const NHA_ID: u16 = 1;
+const NHA_GROUP: u16 = 2;
+const NHA_GROUP_TYPE: u16 = 3;
+const NHA_BLACKHOLE: u16 = 4;
+const NHA_OIF: u16 = 5;
+const NHA_GATEWAY: u16 = 6;
+const NHA_FDB: u16 = 11;
+const NHA_RES_GROUP: u16 = 12;
+
+const NHA_RES_GROUP_BUCKETS: u16 = 1;
+const NHA_RES_GROUP_IDLE_TIMER: u16 = 2;
+const NHA_RES_GROUP_UNBALANCED_TIMER: u16 = 3;
+
+const NEXTHOP_GRP_TYPE_MPATH: u16 = 0;
+const NEXTHOP_GRP_TYPE_RES: u16 = 1;
+
+const RTNH_F_ONLINK: u32 = 4;
+
+const RTPROT_UNSPEC: u8 = 0;
+const RTPROT_BOOT: u8 = 3;
+const RTPROT_STATIC: u8 = 4;
+
+const AF_UNSPEC: u8 = 0;
+const AF_INET: u8 = 2;
+const AF_INET6: u8 = 10;
+
+pub(crate) const RTA_NH_ID: u16 = 30;
+
+// The resilient group timers are in clock_t
+const USER_HZ: u32 = 100;
+
+// Size of `struct nhmsg`
+const NHMSG_LEN: usize = 8;
+// Size of `struct nexthop_grp`
+const NEXTHOP_GRP_LEN: usize = 8;
+
+const ENOENT: i32 = 2;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct NexthopMessage {
+    msg_type: u16,
+    family: u8,
+    protocol: u8,
+    flags: u32,
+    attrs: Vec<NlAttr>,
+}
+
+impl NetlinkSerializable for NexthopMessage {
+    fn message_type(&self) -> u16 {
+        self.msg_type
+    }
+
+    fn buffer_len(&self) -> usize {
+        NHMSG_LEN + self.attrs.as_slice().buffer_len()
+    }
+
+    fn serialize(&self, buffer: &mut [u8]) {
+        buffer[0] = self.family;
+        // Scope and reserved byte
+        buffer[1] = 0;
+        buffer[2] = self.protocol;
+        buffer[3] = 0;
+        buffer[4..NHMSG_LEN].copy_from_slice(&self.flags.to_ne_bytes());
+        self.attrs.as_slice().emit(&mut buffer[NHMSG_LEN..]);
+    }
+}
+
+impl NetlinkDeserializable for NexthopMessage {
+    type Error = DecodeError;
+
+    fn deserialize(
+        header: &NetlinkHeader,
+        payload: &[u8],
+    ) -> Result<Self, Self::Error> {
+        if payload.len() < NHMSG_LEN {
+            return Err(format!(
+                "Invalid nexthop message length {}",
+                payload.len()
+            )
+            .into());
+        }
+        Ok(Self {
+            msg_type: header.message_type,
+            family: payload[0],
+            protocol: payload[2],
+            flags: u32::from_ne_bytes([
+                payload[4], payload[5], payload[6], payload[7],
+            ]),
+            attrs: parse_nl_attrs(&payload[NHMSG_LEN..])?,
+        })
+    }
+}
+
+impl From<NexthopMessage> for NetlinkPayload<NexthopMessage> {
+    fn from(msg: NexthopMessage) -> Self {
+        NetlinkPayload::InnerMessage(msg)
+    }
+}
+
+fn new_nexthop_handle() -> Result<ConnectionHandle<NexthopMessage>, NmstateError>
+{
+    let (conn, handle, _) =
+        rtnetlink::proto::new_connection::<NexthopMessage>(NETLINK_ROUTE)
+            .map_err(|e| {
+                NmstateError::new(
+                    ErrorKind::Bug,
+                    format!("Failed to create nexthop netlink connection: {e}"),
+                )
+            })?;
+    tokio::spawn(conn);
+    Ok(handle)
+}
+
+async fn nexthop_request(
+    handle: &ConnectionHandle<NexthopMessage>,
+    msg: NexthopMessage,
+    flags: u16,
+) -> Result<Vec<NexthopMessage>, std::io::Error> {
+    let mut nl_msg = NetlinkMessage::from(msg);
+    nl_msg.header.flags = flags;
+    nl_msg.finalize();
+
+    let mut replies =
+        handle.request(nl_msg, SocketAddr::new(0, 0)).map_err(|e| {
+            std::io::Error::new(std::io::ErrorKind::Other, e.to_string())
+        })?;
+
+    let mut ret = Vec::new();
+    while let Some(reply) = replies.next().await {
+        match reply.payload {
+            NetlinkPayload::InnerMessage(msg) => ret.push(msg),
+            NetlinkPayload::Error(e) if e.code.is_some() => {
+                return Err(e.to_io());
+            }
+            _ => (),
+        }
+    }
+    Ok(ret)
+}
+
+async fn dump_nexthops() -> Result<Vec<NexthopMessage>, NmstateError> {
+    let handle = new_nexthop_handle()?;
+    nexthop_request(
+        &handle,
+        NexthopMessage {
+            msg_type: RTM_GETNEXTHOP,
+            ..Default::default()
+        },
+        NLM_F_REQUEST | NLM_F_DUMP,
+    )
+    .await
+    .map_err(|e| {
+        NmstateError::new(
+            ErrorKind::PluginFailure,
+            format!("Failed to dump nexthops: {e}"),
+        )
+    })
+}
+
+// Only nexthops created by static or boot protocol are included, nexthops
+// created by routing daemons are managed by their daemons.
+pub(crate) async fn get_nexthops(
+    np_ifaces: &HashMap<String, nispor::Iface>,
+) -> Nexthops {
+    let nh_msgs = match dump_nexthops().await {
+        Ok(m) => m,
+        Err(e) => {
+            log::debug!("Failed to query nexthops: {e}");
+            return Nexthops::new();
+        }
+    };
+    let index_to_name: HashMap<u32, &str> = np_ifaces
+        .values()
+        .map(|i| (i.index, i.name.as_str()))
+        .collect();
+
+    let mut nhs: Vec<NexthopEntry> = nh_msgs
+        .iter()
+        .filter(|m| {
+            [RTPROT_UNSPEC, RTPROT_BOOT, RTPROT_STATIC].contains(&m.protocol)
+        })
+        .filter_map(|m| nh_msg_to_nmstate(m, &index_to_name))
+        .collect();
+
+    if nhs.is_empty() {
+        Nexthops::new()
+    } else {
+        nhs.sort_unstable_by_key(|n| n.id);
+        Nexthops { config: Some(nhs) }
+    }
+}
+
+fn nh_msg_to_nmstate(
+    nh_msg: &NexthopMessage,
+    index_to_name: &HashMap<u32, &str>,
+) -> Option<NexthopEntry> {
+    let attrs = nh_msg.attrs.as_slice();
+    if find_nl_attr(attrs, NHA_FDB).is_some() {
+        log::debug!("Ignoring FDB nexthop {:?}", nh_msg);
+        return None;
+    }
+    let mut ret = NexthopEntry::new();
+    ret.id = Some(find_nl_attr(attrs, NHA_ID).and_then(|a| a.as_u32())?);
+
+    if let Some(group) = find_nl_attr(attrs, NHA_GROUP) {
+        ret.group = Some(
+            group
+                .as_bytes()
+                .chunks_exact(NEXTHOP_GRP_LEN)
+                .map(|b| {
+                    NexthopGroupMember::new(
+                        u32::from_ne_bytes([b[0], b[1], b[2], b[3]]),
+                        // The kernel is storing weight - 1
+                        Some(u16::from_le_bytes([b[4], b[5]]) + 1),
+                    )
+                })
+                .collect(),
+        );
+        ret.group_type = Some(
+            match find_nl_attr(attrs, NHA_GROUP_TYPE).and_then(|a| a.as_u16()) {
+                Some(NEXTHOP_GRP_TYPE_RES) => NexthopGroupType::Resilient,
+                _ => NexthopGroupType::Multipath,
+            },
+        );
+        if let Some(res_attr) = find_nl_attr(attrs, NHA_RES_GROUP) {
+            if let Ok(res_attrs) = parse_nl_attrs(res_attr.as_bytes()) {
+                let mut res_conf = NexthopResilientConfig::new();
+                res_conf.buckets =
+                    find_nl_attr(res_attrs.as_slice(), NHA_RES_GROUP_BUCKETS)
+                        .and_then(|a| a.as_u16());
+                res_conf.idle_timer = find_nl_attr(
+                    res_attrs.as_slice(),
+                    NHA_RES_GROUP_IDLE_TIMER,
+                )
+                .and_then(|a| a.as_u32())
+                .map(|t| t / USER_HZ);
+                res_conf.unbalanced_timer = find_nl_attr(
+                    res_attrs.as_slice(),
+                    NHA_RES_GROUP_UNBALANCED_TIMER,
+                )
+                .and_then(|a| a.as_u32())
+                .map(|t| t / USER_HZ);
+                ret.resilient = Some(res_conf);
+            }
+        }
+        return Some(ret);
+    }
+
+    ret.family = match nh_msg.family {
+        AF_INET => Some(AddressFamily::IPv4),
+        AF_INET6 => Some(AddressFamily::IPv6),
+        _ => None,
+    };
+    if find_nl_attr(attrs, NHA_BLACKHOLE).is_some() {
+        ret.blackhole = Some(true);
+        return Some(ret);
+    }
+    if let Some(index) = find_nl_attr(attrs, NHA_OIF).and_then(|a| a.as_u32()) {
+        match index_to_name.get(&index) {
+            Some(name) => ret.next_hop_iface = Some(name.to_string()),
+            None => {
+                log::debug!(
+                    "Ignoring nexthop {:?} with unknown interface index \
+                    {index}",
+                    ret.id
+                );
+                return None;
+            }
+        }
+    }
+    ret.next_hop_addr = find_nl_attr(attrs, NHA_GATEWAY).and_then(|a| {
+        let bytes = a.as_bytes();
+        if let Ok(octets) = <[u8; 4]>::try_from(bytes) {
+            Some(IpAddr::from(octets).to_string())
+        } else if let Ok(octets) = <[u8; 16]>::try_from(bytes) {
+            Some(IpAddr::from(octets).to_string())
+        } else {
+            None
+        }
+    });
+    if nh_msg.flags & RTNH_F_ONLINK > 0 {
+        ret.onlink = Some(true);
+    }
+    Some(ret)
+}
+
+fn nmstate_nh_to_nh_msg(
+    nh: &NexthopEntry,
+) -> Result<NexthopMessage, NmstateError> {
+    let mut msg = NexthopMessage {
+        msg_type: RTM_NEWNEXTHOP,
+        protocol: RTPROT_STATIC,
+        ..Default::default()
+    };
+    if let Some(id) = nh.id {
+        msg.attrs.push(NlAttr::new(NHA_ID, NlAttrValue::U32(id)));
+    }
+    if let Some(members) = nh.group.as_deref() {
+        msg.family = AF_UNSPEC;
+        let mut buf = Vec::new();
+        for member in members {
+            buf.extend_from_slice(&member.id.to_ne_bytes());
+            // Weight is in the range of 1-256, the kernel is storing
+            // weight - 1 as u8
+            buf.push((member.weight.unwrap_or(1).saturating_sub(1)) as u8);
+            buf.extend_from_slice(&[0u8; 3]);
+        }
+        msg.attrs
+            .push(NlAttr::new(NHA_GROUP, NlAttrValue::Binary(buf)));
+        if nh.group_type == Some(NexthopGroupType::Resilient) {
+            msg.attrs.push(NlAttr::new(
+                NHA_GROUP_TYPE,
+                NlAttrValue::U16(NEXTHOP_GRP_TYPE_RES),
+            ));
+            let mut res_attrs = Vec::new();
+            if let Some(res_conf) = nh.resilient.as_ref() {
+                if let Some(v) = res_conf.buckets {
+                    res_attrs.push(NlAttr::new(
+                        NHA_RES_GROUP_BUCKETS,
+                        NlAttrValue::U16(v),
+                    ));
+                }
+                if let Some(v) = res_conf.idle_timer {
+                    res_attrs.push(NlAttr::new(
+                        NHA_RES_GROUP_IDLE_TIMER,
+                        NlAttrValue::U32(v.saturating_mul(USER_HZ)),
+                    ));
+                }
+                if let Some(v) = res_conf.unbalanced_timer {
+                    res_attrs.push(NlAttr::new(
+                        NHA_RES_GROUP_UNBALANCED_TIMER,
+                        NlAttrValue::U32(v.saturating_mul(USER_HZ)),
+                    ));
+                }
+            }
+            msg.attrs.push(NlAttr::new(
+                NHA_RES_GROUP,
+                NlAttrValue::Nested(res_attrs),
+            ));
+        } else {
+            msg.attrs.push(NlAttr::new(
+                NHA_GROUP_TYPE,
+                NlAttrValue::U16(NEXTHOP_GRP_TYPE_MPATH),
+            ));
+        }
+        return Ok(msg);
+    }
+
+    msg.family = if nh.is_ipv6() { AF_INET6 } else { AF_INET };
+    if nh.blackhole == Some(true) {
+        msg.attrs
+            .push(NlAttr::new(NHA_BLACKHOLE, NlAttrValue::Flag));
+        return Ok(msg);
+    }
+    if let Some(iface_name) = nh.next_hop_iface.as_deref() {
+        if let Some(index) = get_iface_index(iface_name) {
+            msg.attrs
+                .push(NlAttr::new(NHA_OIF, NlAttrValue::U32(index)));
+        } else {
+            let e = NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "Next hop interface {iface_name} of nexthop {nh} \
+                    not found"
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+    }
+    if let Some(via) = nh.next_hop_addr.as_deref() {
+        let octets = match via.parse::<IpAddr>() {
+            Ok(IpAddr::V4(i)) => i.octets().to_vec(),
+            Ok(IpAddr::V6(i)) => i.octets().to_vec(),
+            Err(e) => {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!("Invalid next-hop-address of nexthop {nh}: {e}"),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        };
+        msg.attrs
+            .push(NlAttr::new(NHA_GATEWAY, NlAttrValue::Binary(octets)));
+    }
+    if nh.onlink == Some(true) {
+        msg.flags |= RTNH_F_ONLINK;
+    }
+    Ok(msg)
+}
+
+// Nexthop group members should be created before group.
+pub(crate) async fn apply_nexthops(
+    merged_nhs: &MergedNexthops,
+) -> Result<(), NmstateError> {
+    let mut nhs: Vec<&NexthopEntry> = merged_nhs
+        .for_apply
+        .iter()
+        .filter(|n| !n.is_absent())
+        .collect();
+    if nhs.is_empty() {
+        return Ok(());
+    }
+    nhs.sort_by_key(|n| n.is_group());
+
+    let handle = new_nexthop_handle()?;
+    for nh in nhs {
+        if let Err(e) = nexthop_request(
+            &handle,
+            nmstate_nh_to_nh_msg(nh)?,
+            NLM_F_REQUEST | NLM_F_ACK | NLM_F_CREATE | NLM_F_REPLACE,
+        )
+        .await
+        {
+            let e = NmstateError::new(
+                ErrorKind::PluginFailure,
+                format!("Failed to add nexthop {nh}: {e}"),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+    }
+    Ok(())
+}
+
+// Nexthop group should be removed before its members. Kernel will remove the
+// nexthop group automatically when all its members are removed, hence
+// nexthop not found is ignored.
+pub(crate) async fn delete_nexthops(
+    merged_nhs: &MergedNexthops,
+) -> Result<(), NmstateError> {
+    let mut nhs: Vec<&NexthopEntry> = merged_nhs
+        .for_apply
+        .iter()
+        .filter(|n| n.is_absent())
+        .collect();
+    if nhs.is_empty() {
+        return Ok(());
+    }
+    nhs.sort_by_key(|n| !n.is_group());
+
+    let handle = new_nexthop_handle()?;
+    for nh in nhs {
+        let id = if let Some(i) = nh.id {
+            i
+        } else {
+            continue;
+        };
+        let msg = NexthopMessage {
+            msg_type: RTM_DELNEXTHOP,
+            attrs: vec![NlAttr::new(NHA_ID, NlAttrValue::U32(id))],
+            ..Default::default()
+        };
+        if let Err(e) =
+            nexthop_request(&handle, msg, NLM_F_REQUEST | NLM_F_ACK).await
+        {
+            if e.raw_os_error() == Some(ENOENT) {
+                continue;
+            }
+            let e = NmstateError::new(
+                ErrorKind::PluginFailure,
+                format!("Failed to remove nexthop {nh}: {e}"),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+    }
+    Ok(())
+}
+
+// Nispor does not expose the nexthop ID of route, query the routes using
+// nexthop objects of specified protocols via rtnetlink directly.
+// Only do so when nexthop exists to avoid additional route dump.
+pub(crate) async fn get_nexthop_routes(
+    protocols: &[nispor::RouteProtocol],
+) -> Vec<RouteEntry> {
+    let mut ret = Vec::new();
+    match dump_nexthops().await {
+        Ok(m) if !m.is_empty() => (),
+        _ => return ret,
+    }
+    let (mut conn, handle, _) = match rtnetlink::new_connection() {
+        Ok(c) => c,
+        Err(e) => {
+            log::warn!("Failed to create route netlink connection: {e}");
+            return ret;
+        }
+    };
+    if let Err(e) = conn
+        .socket_mut()
+        .socket_mut()
+        .set_netlink_get_strict_chk(true)
+    {
+        log::warn!("Failed to enable kernel space route filter: {e}");
+    }
+    tokio::spawn(conn);
+
+    for protocol in protocols {
+        for is_ipv6 in [false, true] {
+            let rt_msg = if is_ipv6 {
+                RouteMessageBuilder::<std::net::Ipv6Addr>::new().build()
+            } else {
+                RouteMessageBuilder::<std::net::Ipv4Addr>::new().build()
+            };
+            let mut request = handle.route().get(rt_msg);
+            let rt_nlmsg = request.message_mut();
+            rt_nlmsg.header.kind = RouteType::Unspec;
+            rt_nlmsg.header.protocol = (*protocol).into();
+            rt_nlmsg.attributes.push(RouteAttribute::Table(0));
+            let mut routes = request.execute();
+            loop {
+                match routes.try_next().await {
+                    Ok(Some(rt_msg)) => {
+                        if let Some(route) = rt_msg_to_nmstate(&rt_msg) {
+                            ret.push(route);
+                        }
+                    }
+                    Ok(None) => break,
+                    Err(e) => {
+                        log::warn!(
+                            "Failed to retrieve {protocol:?} route using \
+                            nexthop: {e}"
+                        );
+                        break;
+                    }
+                }
+            }
+        }
+    }
+    ret
+}
+
+fn rt_msg_to_nmstate(rt_msg: &RouteMessage) -> Option<RouteEntry> {
+    let mut nexthop_id = None;
+    let mut dst = None;
+    let mut table_id = u32::from(rt_msg.header.table);
+    let mut metric = None;
+    for attr in rt_msg.attributes.as_slice() {
+        match attr {
+            RouteAttribute::Other(nla) if nla.kind() == RTA_NH_ID => {
+                let mut buf = [0u8; 4];
+                if nla.value_len() == buf.len() {
+                    nla.emit_value(&mut buf);
+                    nexthop_id = Some(u32::from_ne_bytes(buf));
+                }
+            }
+            RouteAttribute::Destination(RouteAddress::Inet(i)) => {
+                dst = Some(IpAddr::from(*i));
+            }
+            RouteAttribute::Destination(RouteAddress::Inet6(i)) => {
+                dst = Some(IpAddr::from(*i));
+            }
+            RouteAttribute::Table(t) => table_id = *t,
+            RouteAttribute::Priority(m) => metric = Some(i64::from(*m)),
+            _ => (),
+        }
+    }
+    let nexthop_id = nexthop_id?;
+    let dst = match dst {
+        Some(d) => d,
+        None => match rt_msg.header.address_family {
+            RtAddressFamily::Inet => IpAddr::from([0u8; 4]),
+            RtAddressFamily::Inet6 => IpAddr::from([0u8; 16]),
+            _ => return None,
+        },
+    };
+
+    let mut ret = RouteEntry::new();
+    ret.destination =
+        Some(format!("{dst}/{}", rt_msg.header.destination_prefix_length));
+    ret.nexthop_id = Some(nexthop_id);
+    ret.table_id = Some(table_id);
+    ret.metric = Some(metric.unwrap_or_default());
+    ret.protocol = np_route_protocol_to_nmstate(rt_msg.header.protocol.into());
+    ret.scope = match rt_msg.header.scope {
+        rt::RouteScope::Site => Some(RouteScope::Site),
+        rt::RouteScope::Link => Some(RouteScope::Link),
+        rt::RouteScope::Host => Some(RouteScope::Host),
+        _ => None,
+    };
+    Some(ret)
+}
@@ -0,0 +1,208 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Write as _FmtWrite;
+use std::io::Write;
+use std::os::unix::fs::OpenOptionsExt;
+use std::str::FromStr;
+
+use crate::{
+    DnsClientState, DnsOverTls, DnsState, Dnssec, ErrorKind, MergedDnsState,
+    NmstateError,
+};
+
+const RESOLVED_CONF_DIR: &str = "/etc/systemd/resolved.conf.d";
+const RESOLVED_CONF_PATH: &str = "/etc/systemd/resolved.conf.d/nmstate.conf";
+const RESOLVED_SERVICE: &str = "systemd-resolved.service";
+
+const RESOLVED_DBUS_NAME: &str = "org.freedesktop.resolve1";
+const RESOLVED_DBUS_PATH: &str = "/org/freedesktop/resolve1";
+const RESOLVED_DBUS_IFACE: &str = "org.freedesktop.resolve1.Manager";
+
+const SYSTEMD_DBUS_NAME: &str = "org.freedesktop.systemd1";
+const SYSTEMD_DBUS_PATH: &str = "/org/freedesktop/systemd1";
+const SYSTEMD_DBUS_IFACE: &str = "org.freedesktop.systemd1.Manager";
+
+// Read DNSSEC and DNS-over-TLS policy from nmstate owned systemd-resolved
+// config. Return (None, None) if not found.
+fn get_config_dns_policy() -> (Option<DnsOverTls>, Option<Dnssec>) {
+    let content = match std::fs::read_to_string(RESOLVED_CONF_PATH) {
+        Ok(c) => c,
+        Err(_) => return (None, None),
+    };
+    let mut dns_over_tls = None;
+    let mut dnssec = None;
+    for line in content.lines().map(|l| l.trim()) {
+        if let Some(v) = line.strip_prefix("DNSOverTLS=") {
+            match DnsOverTls::from_str(v.trim()) {
+                Ok(v) => dns_over_tls = Some(v),
+                Err(e) => log::warn!("{RESOLVED_CONF_PATH}: {e}"),
+            }
+        } else if let Some(v) = line.strip_prefix("DNSSEC=") {
+            match Dnssec::from_str(v.trim()) {
+                Ok(v) => dnssec = Some(v),
+                Err(e) => log::warn!("{RESOLVED_CONF_PATH}: {e}"),
+            }
+        }
+    }
+    (dns_over_tls, dnssec)
+}
+
+// Query effective DNSSEC and DNS-over-TLS policy from systemd-resolved.
+// Return None if systemd-resolved is not running.
+fn get_running_dns_policy() -> Option<(Option<DnsOverTls>, Option<Dnssec>)> {
+    let connection = match zbus::Connection::new_system() {
+        Ok(c) => c,
+        Err(e) => {
+            log::debug!("Failed to connect to system D-Bus: {e}");
+            return None;
+        }
+    };
+    let proxy = zbus::Proxy::new(
+        &connection,
+        RESOLVED_DBUS_NAME,
+        RESOLVED_DBUS_PATH,
+        RESOLVED_DBUS_IFACE,
+    )
+    .ok()?;
+    let dns_over_tls = match proxy.get_property::<String>("DNSOverTLS") {
+        Ok(v) => DnsOverTls::from_str(v.as_str()).ok(),
+        Err(e) => {
+            log::debug!("Failed to query DNSOverTLS of systemd-resolved: {e}");
+            return None;
+        }
+    };
+    let dnssec = proxy
+        .get_property::<String>("DNSSEC")
+        .ok()
+        .and_then(|v| Dnssec::from_str(v.as_str()).ok());
+    Some((dns_over_tls, dnssec))
+}
+
+// The DNS-over-TLS policy stored in NetworkManager profiles takes precedence
+// over the one stored in systemd-resolved config.
+pub(crate) fn append_resolved_dns_policy(
+    dns_state: &mut DnsState,
+    running_config_only: bool,
+) {
+    let (dns_over_tls, dnssec) = get_config_dns_policy();
+    if dns_over_tls.is_some() || dnssec.is_some() {
+        let config =
+            dns_state.config.get_or_insert_with(DnsClientState::default);
+        if config.dns_over_tls.is_none() {
+            config.dns_over_tls = dns_over_tls;
+        }
+        config.dnssec = dnssec;
+    }
+    if !running_config_only {
+        if let Some((dns_over_tls, dnssec)) = get_running_dns_policy() {
+            let running = dns_state
+                .running
+                .get_or_insert_with(DnsClientState::default);
+            running.dns_over_tls = dns_over_tls;
+            running.dnssec = dnssec;
+        }
+    }
+}
+
+// Return None if nmstate owned systemd-resolved config does not exist.
+pub(crate) fn get_resolved_conf() -> Option<String> {
+    std::fs::read_to_string(RESOLVED_CONF_PATH).ok()
+}
+
+// NetworkManager stores DNS-over-TLS policy into `connection.dns-over-tls` of
+// connection profiles, hence only DNSSEC policy is stored to systemd-resolved
+// config when `with_dns_over_tls` is false.
+pub(crate) fn apply_resolved_dns_policy(
+    merged_dns: &MergedDnsState,
+    with_dns_over_tls: bool,
+) -> Result<(), NmstateError> {
+    let dns_over_tls = if with_dns_over_tls {
+        merged_dns.dns_over_tls
+    } else {
+        None
+    };
+    let mut content = String::new();
+    if dns_over_tls.is_some() || merged_dns.dnssec.is_some() {
+        writeln!(content, "# Generated by nmstate\n[Resolve]").ok();
+        if let Some(v) = dns_over_tls {
+            writeln!(content, "DNSOverTLS={v}").ok();
+        }
+        if let Some(v) = merged_dns.dnssec {
+            writeln!(content, "DNSSEC={v}").ok();
+        }
+    }
+    set_resolved_conf(content.as_str())
+}
+
+// The systemd-resolved config is not included in NetworkManager checkpoint,
+// restore it to the content stored before apply.
+pub(crate) fn restore_resolved_conf(content: Option<&str>) {
+    log::info!("Restoring {RESOLVED_CONF_PATH}");
+    if let Err(e) = set_resolved_conf(content.unwrap_or_default()) {
+        log::warn!("Failed to restore {RESOLVED_CONF_PATH}: {e}");
+    }
+}
+
+// Empty content means removing the config file. The systemd-resolved is only
+// restarted when the config changed.
+fn set_resolved_conf(content: &str) -> Result<(), NmstateError> {
+    let cur_content =
+        std::fs::read_to_string(RESOLVED_CONF_PATH).unwrap_or_default();
+    if cur_content == content {
+        return Ok(());
+    }
+
+    if content.is_empty() {
+        log::info!("Removing {RESOLVED_CONF_PATH}");
+        if let Err(e) = std::fs::remove_file(RESOLVED_CONF_PATH) {
+            let e = NmstateError::new(
+                ErrorKind::Bug,
+                format!("Failed to remove {RESOLVED_CONF_PATH}: {e}"),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+    } else {
+        log::info!("Overriding {RESOLVED_CONF_PATH} with:\n{content}");
+        if let Err(e) = write_resolved_conf(content) {
+            let e = NmstateError::new(
+                ErrorKind::Bug,
+                format!("Failed to apply config to {RESOLVED_CONF_PATH}: {e}"),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+    }
+    restart_resolved();
+    Ok(())
+}
+
+fn write_resolved_conf(content: &str) -> Result<(), std::io::Error> {
+    std::fs::create_dir_all(RESOLVED_CONF_DIR)?;
+    let mut fd = std::fs::OpenOptions::new()
+        .create(true)
+        .write(true)
+        .truncate(true)
+        .mode(0o644)
+        .open(RESOLVED_CONF_PATH)?;
+    fd.write_all(content.as_bytes())
+}
+
+// systemd-resolved only load config on start, restart it if running.
+fn restart_resolved() {
+    let result = zbus::Connection::new_system().and_then(|connection| {
+        let proxy = zbus::Proxy::new(
+            &connection,
+            SYSTEMD_DBUS_NAME,
+            SYSTEMD_DBUS_PATH,
+            SYSTEMD_DBUS_IFACE,
+        )?;
+        proxy.call::<_, zvariant::OwnedObjectPath>(
+            "TryRestartUnit",
+            &(RESOLVED_SERVICE, "replace"),
+        )
+    });
+    if let Err(e) = result {
+        log::warn!("Failed to restart {RESOLVED_SERVICE}: {e}");
+    }
+}
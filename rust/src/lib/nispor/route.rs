@@ -1,13 +1,32 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use std::net::IpAddr;
+
 use log::warn;
+use rtnetlink::{
+    packet_core::DefaultNla,
+    packet_route::route::{
+        self as rt, RouteAttribute, RouteMessage, RouteMetric,
+    },
+    RouteMessageBuilder,
+};
+
+use super::{
+    base_iface::get_iface_index,
+    nexthop::{get_nexthop_routes, RTA_NH_ID},
+};
 
 use crate::{
-    ErrorKind, MergedRoutes, NmstateError, RouteEntry, RouteType, Routes,
+    ErrorKind, MergedRoutes, NmstateError, RouteEntry, RouteMetricLock,
+    RoutePreference, RouteProtocol, RouteScope, RouteType, Routes,
 };
 
-const SUPPORTED_ROUTE_SCOPE: [nispor::RouteScope; 2] =
-    [nispor::RouteScope::Universe, nispor::RouteScope::Link];
+const SUPPORTED_ROUTE_SCOPE: [nispor::RouteScope; 4] = [
+    nispor::RouteScope::Universe,
+    nispor::RouteScope::Site,
+    nispor::RouteScope::Link,
+    nispor::RouteScope::Host,
+];
 
 const SUPPORTED_ROUTE_PROTOCOL: [nispor::RouteProtocol; 7] = [
     nispor::RouteProtocol::Boot,
@@ -26,9 +45,14 @@ const IPV4_DEFAULT_GATEWAY: &str = "0.0.0.0/0";
 const IPV6_DEFAULT_GATEWAY: &str = "::/0";
 const IPV4_EMPTY_NEXT_HOP_ADDRESS: &str = "0.0.0.0";
 const IPV6_EMPTY_NEXT_HOP_ADDRESS: &str = "::";
+const DEFAULT_ROUTE_TABLE: u32 = 254;
 
 // kernel values
+const RTAX_MTU: u32 = 2;
 const RTAX_CWND: u32 = 7;
+const RTAX_ADVMSS: u32 = 8;
+const RTAX_INITCWND: u32 = 11;
+const RTAX_INITRWND: u32 = 14;
 
 pub(crate) async fn get_routes(running_config_only: bool) -> Routes {
     let mut ret = Routes::new();
@@ -65,6 +89,16 @@ pub(crate) async fn get_routes(running_config_only: bool) -> Routes {
         }
     }
 
+    // Nispor shows route using nexthop object as normal route, replace them
+    // with the ones holding nexthop ID.
+    let nh_routes = get_nexthop_routes(protocols).await;
+    let is_nh_route = |route: &RouteEntry| {
+        nh_routes.iter().any(|nh_rt| {
+            nh_rt.destination == route.destination
+                && nh_rt.table_id == route.table_id
+        })
+    };
+
     if !running_config_only {
         let mut running_routes = Vec::new();
         for np_route in np_routes
@@ -81,6 +115,8 @@ pub(crate) async fn get_routes(running_config_only: bool) -> Routes {
                 running_routes.push(np_route_to_nmstate(np_route));
             }
         }
+        running_routes.retain(|r| !is_nh_route(r));
+        running_routes.extend_from_slice(nh_routes.as_slice());
         ret.running = Some(running_routes);
     }
 
@@ -99,6 +135,13 @@ pub(crate) async fn get_routes(running_config_only: bool) -> Routes {
             config_routes.push(np_route_to_nmstate(np_route));
         }
     }
+    config_routes.retain(|r| !is_nh_route(r));
+    for nh_route in nh_routes.iter().filter(|r| {
+        [Some(RouteProtocol::Boot), Some(RouteProtocol::Static)]
+            .contains(&r.protocol)
+    }) {
+        config_routes.push(nh_route.clone());
+    }
     ret.config = Some(config_routes);
     ret
 }
@@ -148,6 +191,7 @@ fn np_routetype_to_nmstate(np_route: &nispor::Route) -> RouteEntry {
     // we require both cwnd and its lock flag to consider cwnd as set.
     let cwnd_lock = np_route.lock.unwrap_or(0) & (1 << RTAX_CWND) != 0;
     route_entry.cwnd = if cwnd_lock { np_route.cwnd } else { None };
+    np_route_attrs_to_nmstate(np_route, &mut route_entry);
 
     route_entry
 }
@@ -206,10 +250,100 @@ fn np_route_to_nmstate(np_route: &nispor::Route) -> RouteEntry {
     // we require both cwnd and its lock flag to consider cwnd as set.
     let cwnd_lock = np_route.lock.unwrap_or(0) & (1 << RTAX_CWND) != 0;
     route_entry.cwnd = if cwnd_lock { np_route.cwnd } else { None };
+    np_route_metrics_to_nmstate(np_route, &mut route_entry);
+    np_route_attrs_to_nmstate(np_route, &mut route_entry);
 
     route_entry
 }
 
+// Only show scope and preference when not default
+fn np_route_attrs_to_nmstate(
+    np_route: &nispor::Route,
+    route_entry: &mut RouteEntry,
+) {
+    route_entry.scope = match np_route.scope {
+        nispor::RouteScope::Site => Some(RouteScope::Site),
+        nispor::RouteScope::Link => Some(RouteScope::Link),
+        nispor::RouteScope::Host => Some(RouteScope::Host),
+        _ => None,
+    };
+    route_entry.protocol = np_route_protocol_to_nmstate(np_route.protocol);
+    // The `nispor::RoutePreference` is not public, convert it via its
+    // serialized string
+    route_entry.pref = np_route
+        .preference
+        .as_ref()
+        .and_then(|p| serde_json::to_value(p).ok())
+        .and_then(|v| serde_json::from_value::<RoutePreference>(v).ok())
+        .filter(|p| *p != RoutePreference::Medium);
+}
+
+pub(crate) fn np_route_protocol_to_nmstate(
+    protocol: nispor::RouteProtocol,
+) -> Option<RouteProtocol> {
+    match protocol {
+        nispor::RouteProtocol::Kernel => Some(RouteProtocol::Kernel),
+        nispor::RouteProtocol::Boot => Some(RouteProtocol::Boot),
+        nispor::RouteProtocol::Static => Some(RouteProtocol::Static),
+        nispor::RouteProtocol::Ra => Some(RouteProtocol::Ra),
+        nispor::RouteProtocol::Dhcp => Some(RouteProtocol::Dhcp),
+        nispor::RouteProtocol::Mrouted => Some(RouteProtocol::Mrouted),
+        nispor::RouteProtocol::KeepAlived => Some(RouteProtocol::Keepalived),
+        nispor::RouteProtocol::Babel => Some(RouteProtocol::Babel),
+        nispor::RouteProtocol::Zebra => Some(RouteProtocol::Zebra),
+        nispor::RouteProtocol::Bird => Some(RouteProtocol::Bird),
+        nispor::RouteProtocol::Bgp => Some(RouteProtocol::Bgp),
+        nispor::RouteProtocol::Isis => Some(RouteProtocol::Isis),
+        nispor::RouteProtocol::Ospf => Some(RouteProtocol::Ospf),
+        nispor::RouteProtocol::Rip => Some(RouteProtocol::Rip),
+        nispor::RouteProtocol::Eigrp => Some(RouteProtocol::Eigrp),
+        _ => {
+            log::debug!("Got unsupported route protocol {:?}", protocol);
+            None
+        }
+    }
+}
+
+fn np_route_metrics_to_nmstate(
+    np_route: &nispor::Route,
+    route_entry: &mut RouteEntry,
+) {
+    route_entry.mtu = np_route.mtu;
+    route_entry.advmss = np_route.advmss;
+    route_entry.initcwnd = np_route.initcwnd;
+    route_entry.initrwnd = np_route.initrwnd;
+    route_entry.rto_min = np_route.rto_min;
+    if np_route.quickack.unwrap_or_default() != 0 {
+        route_entry.quickack = Some(true);
+    }
+    // The `nispor::RouteFlag` is not public, compare its serialized string
+    if np_route.flags.iter().any(|f| {
+        serde_json::to_value(f)
+            .ok()
+            .as_ref()
+            .and_then(|v| v.as_str())
+            == Some("onlink")
+    }) {
+        route_entry.onlink = Some(true);
+    }
+
+    let lock = np_route.lock.unwrap_or_default();
+    let mut locks = Vec::new();
+    for (rtax, metric_lock, value) in [
+        (RTAX_MTU, RouteMetricLock::Mtu, np_route.mtu),
+        (RTAX_ADVMSS, RouteMetricLock::Advmss, np_route.advmss),
+        (RTAX_INITCWND, RouteMetricLock::Initcwnd, np_route.initcwnd),
+        (RTAX_INITRWND, RouteMetricLock::Initrwnd, np_route.initrwnd),
+    ] {
+        if lock & (1 << rtax) != 0 && value.is_some() {
+            locks.push(metric_lock);
+        }
+    }
+    if !locks.is_empty() {
+        route_entry.lock = Some(locks);
+    }
+}
+
 fn is_multipath(np_route: &nispor::Route) -> bool {
     np_route
         .multipath
@@ -229,6 +363,12 @@ fn flat_multipath_route(np_route: &nispor::Route) -> Vec<RouteEntry> {
             if np_route.address_family == nispor::AddressFamily::IPv4 {
                 route.weight = Some(mp_route.weight);
             }
+            if mp_route
+                .flags
+                .contains(&nispor::MultipathRouteFlags::OnLink)
+            {
+                route.onlink = Some(true);
+            }
             ret.push(route);
         }
     }
@@ -244,6 +384,7 @@ fn nmstate_to_nispor_route_conf(
     ret.dst = nmstate_rt.destination.clone().unwrap_or_default();
     ret.oif.clone_from(&nmstate_rt.next_hop_iface);
     ret.via.clone_from(&nmstate_rt.next_hop_addr);
+    ret.protocol = nmstate_rt.protocol.map(nmstate_route_protocol_to_np);
     ret.metric = nmstate_rt.metric.and_then(|m| {
         if let Ok(i) = u32::try_from(m) {
             Some(i)
@@ -280,21 +421,244 @@ fn nmstate_to_nispor_route_conf(
         ));
     }
 
-    if nmstate_rt.cwnd.is_some() {
-        return Err(NmstateError::new(
-            ErrorKind::NotImplementedError,
-            "nispor apply does not support route congestion window yet".into(),
-        ));
-    }
     Ok(ret)
 }
 
+// Routes with metrics, flags, scope, preference or nexthop ID are applied by
+// `apply_routes_with_metrics()` after this.
 pub(crate) fn gen_nispor_route_confs(
     merged_routes: &MergedRoutes,
 ) -> Result<Vec<nispor::RouteConf>, NmstateError> {
     let mut ret = Vec::new();
-    for nmstate_rt in merged_routes.changed_routes.as_slice() {
+    for nmstate_rt in
+        merged_routes.changed_routes.as_slice().iter().filter(|r| {
+            !need_rtnetlink(r) || (r.is_absent() && r.nexthop_id.is_none())
+        })
+    {
         ret.push(nmstate_to_nispor_route_conf(nmstate_rt)?)
     }
     Ok(ret)
 }
+
+// Nispor does not support route metrics, flags, scope, preference and nexthop
+// yet, use rtnetlink directly. Route using nexthop is also removed here.
+pub(crate) async fn apply_routes_with_metrics(
+    merged_routes: &MergedRoutes,
+) -> Result<(), NmstateError> {
+    let routes: Vec<&RouteEntry> = merged_routes
+        .changed_routes
+        .as_slice()
+        .iter()
+        .filter(|r| {
+            need_rtnetlink(r) && (!r.is_absent() || r.nexthop_id.is_some())
+        })
+        .collect();
+    if routes.is_empty() {
+        return Ok(());
+    }
+    let (conn, handle, _) = rtnetlink::new_connection().map_err(|e| {
+        NmstateError::new(
+            ErrorKind::Bug,
+            format!("Failed to create route netlink connection: {e}"),
+        )
+    })?;
+    tokio::spawn(conn);
+    for route in routes.iter().filter(|r| r.is_absent()) {
+        let message = nmstate_route_to_rt_message(route)?;
+        if let Err(e) = handle.route().del(message).execute().await {
+            let e = NmstateError::new(
+                ErrorKind::PluginFailure,
+                format!("Failed to remove route {route}: {e}"),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+    }
+    for route in routes.iter().filter(|r| !r.is_absent()) {
+        let message = nmstate_route_to_rt_message(route)?;
+        // Use NLM_F_REPLACE as current route might only have different
+        // metrics
+        if let Err(e) = handle.route().add(message).replace().execute().await {
+            let e = NmstateError::new(
+                ErrorKind::PluginFailure,
+                format!("Failed to add route {route}: {e}"),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+    }
+    Ok(())
+}
+
+fn nmstate_route_to_rt_message(
+    route: &RouteEntry,
+) -> Result<RouteMessage, NmstateError> {
+    let invalid_route = |e: String| {
+        let e = NmstateError::new(
+            ErrorKind::InvalidArgument,
+            format!("Invalid route {route}: {e}"),
+        );
+        log::error!("{}", e);
+        e
+    };
+    let dst = route.destination.as_deref().unwrap_or_default();
+    let (dst_ip, dst_prefix) = match dst.split_once('/') {
+        Some((ip, prefix)) => (
+            ip.parse::<IpAddr>()
+                .map_err(|e| invalid_route(e.to_string()))?,
+            prefix
+                .parse::<u8>()
+                .map_err(|e| invalid_route(e.to_string()))?,
+        ),
+        None => {
+            let ip = dst
+                .parse::<IpAddr>()
+                .map_err(|e| invalid_route(e.to_string()))?;
+            (ip, if ip.is_ipv6() { 128 } else { 32 })
+        }
+    };
+
+    let mut builder = RouteMessageBuilder::<IpAddr>::new()
+        .destination_prefix(dst_ip, dst_prefix)
+        .map_err(|e| invalid_route(e.to_string()))?
+        .scope(match route.scope.unwrap_or_default() {
+            RouteScope::Global => rt::RouteScope::Universe,
+            RouteScope::Site => rt::RouteScope::Site,
+            RouteScope::Link => rt::RouteScope::Link,
+            RouteScope::Host => rt::RouteScope::Host,
+        })
+        .protocol(
+            route
+                .protocol
+                .map(nmstate_route_protocol_to_np)
+                .unwrap_or(nispor::RouteProtocol::Static)
+                .into(),
+        )
+        .table_id(match route.table_id {
+            None | Some(RouteEntry::USE_DEFAULT_ROUTE_TABLE) => {
+                DEFAULT_ROUTE_TABLE
+            }
+            Some(t) => t,
+        });
+    if let Some(metric) = route.metric.and_then(|m| u32::try_from(m).ok()) {
+        builder = builder.priority(metric);
+    }
+    if let Some(iface_name) = route.next_hop_iface.as_deref() {
+        if let Some(index) = get_iface_index(iface_name) {
+            builder = builder.output_interface(index);
+        } else {
+            return Err(invalid_route(format!(
+                "next hop interface {iface_name} not found"
+            )));
+        }
+    }
+    if let Some(via) = route.next_hop_addr.as_deref() {
+        let via = via
+            .parse::<IpAddr>()
+            .map_err(|e| invalid_route(e.to_string()))?;
+        if !via.is_unspecified() {
+            builder = builder
+                .gateway(via)
+                .map_err(|e| invalid_route(e.to_string()))?;
+        }
+    }
+    if let Some(src) = route.source.as_deref() {
+        let src = src
+            .parse::<IpAddr>()
+            .map_err(|e| invalid_route(e.to_string()))?;
+        builder = builder
+            .pref_source(src)
+            .map_err(|e| invalid_route(e.to_string()))?;
+    }
+    if route.onlink == Some(true) {
+        builder = builder.onlink();
+    }
+    let mut message = builder.build();
+    if let Some(nexthop_id) = route.nexthop_id {
+        message
+            .attributes
+            .push(RouteAttribute::Other(DefaultNla::new(
+                RTA_NH_ID,
+                nexthop_id.to_ne_bytes().to_vec(),
+            )));
+    }
+    if let Some(pref) = route.pref {
+        message
+            .attributes
+            .push(RouteAttribute::Preference(match pref {
+                RoutePreference::Low => rt::RoutePreference::Low,
+                RoutePreference::Medium => rt::RoutePreference::Medium,
+                RoutePreference::High => rt::RoutePreference::High,
+            }));
+    }
+
+    let mut lock = 0u32;
+    let mut metrics = Vec::new();
+    if let Some(v) = route.cwnd {
+        lock |= 1 << RTAX_CWND;
+        metrics.push(RouteMetric::Cwnd(v));
+    }
+    if let Some(v) = route.mtu {
+        metrics.push(RouteMetric::Mtu(v));
+    }
+    if let Some(v) = route.advmss {
+        metrics.push(RouteMetric::Advmss(v));
+    }
+    if let Some(v) = route.initcwnd {
+        metrics.push(RouteMetric::InitCwnd(v));
+    }
+    if let Some(v) = route.initrwnd {
+        metrics.push(RouteMetric::InitRwnd(v));
+    }
+    if let Some(v) = route.rto_min {
+        metrics.push(RouteMetric::RtoMin(v));
+    }
+    if route.quickack == Some(true) {
+        metrics.push(RouteMetric::QuickAck(1));
+    }
+    for metric_lock in route.lock.as_deref().unwrap_or_default() {
+        lock |= 1
+            << match metric_lock {
+                RouteMetricLock::Mtu => RTAX_MTU,
+                RouteMetricLock::Advmss => RTAX_ADVMSS,
+                RouteMetricLock::Initcwnd => RTAX_INITCWND,
+                RouteMetricLock::Initrwnd => RTAX_INITRWND,
+            };
+    }
+    if lock != 0 {
+        metrics.insert(0, RouteMetric::Lock(lock));
+    }
+    if !metrics.is_empty() {
+        message.attributes.push(RouteAttribute::Metrics(metrics));
+    }
+    Ok(message)
+}
+
+fn need_rtnetlink(route: &RouteEntry) -> bool {
+    route.has_metrics()
+        || route.scope.is_some()
+        || route.pref.is_some()
+        || route.nexthop_id.is_some()
+}
+
+fn nmstate_route_protocol_to_np(
+    protocol: RouteProtocol,
+) -> nispor::RouteProtocol {
+    match protocol {
+        RouteProtocol::Kernel => nispor::RouteProtocol::Kernel,
+        RouteProtocol::Boot => nispor::RouteProtocol::Boot,
+        RouteProtocol::Static => nispor::RouteProtocol::Static,
+        RouteProtocol::Ra => nispor::RouteProtocol::Ra,
+        RouteProtocol::Dhcp => nispor::RouteProtocol::Dhcp,
+        RouteProtocol::Mrouted => nispor::RouteProtocol::Mrouted,
+        RouteProtocol::Keepalived => nispor::RouteProtocol::KeepAlived,
+        RouteProtocol::Babel => nispor::RouteProtocol::Babel,
+        RouteProtocol::Zebra => nispor::RouteProtocol::Zebra,
+        RouteProtocol::Bird => nispor::RouteProtocol::Bird,
+        RouteProtocol::Bgp => nispor::RouteProtocol::Bgp,
+        RouteProtocol::Isis => nispor::RouteProtocol::Isis,
+        RouteProtocol::Ospf => nispor::RouteProtocol::Ospf,
+        RouteProtocol::Rip => nispor::RouteProtocol::Rip,
+        RouteProtocol::Eigrp => nispor::RouteProtocol::Eigrp,
+    }
+}
@@ -1,89 +1,170 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use futures::stream::TryStreamExt;
 use log::warn;
+use rtnetlink::{
+    packet_core::Nla,
+    packet_route::{
+        route::RouteProtocol,
+        rule::{RuleAction, RuleAttribute, RuleFlags, RuleMessage},
+        AddressFamily as NlAddressFamily,
+    },
+    IpVersion,
+};
 
-use crate::{AddressFamily, RouteRuleAction, RouteRuleEntry, RouteRules};
+use crate::{
+    AddressFamily, RouteRuleAction, RouteRuleEntry, RouteRulePortRange,
+    RouteRuleUidRange, RouteRules,
+};
+
+const FRA_DSCP: u16 = 25;
 
 // Due to a bug in NetworkManager all route rules added using NetworkManager are
 // using RTM_PROTOCOL Unspec. Therefore, we need to support it until it is
 // fixed.
-const SUPPORTED_STATIC_ROUTE_PROTOCOL: [nispor::RouteProtocol; 3] = [
-    nispor::RouteProtocol::Boot,
-    nispor::RouteProtocol::Static,
-    nispor::RouteProtocol::Unspec,
+const SUPPORTED_STATIC_ROUTE_PROTOCOL: [RouteProtocol; 3] = [
+    RouteProtocol::Boot,
+    RouteProtocol::Static,
+    RouteProtocol::Unspec,
 ];
 
-const SUPPORTED_ROUTE_PROTOCOL: [nispor::RouteProtocol; 8] = [
-    nispor::RouteProtocol::Boot,
-    nispor::RouteProtocol::Static,
-    nispor::RouteProtocol::Ra,
-    nispor::RouteProtocol::Dhcp,
-    nispor::RouteProtocol::Mrouted,
-    nispor::RouteProtocol::KeepAlived,
-    nispor::RouteProtocol::Babel,
-    nispor::RouteProtocol::Unspec,
+const SUPPORTED_ROUTE_PROTOCOL: [RouteProtocol; 8] = [
+    RouteProtocol::Boot,
+    RouteProtocol::Static,
+    RouteProtocol::Ra,
+    RouteProtocol::Dhcp,
+    RouteProtocol::Mrouted,
+    RouteProtocol::KeepAlived,
+    RouteProtocol::Babel,
+    RouteProtocol::Unspec,
 ];
 
-pub(crate) fn get_route_rules(
-    np_rules: &[nispor::RouteRule],
-    running_config_only: bool,
-) -> RouteRules {
+// Nispor does not expose the ipproto, port ranges, uid range and dscp of
+// route rule, hence query route rules via rtnetlink directly.
+pub(crate) async fn get_route_rules(running_config_only: bool) -> RouteRules {
     let mut ret = RouteRules::new();
-
-    let mut rules = Vec::new();
     let protocols = if running_config_only {
         SUPPORTED_STATIC_ROUTE_PROTOCOL.as_slice()
     } else {
         SUPPORTED_ROUTE_PROTOCOL.as_slice()
     };
 
-    for np_rule in np_rules {
-        let mut rule = RouteRuleEntry::new();
-        // We only support route rules with 'table' action
-        match np_rule.action {
-            nispor::RuleAction::Table => (),
-            nispor::RuleAction::Blackhole => {
-                rule.action = Some(RouteRuleAction::Blackhole)
-            }
-            nispor::RuleAction::Unreachable => {
-                rule.action = Some(RouteRuleAction::Unreachable)
-            }
-            nispor::RuleAction::Prohibit => {
-                rule.action = Some(RouteRuleAction::Prohibit)
-            }
-            _ => {
-                log::debug!("Got unsupported route rule {:?}", np_rule);
-                continue;
-            }
+    let (conn, handle, _) = match rtnetlink::new_connection() {
+        Ok(c) => c,
+        Err(e) => {
+            warn!("Failed to create route netlink connection: {e}");
+            return ret;
         }
-        // Filter out the routes with protocols that we do not support
-        if let Some(rule_protocol) = np_rule.protocol.as_ref() {
-            if !protocols.contains(rule_protocol) {
-                continue;
+    };
+    tokio::spawn(conn);
+
+    let mut rules = Vec::new();
+    for ip_version in [IpVersion::V6, IpVersion::V4] {
+        let mut rule_msgs = handle.rule().get(ip_version).execute();
+        loop {
+            match rule_msgs.try_next().await {
+                Ok(Some(rule_msg)) => {
+                    if let Some(rule) = nl_rule_to_nmstate(&rule_msg, protocols)
+                    {
+                        rules.push(rule);
+                    }
+                }
+                Ok(None) => break,
+                Err(e) => {
+                    warn!("Failed to retrieve route rules: {e}");
+                    break;
+                }
             }
         }
-        rule.iif.clone_from(&np_rule.iif);
-        rule.ip_to.clone_from(&np_rule.dst);
-        rule.ip_from.clone_from(&np_rule.src);
-        rule.table_id = np_rule.table;
-        rule.priority = np_rule.priority.map(i64::from);
-        rule.fwmark = np_rule.fw_mark;
-        rule.fwmask = np_rule.fw_mask;
-        rule.suppress_prefix_length = np_rule.suppress_prefix_len;
-        rule.family = match np_rule.address_family {
-            nispor::AddressFamily::IPv4 => Some(AddressFamily::IPv4),
-            nispor::AddressFamily::IPv6 => Some(AddressFamily::IPv6),
-            _ => {
-                warn!(
-                    "Unsupported route rule family {:?}",
-                    np_rule.address_family
-                );
-                None
-            }
-        };
-        rules.push(rule);
     }
     ret.config = Some(rules);
 
     ret
 }
+
+// Return None if route rule action or protocol is not supported.
+pub(crate) fn nl_rule_to_nmstate(
+    rule_msg: &RuleMessage,
+    protocols: &[RouteProtocol],
+) -> Option<RouteRuleEntry> {
+    let header = &rule_msg.header;
+    let mut rule = RouteRuleEntry::new();
+    // We only support route rules with 'table' action
+    match header.action {
+        RuleAction::ToTable | RuleAction::Goto => (),
+        RuleAction::Blackhole => rule.action = Some(RouteRuleAction::Blackhole),
+        RuleAction::Unreachable => {
+            rule.action = Some(RouteRuleAction::Unreachable)
+        }
+        RuleAction::Prohibit => rule.action = Some(RouteRuleAction::Prohibit),
+        _ => {
+            log::debug!("Got unsupported route rule {:?}", rule_msg);
+            return None;
+        }
+    }
+    rule.family = match header.family {
+        NlAddressFamily::Inet => Some(AddressFamily::IPv4),
+        NlAddressFamily::Inet6 => Some(AddressFamily::IPv6),
+        _ => {
+            warn!("Unsupported route rule family {:?}", header.family);
+            None
+        }
+    };
+    if header.table > 0 {
+        rule.table_id = Some(header.table.into());
+    }
+    if header.tos != 0 {
+        rule.tos = Some(header.tos);
+    }
+    if header.flags.contains(RuleFlags::Invert) {
+        rule.invert = Some(true);
+    }
+    for nla in rule_msg.attributes.as_slice() {
+        match nla {
+            // Filter out the rules with protocols that we do not support
+            RuleAttribute::Protocol(d) if !protocols.contains(d) => {
+                return None;
+            }
+            RuleAttribute::Destination(d) => {
+                rule.ip_to = Some(format!("{d}/{}", header.dst_len));
+            }
+            RuleAttribute::Source(d) => {
+                rule.ip_from = Some(format!("{d}/{}", header.src_len));
+            }
+            RuleAttribute::Iifname(d) => rule.iif = Some(d.to_string()),
+            RuleAttribute::Oifname(d) => rule.oif = Some(d.to_string()),
+            RuleAttribute::Goto(d) if header.action == RuleAction::Goto => {
+                rule.goto = Some(*d);
+            }
+            RuleAttribute::Priority(d) => rule.priority = Some(i64::from(*d)),
+            RuleAttribute::FwMark(d) => rule.fwmark = Some(*d),
+            RuleAttribute::FwMask(d) => rule.fwmask = Some(*d),
+            RuleAttribute::SuppressPrefixLen(d) if *d != u32::MAX => {
+                rule.suppress_prefix_length = Some(*d);
+            }
+            RuleAttribute::Table(d) if *d > 0 => rule.table_id = Some(*d),
+            RuleAttribute::L3MDev(true) => rule.l3mdev = Some(true),
+            RuleAttribute::IpProtocol(d) => {
+                rule.ipproto = u8::try_from(i32::from(*d)).ok();
+            }
+            RuleAttribute::SourcePortRange(d) => {
+                rule.sport = Some(RouteRulePortRange::new(d.start, d.end));
+            }
+            RuleAttribute::DestinationPortRange(d) => {
+                rule.dport = Some(RouteRulePortRange::new(d.start, d.end));
+            }
+            RuleAttribute::UidRange(d) => {
+                rule.uid_range = Some(RouteRuleUidRange::new(d.start, d.end));
+            }
+            RuleAttribute::Other(nla)
+                if nla.kind() == FRA_DSCP && nla.value_len() == 1 =>
+            {
+                let mut buf = [0u8; 1];
+                nla.emit_value(&mut buf);
+                rule.dscp = Some(buf[0]);
+            }
+            _ => (),
+        }
+    }
+    Some(rule)
+}
@@ -8,16 +8,22 @@ use crate::{
         bond::{append_bond_port_config, np_bond_to_nmstate},
         dns::get_dns,
         error::np_error_to_nmstate,
-        ethernet::np_ethernet_to_nmstate,
+        ethernet::{append_sriov_eswitch_info, np_ethernet_to_nmstate},
+        ethtool::append_ethtool_netlink_info,
         hostname::get_hostname_state,
-        hsr::np_hsr_to_nmstate,
+        hsr::{append_hsr_interlink_info, np_hsr_to_nmstate},
         infiniband::np_ib_to_nmstate,
+        ip::append_ipv4_addr_flags,
         ipvlan::np_ipvlan_to_nmstate,
         linux_bridge::{append_bridge_port_config, np_bridge_to_nmstate},
+        lldp::append_lldp_info,
         mac_vlan::{np_mac_vlan_to_nmstate, np_mac_vtap_to_nmstate},
-        macsec::np_macsec_to_nmstate,
+        macsec::{append_macsec_static_sak_info, np_macsec_to_nmstate},
+        neighbor::get_neighbors,
+        nexthop::get_nexthops,
         route::get_routes,
         route_rule::get_route_rules,
+        sysctl::{append_ip_sysctl_info, get_ip_forwarding},
         veth::np_veth_to_nmstate,
         vlan::np_vlan_to_nmstate,
         vrf::np_vrf_to_nmstate,
@@ -31,9 +37,11 @@ use crate::{
 pub(crate) async fn nispor_retrieve(
     running_config_only: bool,
     kernel_only: bool,
+    lldp_listen_time: u32,
 ) -> Result<NetworkState, NmstateError> {
     let mut net_state = NetworkState {
         hostname: get_hostname_state(),
+        ip_forwarding: get_ip_forwarding(),
         ..Default::default()
     };
     let mut filter = nispor::NetStateFilter::default();
@@ -166,10 +174,28 @@ pub(crate) async fn nispor_retrieve(
         net_state.append_interface_data(iface);
     }
     set_controller_type(&mut net_state.interfaces);
+    append_ethtool_netlink_info(&mut net_state.interfaces).await;
+    append_sriov_eswitch_info(&mut net_state.interfaces).await;
+    append_macsec_static_sak_info(&mut net_state.interfaces).await;
+    append_hsr_interlink_info(&mut net_state.interfaces).await;
+    append_ip_sysctl_info(&mut net_state.interfaces);
+    append_ipv4_addr_flags(&mut net_state.interfaces, &np_state.ifaces).await;
     net_state.routes = get_routes(running_config_only).await;
-    net_state.rules = get_route_rules(&np_state.rules, running_config_only);
+    net_state.rules = get_route_rules(running_config_only).await;
+    net_state.nexthops = get_nexthops(&np_state.ifaces).await;
+    net_state.neighbors =
+        get_neighbors(&np_state.ifaces, running_config_only).await;
     if kernel_only {
         net_state.dns = get_dns();
+        append_lldp_info(
+            &mut net_state.interfaces,
+            if running_config_only {
+                0
+            } else {
+                lldp_listen_time
+            },
+        )
+        .await;
     }
     Ok(net_state)
 }
@@ -0,0 +1,254 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    ErrorKind, Interfaces, IpForwarding, Ipv4Sysctl, Ipv6Sysctl, Ipv6TempAddr,
+    MergedInterfaces, MergedIpForwarding, NmstateError, RpFilter,
+};
+
+const IPV4_FORWARD_PATH: &str = "/proc/sys/net/ipv4/ip_forward";
+const IPV6_FORWARD_PATH: &str = "/proc/sys/net/ipv6/conf/all/forwarding";
+const IPV4_CONF_DIR: &str = "/proc/sys/net/ipv4/conf";
+const IPV6_CONF_DIR: &str = "/proc/sys/net/ipv6/conf";
+const DEFAULT_CONF_NAME: &str = "default";
+
+pub(crate) fn get_ip_forwarding() -> Option<IpForwarding> {
+    let ret = IpForwarding {
+        ipv4: read_sysctl(IPV4_FORWARD_PATH).map(|v| v != 0),
+        ipv6: read_sysctl(IPV6_FORWARD_PATH).map(|v| v != 0),
+    };
+    if ret == IpForwarding::default() {
+        None
+    } else {
+        Some(ret)
+    }
+}
+
+// Only store sysctl values different from kernel default to `sysctl`,
+// the kernel default values are stored to `sysctl_default` for resolving
+// effective values.
+pub(crate) fn append_ip_sysctl_info(ifaces: &mut Interfaces) {
+    let ipv4_default = get_ipv4_sysctl(DEFAULT_CONF_NAME);
+    let ipv6_default = get_ipv6_sysctl(DEFAULT_CONF_NAME);
+
+    for iface in ifaces.kernel_ifaces.values_mut() {
+        let base_iface = iface.base_iface_mut();
+        let iface_name = base_iface.name.as_str();
+        if let (Some(ip), Some(default)) =
+            (base_iface.ipv4.as_mut(), ipv4_default.as_ref())
+        {
+            if let Some(cur) = get_ipv4_sysctl(iface_name) {
+                let mut sysctl = cur.clone();
+                if cur.forwarding == default.forwarding {
+                    sysctl.forwarding = None;
+                }
+                if cur.rp_filter == default.rp_filter {
+                    sysctl.rp_filter = None;
+                }
+                if cur.accept_local == default.accept_local {
+                    sysctl.accept_local = None;
+                }
+                if cur.proxy_arp == default.proxy_arp {
+                    sysctl.proxy_arp = None;
+                }
+                if cur.arp_ignore == default.arp_ignore {
+                    sysctl.arp_ignore = None;
+                }
+                if cur.arp_announce == default.arp_announce {
+                    sysctl.arp_announce = None;
+                }
+                if !sysctl.is_empty() {
+                    ip.sysctl = Some(sysctl);
+                }
+                ip.sysctl_default = Some(default.clone());
+            }
+        }
+        if let (Some(ip), Some(default)) =
+            (base_iface.ipv6.as_mut(), ipv6_default.as_ref())
+        {
+            if let Some(cur) = get_ipv6_sysctl(iface_name) {
+                let mut sysctl = cur.clone();
+                if cur.forwarding == default.forwarding {
+                    sysctl.forwarding = None;
+                }
+                if cur.accept_ra == default.accept_ra {
+                    sysctl.accept_ra = None;
+                }
+                if cur.use_tempaddr == default.use_tempaddr {
+                    sysctl.use_tempaddr = None;
+                }
+                if !sysctl.is_empty() {
+                    ip.sysctl = Some(sysctl);
+                }
+                ip.sysctl_default = Some(default.clone());
+                if ip.enabled {
+                    let dir = format!("{IPV6_CONF_DIR}/{iface_name}");
+                    ip.ip6_privacy = cur.use_tempaddr;
+                    ip.dad_transmits =
+                        read_sysctl(&format!("{dir}/dad_transmits"))
+                            .and_then(|v| u32::try_from(v).ok());
+                    ip.mtu_from_ra =
+                        read_sysctl(&format!("{dir}/accept_ra_mtu"))
+                            .map(|v| v != 0);
+                }
+            }
+        }
+    }
+}
+
+fn get_ipv4_sysctl(conf_name: &str) -> Option<Ipv4Sysctl> {
+    let dir = format!("{IPV4_CONF_DIR}/{conf_name}");
+    if !std::path::Path::new(&dir).is_dir() {
+        return None;
+    }
+    let mut ret = Ipv4Sysctl::new();
+    ret.forwarding = read_sysctl(&format!("{dir}/forwarding")).map(|v| v != 0);
+    ret.rp_filter = read_sysctl(&format!("{dir}/rp_filter"))
+        .map(|v| RpFilter::from(v.clamp(0, u8::MAX as i32) as u8));
+    ret.accept_local =
+        read_sysctl(&format!("{dir}/accept_local")).map(|v| v != 0);
+    ret.proxy_arp = read_sysctl(&format!("{dir}/proxy_arp")).map(|v| v != 0);
+    ret.arp_ignore = read_sysctl(&format!("{dir}/arp_ignore"))
+        .and_then(|v| u8::try_from(v).ok());
+    ret.arp_announce = read_sysctl(&format!("{dir}/arp_announce"))
+        .and_then(|v| u8::try_from(v).ok());
+    Some(ret)
+}
+
+fn get_ipv6_sysctl(conf_name: &str) -> Option<Ipv6Sysctl> {
+    let dir = format!("{IPV6_CONF_DIR}/{conf_name}");
+    if !std::path::Path::new(&dir).is_dir() {
+        return None;
+    }
+    let mut ret = Ipv6Sysctl::new();
+    ret.forwarding = read_sysctl(&format!("{dir}/forwarding")).map(|v| v != 0);
+    ret.accept_ra = read_sysctl(&format!("{dir}/accept_ra"))
+        .and_then(|v| u8::try_from(v).ok());
+    ret.use_tempaddr =
+        read_sysctl(&format!("{dir}/use_tempaddr")).map(Ipv6TempAddr::from);
+    Some(ret)
+}
+
+fn read_sysctl(path: &str) -> Option<i32> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => match content.trim().parse::<i32>() {
+            Ok(v) => Some(v),
+            Err(e) => {
+                log::debug!("Invalid sysctl value {content} of {path}: {e}");
+                None
+            }
+        },
+        Err(e) => {
+            log::debug!("Failed to read sysctl {path}: {e}");
+            None
+        }
+    }
+}
+
+fn write_sysctl(path: &str, value: i32) -> Result<(), NmstateError> {
+    log::debug!("Setting sysctl {path} to {value}");
+    std::fs::write(path, format!("{value}")).map_err(|e| {
+        let e = NmstateError::new(
+            ErrorKind::PluginFailure,
+            format!("Failed to set sysctl {path} to {value}: {e}"),
+        );
+        log::error!("{}", e);
+        e
+    })
+}
+
+pub(crate) fn apply_ip_forwarding(
+    merged_ip_forwarding: &MergedIpForwarding,
+) -> Result<(), NmstateError> {
+    if let Some(desired) = merged_ip_forwarding.desired.as_ref() {
+        if let Some(v) = desired.ipv4 {
+            write_sysctl(IPV4_FORWARD_PATH, v.into())?;
+        }
+        if let Some(v) = desired.ipv6 {
+            write_sysctl(IPV6_FORWARD_PATH, v.into())?;
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn apply_ip_sysctl(
+    merged_ifaces: &MergedInterfaces,
+) -> Result<(), NmstateError> {
+    for apply_iface in merged_ifaces
+        .kernel_ifaces
+        .values()
+        .filter_map(|i| i.for_apply.as_ref())
+        .filter(|i| i.is_up())
+    {
+        let base_iface = apply_iface.base_iface();
+        let iface_name = base_iface.name.as_str();
+        if let Some(sysctl) =
+            base_iface.ipv4.as_ref().and_then(|i| i.sysctl.as_ref())
+        {
+            let dir = format!("{IPV4_CONF_DIR}/{iface_name}");
+            if let Some(v) = sysctl.forwarding {
+                write_sysctl(&format!("{dir}/forwarding"), v.into())?;
+            }
+            if let Some(v) = sysctl.rp_filter {
+                write_sysctl(&format!("{dir}/rp_filter"), u8::from(v).into())?;
+            }
+            if let Some(v) = sysctl.accept_local {
+                write_sysctl(&format!("{dir}/accept_local"), v.into())?;
+            }
+            if let Some(v) = sysctl.proxy_arp {
+                write_sysctl(&format!("{dir}/proxy_arp"), v.into())?;
+            }
+            if let Some(v) = sysctl.arp_ignore {
+                write_sysctl(&format!("{dir}/arp_ignore"), v.into())?;
+            }
+            if let Some(v) = sysctl.arp_announce {
+                write_sysctl(&format!("{dir}/arp_announce"), v.into())?;
+            }
+        }
+        if let Some(ip) = base_iface.ipv6.as_ref() {
+            let dir = format!("{IPV6_CONF_DIR}/{iface_name}");
+            if let Some(sysctl) = ip.sysctl.as_ref() {
+                if let Some(v) = sysctl.forwarding {
+                    write_sysctl(&format!("{dir}/forwarding"), v.into())?;
+                }
+                if let Some(v) = sysctl.accept_ra {
+                    write_sysctl(&format!("{dir}/accept_ra"), v.into())?;
+                }
+                if let Some(v) = sysctl.use_tempaddr {
+                    write_sysctl(&format!("{dir}/use_tempaddr"), v.into())?;
+                }
+            }
+            if let Some(v) = ip.ip6_privacy {
+                write_sysctl(&format!("{dir}/use_tempaddr"), v.into())?;
+            }
+        }
+    }
+    Ok(())
+}
+
+// NetworkManager does not support IPv6 `dad-transmits` and `mtu-from-ra`,
+// hence this is used by both kernel mode and NetworkManager mode.
+pub(crate) fn apply_ipv6_dad_and_ra_mtu(
+    merged_ifaces: &MergedInterfaces,
+) -> Result<(), NmstateError> {
+    for apply_iface in merged_ifaces
+        .kernel_ifaces
+        .values()
+        .filter_map(|i| i.for_apply.as_ref())
+        .filter(|i| i.is_up())
+    {
+        let base_iface = apply_iface.base_iface();
+        if let Some(ip) = base_iface.ipv6.as_ref() {
+            let dir = format!("{IPV6_CONF_DIR}/{}", base_iface.name);
+            if let Some(v) = ip.dad_transmits {
+                write_sysctl(
+                    &format!("{dir}/dad_transmits"),
+                    i32::try_from(v).unwrap_or(i32::MAX),
+                )?;
+            }
+            if let Some(v) = ip.mtu_from_ra {
+                write_sysctl(&format!("{dir}/accept_ra_mtu"), v.into())?;
+            }
+        }
+    }
+    Ok(())
+}
@@ -1,9 +1,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{dns::parse_dns_ipv6_link_local_srv, ip::is_ipv6_addr};
+use crate::dns::{is_ipv6_dns_srv, parse_dns_ipv6_link_local_srv};
 use crate::{
-    DnsClientState, ErrorKind, Interface, InterfaceType, MergedInterface,
-    MergedInterfaces, MergedNetworkState, NmstateError,
+    DnsClientState, DnsOverTls, ErrorKind, Interface, InterfaceType,
+    MergedInterface, MergedInterfaces, MergedNetworkState, NmstateError,
 };
 
 use super::nm_dbus::{
@@ -270,6 +270,7 @@ pub(crate) fn purge_dns_config(
                         Vec::new(),
                         Vec::new(),
                         None,
+                        None,
                     )?;
                 }
             }
@@ -289,10 +290,10 @@ fn save_dns_to_iface(
         .dns
         .servers
         .first()
-        .map(|s| is_ipv6_addr(s.as_str()))
+        .map(|s| is_ipv6_dns_srv(s.as_str()))
         .unwrap_or_default();
     for srv in merged_state.dns.servers.as_slice() {
-        if is_ipv6_addr(srv) {
+        if is_ipv6_dns_srv(srv) {
             v6_servers.push(srv.to_string())
         } else {
             v4_servers.push(srv.to_string())
@@ -375,6 +376,7 @@ fn _save_dns_to_iface(
                     merged_state.dns.searches.clone(),
                     merged_state.dns.options.clone(),
                     Some(DEFAULT_DNS_PRIORITY),
+                    merged_state.dns.dns_over_tls,
                 )?;
             } else {
                 set_iface_dns_conf(
@@ -384,6 +386,7 @@ fn _save_dns_to_iface(
                     Vec::new(),
                     Vec::new(),
                     Some(DEFAULT_DNS_PRIORITY + 10),
+                    merged_state.dns.dns_over_tls,
                 )?;
             }
         }
@@ -407,12 +410,15 @@ fn set_iface_dns_conf(
     searches: Vec<String>,
     options: Vec<String>,
     priority: Option<i32>,
+    dns_over_tls: Option<DnsOverTls>,
 ) -> Result<(), NmstateError> {
     let dns_conf = DnsClientState {
         server: Some(servers.clone()),
         search: Some(searches.clone()),
         options: Some(options.clone()),
+        dns_over_tls,
         priority,
+        ..Default::default()
     };
     if is_ipv6 {
         if let Some(ip_conf) = iface.base_iface_mut().ipv6.as_mut() {
@@ -483,7 +489,7 @@ pub(crate) fn get_cur_dns_ifaces(
 fn is_mixed_dns_servers(srvs: &[String]) -> bool {
     let mut pattern = String::new();
     for srv in srvs {
-        let cur_char = if is_ipv6_addr(srv) { '6' } else { '4' };
+        let cur_char = if is_ipv6_dns_srv(srv) { '6' } else { '4' };
         if !pattern.ends_with(cur_char) {
             pattern.push(cur_char);
         }
@@ -496,7 +502,11 @@ impl MergedInterface {
     //  * Desire state has specified IP stack with static IP or auto with
     //    `auto_dns: false`
     //  * The IPv6 address is not empty
+    //  * Not holding interface level DNS config
     pub(crate) fn is_iface_prefered_for_dns(&self, is_ipv6: bool) -> bool {
+        if self.has_iface_dns() {
+            return false;
+        }
         if let Some(apply_iface) = self.for_apply.as_ref() {
             if is_ipv6 {
                 apply_iface.base_iface().ipv6.as_ref().map(|ip_conf| {
@@ -581,6 +591,7 @@ pub(crate) fn store_dns_search_or_option_to_iface(
                         merged_state.dns.searches.clone(),
                         merged_state.dns.options.clone(),
                         Some(DEFAULT_DNS_PRIORITY),
+                        merged_state.dns.dns_over_tls,
                     )?;
                     return Ok(());
                 }
@@ -601,6 +612,7 @@ pub(crate) fn store_dns_search_or_option_to_iface(
                         merged_state.dns.searches.clone(),
                         merged_state.dns.options.clone(),
                         Some(DEFAULT_DNS_PRIORITY),
+                        merged_state.dns.dns_over_tls,
                     )?;
                     return Ok(());
                 }
@@ -627,6 +639,7 @@ fn set_iface_dns_search_or_option(
     searches: Vec<String>,
     options: Vec<String>,
     is_ipv6: bool,
+    dns_over_tls: Option<DnsOverTls>,
 ) -> Result<(), NmstateError> {
     if iface.for_apply.is_none() {
         iface.mark_as_changed();
@@ -652,6 +665,7 @@ fn set_iface_dns_search_or_option(
             searches,
             options,
             Some(DEFAULT_DNS_PRIORITY),
+            dns_over_tls,
         )?;
     }
     Ok(())
@@ -681,6 +695,9 @@ fn store_dns_search_or_options_to_auto_iface(
                 Some(i) => i,
                 None => continue,
             };
+        if iface.has_iface_dns() {
+            continue;
+        }
         if iface
             .merged
             .base_iface()
@@ -694,6 +711,7 @@ fn store_dns_search_or_options_to_auto_iface(
                 merged_state.dns.searches.clone(),
                 merged_state.dns.options.clone(),
                 true,
+                merged_state.dns.dns_over_tls,
             );
         }
         if iface
@@ -709,6 +727,7 @@ fn store_dns_search_or_options_to_auto_iface(
                 merged_state.dns.searches.clone(),
                 merged_state.dns.options.clone(),
                 false,
+                merged_state.dns.dns_over_tls,
             );
         }
     }
@@ -742,6 +761,9 @@ fn store_dns_search_or_options_to_auto_iface(
                 Some(i) => i,
                 None => continue,
             };
+        if iface.has_iface_dns() {
+            continue;
+        }
         if iface
             .merged
             .base_iface()
@@ -755,6 +777,7 @@ fn store_dns_search_or_options_to_auto_iface(
                 merged_state.dns.searches.clone(),
                 merged_state.dns.options.clone(),
                 true,
+                merged_state.dns.dns_over_tls,
             );
         }
         if iface
@@ -770,6 +793,7 @@ fn store_dns_search_or_options_to_auto_iface(
                 merged_state.dns.searches.clone(),
                 merged_state.dns.options.clone(),
                 false,
+                merged_state.dns.dns_over_tls,
             );
         }
     }
@@ -809,6 +833,9 @@ fn store_dns_search_or_options_to_ip_enabled_iface(
                 Some(i) => i,
                 None => continue,
             };
+        if iface.has_iface_dns() {
+            continue;
+        }
         if iface
             .merged
             .base_iface()
@@ -822,6 +849,7 @@ fn store_dns_search_or_options_to_ip_enabled_iface(
                 merged_state.dns.searches.clone(),
                 merged_state.dns.options.clone(),
                 true,
+                merged_state.dns.dns_over_tls,
             );
         }
         if iface
@@ -837,6 +865,7 @@ fn store_dns_search_or_options_to_ip_enabled_iface(
                 merged_state.dns.searches.clone(),
                 merged_state.dns.options.clone(),
                 false,
+                merged_state.dns.dns_over_tls,
             );
         }
     }
@@ -870,6 +899,9 @@ fn store_dns_search_or_options_to_ip_enabled_iface(
                 Some(i) => i,
                 None => continue,
             };
+        if iface.has_iface_dns() {
+            continue;
+        }
         if iface
             .merged
             .base_iface()
@@ -883,6 +915,7 @@ fn store_dns_search_or_options_to_ip_enabled_iface(
                 merged_state.dns.searches.clone(),
                 merged_state.dns.options.clone(),
                 true,
+                merged_state.dns.dns_over_tls,
             );
         }
         if iface
@@ -898,6 +931,7 @@ fn store_dns_search_or_options_to_ip_enabled_iface(
                 merged_state.dns.searches.clone(),
                 merged_state.dns.options.clone(),
                 false,
+                merged_state.dns.dns_over_tls,
             );
         }
     }
@@ -12,19 +12,6 @@ use super::{
 pub(crate) fn nm_gen_conf(
     merged_state: &MergedNetworkState,
 ) -> Result<Vec<(String, String)>, NmstateError> {
-    if merged_state
-        .hostname
-        .desired
-        .as_ref()
-        .and_then(|c| c.config.as_ref())
-        .is_some()
-    {
-        log::warn!(
-            "Cannot store hostname configuration to keyfile \
-            of NetworkManager, please edit /etc/hostname manually"
-        );
-    }
-
     let mut merged_state = merged_state.clone();
     store_route_config(&mut merged_state)?;
     store_route_rule_config(&mut merged_state)?;
@@ -351,6 +351,7 @@ pub struct NmSettingConnection {
     pub autoconnect_ports: Option<bool>,
     pub lldp: Option<bool>,
     pub mptcp_flags: Option<u32>,
+    pub dns_over_tls: Option<i32>,
     _other: HashMap<String, zvariant::OwnedValue>,
 }
 
@@ -375,6 +376,7 @@ impl TryFrom<DbusDictionary> for NmSettingConnection {
             ),
             lldp: _from_map!(v, "lldp", i32::try_from)?.map(|i| i == 1),
             mptcp_flags: _from_map!(v, "mptcp-flags", u32::try_from)?,
+            dns_over_tls: _from_map!(v, "dns-over-tls", i32::try_from)?,
             _other: v,
         })
     }
@@ -407,6 +409,9 @@ impl ToDbusValue for NmSettingConnection {
         if let Some(v) = &self.mptcp_flags {
             ret.insert("mptcp-flags", zvariant::Value::new(v));
         }
+        if let Some(v) = &self.dns_over_tls {
+            ret.insert("dns-over-tls", zvariant::Value::new(v));
+        }
 
         ret.insert(
             "autoconnect",
@@ -104,6 +104,12 @@ pub struct NmSettingEthtool {
     pub ring_rx_jumbo: Option<u32>,
     pub ring_rx_mini: Option<u32>,
     pub ring_tx: Option<u32>,
+    pub channels_rx: Option<u32>,
+    pub channels_tx: Option<u32>,
+    pub channels_other: Option<u32>,
+    pub channels_combined: Option<u32>,
+    pub fec_mode: Option<u32>,
+    pub eee_enabled: Option<bool>,
     _other: HashMap<String, zvariant::OwnedValue>,
 }
 
@@ -244,6 +250,16 @@ impl TryFrom<DbusDictionary> for NmSettingEthtool {
             ring_rx_jumbo: _from_map!(v, "ring-rx-jumbo", u32::try_from)?,
             ring_rx_mini: _from_map!(v, "ring-rx-mini", u32::try_from)?,
             ring_tx: _from_map!(v, "ring-tx", u32::try_from)?,
+            channels_rx: _from_map!(v, "channels-rx", u32::try_from)?,
+            channels_tx: _from_map!(v, "channels-tx", u32::try_from)?,
+            channels_other: _from_map!(v, "channels-other", u32::try_from)?,
+            channels_combined: _from_map!(
+                v,
+                "channels-combined",
+                u32::try_from
+            )?,
+            fec_mode: _from_map!(v, "fec-mode", u32::try_from)?,
+            eee_enabled: _from_map!(v, "eee-enabled", bool::try_from)?,
             _other: v,
         })
     }
@@ -354,6 +370,24 @@ impl ToDbusValue for NmSettingEthtool {
         if let Some(v) = &self.ring_tx {
             ret.insert("ring-tx", zvariant::Value::new(v));
         }
+        if let Some(v) = &self.channels_rx {
+            ret.insert("channels-rx", zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.channels_tx {
+            ret.insert("channels-tx", zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.channels_other {
+            ret.insert("channels-other", zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.channels_combined {
+            ret.insert("channels-combined", zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.fec_mode {
+            ret.insert("fec-mode", zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.eee_enabled {
+            ret.insert("eee-enabled", zvariant::Value::new(v));
+        }
         Ok(ret)
     }
 }
@@ -15,6 +15,7 @@ pub struct NmSettingHsr {
     pub port2: Option<String>,
     pub multicast_spec: Option<u32>,
     pub prp: Option<bool>,
+    pub interlink: Option<String>,
     _other: HashMap<String, zvariant::OwnedValue>,
 }
 
@@ -26,6 +27,7 @@ impl TryFrom<DbusDictionary> for NmSettingHsr {
             port2: _from_map!(v, "port2", String::try_from)?,
             multicast_spec: _from_map!(v, "multicast-spec", u32::try_from)?,
             prp: _from_map!(v, "prp", bool::try_from)?,
+            interlink: _from_map!(v, "interlink", String::try_from)?,
             _other: v,
         })
     }
@@ -48,6 +50,9 @@ impl ToDbusValue for NmSettingHsr {
         if let Some(v) = &self.prp {
             ret.insert("prp", zvariant::Value::new(*v));
         }
+        if let Some(v) = &self.interlink {
+            ret.insert("interlink", zvariant::Value::new(v.clone()));
+        }
         ret.extend(self._other.iter().map(|(key, value)| {
             (key.as_str(), zvariant::Value::from(value.clone()))
         }));
@@ -10,6 +10,8 @@ use super::super::{
 };
 
 const GLIB_FILE_PATH_PREFIX: &str = "file://";
+const GLIB_PKCS11_PREFIX: &str = "pkcs11:";
+const PEM_PREFIX: &str = "-----BEGIN";
 
 #[derive(Debug, Clone, PartialEq, Default, Deserialize)]
 #[serde(try_from = "DbusDictionary")]
@@ -23,6 +25,14 @@ pub struct NmSetting8021X {
     pub private_key_password: Option<String>,
     pub phase2_auth: Option<String>,
     pub password: Option<String>,
+    pub anonymous_identity: Option<String>,
+    pub domain_suffix_match: Option<String>,
+    pub altsubject_matches: Option<Vec<String>>,
+    pub phase1_auth_flags: Option<u32>,
+    pub phase2_ca_cert: Option<Vec<u8>>,
+    pub phase2_client_cert: Option<Vec<u8>>,
+    pub phase2_private_key: Option<Vec<u8>>,
+    pub phase2_private_key_password: Option<String>,
     _other: HashMap<String, zvariant::OwnedValue>,
 }
 
@@ -38,6 +48,42 @@ impl TryFrom<DbusDictionary> for NmSetting8021X {
             private_key_password: None,
             phase2_auth: _from_map!(v, "phase2-auth", String::try_from)?,
             password: None,
+            anonymous_identity: _from_map!(
+                v,
+                "anonymous-identity",
+                String::try_from
+            )?,
+            domain_suffix_match: _from_map!(
+                v,
+                "domain-suffix-match",
+                String::try_from
+            )?,
+            altsubject_matches: _from_map!(
+                v,
+                "altsubject-matches",
+                <Vec<String>>::try_from
+            )?,
+            phase1_auth_flags: _from_map!(
+                v,
+                "phase1-auth-flags",
+                u32::try_from
+            )?,
+            phase2_ca_cert: _from_map!(
+                v,
+                "phase2-ca-cert",
+                <Vec<u8>>::try_from
+            )?,
+            phase2_client_cert: _from_map!(
+                v,
+                "phase2-client-cert",
+                <Vec<u8>>::try_from
+            )?,
+            phase2_private_key: _from_map!(
+                v,
+                "phase2-private-key",
+                <Vec<u8>>::try_from
+            )?,
+            phase2_private_key_password: None,
             _other: v,
         })
     }
@@ -70,6 +116,30 @@ impl ToDbusValue for NmSetting8021X {
         if let Some(v) = &self.password {
             ret.insert("password", zvariant::Value::new(v));
         }
+        if let Some(v) = &self.anonymous_identity {
+            ret.insert("anonymous-identity", zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.domain_suffix_match {
+            ret.insert("domain-suffix-match", zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.altsubject_matches {
+            ret.insert("altsubject-matches", zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.phase1_auth_flags {
+            ret.insert("phase1-auth-flags", zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.phase2_ca_cert {
+            ret.insert("phase2-ca-cert", zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.phase2_client_cert {
+            ret.insert("phase2-client-cert", zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.phase2_private_key {
+            ret.insert("phase2-private-key", zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.phase2_private_key_password {
+            ret.insert("phase2-private-key-password", zvariant::Value::new(v));
+        }
         ret.extend(self._other.iter().map(|(key, value)| {
             (key.as_str(), zvariant::Value::from(value.clone()))
         }));
@@ -110,6 +180,79 @@ impl NmSetting8021X {
                 }
             }
         }
+        if let Some(v) = secrets.get("phase2-private-key-password") {
+            match String::try_from(v.clone()) {
+                Ok(s) => {
+                    self.phase2_private_key_password = Some(s);
+                }
+                Err(e) => {
+                    log::warn!(
+                        "Failed to convert phase2_private_key_password: \
+                        {:?} {:?}",
+                        v,
+                        e
+                    );
+                }
+            }
+        }
+    }
+
+    /// Convert file path, PKCS#11 URI or inline PEM content to glib bytes
+    /// used by NetworkManager certificate and private key properties.
+    pub fn cert_to_glib_bytes(value: &str) -> Vec<u8> {
+        if value.starts_with(GLIB_PKCS11_PREFIX) {
+            format!("{value}\0").into_bytes()
+        } else if value.trim_start().starts_with(PEM_PREFIX) {
+            value.as_bytes().to_vec()
+        } else {
+            Self::file_path_to_glib_bytes(value)
+        }
+    }
+
+    /// Convert glib bytes of NetworkManager certificate and private key
+    /// properties to file path, PKCS#11 URI or inline PEM content.
+    pub fn glib_bytes_to_cert(value: &[u8]) -> Result<String, NmError> {
+        if value.starts_with(GLIB_PKCS11_PREFIX.as_bytes()) {
+            match std::str::from_utf8(value) {
+                Ok(s) => Ok(s.trim_end_matches(char::from(0)).to_string()),
+                Err(e) => {
+                    let e = NmError::new(
+                        ErrorKind::InvalidArgument,
+                        format!(
+                            "Failed to parse PKCS#11 URI glib bytes to UTF-8 \
+                            string: {value:?}: {e:?}"
+                        ),
+                    );
+                    log::error!("{}", e);
+                    Err(e)
+                }
+            }
+        } else if value.starts_with(GLIB_FILE_PATH_PREFIX.as_bytes()) {
+            Self::glib_bytes_to_file_path(value)
+        } else {
+            match std::str::from_utf8(value) {
+                Ok(s) if s.trim_start().starts_with(PEM_PREFIX) => {
+                    Ok(s.to_string())
+                }
+                _ => {
+                    let e = NmError::new(
+                        ErrorKind::InvalidArgument,
+                        "Only PEM format is supported for inline \
+                        certificate or private key"
+                            .to_string(),
+                    );
+                    log::error!("{}", e);
+                    Err(e)
+                }
+            }
+        }
+    }
+
+    /// Whether specified glib bytes holding inline certificate or private key
+    /// content instead of file path or PKCS#11 URI.
+    pub fn is_glib_bytes_blob(value: &[u8]) -> bool {
+        !value.starts_with(GLIB_FILE_PATH_PREFIX.as_bytes())
+            && !value.starts_with(GLIB_PKCS11_PREFIX.as_bytes())
     }
 
     pub fn file_path_to_glib_bytes(file_path: &str) -> Vec<u8> {
@@ -101,16 +101,24 @@ pub struct NmSettingIp {
     pub gateway: Option<String>,
     pub may_fail: Option<bool>,
     pub route_metric: Option<i64>,
+    // IPv4 only
+    pub forwarding: Option<i32>,
+    // IPv4 only
+    pub dhcp_vendor_class_identifier: Option<String>,
+    // IPv4 only
+    pub dhcp_reject_servers: Option<Vec<String>>,
     // IPv6 only
     pub ra_timeout: Option<i32>,
     // IPv6 only
     pub addr_gen_mode: Option<i32>,
     // IPv6 only
     pub dhcp_duid: Option<String>,
-    // IPv6 only
     pub dhcp_iaid: Option<String>,
+    pub dhcp_send_release: Option<i32>,
     // IPv6 only
     pub token: Option<String>,
+    // IPv6 only
+    pub ip6_privacy: Option<i32>,
     pub dhcp_send_hostname: Option<bool>,
     pub dhcp_fqdn: Option<String>,
     pub dhcp_hostname: Option<String>,
@@ -149,6 +157,8 @@ impl TryFrom<DbusDictionary> for NmSettingIp {
             may_fail: _from_map!(v, "may-fail", bool::try_from)?,
             route_metric: _from_map!(v, "route-metric", i64::try_from)?,
             token: _from_map!(v, "token", String::try_from)?,
+            forwarding: _from_map!(v, "forwarding", i32::try_from)?,
+            ip6_privacy: _from_map!(v, "ip6-privacy", i32::try_from)?,
             dhcp_send_hostname: _from_map!(
                 v,
                 "dhcp-send-hostname",
@@ -156,6 +166,21 @@ impl TryFrom<DbusDictionary> for NmSettingIp {
             )?,
             dhcp_fqdn: _from_map!(v, "dhcp-fqdn", String::try_from)?,
             dhcp_hostname: _from_map!(v, "dhcp-hostname", String::try_from)?,
+            dhcp_vendor_class_identifier: _from_map!(
+                v,
+                "dhcp-vendor-class-identifier",
+                String::try_from
+            )?,
+            dhcp_reject_servers: _from_map!(
+                v,
+                "dhcp-reject-servers",
+                Vec::<String>::try_from
+            )?,
+            dhcp_send_release: _from_map!(
+                v,
+                "dhcp-send-release",
+                i32::try_from
+            )?,
             ..Default::default()
         };
 
@@ -222,7 +247,10 @@ impl ToDbusValue for NmSettingIp {
         ret.insert("route-data", nm_ip_routes_to_value(&self.routes)?);
         ret.insert("routing-rules", nm_ip_rules_to_value(&self.route_rules)?);
         if let Some(dns_servers) = self.dns.as_ref() {
-            if !dns_servers.is_empty() {
+            if dns_servers.iter().any(|s| s.contains("://")) {
+                // The name server URI is only supported by `dns-data`
+                ret.insert("dns-data", zvariant::Value::new(dns_servers));
+            } else if !dns_servers.is_empty() {
                 // We still use the `dns` instead of `dns-data` as the
                 // `dns-data` is only supported by NM 1.41+ which is not widely
                 // available yet. And we do not know the NM version yet in this
@@ -281,6 +309,21 @@ impl ToDbusValue for NmSettingIp {
         if let Some(v) = &self.token {
             ret.insert("token", zvariant::Value::new(v));
         }
+        if let Some(v) = self.forwarding {
+            ret.insert("forwarding", zvariant::Value::new(v));
+        }
+        if let Some(v) = self.ip6_privacy {
+            ret.insert("ip6-privacy", zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.dhcp_vendor_class_identifier {
+            ret.insert("dhcp-vendor-class-identifier", zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.dhcp_reject_servers {
+            ret.insert("dhcp-reject-servers", zvariant::Value::new(v));
+        }
+        if let Some(v) = self.dhcp_send_release {
+            ret.insert("dhcp-send-release", zvariant::Value::new(v));
+        }
         if let Some(v) = &self.dhcp_send_hostname {
             ret.insert("dhcp-send-hostname", zvariant::Value::new(v));
         }
@@ -34,6 +34,9 @@ pub struct NmSettingSriov {
     pub autoprobe_drivers: Option<bool>,
     pub total_vfs: Option<u32>,
     pub vfs: Option<Vec<NmSettingSriovVf>>,
+    pub eswitch_mode: Option<i32>,
+    pub eswitch_inline_mode: Option<i32>,
+    pub eswitch_encap_mode: Option<i32>,
     _other: DbusDictionary,
 }
 
@@ -52,6 +55,17 @@ impl TryFrom<DbusDictionary> for NmSettingSriov {
             },
             total_vfs: _from_map!(v, "total-vfs", u32::try_from)?,
             vfs: _from_map!(v, "vfs", own_value_to_vfs)?,
+            eswitch_mode: _from_map!(v, "eswitch-mode", i32::try_from)?,
+            eswitch_inline_mode: _from_map!(
+                v,
+                "eswitch-inline-mode",
+                i32::try_from
+            )?,
+            eswitch_encap_mode: _from_map!(
+                v,
+                "eswitch-encap-mode",
+                i32::try_from
+            )?,
             _other: v,
         })
     }
@@ -81,6 +95,15 @@ impl ToDbusValue for NmSettingSriov {
             }
             ret.insert("vfs", zvariant::Value::Array(vf_values));
         }
+        if let Some(v) = &self.eswitch_mode {
+            ret.insert("eswitch-mode", zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.eswitch_inline_mode {
+            ret.insert("eswitch-inline-mode", zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.eswitch_encap_mode {
+            ret.insert("eswitch-encap-mode", zvariant::Value::new(v));
+        }
 
         ret.extend(self._other.iter().map(|(key, value)| {
             (key.as_str(), zvariant::Value::from(value.clone()))
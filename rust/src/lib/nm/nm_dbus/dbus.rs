@@ -27,6 +27,10 @@ pub(crate) const NM_DBUS_INTERFACE_AC: &str =
     "org.freedesktop.NetworkManager.Connection.Active";
 pub(crate) const NM_DBUS_INTERFACE_DEV: &str =
     "org.freedesktop.NetworkManager.Device";
+pub(crate) const NM_DBUS_INTERFACE_DHCP4_CONFIG: &str =
+    "org.freedesktop.NetworkManager.DHCP4Config";
+pub(crate) const NM_DBUS_INTERFACE_DHCP6_CONFIG: &str =
+    "org.freedesktop.NetworkManager.DHCP6Config";
 
 const NM_DBUS_INTERFACE_DEVICE: &str = "org.freedesktop.NetworkManager.Device";
 
@@ -2,8 +2,12 @@
 
 use std::collections::HashMap;
 
+use base64::{engine::general_purpose::STANDARD, Engine};
+
 use super::super::{NmError, NmSetting8021X, ToKeyfile};
 
+const KEYFILE_BLOB_PREFIX: &str = "data:;base64,";
+
 impl ToKeyfile for NmSetting8021X {
     fn to_keyfile(&self) -> Result<HashMap<String, zvariant::Value>, NmError> {
         let mut ret = HashMap::new();
@@ -11,14 +15,7 @@ impl ToKeyfile for NmSetting8021X {
             ret.insert("identity".to_string(), zvariant::Value::new(v));
         }
         if let Some(v) = &self.private_key {
-            ret.insert(
-                "private-key".to_string(),
-                if let Ok(path) = Self::glib_bytes_to_file_path(v) {
-                    zvariant::Value::new(path)
-                } else {
-                    zvariant::Value::new(v)
-                },
-            );
+            ret.insert("private-key".to_string(), cert_to_keyfile_value(v));
         }
         if let Some(v) = &self.eap {
             // Need NULL append at the end
@@ -27,24 +24,10 @@ impl ToKeyfile for NmSetting8021X {
             ret.insert("eap".to_string(), zvariant::Value::new(new_eaps));
         }
         if let Some(v) = &self.client_cert {
-            ret.insert(
-                "client-cert".to_string(),
-                if let Ok(path) = Self::glib_bytes_to_file_path(v) {
-                    zvariant::Value::new(path)
-                } else {
-                    zvariant::Value::new(v)
-                },
-            );
+            ret.insert("client-cert".to_string(), cert_to_keyfile_value(v));
         }
         if let Some(v) = &self.ca_cert {
-            ret.insert(
-                "ca-cert".to_string(),
-                if let Ok(path) = Self::glib_bytes_to_file_path(v) {
-                    zvariant::Value::new(path)
-                } else {
-                    zvariant::Value::new(v)
-                },
-            );
+            ret.insert("ca-cert".to_string(), cert_to_keyfile_value(v));
         }
         if let Some(v) = &self.private_key_password {
             ret.insert(
@@ -58,6 +41,69 @@ impl ToKeyfile for NmSetting8021X {
         if let Some(v) = &self.password {
             ret.insert("password".to_string(), zvariant::Value::new(v));
         }
+        if let Some(v) = &self.anonymous_identity {
+            ret.insert(
+                "anonymous-identity".to_string(),
+                zvariant::Value::new(v),
+            );
+        }
+        if let Some(v) = &self.domain_suffix_match {
+            ret.insert(
+                "domain-suffix-match".to_string(),
+                zvariant::Value::new(v),
+            );
+        }
+        if let Some(v) = &self.altsubject_matches {
+            // Need NULL append at the end
+            let mut new_matches = v.clone();
+            new_matches.push("".to_string());
+            ret.insert(
+                "altsubject-matches".to_string(),
+                zvariant::Value::new(new_matches),
+            );
+        }
+        if let Some(v) = &self.phase1_auth_flags {
+            ret.insert(
+                "phase1-auth-flags".to_string(),
+                zvariant::Value::new(v),
+            );
+        }
+        if let Some(v) = &self.phase2_ca_cert {
+            ret.insert("phase2-ca-cert".to_string(), cert_to_keyfile_value(v));
+        }
+        if let Some(v) = &self.phase2_client_cert {
+            ret.insert(
+                "phase2-client-cert".to_string(),
+                cert_to_keyfile_value(v),
+            );
+        }
+        if let Some(v) = &self.phase2_private_key {
+            ret.insert(
+                "phase2-private-key".to_string(),
+                cert_to_keyfile_value(v),
+            );
+        }
+        if let Some(v) = &self.phase2_private_key_password {
+            ret.insert(
+                "phase2-private-key-password".to_string(),
+                zvariant::Value::new(v),
+            );
+        }
         Ok(ret)
     }
 }
+
+// The keyfile stores file path or PKCS#11 URI as plain string, while the
+// inline certificate or private key is stored as base64 encoded data URI.
+fn cert_to_keyfile_value(value: &[u8]) -> zvariant::Value<'static> {
+    if NmSetting8021X::is_glib_bytes_blob(value) {
+        zvariant::Value::new(format!(
+            "{KEYFILE_BLOB_PREFIX}{}",
+            STANDARD.encode(value)
+        ))
+    } else if let Ok(cert) = NmSetting8021X::glib_bytes_to_cert(value) {
+        zvariant::Value::new(cert)
+    } else {
+        zvariant::Value::new(value.to_vec())
+    }
+}
@@ -8,8 +8,14 @@ impl ToKeyfile for NmSettingIp {
     fn to_keyfile(&self) -> Result<HashMap<String, zvariant::Value>, NmError> {
         let mut ret = HashMap::new();
         for (k, v) in self.to_value()?.drain() {
-            if !["address-data", "route-data", "dns", "routing-rules"]
-                .contains(&k)
+            if ![
+                "address-data",
+                "route-data",
+                "dns",
+                "dns-data",
+                "routing-rules",
+            ]
+            .contains(&k)
             {
                 ret.insert(k.to_string(), v);
             }
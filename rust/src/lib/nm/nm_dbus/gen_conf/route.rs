@@ -35,6 +35,42 @@ impl NmIpRoute {
             if let Some(lock_cwnd) = self.lock_cwnd {
                 write!(opt_string, ",lock-cwnd={}", lock_cwnd).ok();
             }
+            if let Some(mtu) = self.mtu {
+                write!(opt_string, ",mtu={}", mtu).ok();
+            }
+            if let Some(lock_mtu) = self.lock_mtu {
+                write!(opt_string, ",lock-mtu={}", lock_mtu).ok();
+            }
+            if let Some(advmss) = self.advmss {
+                write!(opt_string, ",advmss={}", advmss).ok();
+            }
+            if let Some(lock_advmss) = self.lock_advmss {
+                write!(opt_string, ",lock-advmss={}", lock_advmss).ok();
+            }
+            if let Some(initcwnd) = self.initcwnd {
+                write!(opt_string, ",initcwnd={}", initcwnd).ok();
+            }
+            if let Some(lock_initcwnd) = self.lock_initcwnd {
+                write!(opt_string, ",lock-initcwnd={}", lock_initcwnd).ok();
+            }
+            if let Some(initrwnd) = self.initrwnd {
+                write!(opt_string, ",initrwnd={}", initrwnd).ok();
+            }
+            if let Some(lock_initrwnd) = self.lock_initrwnd {
+                write!(opt_string, ",lock-initrwnd={}", lock_initrwnd).ok();
+            }
+            if let Some(quickack) = self.quickack {
+                write!(opt_string, ",quickack={}", quickack).ok();
+            }
+            if let Some(rto_min) = self.rto_min {
+                write!(opt_string, ",rto_min={}", rto_min).ok();
+            }
+            if let Some(onlink) = self.onlink {
+                write!(opt_string, ",onlink={}", onlink).ok();
+            }
+            if let Some(scope) = self.scope {
+                write!(opt_string, ",scope={}", scope).ok();
+            }
             if let Some(src) = self.src.as_ref() {
                 write!(opt_string, ",src={}", src).ok();
             }
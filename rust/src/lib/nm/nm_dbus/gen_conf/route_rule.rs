@@ -15,6 +15,9 @@ impl NmIpRouteRule {
             let mut keys = Vec::new();
             let prio_str = format!("priority {priority}");
             keys.push(prio_str);
+            if self.invert == Some(true) {
+                keys.push("not".to_string());
+            }
 
             let to_str = match (self.to.as_ref(), self.to_len.as_ref()) {
                 (Some(t), Some(t_len)) => format!("to {t}/{t_len}"),
@@ -51,6 +54,30 @@ impl NmIpRouteRule {
             if let Some(iif) = self.iifname.as_ref() {
                 keys.push(format!("iif {iif}"));
             }
+            if let Some(oif) = self.oifname.as_ref() {
+                keys.push(format!("oif {oif}"));
+            }
+            if let Some(v) = self.tos {
+                keys.push(format!("tos {v}"));
+            }
+            if let Some(v) = self.ipproto {
+                keys.push(format!("ipproto {v}"));
+            }
+            if let Some(v) =
+                port_range_to_keyfile("sport", self.sport_start, self.sport_end)
+            {
+                keys.push(v);
+            }
+            if let Some(v) =
+                port_range_to_keyfile("dport", self.dport_start, self.dport_end)
+            {
+                keys.push(v);
+            }
+            if let (Some(start), Some(end)) =
+                (self.uid_range_start, self.uid_range_end)
+            {
+                keys.push(format!("uidrange {start}-{end}"));
+            }
             if let Some(v) = self.suppress_prefixlength {
                 keys.push(format!("suppress_prefixlength {v}"));
             }
@@ -79,3 +106,18 @@ impl NmIpRouteRule {
         ret
     }
 }
+
+fn port_range_to_keyfile(
+    name: &str,
+    start: Option<u16>,
+    end: Option<u16>,
+) -> Option<String> {
+    match (start, end) {
+        (Some(start), Some(end)) if start == end => {
+            Some(format!("{name} {start}"))
+        }
+        (Some(start), Some(end)) => Some(format!("{name} {start}-{end}")),
+        (Some(start), None) => Some(format!("{name} {start}")),
+        _ => None,
+    }
+}
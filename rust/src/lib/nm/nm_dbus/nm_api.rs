@@ -1,5 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::time::{Duration, Instant};
 
@@ -16,7 +17,8 @@ use super::{
     error::{ErrorKind, NmError},
     lldp::NmLldpNeighbor,
     query_apply::device::{
-        nm_dev_delete, nm_dev_from_obj_path, nm_dev_get_llpd,
+        nm_dev_delete, nm_dev_from_obj_path, nm_dev_get_dhcp_options,
+        nm_dev_get_llpd,
     },
     NmIfaceType,
 };
@@ -315,6 +317,22 @@ impl NmApi<'_> {
         nm_dev_get_llpd(&self.dbus.connection, nm_dev_obj_path)
     }
 
+    pub fn device_dhcp4_options_get(
+        &mut self,
+        nm_dev_obj_path: &str,
+    ) -> Result<HashMap<String, String>, NmError> {
+        self.extend_timeout_if_required()?;
+        nm_dev_get_dhcp_options(&self.dbus.connection, nm_dev_obj_path, false)
+    }
+
+    pub fn device_dhcp6_options_get(
+        &mut self,
+        nm_dev_obj_path: &str,
+    ) -> Result<HashMap<String, String>, NmError> {
+        self.extend_timeout_if_required()?;
+        nm_dev_get_dhcp_options(&self.dbus.connection, nm_dev_obj_path, true)
+    }
+
     // If any device is with NewActivation or IpConfig state,
     // we wait its activation.
     pub fn wait_checkpoint_rollback(
@@ -1,10 +1,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::HashMap;
 use std::convert::TryFrom;
 
 use super::super::{
     connection::DbusDictionary,
-    dbus::{NM_DBUS_INTERFACE_DEV, NM_DBUS_INTERFACE_ROOT},
+    dbus::{
+        obj_path_to_string, NM_DBUS_INTERFACE_DEV,
+        NM_DBUS_INTERFACE_DHCP4_CONFIG, NM_DBUS_INTERFACE_DHCP6_CONFIG,
+        NM_DBUS_INTERFACE_ROOT,
+    },
     lldp::NmLldpNeighbor,
     ErrorKind, NmDevice, NmDeviceState, NmDeviceStateReason, NmError,
     NmIfaceType,
@@ -250,6 +255,62 @@ pub(crate) fn nm_dev_get_llpd(
     }
 }
 
+// Return empty HashMap if DHCP client is not running on this device.
+pub(crate) fn nm_dev_get_dhcp_options(
+    dbus_conn: &zbus::Connection,
+    obj_path: &str,
+    is_ipv6: bool,
+) -> Result<HashMap<String, String>, NmError> {
+    let (prop_name, dhcp_iface_name) = if is_ipv6 {
+        ("Dhcp6Config", NM_DBUS_INTERFACE_DHCP6_CONFIG)
+    } else {
+        ("Dhcp4Config", NM_DBUS_INTERFACE_DHCP4_CONFIG)
+    };
+    let proxy = zbus::Proxy::new(
+        dbus_conn,
+        NM_DBUS_INTERFACE_ROOT,
+        obj_path,
+        NM_DBUS_INTERFACE_DEV,
+    )?;
+    let dhcp_obj_path =
+        match proxy.get_property::<zvariant::OwnedObjectPath>(prop_name) {
+            Ok(p) => obj_path_to_string(p),
+            Err(e) => {
+                return Err(NmError::new(
+                    ErrorKind::Bug,
+                    format!(
+                        "Failed to retrieve {prop_name} of device \
+                        {obj_path}: {e}"
+                    ),
+                ));
+            }
+        };
+    if dhcp_obj_path.is_empty() || dhcp_obj_path == "/" {
+        return Ok(HashMap::new());
+    }
+    let proxy = zbus::Proxy::new(
+        dbus_conn,
+        NM_DBUS_INTERFACE_ROOT,
+        dhcp_obj_path.as_str(),
+        dhcp_iface_name,
+    )?;
+    match proxy.get_property::<DbusDictionary>("Options") {
+        Ok(opts) => {
+            let mut ret = HashMap::new();
+            for (key, value) in opts {
+                if let Ok(v) = String::try_from(value) {
+                    ret.insert(key, v);
+                }
+            }
+            Ok(ret)
+        }
+        Err(e) => Err(NmError::new(
+            ErrorKind::Bug,
+            format!("Failed to retrieve DHCP options of {dhcp_obj_path}: {e}"),
+        )),
+    }
+}
+
 fn nm_dev_get_mac_address(
     dbus_conn: &zbus::Connection,
     obj_path: &str,
@@ -3,6 +3,7 @@
 use super::nm_dbus::{NmActiveConnection, NmConnection, NmIfaceType};
 use super::settings::{
     fix_ip_dhcp_timeout, get_exist_profile, iface_to_nm_connections,
+    validate_nm_ethtool, validate_nm_ip_forwarding, validate_nm_ip_sysctl,
 };
 
 use crate::{
@@ -23,6 +24,8 @@ pub(crate) fn perpare_nm_conns(
     nm_acs: &[NmActiveConnection],
     gen_conf_mode: bool,
 ) -> Result<PerparedNmConnections, NmstateError> {
+    validate_nm_ip_forwarding(&merged_state.ip_forwarding)?;
+
     let mut nm_conns_to_update: Vec<NmConnection> = Vec::new();
     let mut nm_conns_to_activate: Vec<NmConnection> = Vec::new();
 
@@ -69,6 +72,8 @@ pub(crate) fn perpare_nm_conns(
         } else {
             continue;
         };
+        validate_nm_ethtool(merged_iface, gen_conf_mode)?;
+        validate_nm_ip_sysctl(merged_iface)?;
 
         for mut nm_conn in iface_to_nm_connections(
             merged_iface,
@@ -105,7 +110,7 @@ pub(crate) fn perpare_nm_conns(
         }
     }
 
-    fix_ip_dhcp_timeout(&mut nm_conns_to_update);
+    fix_ip_dhcp_timeout(&mut nm_conns_to_update, &merged_state.interfaces);
 
     Ok(PerparedNmConnections {
         to_store: nm_conns_to_update,
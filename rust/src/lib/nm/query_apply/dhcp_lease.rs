@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use crate::DhcpLease;
+
+// NetworkManager stores multiple values in a single space separated string.
+fn parse_list(value: &str) -> Option<Vec<String>> {
+    let ret: Vec<String> =
+        value.split_whitespace().map(|s| s.to_string()).collect();
+    if ret.is_empty() {
+        None
+    } else {
+        Some(ret)
+    }
+}
+
+fn get_str(opts: &HashMap<String, String>, key: &str) -> Option<String> {
+    opts.get(key).filter(|v| !v.is_empty()).cloned()
+}
+
+fn get_list(opts: &HashMap<String, String>, key: &str) -> Option<Vec<String>> {
+    opts.get(key).and_then(|v| parse_list(v))
+}
+
+fn get_num<T: std::str::FromStr>(
+    opts: &HashMap<String, String>,
+    key: &str,
+) -> Option<T> {
+    opts.get(key).and_then(|v| match v.parse::<T>() {
+        Ok(i) => Some(i),
+        Err(_) => {
+            log::debug!("Ignoring invalid DHCP option {key}: {v}");
+            None
+        }
+    })
+}
+
+pub(crate) fn nm_dhcp4_opts_to_nmstate(
+    opts: &HashMap<String, String>,
+) -> Option<DhcpLease> {
+    if opts.is_empty() {
+        return None;
+    }
+    Some(DhcpLease {
+        server: get_str(opts, "dhcp_server_identifier"),
+        lease_time: get_num(opts, "dhcp_lease_time"),
+        expiry: get_num(opts, "expiry"),
+        routers: get_list(opts, "routers"),
+        dns_servers: get_list(opts, "domain_name_servers"),
+        domain_name: get_str(opts, "domain_name"),
+        domain_search: get_list(opts, "domain_search"),
+        ntp_servers: get_list(opts, "ntp_servers"),
+        mtu: get_num(opts, "interface_mtu"),
+        ..Default::default()
+    })
+}
+
+pub(crate) fn nm_dhcp6_opts_to_nmstate(
+    opts: &HashMap<String, String>,
+) -> Option<DhcpLease> {
+    if opts.is_empty() {
+        return None;
+    }
+    let lease_time: Option<u64> = get_num(opts, "max_life");
+    let life_starts: Option<u64> = get_num(opts, "life_starts");
+    Some(DhcpLease {
+        server: get_str(opts, "dhcp6_server_id"),
+        lease_time,
+        expiry: life_starts.zip(lease_time).map(|(s, l)| s + l),
+        dns_servers: get_list(opts, "dhcp6_name_servers"),
+        domain_search: get_list(opts, "dhcp6_domain_search"),
+        ntp_servers: get_list(opts, "dhcp6_ntp_servers"),
+        prefix_delegation: get_list(opts, "ip6_prefix"),
+        ..Default::default()
+    })
+}
@@ -5,12 +5,16 @@ use std::str::FromStr;
 use super::super::{
     dns::{extract_ipv6_link_local_iface_from_dns_srv, get_cur_dns_ifaces},
     error::nm_error_to_nmstate,
-    nm_dbus::{NmApi, NmDnsEntry, NmGlobalDnsConfig, NmSettingIp},
+    nm_dbus::{
+        NmApi, NmConnection, NmDnsEntry, NmGlobalDnsConfig, NmSettingIp,
+    },
+    settings::NMSTATE_IFACE_DNS,
 };
 
 use crate::{
-    ip::is_ipv6_unicast_link_local, DnsClientState, DnsState, Interfaces,
-    MergedInterfaces, MergedNetworkState, NmstateError,
+    ip::is_ipv6_unicast_link_local, DnsClientState, DnsState,
+    InterfaceDnsConfig, Interfaces, MergedInterfaces, MergedNetworkState,
+    NmstateError,
 };
 
 pub(crate) fn nm_dns_to_nmstate(
@@ -41,9 +45,46 @@ pub(crate) fn nm_dns_to_nmstate(
         search: nm_ip_setting.dns_search.clone(),
         options: nm_ip_setting.dns_options.clone(),
         priority: nm_ip_setting.dns_priority,
+        ..Default::default()
     }
 }
 
+// Return None if the DNS config in IP settings is not interface level DNS
+// config.
+pub(crate) fn nm_iface_dns_to_nmstate(
+    iface_name: &str,
+    nm_conn: &NmConnection,
+) -> Option<InterfaceDnsConfig> {
+    nm_conn
+        .user
+        .as_ref()
+        .and_then(|nm_setting| nm_setting.data.as_ref())
+        .and_then(|data| data.get(NMSTATE_IFACE_DNS))?;
+    let mut servers: Vec<String> = Vec::new();
+    let mut searches: Vec<String> = Vec::new();
+    let mut priority = None;
+    for nm_ip_setting in [nm_conn.ipv4.as_ref(), nm_conn.ipv6.as_ref()]
+        .into_iter()
+        .flatten()
+    {
+        let dns_conf = nm_dns_to_nmstate(iface_name, nm_ip_setting);
+        servers.extend(dns_conf.server.unwrap_or_default());
+        for sch in dns_conf.search.unwrap_or_default() {
+            if !searches.contains(&sch) {
+                searches.push(sch);
+            }
+        }
+        if priority.is_none() {
+            priority = dns_conf.priority;
+        }
+    }
+    Some(InterfaceDnsConfig {
+        server: Some(servers),
+        search: Some(searches),
+        priority: Some(priority.unwrap_or_default()),
+    })
+}
+
 pub(crate) fn retrieve_dns_info(
     nm_api: &mut NmApi,
     ifaces: &Interfaces,
@@ -75,6 +116,7 @@ pub(crate) fn retrieve_dns_info(
     dns_confs.sort_unstable_by_key(|d| d.priority.unwrap_or_default());
     let mut config_srvs: Vec<String> = Vec::new();
     let mut config_schs: Vec<String> = Vec::new();
+    let dns_over_tls = dns_confs.iter().find_map(|d| d.dns_over_tls);
     for dns_conf in dns_confs {
         if let Some(srvs) = dns_conf.server.as_ref() {
             config_srvs.extend_from_slice(srvs);
@@ -144,6 +186,7 @@ pub(crate) fn retrieve_dns_info(
             } else {
                 Some(dns_options)
             },
+            dns_over_tls,
             ..Default::default()
         }),
     })
@@ -1,39 +1,116 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use super::super::nm_dbus::NmSetting8021X;
+use super::super::nm_dbus::{
+    NmDevice, NmDeviceState, NmDeviceStateReason, NmSetting8021X,
+};
 
-use crate::Ieee8021XConfig;
+use crate::{Ieee8021XAuthFailureReason, Ieee8021XAuthState, Ieee8021XConfig};
 
 pub(crate) fn nm_802_1x_to_nmstate(
     nm_setting: &NmSetting8021X,
 ) -> Ieee8021XConfig {
     Ieee8021XConfig {
         identity: nm_setting.identity.clone(),
-        private_key: nm_setting
-            .private_key
-            .as_deref()
-            .and_then(vec_u8_to_file_path),
+        private_key: nm_setting.private_key.as_deref().and_then(vec_u8_to_cert),
         eap: nm_setting.eap.clone(),
-        client_cert: nm_setting
-            .client_cert
-            .as_deref()
-            .and_then(vec_u8_to_file_path),
-        ca_cert: nm_setting.ca_cert.as_deref().and_then(vec_u8_to_file_path),
+        client_cert: nm_setting.client_cert.as_deref().and_then(vec_u8_to_cert),
+        ca_cert: nm_setting.ca_cert.as_deref().and_then(vec_u8_to_cert),
         private_key_password: nm_setting.private_key_password.clone(),
         phase2_auth: nm_setting.phase2_auth.clone(),
         password: nm_setting.password.clone(),
+        anonymous_identity: nm_setting.anonymous_identity.clone(),
+        domain_suffix_match: nm_setting.domain_suffix_match.clone(),
+        altsubject_matches: nm_setting.altsubject_matches.clone(),
+        phase1_auth_flags: nm_setting.phase1_auth_flags,
+        phase2_ca_cert: nm_setting
+            .phase2_ca_cert
+            .as_deref()
+            .and_then(vec_u8_to_cert),
+        phase2_client_cert: nm_setting
+            .phase2_client_cert
+            .as_deref()
+            .and_then(vec_u8_to_cert),
+        phase2_private_key: nm_setting
+            .phase2_private_key
+            .as_deref()
+            .and_then(vec_u8_to_cert),
+        phase2_private_key_password: nm_setting
+            .phase2_private_key_password
+            .clone(),
+        auth_state: None,
+        auth_failure_reason: None,
+    }
+}
+
+fn nm_dev_state_reason_to_auth_failure(
+    reason: &NmDeviceStateReason,
+) -> Option<Ieee8021XAuthFailureReason> {
+    match reason {
+        NmDeviceStateReason::NoSecrets => {
+            Some(Ieee8021XAuthFailureReason::NoSecrets)
+        }
+        NmDeviceStateReason::SupplicantDisconnect => {
+            Some(Ieee8021XAuthFailureReason::SupplicantDisconnect)
+        }
+        NmDeviceStateReason::SupplicantConfigFailed => {
+            Some(Ieee8021XAuthFailureReason::SupplicantConfigFailed)
+        }
+        NmDeviceStateReason::SupplicantFailed => {
+            Some(Ieee8021XAuthFailureReason::SupplicantFailed)
+        }
+        NmDeviceStateReason::SupplicantTimeout => {
+            Some(Ieee8021XAuthFailureReason::SupplicantTimeout)
+        }
+        _ => None,
+    }
+}
+
+// NetworkManager does not expose supplicant state of wired device, deduce it
+// from device state and its reason.
+// Caller should only invoke this for NM profile holding 802.1X setting.
+// Return None if state unknown.
+// The `NeedAuth` state means NM is waiting for secret agent, the
+// authentication has not been attempted yet, hence not reported.
+pub(crate) fn nm_dev_to_8021x_auth_state(
+    nm_dev: &NmDevice,
+) -> Option<(Ieee8021XAuthState, Option<Ieee8021XAuthFailureReason>)> {
+    match nm_dev.state {
+        NmDeviceState::Failed | NmDeviceState::Disconnected => {
+            nm_dev_state_reason_to_auth_failure(&nm_dev.state_reason)
+                .map(|reason| (Ieee8021XAuthState::Failed, Some(reason)))
+        }
+        NmDeviceState::Prepare | NmDeviceState::Config => {
+            Some((Ieee8021XAuthState::Connecting, None))
+        }
+        NmDeviceState::IpConfig
+        | NmDeviceState::IpCheck
+        | NmDeviceState::Secondaries
+        | NmDeviceState::Activated => {
+            Some((Ieee8021XAuthState::Authenticated, None))
+        }
+        _ => None,
     }
 }
 
-fn vec_u8_to_file_path(raw: &[u8]) -> Option<String> {
-    match NmSetting8021X::glib_bytes_to_file_path(raw) {
+fn vec_u8_to_cert(raw: &[u8]) -> Option<String> {
+    match NmSetting8021X::glib_bytes_to_cert(raw) {
         Ok(s) => Some(s),
         Err(e) => {
-            log::error!(
-                "Unsupported NetworkManager 802.1x glib bytes: {:?}, error: {}",
-                raw,
-                e
-            );
+            // Do not log inline content as it might be private key
+            if NmSetting8021X::is_glib_bytes_blob(raw) {
+                log::error!(
+                    "Unsupported NetworkManager 802.1x inline content, \
+                    error: {}",
+                    e
+                );
+            } else {
+                log::error!(
+                    "Unsupported NetworkManager 802.1x glib bytes: {:?}, \
+                    error: {}",
+                    raw,
+                    e
+                );
+            }
             None
         }
     }
@@ -10,7 +10,8 @@ use super::dns::nm_dns_to_nmstate;
 
 use crate::{
     AddressFamily, Dhcpv4ClientId, Dhcpv6Duid, InterfaceIpv4, InterfaceIpv6,
-    Ipv6AddrGenMode, RouteRuleAction, RouteRuleEntry, WaitIp,
+    Ipv6AddrGenMode, RouteRuleAction, RouteRuleEntry, RouteRulePortRange,
+    RouteRuleUidRange, WaitIp,
 };
 
 const ADDR_GEN_MODE_EUI64: i32 = 0;
@@ -41,6 +42,7 @@ pub(crate) fn nm_ip_setting_to_nmstate4(
 
         let (auto_dns, auto_gateway, auto_routes, auto_table_id) =
             parse_dhcp_opts(nm_ip_setting);
+        let is_dhcp = enabled && dhcp == Some(true);
         InterfaceIpv4 {
             enabled,
             enabled_defined: true,
@@ -74,6 +76,38 @@ pub(crate) fn nm_ip_setting_to_nmstate4(
             } else {
                 None
             },
+            dhcp_vendor_class_identifier: if is_dhcp {
+                nm_ip_setting.dhcp_vendor_class_identifier.clone()
+            } else {
+                None
+            },
+            dhcp_iaid: if is_dhcp {
+                nm_ip_setting.dhcp_iaid.clone()
+            } else {
+                None
+            },
+            dhcp_fqdn: if is_dhcp && dhcp_send_hostname {
+                nm_ip_setting.dhcp_fqdn.clone()
+            } else {
+                None
+            },
+            // NetworkManager use 0 for global default
+            dhcp_timeout: if is_dhcp {
+                nm_ip_setting
+                    .dhcp_timeout
+                    .filter(|v| *v > 0)
+                    .map(|v| v as u32)
+            } else {
+                None
+            },
+            dhcp_reject_servers: if is_dhcp {
+                nm_ip_setting
+                    .dhcp_reject_servers
+                    .clone()
+                    .filter(|s| !s.is_empty())
+            } else {
+                None
+            },
             ..Default::default()
         }
     } else {
@@ -145,6 +179,29 @@ pub(crate) fn nm_ip_setting_to_nmstate6(
         if let Some(token) = nm_ip_setting.token.as_ref() {
             ret.token = Some(token.to_string());
         }
+        // NetworkManager use -1 for global default
+        if let Some(v) = nm_ip_setting.ip6_privacy.filter(|v| *v >= 0) {
+            ret.ip6_privacy = Some(v.into());
+        }
+        // NetworkManager use 0 for global default
+        if autoconf == Some(true) {
+            ret.ra_timeout = nm_ip_setting
+                .ra_timeout
+                .filter(|v| *v > 0)
+                .map(|v| v as u32);
+        }
+        if dhcp == Some(true) {
+            ret.dhcp_timeout = nm_ip_setting
+                .dhcp_timeout
+                .filter(|v| *v > 0)
+                .map(|v| v as u32);
+            ret.dhcp_iaid.clone_from(&nm_ip_setting.dhcp_iaid);
+            // NetworkManager use -1 for global default
+            ret.dhcp_send_release = nm_ip_setting
+                .dhcp_send_release
+                .filter(|v| *v >= 0)
+                .map(|v| v > 0);
+        }
         ret
     } else {
         InterfaceIpv6::default()
@@ -294,6 +351,33 @@ fn nm_rules_to_nmstate(
                 }
             }
         }
+        rule.ipproto = nm_rule.ipproto.filter(|v| *v != 0);
+        if let (Some(start), Some(end)) =
+            (nm_rule.sport_start, nm_rule.sport_end)
+        {
+            if start != 0 || end != 0 {
+                rule.sport = Some(RouteRulePortRange::new(start, end));
+            }
+        }
+        if let (Some(start), Some(end)) =
+            (nm_rule.dport_start, nm_rule.dport_end)
+        {
+            if start != 0 || end != 0 {
+                rule.dport = Some(RouteRulePortRange::new(start, end));
+            }
+        }
+        if let (Some(start), Some(end)) =
+            (nm_rule.uid_range_start, nm_rule.uid_range_end)
+        {
+            rule.uid_range = Some(RouteRuleUidRange::new(start, end));
+        }
+        if let Some(v) = nm_rule.oifname.as_ref() {
+            rule.oif = Some(v.to_string());
+        }
+        rule.tos = nm_rule.tos.filter(|v| *v != 0);
+        if nm_rule.invert == Some(true) {
+            rule.invert = Some(true);
+        }
         if let Some(v) = nm_rule.action.as_ref() {
             rule.action = Some(match v {
                 NmIpRouteRuleAction::Blackhole => RouteRuleAction::Blackhole,
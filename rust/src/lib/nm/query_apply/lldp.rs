@@ -8,9 +8,9 @@ use super::super::nm_dbus::{
 
 use crate::{
     LldpAddressFamily, LldpChassisId, LldpConfig, LldpMacPhy, LldpMaxFrameSize,
-    LldpMgmtAddr, LldpMgmtAddrs, LldpNeighborTlv, LldpPortId, LldpPpvids,
-    LldpSystemCapabilities, LldpSystemDescription, LldpSystemName, LldpVlan,
-    LldpVlans,
+    LldpMedNetworkPolicies, LldpMgmtAddr, LldpMgmtAddrs, LldpNeighborTlv,
+    LldpPortId, LldpPpvids, LldpSystemCapabilities, LldpSystemDescription,
+    LldpSystemName, LldpVlan, LldpVlans,
 };
 
 pub(crate) fn is_lldp_enabled(nm_conn: &NmConnection) -> bool {
@@ -64,6 +64,9 @@ fn nm_neighbor_to_nmstate(nm_info: &NmLldpNeighbor) -> Vec<LldpNeighborTlv> {
     if let Some(c) = get_max_frame_size(nm_info) {
         ret.push(c)
     }
+    if let Some(c) = get_med_network_policies(nm_info) {
+        ret.push(c)
+    }
 
     ret
 }
@@ -257,3 +260,13 @@ fn get_max_frame_size(nm_info: &NmLldpNeighbor) -> Option<LldpNeighborTlv> {
     }
     None
 }
+
+fn get_med_network_policies(
+    nm_info: &NmLldpNeighbor,
+) -> Option<LldpNeighborTlv> {
+    nm_info
+        .raw
+        .as_deref()
+        .and_then(LldpMedNetworkPolicies::from_lldp_frame)
+        .map(LldpNeighborTlv::MedNetworkPolicies)
+}
@@ -2,6 +2,7 @@
 
 mod apply;
 pub(crate) mod device;
+mod dhcp_lease;
 pub(crate) mod dispatch;
 pub(crate) mod dns;
 mod ieee8021x;
@@ -20,8 +21,13 @@ mod vrf;
 mod vxlan;
 
 pub(crate) use self::apply::nm_apply;
+pub(crate) use self::dhcp_lease::{
+    nm_dhcp4_opts_to_nmstate, nm_dhcp6_opts_to_nmstate,
+};
 pub(crate) use self::dns::retrieve_dns_info;
-pub(crate) use self::ieee8021x::nm_802_1x_to_nmstate;
+pub(crate) use self::ieee8021x::{
+    nm_802_1x_to_nmstate, nm_dev_to_8021x_auth_state,
+};
 pub(crate) use self::ip::{
     nm_ip_setting_to_nmstate4, nm_ip_setting_to_nmstate6, query_nmstate_wait_ip,
 };
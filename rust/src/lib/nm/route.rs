@@ -1,11 +1,37 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{MergedNetworkState, NmstateError};
+use crate::{
+    ErrorKind, MergedNetworkState, NmstateError, RouteEntry, RoutePreference,
+    RouteProtocol,
+};
 
 pub(crate) fn store_route_config(
     merged_state: &mut MergedNetworkState,
 ) -> Result<(), NmstateError> {
+    // NetworkManager does not support kernel nexthop objects
+    if merged_state.nexthops.is_changed() {
+        let e = NmstateError::new(
+            ErrorKind::NotSupportedError,
+            "NetworkManager does not support nexthop objects, please use \
+            kernel only mode"
+                .into(),
+        );
+        log::error!("{}", e);
+        return Err(e);
+    }
+    // NetworkManager does not support static neighbors
+    if merged_state.neighbors.is_changed() {
+        let e = NmstateError::new(
+            ErrorKind::NotSupportedError,
+            "NetworkManager does not support static neighbors, please use \
+            kernel only mode"
+                .into(),
+        );
+        log::error!("{}", e);
+        return Err(e);
+    }
     if merged_state.routes.is_changed() {
+        validate_nm_routes(merged_state)?;
         let empty_rts = Vec::new();
         for iface_name in merged_state.routes.route_changed_ifaces.as_slice() {
             let rts =
@@ -14,6 +40,13 @@ pub(crate) fn store_route_config(
                 } else {
                     &empty_rts
                 };
+            // Routes using nexthop objects are not managed by
+            // NetworkManager
+            let rts: Vec<RouteEntry> = rts
+                .iter()
+                .filter(|r| r.nexthop_id.is_none())
+                .cloned()
+                .collect();
             if let Some(iface) =
                 merged_state.interfaces.kernel_ifaces.get_mut(iface_name)
             {
@@ -33,10 +66,61 @@ pub(crate) fn store_route_config(
                             .ipv6
                             .clone_from(&iface.merged.base_iface_mut().ipv6);
                     }
-                    apply_iface.base_iface_mut().routes = Some(rts.clone());
+                    apply_iface.base_iface_mut().routes = Some(rts);
                 }
             }
         }
     }
     Ok(())
 }
+
+// NetworkManager always install route using static protocol and does not
+// support route preference or nexthop objects.
+fn validate_nm_routes(
+    merged_state: &MergedNetworkState,
+) -> Result<(), NmstateError> {
+    for rt in merged_state
+        .routes
+        .desired
+        .config
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .filter(|r| !r.is_absent())
+    {
+        if rt.protocol.is_some() && rt.protocol != Some(RouteProtocol::Static) {
+            let e = NmstateError::new(
+                ErrorKind::NotSupportedError,
+                format!(
+                    "NetworkManager only support static route protocol, \
+                    but got route {rt}"
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+        if rt.pref.is_some() && rt.pref != Some(RoutePreference::Medium) {
+            let e = NmstateError::new(
+                ErrorKind::NotSupportedError,
+                format!(
+                    "NetworkManager does not support route preference, \
+                    but got route {rt}"
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+        if rt.nexthop_id.is_some() {
+            let e = NmstateError::new(
+                ErrorKind::NotSupportedError,
+                format!(
+                    "NetworkManager does not support route using nexthop \
+                    object, but got route {rt}"
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+    }
+    Ok(())
+}
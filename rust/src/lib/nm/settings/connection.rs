@@ -225,7 +225,7 @@ pub(crate) fn iface_to_nm_connections(
             gen_nm_ib_setting(iface, &mut nm_conn);
         }
         Interface::MacSec(iface) => {
-            gen_nm_macsec_setting(iface, &mut nm_conn);
+            gen_nm_macsec_setting(iface, &mut nm_conn)?;
         }
         Interface::Loopback(iface) => {
             gen_nm_loopback_setting(iface, &mut nm_conn);
@@ -1,8 +1,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use super::super::nm_dbus::NmSettingIp;
+use super::super::nm_dbus::{NmConnection, NmSettingIp};
 
-use crate::DnsClientState;
+use crate::{DnsClientState, DnsOverTls, Interface};
+
+const NM_DNS_OVER_TLS_DEFAULT: i32 = -1;
+const NM_DNS_OVER_TLS_NO: i32 = 0;
+const NM_DNS_OVER_TLS_OPPORTUNISTIC: i32 = 1;
+const NM_DNS_OVER_TLS_YES: i32 = 2;
 
 pub(crate) fn apply_nm_dns_setting(
     nm_ip_setting: &mut NmSettingIp,
@@ -13,3 +18,108 @@ pub(crate) fn apply_nm_dns_setting(
     nm_ip_setting.dns_priority = dns_conf.priority;
     nm_ip_setting.dns_options.clone_from(&dns_conf.options);
 }
+
+// The global DNS config stored in `InterfaceIpv4.dns` and `InterfaceIpv6.dns`
+// has already been applied, the interface level DNS config should override it.
+pub(crate) fn apply_nm_iface_dns_setting(
+    iface: &Interface,
+    nm_conn: &mut NmConnection,
+) {
+    let base_iface = iface.base_iface();
+    let dns_conf = match base_iface.dns.as_ref() {
+        Some(d) => d,
+        None => return,
+    };
+    if dns_conf.is_empty() {
+        // Purge interface level DNS unless global DNS config is stored to
+        // this interface.
+        if base_iface
+            .ipv4
+            .as_ref()
+            .and_then(|i| i.dns.as_ref())
+            .is_none()
+        {
+            if let Some(nm_ip_setting) = nm_conn.ipv4.as_mut() {
+                purge_nm_dns_setting(nm_ip_setting);
+            }
+        }
+        if base_iface
+            .ipv6
+            .as_ref()
+            .and_then(|i| i.dns.as_ref())
+            .is_none()
+        {
+            if let Some(nm_ip_setting) = nm_conn.ipv6.as_mut() {
+                purge_nm_dns_setting(nm_ip_setting);
+            }
+        }
+        return;
+    }
+    if let Some(nm_ip_setting) = nm_conn.ipv4.as_mut() {
+        if base_iface.is_ipv4_enabled() {
+            nm_ip_setting.dns = Some(dns_conf.ipv4_servers());
+            nm_ip_setting.dns_search.clone_from(&dns_conf.search);
+            nm_ip_setting.dns_priority = dns_conf.priority;
+        } else {
+            purge_nm_dns_setting(nm_ip_setting);
+        }
+    }
+    if let Some(nm_ip_setting) = nm_conn.ipv6.as_mut() {
+        if base_iface.is_ipv6_enabled() {
+            nm_ip_setting.dns = Some(dns_conf.ipv6_servers());
+            nm_ip_setting.dns_search.clone_from(&dns_conf.search);
+            nm_ip_setting.dns_priority = dns_conf.priority;
+        } else {
+            purge_nm_dns_setting(nm_ip_setting);
+        }
+    }
+}
+
+fn purge_nm_dns_setting(nm_ip_setting: &mut NmSettingIp) {
+    nm_ip_setting.dns = None;
+    nm_ip_setting.dns_search = None;
+    nm_ip_setting.dns_priority = None;
+}
+
+// The DNS-over-TLS mode is a connection wide setting in NetworkManager, only
+// set it when global DNS config is stored to this interface.
+pub(crate) fn apply_nm_dns_over_tls_setting(
+    iface: &Interface,
+    nm_conn: &mut NmConnection,
+) {
+    let base_iface = iface.base_iface();
+    let dns_confs: Vec<&DnsClientState> = [
+        base_iface.ipv4.as_ref().and_then(|i| i.dns.as_ref()),
+        base_iface.ipv6.as_ref().and_then(|i| i.dns.as_ref()),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    if dns_confs.is_empty() {
+        return;
+    }
+    let dns_over_tls = dns_confs.iter().find_map(|d| d.dns_over_tls);
+    let nm_conn_set = nm_conn.connection.get_or_insert_with(Default::default);
+    nm_conn_set.dns_over_tls = Some(nmstate_dns_over_tls_to_nm(dns_over_tls));
+}
+
+fn nmstate_dns_over_tls_to_nm(dns_over_tls: Option<DnsOverTls>) -> i32 {
+    match dns_over_tls {
+        Some(DnsOverTls::No) => NM_DNS_OVER_TLS_NO,
+        Some(DnsOverTls::Opportunistic) => NM_DNS_OVER_TLS_OPPORTUNISTIC,
+        Some(DnsOverTls::Yes) => NM_DNS_OVER_TLS_YES,
+        None => NM_DNS_OVER_TLS_DEFAULT,
+    }
+}
+
+#[cfg(feature = "query_apply")]
+pub(crate) fn nm_dns_over_tls_to_nmstate(
+    nm_dns_over_tls: Option<i32>,
+) -> Option<DnsOverTls> {
+    match nm_dns_over_tls {
+        Some(NM_DNS_OVER_TLS_NO) => Some(DnsOverTls::No),
+        Some(NM_DNS_OVER_TLS_OPPORTUNISTIC) => Some(DnsOverTls::Opportunistic),
+        Some(NM_DNS_OVER_TLS_YES) => Some(DnsOverTls::Yes),
+        _ => None,
+    }
+}
@@ -4,10 +4,18 @@ use std::collections::HashMap;
 
 use crate::nm::nm_dbus::{NmConnection, NmSettingEthtool};
 use crate::{
-    ErrorKind, EthtoolCoalesceConfig, EthtoolFeatureConfig, EthtoolPauseConfig,
-    EthtoolRingConfig, Interface, NmstateError,
+    ErrorKind, EthtoolChannelsConfig, EthtoolCoalesceConfig,
+    EthtoolFeatureConfig, EthtoolFecConfig, EthtoolFecMode, EthtoolPauseConfig,
+    EthtoolRingConfig, Interface, MergedInterface, NmstateError,
 };
 
+// NM is using the same values as kernel `ETHTOOL_FEC_*` flags
+const NM_ETHTOOL_FEC_MODE_AUTO: u32 = 1 << 1;
+const NM_ETHTOOL_FEC_MODE_OFF: u32 = 1 << 2;
+const NM_ETHTOOL_FEC_MODE_RS: u32 = 1 << 3;
+const NM_ETHTOOL_FEC_MODE_BASER: u32 = 1 << 4;
+const NM_ETHTOOL_FEC_MODE_LLRS: u32 = 1 << 5;
+
 const KERNEL_ETHTOOL_FEATURE_2_NM: [(&str, &str); 10] = [
     ("rx-checksum", "feature-rx"),
     ("tx-scatter-gather", "feature-sg"),
@@ -21,6 +29,74 @@ const KERNEL_ETHTOOL_FEATURE_2_NM: [(&str, &str); 10] = [
     ("tx-vlan-hw-insert", "feature-txvlan"),
 ];
 
+// NetworkManager cannot persist ethtool RSS, advertised link modes, EEE
+// low power idle settings and private flags. They are applied to kernel
+// directly after NetworkManager activation, hence only raise error in
+// gen_conf mode.
+pub(crate) fn validate_nm_ethtool(
+    merged_iface: &MergedInterface,
+    gen_conf_mode: bool,
+) -> Result<(), NmstateError> {
+    let des_conf = match merged_iface
+        .for_apply
+        .as_ref()
+        .and_then(|i| i.base_iface().ethtool.as_ref())
+    {
+        Some(c) => c,
+        None => return Ok(()),
+    };
+
+    if gen_conf_mode && des_conf.rss.is_some() {
+        return Err(nm_ethtool_not_supported(
+            merged_iface.merged.name(),
+            "rss",
+        ));
+    }
+    if gen_conf_mode
+        && des_conf
+            .link_modes
+            .as_ref()
+            .and_then(|l| l.advertised.as_ref())
+            .is_some()
+    {
+        return Err(nm_ethtool_not_supported(
+            merged_iface.merged.name(),
+            "link-modes advertised",
+        ));
+    }
+    if gen_conf_mode
+        && des_conf
+            .eee
+            .as_ref()
+            .map(|e| e.tx_lpi_enabled.is_some() || e.tx_lpi_timer.is_some())
+            .unwrap_or_default()
+    {
+        return Err(nm_ethtool_not_supported(
+            merged_iface.merged.name(),
+            "eee low power idle",
+        ));
+    }
+    if gen_conf_mode && des_conf.private_flags.is_some() {
+        return Err(nm_ethtool_not_supported(
+            merged_iface.merged.name(),
+            "private-flags",
+        ));
+    }
+    Ok(())
+}
+
+fn nm_ethtool_not_supported(iface_name: &str, prop: &str) -> NmstateError {
+    let e = NmstateError::new(
+        ErrorKind::NotSupportedError,
+        format!(
+            "NetworkManager cannot persist ethtool {prop} of interface \
+            {iface_name}, please use kernel mode instead"
+        ),
+    );
+    log::error!("{}", e);
+    e
+}
+
 pub(crate) fn gen_ethtool_setting(
     iface: &Interface,
     nm_conn: &mut NmConnection,
@@ -41,6 +117,15 @@ pub(crate) fn gen_ethtool_setting(
         if let Some(ring_conf) = ethtool_iface.ring.as_ref() {
             apply_ring_options(&mut nm_ethtool_set, ring_conf);
         }
+        if let Some(channels_conf) = ethtool_iface.channels.as_ref() {
+            apply_channels_options(&mut nm_ethtool_set, channels_conf);
+        }
+        if let Some(fec_conf) = ethtool_iface.fec.as_ref() {
+            apply_fec_options(&mut nm_ethtool_set, fec_conf);
+        }
+        if let Some(eee_conf) = ethtool_iface.eee.as_ref() {
+            nm_ethtool_set.eee_enabled = eee_conf.enabled;
+        }
         nm_conn.ethtool = Some(nm_ethtool_set);
     }
     Ok(())
@@ -116,3 +201,26 @@ fn apply_ring_options(
     nm_ethtool_set.ring_rx_mini = ring_conf.rx_mini;
     nm_ethtool_set.ring_tx = ring_conf.tx;
 }
+
+fn apply_channels_options(
+    nm_ethtool_set: &mut NmSettingEthtool,
+    channels_conf: &EthtoolChannelsConfig,
+) {
+    nm_ethtool_set.channels_rx = channels_conf.rx;
+    nm_ethtool_set.channels_tx = channels_conf.tx;
+    nm_ethtool_set.channels_other = channels_conf.other;
+    nm_ethtool_set.channels_combined = channels_conf.combined;
+}
+
+fn apply_fec_options(
+    nm_ethtool_set: &mut NmSettingEthtool,
+    fec_conf: &EthtoolFecConfig,
+) {
+    nm_ethtool_set.fec_mode = fec_conf.mode.map(|m| match m {
+        EthtoolFecMode::Auto => NM_ETHTOOL_FEC_MODE_AUTO,
+        EthtoolFecMode::Off => NM_ETHTOOL_FEC_MODE_OFF,
+        EthtoolFecMode::Rs => NM_ETHTOOL_FEC_MODE_RS,
+        EthtoolFecMode::Baser => NM_ETHTOOL_FEC_MODE_BASER,
+        EthtoolFecMode::Llrs => NM_ETHTOOL_FEC_MODE_LLRS,
+    });
+}
@@ -17,6 +17,11 @@ pub(crate) fn gen_nm_hsr_setting(
             HsrProtocol::Prp => Some(true),
             HsrProtocol::Hsr => Some(false),
         };
+        match hsr_conf.interlink.as_deref() {
+            Some("") => nm_hsr_set.interlink = None,
+            Some(i) => nm_hsr_set.interlink = Some(i.to_string()),
+            None => (),
+        }
     }
     nm_conn.hsr = Some(nm_hsr_set);
 }
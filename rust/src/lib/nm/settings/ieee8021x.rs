@@ -9,50 +9,79 @@ pub(crate) fn gen_nm_802_1x_setting(
     nm_conn: &mut NmConnection,
 ) {
     if let Some(conf) = iface.base_iface().ieee8021x.as_ref() {
+        let cur_setting = nm_conn.ieee8021x.as_ref();
         let mut nm_setting = NmSetting8021X::default();
         nm_setting.identity.clone_from(&conf.identity);
         nm_setting.eap.clone_from(&conf.eap);
-        nm_setting.private_key = conf
-            .private_key
-            .as_deref()
-            .map(NmSetting8021X::file_path_to_glib_bytes);
+        nm_setting.private_key = gen_private_key(
+            conf.private_key.as_deref(),
+            cur_setting.and_then(|c| c.private_key.as_ref()),
+        );
         nm_setting.client_cert = conf
             .client_cert
             .as_deref()
-            .map(NmSetting8021X::file_path_to_glib_bytes);
+            .map(NmSetting8021X::cert_to_glib_bytes);
         nm_setting.ca_cert = conf
             .ca_cert
             .as_deref()
-            .map(NmSetting8021X::file_path_to_glib_bytes);
-        if conf.private_key_password.as_deref()
-            == Some(NetworkState::PASSWORD_HID_BY_NMSTATE)
-        {
-            if let Some(cur_pass) = nm_conn
-                .ieee8021x
-                .as_ref()
-                .and_then(|c| c.private_key_password.as_deref())
-            {
-                nm_setting.private_key_password = Some(cur_pass.to_string());
-            }
-        } else {
-            nm_setting
-                .private_key_password
-                .clone_from(&conf.private_key_password);
-        }
+            .map(NmSetting8021X::cert_to_glib_bytes);
+        nm_setting.private_key_password = gen_secret(
+            conf.private_key_password.as_deref(),
+            cur_setting.and_then(|c| c.private_key_password.as_deref()),
+        );
         nm_setting.phase2_auth.clone_from(&conf.phase2_auth);
-        if conf.password.as_deref()
-            == Some(NetworkState::PASSWORD_HID_BY_NMSTATE)
-        {
-            if let Some(cur_pass) = nm_conn
-                .ieee8021x
-                .as_ref()
-                .and_then(|c| c.password.as_deref())
-            {
-                nm_setting.password = Some(cur_pass.to_string());
-            }
-        } else {
-            nm_setting.password.clone_from(&conf.password);
-        }
+        nm_setting.password = gen_secret(
+            conf.password.as_deref(),
+            cur_setting.and_then(|c| c.password.as_deref()),
+        );
+        nm_setting
+            .anonymous_identity
+            .clone_from(&conf.anonymous_identity);
+        nm_setting
+            .domain_suffix_match
+            .clone_from(&conf.domain_suffix_match);
+        nm_setting
+            .altsubject_matches
+            .clone_from(&conf.altsubject_matches);
+        nm_setting.phase1_auth_flags = conf.phase1_auth_flags;
+        nm_setting.phase2_ca_cert = conf
+            .phase2_ca_cert
+            .as_deref()
+            .map(NmSetting8021X::cert_to_glib_bytes);
+        nm_setting.phase2_client_cert = conf
+            .phase2_client_cert
+            .as_deref()
+            .map(NmSetting8021X::cert_to_glib_bytes);
+        nm_setting.phase2_private_key = gen_private_key(
+            conf.phase2_private_key.as_deref(),
+            cur_setting.and_then(|c| c.phase2_private_key.as_ref()),
+        );
+        nm_setting.phase2_private_key_password = gen_secret(
+            conf.phase2_private_key_password.as_deref(),
+            cur_setting.and_then(|c| c.phase2_private_key_password.as_deref()),
+        );
         nm_conn.ieee8021x = Some(nm_setting);
     }
 }
+
+// Use current secret if desired one is hidden by nmstate
+fn gen_secret(desired: Option<&str>, current: Option<&str>) -> Option<String> {
+    if desired == Some(NetworkState::PASSWORD_HID_BY_NMSTATE) {
+        current.map(|s| s.to_string())
+    } else {
+        desired.map(|s| s.to_string())
+    }
+}
+
+// The inline private key is hidden by nmstate when querying, use current
+// one in that case.
+fn gen_private_key(
+    desired: Option<&str>,
+    current: Option<&Vec<u8>>,
+) -> Option<Vec<u8>> {
+    if desired == Some(NetworkState::PASSWORD_HID_BY_NMSTATE) {
+        current.cloned()
+    } else {
+        desired.map(NmSetting8021X::cert_to_glib_bytes)
+    }
+}
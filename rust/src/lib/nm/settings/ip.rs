@@ -3,19 +3,64 @@
 use std::ops::BitXor;
 
 use super::{
-    dns::apply_nm_dns_setting, route::gen_nm_ip_routes,
+    dns::{
+        apply_nm_dns_over_tls_setting, apply_nm_dns_setting,
+        apply_nm_iface_dns_setting,
+    },
+    route::gen_nm_ip_routes,
     route_rule::gen_nm_ip_rules,
 };
 use crate::nm::nm_dbus::{NmConnection, NmSettingIp, NmSettingIpMethod};
 use crate::{
     BaseInterface, Dhcpv4ClientId, Dhcpv6Duid, ErrorKind, Interface,
-    InterfaceIpAddr, InterfaceIpv4, InterfaceIpv6, Ipv6AddrGenMode,
-    NmstateError, RouteEntry, WaitIp,
+    InterfaceIpAddr, InterfaceIpAddrFlag, InterfaceIpv4, InterfaceIpv6,
+    Ipv6AddrGenMode, MergedInterfaces, NmstateError, RouteEntry, WaitIp,
 };
 
 const ADDR_GEN_MODE_EUI64: i32 = 0;
 const ADDR_GEN_MODE_STABLE_PRIVACY: i32 = 1;
 
+const NM_IP_FORWARDING_NO: i32 = 0;
+const NM_IP_FORWARDING_YES: i32 = 1;
+
+// NetworkManager always adds IP address with `noprefixroute` flag and manage
+// the prefix route by itself. Peer address and other flags are not supported.
+fn validate_nm_ip_addrs(addrs: &[InterfaceIpAddr]) -> Result<(), NmstateError> {
+    for addr in addrs {
+        if let Some(peer) = addr.peer.as_ref() {
+            let e = NmstateError::new(
+                ErrorKind::NotSupportedError,
+                format!(
+                    "NetworkManager backend does not support peer address \
+                    {peer} of IP address {}",
+                    addr.ip
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+        if let Some(flag) = addr
+            .flags
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .find(|f| **f != InterfaceIpAddrFlag::Noprefixroute)
+        {
+            let e = NmstateError::new(
+                ErrorKind::NotSupportedError,
+                format!(
+                    "NetworkManager backend does not support IP address \
+                    flag {flag} of IP address {}",
+                    addr.ip
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+    }
+    Ok(())
+}
+
 fn gen_nm_ipv4_setting(
     iface_ip: Option<&InterfaceIpv4>,
     routes: Option<&[RouteEntry]>,
@@ -59,13 +104,15 @@ fn gen_nm_ipv4_setting(
     } else {
         NmSettingIpMethod::Disabled
     };
+    validate_nm_ip_addrs(nmstate_ip_addrs.as_slice())?;
     for ip_addr in nmstate_ip_addrs {
         addresses.push(format!("{}/{}", ip_addr.ip, ip_addr.prefix_length));
     }
     nm_setting.method = Some(method);
     nm_setting.addresses = addresses;
     if iface_ip.is_auto() {
-        nm_setting.dhcp_timeout = Some(i32::MAX);
+        nm_setting.dhcp_timeout =
+            Some(nmstate_timeout_to_nm(iface_ip.dhcp_timeout));
         nm_setting.route_metric = iface_ip.auto_route_metric.map(|i| i.into());
         nm_setting.dhcp_client_id = Some(nmstate_dhcp_client_id_to_nm(
             iface_ip
@@ -104,6 +151,52 @@ fn gen_nm_ipv4_setting(
                     }
                 }
             }
+            if let Some(v) = iface_ip.dhcp_fqdn.as_deref() {
+                if v.is_empty() {
+                    nm_setting.dhcp_fqdn = None;
+                } else {
+                    nm_setting.dhcp_fqdn = Some(v.to_string());
+                    nm_setting.dhcp_hostname = None;
+                }
+            }
+        }
+        if let Some(v) = iface_ip.dhcp_vendor_class_identifier.as_deref() {
+            if v.is_empty() {
+                nm_setting.dhcp_vendor_class_identifier = None;
+            } else {
+                nm_setting.dhcp_vendor_class_identifier = Some(v.to_string());
+            }
+        }
+        if let Some(v) = iface_ip.dhcp_iaid.as_deref() {
+            nm_setting.dhcp_iaid = Some(v.to_string());
+        }
+        if let Some(v) = iface_ip.dhcp_reject_servers.as_ref() {
+            nm_setting.dhcp_reject_servers = Some(v.clone());
+        }
+        for (prop_name, is_defined) in [
+            (
+                "dhcp-user-class",
+                iface_ip.dhcp_user_class.as_ref().map(|v| !v.is_empty()),
+            ),
+            (
+                "dhcp-request-options",
+                iface_ip
+                    .dhcp_request_options
+                    .as_ref()
+                    .map(|v| !v.is_empty()),
+            ),
+        ] {
+            if is_defined == Some(true) {
+                let e = NmstateError::new(
+                    ErrorKind::NotSupportedError,
+                    format!(
+                        "NetworkManager backend does not support \
+                        {prop_name} of DHCPv4 yet"
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
         }
     }
     if iface_ip.enabled {
@@ -122,6 +215,14 @@ fn gen_nm_ipv4_setting(
     if let Some(dns) = &iface_ip.dns {
         apply_nm_dns_setting(&mut nm_setting, dns);
     }
+    // Other IPv4 sysctl are only supported in kernel mode
+    if let Some(v) = iface_ip.sysctl.as_ref().and_then(|s| s.forwarding) {
+        nm_setting.forwarding = Some(if v {
+            NM_IP_FORWARDING_YES
+        } else {
+            NM_IP_FORWARDING_NO
+        });
+    }
     nm_conn.ipv4 = Some(nm_setting);
     Ok(())
 }
@@ -181,6 +282,7 @@ fn gen_nm_ipv6_setting(
     } else {
         NmSettingIpMethod::Disabled
     };
+    validate_nm_ip_addrs(nmstate_ip_addrs.as_slice())?;
     for ip_addr in nmstate_ip_addrs {
         addresses.push(format!("{}/{}", ip_addr.ip, ip_addr.prefix_length));
     }
@@ -189,8 +291,10 @@ fn gen_nm_ipv6_setting(
     nm_setting.addr_gen_mode =
         Some(nmstate_addr_gen_mode_to_nm(iface_ip.addr_gen_mode.as_ref()));
     if iface_ip.is_auto() {
-        nm_setting.dhcp_timeout = Some(i32::MAX);
-        nm_setting.ra_timeout = Some(i32::MAX);
+        nm_setting.dhcp_timeout =
+            Some(nmstate_timeout_to_nm(iface_ip.dhcp_timeout));
+        nm_setting.ra_timeout =
+            Some(nmstate_timeout_to_nm(iface_ip.ra_timeout));
         nm_setting.dhcp_duid = Some(
             iface_ip
                 .dhcp_duid
@@ -198,7 +302,11 @@ fn gen_nm_ipv6_setting(
                 .unwrap_or(&Dhcpv6Duid::LinkLayerAddress)
                 .to_string(),
         );
-        nm_setting.dhcp_iaid = Some("mac".to_string());
+        nm_setting.dhcp_iaid =
+            Some(iface_ip.dhcp_iaid.as_deref().unwrap_or("mac").to_string());
+        if let Some(v) = iface_ip.dhcp_send_release {
+            nm_setting.dhcp_send_release = Some(v.into());
+        }
         if let Some(token) = iface_ip.token.as_ref() {
             if token.is_empty() || token == "::" {
                 nm_setting.token = None;
@@ -248,6 +356,14 @@ fn gen_nm_ipv6_setting(
     if let Some(dns) = &iface_ip.dns {
         apply_nm_dns_setting(&mut nm_setting, dns);
     }
+    // The `dad-transmits` and `mtu-from-ra` are applied to kernel directly,
+    // other IPv6 sysctl are only supported in kernel mode
+    if let Some(v) = iface_ip
+        .ip6_privacy
+        .or_else(|| iface_ip.sysctl.as_ref().and_then(|s| s.use_tempaddr))
+    {
+        nm_setting.ip6_privacy = Some(v.into());
+    }
     nm_conn.ipv6 = Some(nm_setting);
     Ok(())
 }
@@ -262,6 +378,8 @@ pub(crate) fn gen_nm_ip_setting(
         gen_nm_ipv4_setting(base_iface.ipv4.as_ref(), routes, nm_conn)?;
         gen_nm_ipv6_setting(base_iface.ipv6.as_ref(), routes, nm_conn)?;
         apply_nmstate_wait_ip(base_iface, nm_conn);
+        apply_nm_dns_over_tls_setting(iface, nm_conn);
+        apply_nm_iface_dns_setting(iface, nm_conn);
     } else {
         nm_conn.ipv4 = None;
         nm_conn.ipv6 = None;
@@ -354,15 +472,37 @@ fn apply_nmstate_wait_ip(
 }
 
 // Even user not desired IP section changes, we should set ipv4.dhcp_timeout
-// and ipv6.dhcp_timeout to i32::MAX to make sure NetworkManager never
-// deactivate a desired interface
-pub(crate) fn fix_ip_dhcp_timeout(nm_conns: &mut [NmConnection]) {
+// and ipv6.dhcp_timeout to make sure NetworkManager never deactivate a desired
+// interface unless user desired otherwise: use the merged `dhcp-timeout` so
+// the existing one is preserved, or i32::MAX(infinity) if not defined.
+pub(crate) fn fix_ip_dhcp_timeout(
+    nm_conns: &mut [NmConnection],
+    merged_ifaces: &MergedInterfaces,
+) {
     for nm_conn in nm_conns {
+        let merged_iface = nm_conn
+            .iface_name()
+            .and_then(|n| merged_ifaces.kernel_ifaces.get(n));
+        let desired_ipv4_timeout = merged_iface
+            .and_then(|i| i.merged.base_iface().ipv4.as_ref())
+            .and_then(|i| i.dhcp_timeout);
+        let desired_ipv6_timeout = merged_iface
+            .and_then(|i| i.merged.base_iface().ipv6.as_ref())
+            .and_then(|i| i.dhcp_timeout);
         if let Some(nm_ip_set) = nm_conn.ipv4.as_mut() {
-            nm_ip_set.dhcp_timeout = Some(i32::MAX);
+            nm_ip_set.dhcp_timeout =
+                Some(nmstate_timeout_to_nm(desired_ipv4_timeout));
         }
         if let Some(nm_ip_set) = nm_conn.ipv6.as_mut() {
-            nm_ip_set.dhcp_timeout = Some(i32::MAX);
+            nm_ip_set.dhcp_timeout =
+                Some(nmstate_timeout_to_nm(desired_ipv6_timeout));
         }
     }
 }
+
+// Use i32::MAX(infinity in NetworkManager) if not defined
+fn nmstate_timeout_to_nm(timeout: Option<u32>) -> i32 {
+    timeout
+        .and_then(|t| i32::try_from(t).ok())
+        .unwrap_or(i32::MAX)
+}
@@ -2,15 +2,27 @@
 
 use crate::nm::nm_dbus::{NmConnection, NmSettingMacSec};
 
-use crate::{MacSecInterface, MacSecOffload};
+use crate::{ErrorKind, MacSecInterface, MacSecOffload, NmstateError};
 
 pub(crate) fn gen_nm_macsec_setting(
     iface: &MacSecInterface,
     nm_conn: &mut NmConnection,
-) {
+) -> Result<(), NmstateError> {
     let mut nm_macsec_set =
         nm_conn.macsec.as_ref().cloned().unwrap_or_default();
     if let Some(macsec_conf) = iface.macsec.as_ref() {
+        if macsec_conf.static_sak.is_some() {
+            let e = NmstateError::new(
+                ErrorKind::NotSupportedError,
+                format!(
+                    "NetworkManager does not support MACsec static SAK, \
+                    please use kernel mode for MACsec interface {}",
+                    iface.base.name
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
         nm_macsec_set.parent = Some(macsec_conf.base_iface.clone());
         nm_macsec_set.encrypt = Some(macsec_conf.encrypt);
         nm_macsec_set.mka_cak.clone_from(&macsec_conf.mka_cak);
@@ -26,5 +38,6 @@ pub(crate) fn gen_nm_macsec_setting(
             });
         }
     }
-    nm_conn.macsec = Some(nm_macsec_set)
+    nm_conn.macsec = Some(nm_macsec_set);
+    Ok(())
 }
@@ -18,6 +18,7 @@ mod ovs;
 mod route;
 mod route_rule;
 mod sriov;
+mod sysctl;
 mod user;
 mod veth;
 mod vlan;
@@ -29,9 +30,15 @@ mod wired;
 #[cfg(feature = "query_apply")]
 pub(crate) use self::connection::iface_type_to_nm;
 pub(crate) use self::connection::{get_exist_profile, iface_to_nm_connections};
+pub(crate) use self::ethtool::validate_nm_ethtool;
 pub(crate) use self::ip::fix_ip_dhcp_timeout;
+pub(crate) use self::sysctl::{
+    validate_nm_ip_forwarding, validate_nm_ip_sysctl,
+};
 
 #[cfg(feature = "query_apply")]
 pub(crate) use self::bond::get_bond_balance_slb;
 #[cfg(feature = "query_apply")]
-pub(crate) use self::user::NMSTATE_DESCRIPTION;
+pub(crate) use self::dns::nm_dns_over_tls_to_nmstate;
+#[cfg(feature = "query_apply")]
+pub(crate) use self::user::{NMSTATE_DESCRIPTION, NMSTATE_IFACE_DNS};
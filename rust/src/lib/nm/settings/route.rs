@@ -3,9 +3,16 @@
 use super::super::nm_dbus::NmIpRoute;
 
 use crate::{
-    ip::is_ipv6_addr, InterfaceIpAddr, NmstateError, RouteEntry, RouteType,
+    ip::is_ipv6_addr, InterfaceIpAddr, NmstateError, RouteEntry,
+    RouteMetricLock, RouteScope, RouteType,
 };
 
+// kernel values
+const RT_SCOPE_UNIVERSE: u8 = 0;
+const RT_SCOPE_SITE: u8 = 200;
+const RT_SCOPE_LINK: u8 = 253;
+const RT_SCOPE_HOST: u8 = 254;
+
 pub(crate) fn gen_nm_ip_routes(
     routes: &[RouteEntry],
     is_ipv6: bool,
@@ -44,6 +51,34 @@ pub(crate) fn gen_nm_ip_routes(
         };
         nm_route.cwnd = route.cwnd;
         nm_route.lock_cwnd = route.cwnd.map(|_| true);
+        nm_route.mtu = route.mtu;
+        nm_route.advmss = route.advmss;
+        nm_route.initcwnd = route.initcwnd;
+        nm_route.initrwnd = route.initrwnd;
+        nm_route.rto_min = route.rto_min;
+        nm_route.quickack = route.quickack;
+        nm_route.onlink = route.onlink;
+        for lock in route.lock.as_deref().unwrap_or_default() {
+            match lock {
+                RouteMetricLock::Mtu => nm_route.lock_mtu = Some(true),
+                RouteMetricLock::Advmss => nm_route.lock_advmss = Some(true),
+                RouteMetricLock::Initcwnd => {
+                    nm_route.lock_initcwnd = Some(true)
+                }
+                RouteMetricLock::Initrwnd => {
+                    nm_route.lock_initrwnd = Some(true)
+                }
+            }
+        }
+        // NetworkManager only support route scope for IPv4
+        if !is_ipv6 {
+            nm_route.scope = route.scope.map(|s| match s {
+                RouteScope::Global => RT_SCOPE_UNIVERSE,
+                RouteScope::Site => RT_SCOPE_SITE,
+                RouteScope::Link => RT_SCOPE_LINK,
+                RouteScope::Host => RT_SCOPE_HOST,
+            });
+        }
         ret.push(nm_route);
     }
     Ok(ret)
@@ -82,6 +82,40 @@ pub(crate) fn gen_nm_ip_rules(
                     )
                 })?);
         }
+        if rule.dscp.is_some()
+            || rule.l3mdev == Some(true)
+            || rule.goto.is_some()
+        {
+            let e = NmstateError::new(
+                ErrorKind::NotSupportedError,
+                format!(
+                    "NetworkManager does not support dscp, l3mdev or goto \
+                    in route rule {rule}, please use kernel only mode"
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+        nm_rule.ipproto = rule.ipproto;
+        if let Some(v) = rule.sport.as_ref() {
+            nm_rule.sport_start = Some(v.start);
+            nm_rule.sport_end = Some(v.end);
+        }
+        if let Some(v) = rule.dport.as_ref() {
+            nm_rule.dport_start = Some(v.start);
+            nm_rule.dport_end = Some(v.end);
+        }
+        if let Some(v) = rule.uid_range.as_ref() {
+            nm_rule.uid_range_start = Some(v.start);
+            nm_rule.uid_range_end = Some(v.end);
+        }
+        if let Some(oif) = rule.oif.as_ref() {
+            nm_rule.oifname = Some(oif.to_string());
+        }
+        nm_rule.tos = rule.tos;
+        if rule.invert == Some(true) {
+            nm_rule.invert = Some(true);
+        }
 
         ret.push(nm_rule);
     }
@@ -1,9 +1,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::nm::nm_dbus::{
-    NmConnection, NmSettingSriovVf, NmSettingSriovVfVlan,
+    NmConnection, NmSettingSriov, NmSettingSriovVf, NmSettingSriovVfVlan,
+};
+use crate::{
+    EthernetInterface, SrIovEswitchConfig, SrIovEswitchEncapMode,
+    SrIovEswitchInlineMode, SrIovEswitchMode, SrIovVfConfig,
 };
-use crate::{EthernetInterface, SrIovVfConfig};
 
 pub(crate) fn gen_nm_sriov_setting(
     iface: &EthernetInterface,
@@ -40,6 +43,10 @@ pub(crate) fn gen_nm_sriov_setting(
         ));
     }
 
+    if let Some(eswitch_conf) = sriov_conf.eswitch.as_ref() {
+        apply_eswitch_options(&mut nm_sriov_set, eswitch_conf);
+    }
+
     nm_conn.sriov = Some(nm_sriov_set);
 }
 
@@ -82,3 +89,30 @@ fn gen_nm_vfs(
     }
     ret
 }
+
+// NM is using the same values as kernel devlink
+fn apply_eswitch_options(
+    nm_sriov_set: &mut NmSettingSriov,
+    eswitch_conf: &SrIovEswitchConfig,
+) {
+    if let Some(mode) = eswitch_conf.mode {
+        nm_sriov_set.eswitch_mode = Some(match mode {
+            SrIovEswitchMode::Legacy => 0,
+            SrIovEswitchMode::Switchdev => 1,
+        });
+    }
+    if let Some(inline_mode) = eswitch_conf.inline_mode {
+        nm_sriov_set.eswitch_inline_mode = Some(match inline_mode {
+            SrIovEswitchInlineMode::None => 0,
+            SrIovEswitchInlineMode::Link => 1,
+            SrIovEswitchInlineMode::Network => 2,
+            SrIovEswitchInlineMode::Transport => 3,
+        });
+    }
+    if let Some(encap_mode) = eswitch_conf.encap_mode {
+        nm_sriov_set.eswitch_encap_mode = Some(match encap_mode {
+            SrIovEswitchEncapMode::None => 0,
+            SrIovEswitchEncapMode::Basic => 1,
+        });
+    }
+}
@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{ErrorKind, MergedInterface, MergedIpForwarding, NmstateError};
+
+// NetworkManager can only persist IPv4 `forwarding` and IPv6 `use-tempaddr`
+// (as `ipv6.ip6-privacy`), other IP sysctl and global IP forwarding would be
+// lost on reboot or reactivation and could not be rolled back by checkpoint.
+// To allow applying the state queried, only raise error when desired value is
+// different from current. In gen_conf mode, there is no current value.
+pub(crate) fn validate_nm_ip_sysctl(
+    merged_iface: &MergedInterface,
+) -> Result<(), NmstateError> {
+    let apply_iface = match merged_iface.for_apply.as_ref() {
+        Some(i) => i,
+        None => return Ok(()),
+    };
+    let iface_name = apply_iface.name();
+    let cur_iface = merged_iface.current.as_ref().map(|i| i.base_iface());
+
+    if let Some(des) = apply_iface
+        .base_iface()
+        .ipv4
+        .as_ref()
+        .and_then(|i| i.sysctl.as_ref())
+    {
+        let cur_ip = cur_iface.and_then(|i| i.ipv4.as_ref());
+        let cur = cur_ip.and_then(|i| i.sysctl.as_ref());
+        let default = cur_ip.and_then(|i| i.sysctl_default.as_ref());
+        for (prop, changed) in [
+            (
+                "rp-filter",
+                is_changed(
+                    des.rp_filter,
+                    cur.and_then(|c| c.rp_filter),
+                    default.and_then(|d| d.rp_filter),
+                ),
+            ),
+            (
+                "accept-local",
+                is_changed(
+                    des.accept_local,
+                    cur.and_then(|c| c.accept_local),
+                    default.and_then(|d| d.accept_local),
+                ),
+            ),
+            (
+                "proxy-arp",
+                is_changed(
+                    des.proxy_arp,
+                    cur.and_then(|c| c.proxy_arp),
+                    default.and_then(|d| d.proxy_arp),
+                ),
+            ),
+            (
+                "arp-ignore",
+                is_changed(
+                    des.arp_ignore,
+                    cur.and_then(|c| c.arp_ignore),
+                    default.and_then(|d| d.arp_ignore),
+                ),
+            ),
+            (
+                "arp-announce",
+                is_changed(
+                    des.arp_announce,
+                    cur.and_then(|c| c.arp_announce),
+                    default.and_then(|d| d.arp_announce),
+                ),
+            ),
+        ] {
+            if changed {
+                return Err(nm_sysctl_not_supported(&format!(
+                    "IPv4 sysctl {prop} of interface {iface_name}"
+                )));
+            }
+        }
+    }
+
+    if let Some(des) = apply_iface
+        .base_iface()
+        .ipv6
+        .as_ref()
+        .and_then(|i| i.sysctl.as_ref())
+    {
+        let cur_ip = cur_iface.and_then(|i| i.ipv6.as_ref());
+        let cur = cur_ip.and_then(|i| i.sysctl.as_ref());
+        let default = cur_ip.and_then(|i| i.sysctl_default.as_ref());
+        for (prop, changed) in [
+            (
+                "forwarding",
+                is_changed(
+                    des.forwarding,
+                    cur.and_then(|c| c.forwarding),
+                    default.and_then(|d| d.forwarding),
+                ),
+            ),
+            (
+                "accept-ra",
+                is_changed(
+                    des.accept_ra,
+                    cur.and_then(|c| c.accept_ra),
+                    default.and_then(|d| d.accept_ra),
+                ),
+            ),
+        ] {
+            if changed {
+                return Err(nm_sysctl_not_supported(&format!(
+                    "IPv6 sysctl {prop} of interface {iface_name}"
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn validate_nm_ip_forwarding(
+    merged_ip_forwarding: &MergedIpForwarding,
+) -> Result<(), NmstateError> {
+    if let Some(des) = merged_ip_forwarding.desired.as_ref() {
+        let cur = merged_ip_forwarding.current.as_ref();
+        if is_changed(des.ipv4, cur.and_then(|c| c.ipv4), None)
+            || is_changed(des.ipv6, cur.and_then(|c| c.ipv6), None)
+        {
+            return Err(nm_sysctl_not_supported("global IP forwarding"));
+        }
+    }
+    Ok(())
+}
+
+// Query only shows sysctl holding value different from kernel default
+fn is_changed<T: PartialEq>(
+    desired: Option<T>,
+    current: Option<T>,
+    default: Option<T>,
+) -> bool {
+    desired.is_some() && desired != current.or(default)
+}
+
+fn nm_sysctl_not_supported(prop: &str) -> NmstateError {
+    let e = NmstateError::new(
+        ErrorKind::NotSupportedError,
+        format!(
+            "NetworkManager does not support changing {prop}, please use \
+            kernel mode instead"
+        ),
+    );
+    log::error!("{}", e);
+    e
+}
@@ -2,26 +2,44 @@
 
 use std::collections::HashMap;
 
-use crate::nm::nm_dbus::{NmConnection, NmSettingUser};
+use crate::nm::nm_dbus::NmConnection;
 
 use crate::Interface;
 
 pub(crate) const NMSTATE_DESCRIPTION: &str = "nmstate.interface.description";
+// Indicate the DNS config in IP settings is interface level DNS config
+// instead of global DNS config stored to this interface.
+pub(crate) const NMSTATE_IFACE_DNS: &str = "nmstate.interface.dns";
 
 pub(crate) fn gen_nm_user_setting(
     iface: &Interface,
     nm_conn: &mut NmConnection,
 ) {
-    if let Some(description) = iface.base_iface().description.as_ref() {
-        let mut data: HashMap<String, String> = HashMap::new();
-        if !description.is_empty() {
+    let description = iface.base_iface().description.as_ref();
+    let dns_conf = iface.base_iface().dns.as_ref();
+    if description.is_none() && dns_conf.is_none() {
+        return;
+    }
+    let mut nm_setting = nm_conn.user.clone().unwrap_or_default();
+    let mut data: HashMap<String, String> =
+        nm_setting.data.take().unwrap_or_default();
+    if let Some(description) = description {
+        if description.is_empty() {
+            data.remove(NMSTATE_DESCRIPTION);
+        } else {
             data.insert(
                 NMSTATE_DESCRIPTION.to_string(),
                 description.to_string(),
             );
         }
-        let mut nm_setting = NmSettingUser::default();
-        nm_setting.data = Some(data);
-        nm_conn.user = Some(nm_setting);
     }
+    if let Some(dns_conf) = dns_conf {
+        if dns_conf.is_empty() {
+            data.remove(NMSTATE_IFACE_DNS);
+        } else {
+            data.insert(NMSTATE_IFACE_DNS.to_string(), "yes".to_string());
+        }
+    }
+    nm_setting.data = Some(data);
+    nm_conn.user = Some(nm_setting);
 }
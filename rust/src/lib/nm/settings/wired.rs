@@ -1,8 +1,18 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::nm::nm_dbus::NmConnection;
+use crate::nm::nm_dbus::{NmConnection, NmSettingWired};
 
-use crate::{Interface, InterfaceIdentifier};
+use crate::{
+    EthernetWakeOnLanConfig, EthernetWakeOnLanMode, Interface,
+    InterfaceIdentifier, NetworkState,
+};
+
+const NM_WOL_PHY: u32 = 1 << 1;
+const NM_WOL_UNICAST: u32 = 1 << 2;
+const NM_WOL_MULTICAST: u32 = 1 << 3;
+const NM_WOL_BROADCAST: u32 = 1 << 4;
+const NM_WOL_ARP: u32 = 1 << 5;
+const NM_WOL_MAGIC: u32 = 1 << 6;
 
 pub(crate) fn gen_nm_wired_setting(
     iface: &Interface,
@@ -48,6 +58,10 @@ pub(crate) fn gen_nm_wired_setting(
                 }
                 None => (),
             }
+            if let Some(wol_conf) = eth_conf.wake_on_lan.as_ref() {
+                flag_need_wired = true;
+                apply_wol_options(&mut nm_wired_set, wol_conf);
+            }
         }
     }
 
@@ -62,3 +76,36 @@ pub(crate) fn gen_nm_wired_setting(
         nm_conn.wired = Some(nm_wired_set);
     }
 }
+
+fn apply_wol_options(
+    nm_wired_set: &mut NmSettingWired,
+    wol_conf: &EthernetWakeOnLanConfig,
+) {
+    if let Some(modes) = wol_conf.modes.as_ref() {
+        let mut flags = 0u32;
+        for mode in modes {
+            flags |= match mode {
+                EthernetWakeOnLanMode::Phy => NM_WOL_PHY,
+                EthernetWakeOnLanMode::Unicast => NM_WOL_UNICAST,
+                EthernetWakeOnLanMode::Multicast => NM_WOL_MULTICAST,
+                EthernetWakeOnLanMode::Broadcast => NM_WOL_BROADCAST,
+                EthernetWakeOnLanMode::Arp => NM_WOL_ARP,
+                // NM enables SecureOn when magic with password
+                EthernetWakeOnLanMode::Magic
+                | EthernetWakeOnLanMode::Secureon => NM_WOL_MAGIC,
+            };
+        }
+        nm_wired_set.wake_on_lan = Some(flags);
+        if !wol_conf.is_secureon() {
+            nm_wired_set.wake_on_lan_password = None;
+        }
+    }
+    // Keep current password if it is hid by nmstate
+    if let Some(password) = wol_conf
+        .secureon_password
+        .as_deref()
+        .filter(|p| *p != NetworkState::PASSWORD_HID_BY_NMSTATE)
+    {
+        nm_wired_set.wake_on_lan_password = Some(password.to_string());
+    }
+}
@@ -12,14 +12,18 @@ use super::{
     error::nm_error_to_nmstate,
     query_apply::{
         create_index_for_nm_conns_by_name_type,
-        device::nm_dev_iface_type_to_nmstate, dispatch::get_dispatches,
-        dns::nm_global_dns_to_nmstate, get_description, get_lldp,
-        is_lldp_enabled, nm_802_1x_to_nmstate, nm_ip_setting_to_nmstate4,
-        nm_ip_setting_to_nmstate6, ovs::merge_ovs_netdev_tun_iface,
+        device::nm_dev_iface_type_to_nmstate,
+        dispatch::get_dispatches,
+        dns::{nm_global_dns_to_nmstate, nm_iface_dns_to_nmstate},
+        get_description, get_lldp, is_lldp_enabled, nm_802_1x_to_nmstate,
+        nm_dev_to_8021x_auth_state, nm_dhcp4_opts_to_nmstate,
+        nm_dhcp6_opts_to_nmstate, nm_ip_setting_to_nmstate4,
+        nm_ip_setting_to_nmstate6,
+        ovs::merge_ovs_netdev_tun_iface,
         query_nmstate_wait_ip, retrieve_dns_info,
         vpn::get_supported_vpn_ifaces,
     },
-    settings::get_bond_balance_slb,
+    settings::{get_bond_balance_slb, nm_dns_over_tls_to_nmstate},
 };
 use crate::{
     BaseInterface, BondConfig, BondInterface, BondOptions, DummyInterface,
@@ -33,6 +37,7 @@ use crate::{
 
 pub(crate) fn nm_retrieve(
     running_config_only: bool,
+    include_status_data: bool,
 ) -> Result<NetworkState, NmstateError> {
     let mut net_state = NetworkState::new();
     let mut nm_api = NmApi::new().map_err(nm_error_to_nmstate)?;
@@ -159,6 +164,12 @@ pub(crate) fn nm_retrieve(
             }
         }
     }
+    if !running_config_only {
+        append_8021x_auth_state(&mut net_state, &nm_devs);
+        if include_status_data {
+            append_dhcp_lease(&mut nm_api, &mut net_state, &nm_devs);
+        }
+    }
     for iface in get_supported_vpn_ifaces(&nm_saved_conn_uuid_index, &nm_acs)? {
         net_state.append_interface_data(iface);
     }
@@ -235,6 +246,29 @@ pub(crate) fn nm_conn_to_base_iface(
         };
         base_iface.ipv4 = ipv4;
         base_iface.ipv6 = ipv6;
+        base_iface.dns = nm_iface_dns_to_nmstate(iface_name, nm_conn);
+        // Interface level DNS config should not be treated as global DNS
+        if base_iface.dns.is_some() {
+            if let Some(ip_conf) = base_iface.ipv4.as_mut() {
+                ip_conf.dns = None;
+            }
+            if let Some(ip_conf) = base_iface.ipv6.as_mut() {
+                ip_conf.dns = None;
+            }
+        } else {
+            let dns_over_tls = nm_dns_over_tls_to_nmstate(
+                nm_conn.connection.as_ref().and_then(|c| c.dns_over_tls),
+            );
+            for dns_conf in [
+                base_iface.ipv4.as_mut().and_then(|i| i.dns.as_mut()),
+                base_iface.ipv6.as_mut().and_then(|i| i.dns.as_mut()),
+            ]
+            .into_iter()
+            .flatten()
+            {
+                dns_conf.dns_over_tls = dns_over_tls;
+            }
+        }
         base_iface.wait_ip =
             query_nmstate_wait_ip(nm_conn.ipv4.as_ref(), nm_conn.ipv6.as_ref());
         base_iface.description = get_description(nm_conn);
@@ -613,3 +647,73 @@ fn get_connection_name(
     }
     None
 }
+
+// The DHCP lease is status data, only retrieved for activated interface with
+// dynamic IP enabled.
+fn append_dhcp_lease(
+    nm_api: &mut NmApi,
+    net_state: &mut NetworkState,
+    nm_devs: &[NmDevice],
+) {
+    for nm_dev in nm_devs
+        .iter()
+        .filter(|d| d.state == NmDeviceState::Activated)
+    {
+        let base_iface = match net_state
+            .interfaces
+            .kernel_ifaces
+            .get_mut(nm_dev.name.as_str())
+        {
+            Some(i) => i.base_iface_mut(),
+            None => continue,
+        };
+        if let Some(ipv4) = base_iface.ipv4.as_mut().filter(|i| i.is_auto()) {
+            match nm_api.device_dhcp4_options_get(&nm_dev.obj_path) {
+                Ok(opts) => ipv4.dhcp_lease = nm_dhcp4_opts_to_nmstate(&opts),
+                Err(e) => {
+                    log::warn!(
+                        "Failed to retrieve DHCPv4 lease of interface {}: {}",
+                        nm_dev.name,
+                        e
+                    );
+                    continue;
+                }
+            }
+        }
+        if let Some(ipv6) = base_iface.ipv6.as_mut().filter(|i| i.is_auto()) {
+            match nm_api.device_dhcp6_options_get(&nm_dev.obj_path) {
+                Ok(opts) => ipv6.dhcp_lease = nm_dhcp6_opts_to_nmstate(&opts),
+                Err(e) => {
+                    log::warn!(
+                        "Failed to retrieve DHCPv6 lease of interface {}: {}",
+                        nm_dev.name,
+                        e
+                    );
+                    continue;
+                }
+            }
+        }
+    }
+}
+
+fn append_8021x_auth_state(net_state: &mut NetworkState, nm_devs: &[NmDevice]) {
+    for nm_dev in nm_devs {
+        // Only annotate interfaces already holding 802.1X setting from
+        // their NM profile
+        let conf = match net_state
+            .interfaces
+            .kernel_ifaces
+            .get_mut(nm_dev.name.as_str())
+            .and_then(|i| i.base_iface_mut().ieee8021x.as_mut())
+        {
+            Some(c) => c,
+            None => continue,
+        };
+        if let Some((auth_state, failure_reason)) =
+            nm_dev_to_8021x_auth_state(nm_dev)
+        {
+            conf.auth_state = Some(auth_state);
+            conf.auth_failure_reason = failure_reason;
+        }
+    }
+}
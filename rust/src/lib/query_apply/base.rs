@@ -33,6 +33,13 @@ impl BaseInterface {
         if self.ovsdb.is_none() {
             self.ovsdb = Some(OvsDbIfaceConfig::new_empty());
         }
+        // Interface level DNS None equal to empty
+        if self.dns.is_none() {
+            self.dns = Some(Default::default());
+        }
+        if let Some(dns_conf) = self.dns.as_mut() {
+            dns_conf.sanitize_for_verify();
+        }
         // dispatch script None equal to empty
         if self.dispatch.is_none() {
             self.dispatch = Some(Default::default());
@@ -52,6 +59,9 @@ impl BaseInterface {
         if let Some(mptcp_conf) = self.mptcp.as_mut() {
             mptcp_conf.sanitize_desired_for_verify();
         }
+        if let Some(dns_conf) = self.dns.as_mut() {
+            dns_conf.sanitize_for_verify();
+        }
         // When `profile_name` is the same with iface name, it was hidden during
         // query, we should ignore it during verify
         if self.profile_name.as_deref() == Some(self.name.as_str()) {
@@ -112,6 +122,9 @@ impl BaseInterface {
         if other.ethtool.is_some() {
             self.ethtool.clone_from(&other.ethtool);
         }
+        if other.dns.is_some() {
+            self.dns.clone_from(&other.dns);
+        }
         if other.mptcp.is_some() {
             self.mptcp.clone_from(&other.mptcp);
         }
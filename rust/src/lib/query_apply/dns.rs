@@ -1,6 +1,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{DnsState, ErrorKind, MergedDnsState, NmstateError};
+use crate::{
+    dns::is_ipv6_dns_srv, DnsState, ErrorKind, InterfaceDnsConfig,
+    MergedDnsState, NmstateError,
+};
 
 impl MergedDnsState {
     pub(crate) fn is_desired(&self) -> bool {
@@ -37,6 +40,13 @@ impl MergedDnsState {
         self.servers != cur_servers
             || self.searches != cur_searches
             || self.options != cur_options
+            || self.is_dns_policy_changed()
+    }
+
+    pub(crate) fn is_dns_policy_changed(&self) -> bool {
+        let cur_conf = self.current.config.as_ref();
+        self.dns_over_tls != cur_conf.and_then(|c| c.dns_over_tls)
+            || self.dnssec != cur_conf.and_then(|c| c.dnssec)
     }
 
     pub(crate) fn verify(&self, current: DnsState) -> Result<(), NmstateError> {
@@ -119,6 +129,43 @@ impl MergedDnsState {
             ));
         }
 
+        let cur_dns_over_tls = cur_conf.dns_over_tls;
+        if self.dns_over_tls != cur_dns_over_tls {
+            return Err(NmstateError::new(
+                ErrorKind::VerificationError,
+                format!(
+                    "Failed to apply DNS config: desire DNS-over-TLS \
+                    {:?}, got {:?}",
+                    self.dns_over_tls, cur_dns_over_tls,
+                ),
+            ));
+        }
+
+        let cur_dnssec = cur_conf.dnssec;
+        if self.dnssec != cur_dnssec {
+            return Err(NmstateError::new(
+                ErrorKind::VerificationError,
+                format!(
+                    "Failed to apply DNS config: desire DNSSEC {:?}, \
+                    got {:?}",
+                    self.dnssec, cur_dnssec,
+                ),
+            ));
+        }
+
         Ok(())
     }
 }
+
+impl InterfaceDnsConfig {
+    // Backend store IPv4 and IPv6 name servers separately, hence IPv4 name
+    // servers are always shown before IPv6 ones.
+    pub(crate) fn sanitize_for_verify(&mut self) {
+        if let Some(srvs) = self.server.as_mut() {
+            let (mut v4_srvs, v6_srvs): (Vec<String>, Vec<String>) =
+                srvs.drain(..).partition(|s| !is_ipv6_dns_srv(s));
+            v4_srvs.extend(v6_srvs);
+            *srvs = v4_srvs;
+        }
+    }
+}
@@ -10,6 +10,9 @@ impl HostNameState {
         if other.config.is_some() {
             self.config.clone_from(&other.config);
         }
+        if other.pretty.is_some() {
+            self.pretty.clone_from(&other.pretty);
+        }
     }
 }
 
@@ -68,6 +71,23 @@ impl MergedHostNameState {
                 return Err(e);
             }
         }
+        if let Some(pretty) = desired.pretty.as_ref() {
+            if Some(pretty) != current.pretty.as_ref()
+                && !(pretty.is_empty() && current.pretty.is_none())
+            {
+                let e = NmstateError::new(
+                    ErrorKind::VerificationError,
+                    format!(
+                        "Verification fail, desire hostname.pretty: \
+                        {}, current: {:?}",
+                        pretty,
+                        current.pretty.as_ref()
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
 
         Ok(())
     }
@@ -14,6 +14,9 @@ impl HsrInterface {
     pub(crate) fn sanitize_desired_for_verify(&mut self) {
         if let Some(conf) = &mut self.hsr {
             conf.supervision_address = None;
+            if conf.interlink.as_deref() == Some("") {
+                conf.interlink = None;
+            }
         }
     }
 }
@@ -27,6 +30,9 @@ impl HsrConfig {
                 .clone_from(&other.supervision_address);
             self.multicast_spec = other.multicast_spec;
             self.protocol = other.protocol;
+            if other.interlink.is_some() {
+                self.interlink.clone_from(&other.interlink);
+            }
         }
     }
 }
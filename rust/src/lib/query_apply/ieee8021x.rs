@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{ErrorKind, Ieee8021XAuthState, Ieee8021XConfig, NmstateError};
+
+impl Ieee8021XConfig {
+    // Use VerificationError so the verification retry could wait for
+    // authentication still in progress. Once retry exhausted, the failure
+    // reason of last attempt will be included in the error.
+    pub(crate) fn verify_auth_state(
+        &self,
+        iface_name: &str,
+    ) -> Result<(), NmstateError> {
+        if self.auth_state == Some(Ieee8021XAuthState::Failed) {
+            let e = NmstateError::new(
+                ErrorKind::VerificationError,
+                format!(
+                    "802.1X authentication failed on interface {iface_name}{}",
+                    self.auth_failure_reason
+                        .map(|r| format!(": {r}"))
+                        .unwrap_or_default()
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+        Ok(())
+    }
+}
@@ -31,6 +31,12 @@ impl Interface {
         &mut self,
         current: &Self,
     ) -> Result<(), NmstateError> {
+        // Fail early with clear error when 802.1X authentication failed
+        if self.base_iface().ieee8021x.is_some() {
+            if let Some(conf) = current.base_iface().ieee8021x.as_ref() {
+                conf.verify_auth_state(self.name())?;
+            }
+        }
         let mut current = current.clone();
         self.process_allow_extra_address(&mut current);
 
@@ -1,8 +1,20 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{Interface, InterfaceIpv4, InterfaceIpv6};
+use crate::{
+    Interface, InterfaceIpAddr, InterfaceIpv4, InterfaceIpv6, Ipv4Sysctl,
+    Ipv6Sysctl,
+};
 
 impl InterfaceIpv4 {
+    // Query only shows sysctl holding value different from kernel default,
+    // this function fill the missing ones with kernel default.
+    pub(crate) fn effective_sysctl(&self) -> Option<Ipv4Sysctl> {
+        let default = self.sysctl_default.as_ref()?;
+        let mut ret = self.sysctl.clone().unwrap_or_default();
+        ret.fill_default(default);
+        Some(ret)
+    }
+
     // Sort addresses and dedup
     pub(crate) fn sanitize_current_for_verify(&mut self) {
         if let Some(addrs) = self.addresses.as_mut() {
@@ -22,6 +34,10 @@ impl InterfaceIpv4 {
         if self.dhcp.is_none() {
             self.dhcp = Some(false);
         }
+
+        if let Some(sysctl) = self.effective_sysctl() {
+            self.sysctl = Some(sysctl);
+        }
     }
 
     // Sort addresses and dedup
@@ -82,10 +98,52 @@ impl InterfaceIpv4 {
             self.dhcp_custom_hostname
                 .clone_from(&other.dhcp_custom_hostname);
         }
+        if other.dhcp_vendor_class_identifier.is_some() {
+            self.dhcp_vendor_class_identifier
+                .clone_from(&other.dhcp_vendor_class_identifier);
+        }
+        if other.dhcp_user_class.is_some() {
+            self.dhcp_user_class.clone_from(&other.dhcp_user_class);
+        }
+        if other.dhcp_iaid.is_some() {
+            self.dhcp_iaid.clone_from(&other.dhcp_iaid);
+        }
+        if other.dhcp_fqdn.is_some() {
+            self.dhcp_fqdn.clone_from(&other.dhcp_fqdn);
+        }
+        if other.dhcp_timeout.is_some() {
+            self.dhcp_timeout = other.dhcp_timeout;
+        }
+        if other.dhcp_reject_servers.is_some() {
+            self.dhcp_reject_servers
+                .clone_from(&other.dhcp_reject_servers);
+        }
+        if other.dhcp_request_options.is_some() {
+            self.dhcp_request_options
+                .clone_from(&other.dhcp_request_options);
+        }
+        if other.dhcp_lease.is_some() {
+            self.dhcp_lease.clone_from(&other.dhcp_lease);
+        }
+        if other.sysctl.is_some() {
+            self.sysctl.clone_from(&other.sysctl);
+        }
+        if other.sysctl_default.is_some() {
+            self.sysctl_default.clone_from(&other.sysctl_default);
+        }
     }
 }
 
 impl InterfaceIpv6 {
+    // Query only shows sysctl holding value different from kernel default,
+    // this function fill the missing ones with kernel default.
+    pub(crate) fn effective_sysctl(&self) -> Option<Ipv6Sysctl> {
+        let default = self.sysctl_default.as_ref()?;
+        let mut ret = self.sysctl.clone().unwrap_or_default();
+        ret.fill_default(default);
+        Some(ret)
+    }
+
     // Sort addresses and dedup
     pub(crate) fn sanitize_current_for_verify(&mut self) {
         if let Some(addrs) = self.addresses.as_mut() {
@@ -115,6 +173,10 @@ impl InterfaceIpv6 {
         if self.enabled && self.autoconf.is_none() {
             self.autoconf = Some(false);
         }
+
+        if let Some(sysctl) = self.effective_sysctl() {
+            self.sysctl = Some(sysctl);
+        }
     }
 
     // Sort addresses and dedup
@@ -177,12 +239,43 @@ impl InterfaceIpv6 {
             self.dhcp_custom_hostname
                 .clone_from(&other.dhcp_custom_hostname);
         }
+        if other.dhcp_iaid.is_some() {
+            self.dhcp_iaid.clone_from(&other.dhcp_iaid);
+        }
+        if other.dhcp_send_release.is_some() {
+            self.dhcp_send_release = other.dhcp_send_release;
+        }
+        if other.ip6_privacy.is_some() {
+            self.ip6_privacy = other.ip6_privacy;
+        }
+        if other.dad_transmits.is_some() {
+            self.dad_transmits = other.dad_transmits;
+        }
+        if other.ra_timeout.is_some() {
+            self.ra_timeout = other.ra_timeout;
+        }
+        if other.dhcp_timeout.is_some() {
+            self.dhcp_timeout = other.dhcp_timeout;
+        }
+        if other.mtu_from_ra.is_some() {
+            self.mtu_from_ra = other.mtu_from_ra;
+        }
+        if other.dhcp_lease.is_some() {
+            self.dhcp_lease.clone_from(&other.dhcp_lease);
+        }
+        if other.sysctl.is_some() {
+            self.sysctl.clone_from(&other.sysctl);
+        }
+        if other.sysctl_default.is_some() {
+            self.sysctl_default.clone_from(&other.sysctl_default);
+        }
     }
 }
 
 impl Interface {
     // * If `allow_extra_address: true`, remove current IP address if not found
     //   in desired.
+    // * Ignore flags of current IP address if desired does not mention them.
     pub(crate) fn process_allow_extra_address(&mut self, current: &mut Self) {
         if let (Some(des_ip), Some(cur_ip)) = (
             self.base_iface_mut().ipv4.as_mut(),
@@ -191,6 +284,7 @@ impl Interface {
             if let (Some(des_ip_addrs), Some(cur_ip_addrs)) =
                 (des_ip.addresses.as_ref(), cur_ip.addresses.as_mut())
             {
+                ignore_undesired_addr_flags(des_ip_addrs, cur_ip_addrs);
                 if des_ip.allow_extra_address != Some(false) {
                     cur_ip_addrs.retain(|i| des_ip_addrs.contains(i))
                 }
@@ -205,6 +299,7 @@ impl Interface {
             if let (Some(des_ip_addrs), Some(cur_ip_addrs)) =
                 (des_ip.addresses.as_ref(), cur_ip.addresses.as_mut())
             {
+                ignore_undesired_addr_flags(des_ip_addrs, cur_ip_addrs);
                 if des_ip.allow_extra_address != Some(false) {
                     cur_ip_addrs.retain(|i| des_ip_addrs.contains(i))
                 }
@@ -214,3 +309,32 @@ impl Interface {
         }
     }
 }
+
+fn ignore_undesired_addr_flags(
+    des_addrs: &[InterfaceIpAddr],
+    cur_addrs: &mut [InterfaceIpAddr],
+) {
+    for cur_addr in cur_addrs.iter_mut() {
+        if des_addrs
+            .iter()
+            .any(|d| d.is_same_addr(cur_addr) && d.flags.is_none())
+        {
+            cur_addr.flags = None;
+        }
+    }
+}
+
+impl InterfaceIpAddr {
+    // Whether holding peer address or flags which backend might not support.
+    pub(crate) fn has_peer_or_flags(&self) -> bool {
+        self.peer.is_some()
+            || self.flags.as_ref().map(|f| !f.is_empty()) == Some(true)
+    }
+
+    // Same IP address, prefix length and peer address.
+    pub(crate) fn is_same_addr(&self, other: &Self) -> bool {
+        self.ip == other.ip
+            && self.prefix_length == other.prefix_length
+            && self.peer == other.peer
+    }
+}
@@ -0,0 +1,345 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::Write;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::{
+    LldpAddressFamily, LldpChassisId, LldpMacPhy, LldpMaxFrameSize,
+    LldpMedAppType, LldpMedNetworkPolicies, LldpMedNetworkPolicy, LldpMgmtAddr,
+    LldpMgmtAddrs, LldpNeighborTlv, LldpPortId, LldpPpvids,
+    LldpSystemCapabilities, LldpSystemDescription, LldpSystemName, LldpVlan,
+    LldpVlans,
+};
+
+pub(crate) const LLDP_ETHER_TYPE: u16 = 0x88cc;
+// Nearest bridge multicast address which is not forwarded by 802.1D bridges
+pub(crate) const LLDP_MULTICAST_ADDR: [u8; 6] =
+    [0x01, 0x80, 0xc2, 0x00, 0x00, 0x0e];
+
+const ETHER_HEADER_LEN: usize = 14;
+const LLDP_TLV_HEADER_LEN: usize = 2;
+const LLDP_TLV_MAX_LEN: usize = 0x1ff;
+
+const LLDP_TLV_TYPE_END: u8 = 0;
+const LLDP_TLV_TYPE_CHASSIS_ID: u8 = 1;
+const LLDP_TLV_TYPE_PORT_ID: u8 = 2;
+const LLDP_TLV_TYPE_TTL: u8 = 3;
+const LLDP_TLV_TYPE_SYSTEM_NAME: u8 = 5;
+const LLDP_TLV_TYPE_SYSTEM_DESCRIPTION: u8 = 6;
+const LLDP_TLV_TYPE_SYSTEM_CAPABILITIES: u8 = 7;
+const LLDP_TLV_TYPE_MGMT_ADDR: u8 = 8;
+const LLDP_TLV_TYPE_ORG_SPECIFIC: u8 = 127;
+
+const LLDP_CHASSIS_ID_SUBTYPE_MAC: u8 = 4;
+const LLDP_CHASSIS_ID_SUBTYPE_NETWORK_ADDR: u8 = 5;
+const LLDP_PORT_ID_SUBTYPE_MAC: u8 = 3;
+const LLDP_PORT_ID_SUBTYPE_NETWORK_ADDR: u8 = 4;
+
+const LLDP_MGMT_ADDR_SUBTYPE_IPV4: u8 = 1;
+const LLDP_MGMT_ADDR_SUBTYPE_IPV6: u8 = 2;
+const LLDP_MGMT_ADDR_SUBTYPE_MAC: u8 = 6;
+
+const LLDP_OUI_IEEE_802_1: [u8; 3] = [0x00, 0x80, 0xc2];
+const LLDP_OUI_IEEE_802_3: [u8; 3] = [0x00, 0x12, 0x0f];
+const LLDP_OUI_MED: [u8; 3] = [0x00, 0x12, 0xbb];
+const LLDP_802_1_SUBTYPE_PPVID: u8 = 2;
+const LLDP_802_1_SUBTYPE_VLAN_NAME: u8 = 3;
+const LLDP_802_3_SUBTYPE_MAC_PHY: u8 = 1;
+const LLDP_802_3_SUBTYPE_MAX_FRAME_SIZE: u8 = 4;
+const LLDP_MED_SUBTYPE_NETWORK_POLICY: u8 = 2;
+
+// Split LLDP ethernet frame into TLVs of `(type, value)`, stop at END TLV or
+// malformed TLV.
+pub(crate) fn parse_lldp_frame_tlvs(frame: &[u8]) -> Vec<(u8, &[u8])> {
+    let mut ret = Vec::new();
+    let mut data = match frame.get(ETHER_HEADER_LEN..) {
+        Some(d) => d,
+        None => return ret,
+    };
+    while data.len() >= LLDP_TLV_HEADER_LEN {
+        let header = u16::from_be_bytes([data[0], data[1]]);
+        let tlv_type = (header >> 9) as u8;
+        let len = (header & LLDP_TLV_MAX_LEN as u16) as usize;
+        if tlv_type == LLDP_TLV_TYPE_END {
+            break;
+        }
+        let value =
+            match data.get(LLDP_TLV_HEADER_LEN..LLDP_TLV_HEADER_LEN + len) {
+                Some(v) => v,
+                None => {
+                    log::debug!(
+                    "Ignoring truncated LLDP TLV type {tlv_type} length {len}"
+                );
+                    break;
+                }
+            };
+        ret.push((tlv_type, value));
+        data = &data[LLDP_TLV_HEADER_LEN + len..];
+    }
+    ret
+}
+
+impl LldpMedNetworkPolicies {
+    // NetworkManager does not parse LLDP-MED, hence we parse the raw LLDP
+    // ethernet frame provided by NetworkManager.
+    pub(crate) fn from_lldp_frame(frame: &[u8]) -> Option<Self> {
+        let policies: Vec<LldpMedNetworkPolicy> = parse_lldp_frame_tlvs(frame)
+            .into_iter()
+            .filter(|(tlv_type, _)| *tlv_type == LLDP_TLV_TYPE_ORG_SPECIFIC)
+            .filter_map(|(_, value)| parse_med_network_policy(value))
+            .collect();
+        if policies.is_empty() {
+            None
+        } else {
+            Some(Self::new(policies))
+        }
+    }
+}
+
+// Value layout after OUI and subtype:
+//  * 1 byte application type
+//  * 3 bytes: unknown policy flag(1 bit), tagged flag(1 bit), reserved(1
+//    bit), VLAN ID(12 bits), L2 priority(3 bits), DSCP(6 bits)
+fn parse_med_network_policy(value: &[u8]) -> Option<LldpMedNetworkPolicy> {
+    if value.len() != 8
+        || value[..3] != LLDP_OUI_MED
+        || value[3] != LLDP_MED_SUBTYPE_NETWORK_POLICY
+    {
+        return None;
+    }
+    let app_type = LldpMedAppType::from(value[4]);
+    let policy = u32::from_be_bytes([0, value[5], value[6], value[7]]);
+    Some(LldpMedNetworkPolicy::new(
+        app_type,
+        policy & (1 << 23) > 0,
+        policy & (1 << 22) > 0,
+        ((policy >> 9) & 0xfff) as u16,
+        ((policy >> 6) & 0x7) as u8,
+        (policy & 0x3f) as u8,
+    ))
+}
+
+pub(crate) fn get_lldp_frame_ttl(frame: &[u8]) -> Option<u16> {
+    parse_lldp_frame_tlvs(frame)
+        .into_iter()
+        .find(|(tlv_type, _)| *tlv_type == LLDP_TLV_TYPE_TTL)
+        .and_then(|(_, value)| value.get(..2))
+        .map(|v| u16::from_be_bytes([v[0], v[1]]))
+}
+
+// Convert LLDP ethernet frame to nmstate neighbor TLVs using the same order
+// and string format as NetworkManager.
+pub(crate) fn lldp_frame_to_nmstate(frame: &[u8]) -> Vec<LldpNeighborTlv> {
+    let mut sys_name = None;
+    let mut sys_desc = None;
+    let mut sys_caps = None;
+    let mut chassis_id = None;
+    let mut port_id = None;
+    let mut vlans: Vec<LldpVlan> = Vec::new();
+    let mut mac_phy = None;
+    let mut ppvids: Vec<u32> = Vec::new();
+    let mut mgmt_addrs: Vec<LldpMgmtAddr> = Vec::new();
+    let mut max_frame_size = None;
+
+    for (tlv_type, value) in parse_lldp_frame_tlvs(frame) {
+        match tlv_type {
+            LLDP_TLV_TYPE_CHASSIS_ID => {
+                if let Some((&subtype, id)) = value.split_first() {
+                    chassis_id =
+                        Some(LldpNeighborTlv::ChassisId(LldpChassisId::new(
+                            lldp_id_to_string(
+                                id,
+                                subtype == LLDP_CHASSIS_ID_SUBTYPE_MAC,
+                                subtype == LLDP_CHASSIS_ID_SUBTYPE_NETWORK_ADDR,
+                            ),
+                            subtype.into(),
+                        )));
+                }
+            }
+            LLDP_TLV_TYPE_PORT_ID => {
+                if let Some((&subtype, id)) = value.split_first() {
+                    port_id = Some(LldpNeighborTlv::PortId(LldpPortId::new(
+                        lldp_id_to_string(
+                            id,
+                            subtype == LLDP_PORT_ID_SUBTYPE_MAC,
+                            subtype == LLDP_PORT_ID_SUBTYPE_NETWORK_ADDR,
+                        ),
+                        subtype.into(),
+                    )));
+                }
+            }
+            LLDP_TLV_TYPE_SYSTEM_NAME => {
+                sys_name = Some(LldpNeighborTlv::SystemName(
+                    LldpSystemName::new(lldp_string(value)),
+                ));
+            }
+            LLDP_TLV_TYPE_SYSTEM_DESCRIPTION => {
+                sys_desc = Some(LldpNeighborTlv::SystemDescription(
+                    LldpSystemDescription::new(lldp_string(value)),
+                ));
+            }
+            LLDP_TLV_TYPE_SYSTEM_CAPABILITIES => {
+                if let Some(v) = value.get(..2) {
+                    sys_caps = Some(LldpNeighborTlv::SystemCapabilities(
+                        LldpSystemCapabilities::from(u16::from_be_bytes([
+                            v[0], v[1],
+                        ])),
+                    ));
+                }
+            }
+            LLDP_TLV_TYPE_MGMT_ADDR => {
+                if let Some(addr) = parse_mgmt_addr(value) {
+                    mgmt_addrs.push(addr);
+                }
+            }
+            LLDP_TLV_TYPE_ORG_SPECIFIC if value.len() >= 4 => {
+                let oui = &value[..3];
+                let subtype = value[3];
+                let data = &value[4..];
+                if oui == LLDP_OUI_IEEE_802_1 {
+                    if subtype == LLDP_802_1_SUBTYPE_VLAN_NAME {
+                        if let Some(vlan) = parse_vlan_name(data) {
+                            vlans.push(vlan);
+                        }
+                    } else if subtype == LLDP_802_1_SUBTYPE_PPVID {
+                        // 1 byte flags and 2 bytes PPVID
+                        if let Some(v) = data.get(1..3) {
+                            ppvids
+                                .push(u16::from_be_bytes([v[0], v[1]]).into());
+                        }
+                    }
+                } else if oui == LLDP_OUI_IEEE_802_3 {
+                    if subtype == LLDP_802_3_SUBTYPE_MAC_PHY && data.len() >= 5
+                    {
+                        // 1 byte auto-negotiation support/status, 2 bytes
+                        // PMD auto-negotiation capability and 2 bytes
+                        // operational MAU type.
+                        mac_phy = Some(LldpNeighborTlv::Ieee8023MacPhyConf(
+                            LldpMacPhy::new(
+                                data[0] > 0,
+                                u16::from_be_bytes([data[3], data[4]]),
+                                u16::from_be_bytes([data[1], data[2]]),
+                            ),
+                        ));
+                    } else if subtype == LLDP_802_3_SUBTYPE_MAX_FRAME_SIZE
+                        && data.len() >= 2
+                    {
+                        max_frame_size =
+                            Some(LldpNeighborTlv::Ieee8023MaxFrameSize(
+                                LldpMaxFrameSize::new(
+                                    u16::from_be_bytes([data[0], data[1]])
+                                        .into(),
+                                ),
+                            ));
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+
+    let mut ret = Vec::new();
+    ret.extend(sys_name);
+    ret.extend(sys_desc);
+    ret.extend(sys_caps);
+    ret.extend(chassis_id);
+    ret.extend(port_id);
+    if !vlans.is_empty() {
+        ret.push(LldpNeighborTlv::Ieee8021Vlans(LldpVlans::new(vlans)));
+    }
+    ret.extend(mac_phy);
+    if !ppvids.is_empty() {
+        ret.push(LldpNeighborTlv::Ieee8021Ppvids(LldpPpvids::new(ppvids)));
+    }
+    if !mgmt_addrs.is_empty() {
+        ret.push(LldpNeighborTlv::ManagementAddresses(LldpMgmtAddrs::new(
+            mgmt_addrs,
+        )));
+    }
+    ret.extend(max_frame_size);
+    if let Some(policies) = LldpMedNetworkPolicies::from_lldp_frame(frame) {
+        ret.push(LldpNeighborTlv::MedNetworkPolicies(policies));
+    }
+    ret
+}
+
+// Some switches include trailing NULL in string
+fn lldp_string(data: &[u8]) -> String {
+    String::from_utf8_lossy(data)
+        .trim_end_matches('\0')
+        .to_string()
+}
+
+fn lldp_id_to_string(id: &[u8], is_mac: bool, is_network_addr: bool) -> String {
+    if is_mac {
+        u8_to_mac_string(id)
+    } else if is_network_addr {
+        // First byte is IANA address family
+        id.split_first()
+            .and_then(|(&family, addr)| ip_to_string(family, addr))
+            .unwrap_or_else(|| lldp_string(id))
+    } else {
+        lldp_string(id)
+    }
+}
+
+fn ip_to_string(family: u8, addr: &[u8]) -> Option<String> {
+    match family {
+        LLDP_MGMT_ADDR_SUBTYPE_IPV4 => <[u8; 4]>::try_from(addr)
+            .ok()
+            .map(|a| Ipv4Addr::from(a).to_string()),
+        LLDP_MGMT_ADDR_SUBTYPE_IPV6 => <[u8; 16]>::try_from(addr)
+            .ok()
+            .map(|a| Ipv6Addr::from(a).to_string()),
+        _ => None,
+    }
+}
+
+fn u8_to_mac_string(data: &[u8]) -> String {
+    let mut addr = String::new();
+    for (i, &val) in data.iter().enumerate() {
+        let _ = write!(addr, "{val:02X}");
+        if i != data.len() - 1 {
+            addr.push(':');
+        }
+    }
+    addr
+}
+
+fn parse_vlan_name(data: &[u8]) -> Option<LldpVlan> {
+    let vid = u16::from_be_bytes([*data.first()?, *data.get(1)?]);
+    let name_len = *data.get(2)? as usize;
+    let name = data.get(3..3 + name_len)?;
+    Some(LldpVlan::new(vid.into(), lldp_string(name)))
+}
+
+// Value layout:
+//  * 1 byte address string length(including subtype)
+//  * 1 byte address subtype
+//  * address
+//  * 1 byte interface numbering subtype
+//  * 4 bytes interface number
+//  * OID length and OID, ignored
+fn parse_mgmt_addr(value: &[u8]) -> Option<LldpMgmtAddr> {
+    let addr_str_len = *value.first()? as usize;
+    if addr_str_len < 1 {
+        return None;
+    }
+    let subtype = *value.get(1)?;
+    let addr = value.get(2..1 + addr_str_len)?;
+    let iface = value.get(1 + addr_str_len..6 + addr_str_len)?;
+    let address = if subtype == LLDP_MGMT_ADDR_SUBTYPE_MAC {
+        u8_to_mac_string(addr)
+    } else {
+        ip_to_string(subtype, addr)?
+    };
+    let mut mgmt_addr = LldpMgmtAddr {
+        address,
+        address_subtype: LldpAddressFamily::from(subtype as u16),
+        ..Default::default()
+    };
+    mgmt_addr.interface_number_subtype = iface[0].into();
+    mgmt_addr.interface_number =
+        u32::from_be_bytes([iface[1], iface[2], iface[3], iface[4]]);
+    Some(mgmt_addr)
+}
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{MacSecConfig, MacSecInterface};
+use crate::{MacSecConfig, MacSecInterface, MacSecSaConfig, NetworkState};
 
 impl MacSecInterface {
     pub(crate) fn update_macsec(&mut self, other: &MacSecInterface) {
@@ -15,10 +15,21 @@ impl MacSecInterface {
 impl MacSecConfig {
     // Only allow update `mka_cak` and `mka_ckn` as other values should be
     // provided by nispor netlink code.
+    // The secure associations created by MKA should not be shown as static
+    // SAK.
     fn update(&mut self, other: Option<&Self>) {
         if let Some(other) = other {
             self.mka_cak.clone_from(&other.mka_cak);
             self.mka_ckn.clone_from(&other.mka_ckn);
+            if self.mka_ckn.is_some() {
+                self.static_sak = None;
+            }
         }
     }
 }
+
+impl MacSecSaConfig {
+    pub(crate) fn is_key_hidden(&self) -> bool {
+        self.key.as_deref() == Some(NetworkState::PASSWORD_HID_BY_NMSTATE)
+    }
+}
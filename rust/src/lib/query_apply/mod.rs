@@ -7,6 +7,7 @@ mod dns;
 mod ethernet;
 mod hostname;
 mod hsr;
+mod ieee8021x;
 mod iface;
 mod infiniband;
 mod inter_ifaces;
@@ -14,19 +15,27 @@ mod ip;
 mod ipsec;
 mod ipvlan;
 mod linux_bridge;
+mod lldp;
 mod mac_vlan;
 mod mac_vtap;
 mod macsec;
 mod mptcp;
+mod neighbor;
 mod net_state;
+mod nexthop;
 pub(crate) mod ovn;
 mod ovs;
 mod route;
 mod route_rule;
 mod sriov;
+mod sysctl;
 mod vlan;
 mod vrf;
 mod vxlan;
 
+pub(crate) use lldp::{
+    get_lldp_frame_ttl, lldp_frame_to_nmstate, LLDP_ETHER_TYPE,
+    LLDP_MULTICAST_ADDR,
+};
 #[cfg(test)]
 pub(crate) use route::is_route_delayed_by_nm;
@@ -0,0 +1,64 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    ErrorKind, MergedNeighbors, NeighborEntry, Neighbors, NmstateError,
+};
+
+impl MergedNeighbors {
+    pub(crate) fn gen_diff(&self) -> Neighbors {
+        let cur_neighs = self.current.config.as_deref().unwrap_or_default();
+
+        let changed_neighs: Vec<NeighborEntry> = self
+            .for_apply
+            .iter()
+            .filter(|neigh| {
+                neigh.is_absent()
+                    || !cur_neighs
+                        .iter()
+                        .any(|cur_neigh| neigh.is_match(cur_neigh))
+            })
+            .cloned()
+            .collect();
+
+        Neighbors {
+            config: if changed_neighs.is_empty() {
+                None
+            } else {
+                Some(changed_neighs)
+            },
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn verify(
+        &self,
+        current: &Neighbors,
+    ) -> Result<(), NmstateError> {
+        let cur_neighs = current.config.as_deref().unwrap_or_default();
+        for neigh in self.for_apply.as_slice() {
+            if neigh.is_absent() {
+                if let Some(cur_neigh) = cur_neighs
+                    .iter()
+                    .find(|cur_neigh| neigh.is_same_key(cur_neigh))
+                {
+                    return Err(NmstateError::new(
+                        ErrorKind::VerificationError,
+                        format!(
+                            "Desired absent neighbor {neigh} still found \
+                            after apply: {cur_neigh}"
+                        ),
+                    ));
+                }
+            } else if !cur_neighs
+                .iter()
+                .any(|cur_neigh| neigh.is_match(cur_neigh))
+            {
+                return Err(NmstateError::new(
+                    ErrorKind::VerificationError,
+                    format!("Desired neighbor {neigh} not found after apply"),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
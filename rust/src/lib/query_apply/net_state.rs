@@ -3,7 +3,13 @@
 use std::future::Future;
 
 use crate::{
-    nispor::{nispor_apply, nispor_retrieve, set_running_hostname},
+    nispor::{
+        append_resolved_dns_policy, apply_ipv6_dad_and_ra_mtu,
+        apply_nm_unsupported_ethtool_conf, apply_pretty_hostname,
+        apply_resolved_dns_policy, apply_sriov_vf_link_conf, get_resolved_conf,
+        nispor_apply, nispor_retrieve, restore_resolved_conf,
+        set_running_hostname,
+    },
     nm::{
         nm_apply, nm_checkpoint_create, nm_checkpoint_destroy,
         nm_checkpoint_rollback, nm_checkpoint_timeout_extend, nm_retrieve,
@@ -61,12 +67,19 @@ impl NetworkState {
     /// Retrieve the `NetworkState`.
     /// Only available for feature `query_apply`.
     pub async fn retrieve_async(&mut self) -> Result<&mut Self, NmstateError> {
-        let state =
-            nispor_retrieve(self.running_config_only, self.kernel_only).await?;
+        let state = nispor_retrieve(
+            self.running_config_only,
+            self.kernel_only,
+            self.lldp_listen_time,
+        )
+        .await?;
         self.hostname = state.hostname;
+        self.ip_forwarding = state.ip_forwarding;
         self.interfaces = state.interfaces;
         self.routes = state.routes;
         self.rules = state.rules;
+        self.nexthops = state.nexthops;
+        self.neighbors = state.neighbors;
         self.dns = state.dns;
         if ovsdb_is_running() {
             match ovsdb_retrieve() {
@@ -80,13 +93,22 @@ impl NetworkState {
             }
         }
         if !self.kernel_only {
-            let nm_state = nm_retrieve(self.running_config_only)?;
+            let nm_state = nm_retrieve(
+                self.running_config_only,
+                self.include_status_data,
+            )?;
             // TODO: Priority handling
             self.update_state(&nm_state);
         }
+        if let Some(dns_state) = self.dns.as_mut() {
+            append_resolved_dns_policy(dns_state, self.running_config_only);
+        }
         if !self.include_secrets {
             self.hide_secrets();
         }
+        if !self.include_status_data {
+            self.interfaces.remove_status_data();
+        }
 
         // Purge user space ignored interfaces
         self.interfaces
@@ -205,59 +227,67 @@ impl NetworkState {
 
         log::info!("Created checkpoint {}", &checkpoint);
 
-        with_nm_checkpoint(&checkpoint, self.no_commit, || async {
-            if let Some(pf_state) = pf_state {
-                let pf_merged_state = MergedNetworkState::new(
-                    pf_state,
-                    cur_net_state.clone(),
-                    false,
-                    self.memory_only,
-                )?;
+        let resolved_conf = get_resolved_conf();
+
+        let result =
+            with_nm_checkpoint(&checkpoint, self.no_commit, || async {
+                if let Some(pf_state) = pf_state {
+                    let pf_merged_state = MergedNetworkState::new(
+                        pf_state,
+                        cur_net_state.clone(),
+                        false,
+                        self.memory_only,
+                    )?;
+                    let verify_count = get_proper_verify_retry_count(
+                        &pf_merged_state.interfaces,
+                    );
+                    self.apply_with_nm_backend_and_under_checkpoint(
+                        &pf_merged_state,
+                        &cur_net_state,
+                        &checkpoint,
+                        verify_count,
+                        timeout,
+                    )
+                    .await?;
+                    // Refresh current state
+                    cur_net_state.retrieve_async().await?;
+                    merged_state = Some(MergedNetworkState::new(
+                        self.clone(),
+                        cur_net_state.clone(),
+                        false,
+                        self.memory_only,
+                    )?);
+                }
+
+                let merged_state = if let Some(merged_state) = merged_state {
+                    merged_state
+                } else {
+                    return Err(NmstateError::new(
+                        ErrorKind::Bug,
+                        "Got unexpected None for merged_state in \
+                    apply_with_nm_backend()"
+                            .into(),
+                    ));
+                };
                 let verify_count =
-                    get_proper_verify_retry_count(&pf_merged_state.interfaces);
+                    get_proper_verify_retry_count(&merged_state.interfaces);
+
+                self.interfaces.check_sriov_capability()?;
+
                 self.apply_with_nm_backend_and_under_checkpoint(
-                    &pf_merged_state,
+                    &merged_state,
                     &cur_net_state,
                     &checkpoint,
                     verify_count,
                     timeout,
                 )
-                .await?;
-                // Refresh current state
-                cur_net_state.retrieve_async().await?;
-                merged_state = Some(MergedNetworkState::new(
-                    self.clone(),
-                    cur_net_state.clone(),
-                    false,
-                    self.memory_only,
-                )?);
-            }
-
-            let merged_state = if let Some(merged_state) = merged_state {
-                merged_state
-            } else {
-                return Err(NmstateError::new(
-                    ErrorKind::Bug,
-                    "Got unexpected None for merged_state in \
-                    apply_with_nm_backend()"
-                        .into(),
-                ));
-            };
-            let verify_count =
-                get_proper_verify_retry_count(&merged_state.interfaces);
-
-            self.interfaces.check_sriov_capability()?;
-
-            self.apply_with_nm_backend_and_under_checkpoint(
-                &merged_state,
-                &cur_net_state,
-                &checkpoint,
-                verify_count,
-                timeout,
-            )
-            .await
-        })
-        .await
+                .await
+            })
+            .await;
+        if result.is_err() {
+            restore_resolved_conf(resolved_conf.as_deref());
+        }
+        result
     }
 
     async fn apply_with_nm_backend_and_under_checkpoint(
@@ -273,6 +303,11 @@ impl NetworkState {
         with_retry(RETRY_NM_INTERVAL_MILLISECONDS, RETRY_NM_COUNT, || async {
             nm_checkpoint_timeout_extend(checkpoint, timeout)?;
             nm_apply(merged_state, checkpoint, timeout).await?;
+            // NetworkManager does not support SR-IOV VF link state and RSS
+            // query, apply them to kernel directly
+            apply_sriov_vf_link_conf(&merged_state.interfaces).await?;
+            apply_nm_unsupported_ethtool_conf(&merged_state.interfaces).await?;
+            apply_ipv6_dad_and_ra_mtu(&merged_state.interfaces)?;
             if merged_state.ovsdb.is_changed && ovsdb_is_running() {
                 ovsdb_apply(merged_state)?;
             }
@@ -281,6 +316,15 @@ impl NetworkState {
             {
                 set_running_hostname(running_hostname)?;
             }
+            apply_pretty_hostname(
+                &merged_state.hostname,
+                merged_state.memory_only,
+            )?;
+            // NetworkManager does not support DNSSEC, store it to
+            // systemd-resolved directly
+            if merged_state.dns.is_dns_policy_changed() {
+                apply_resolved_dns_policy(&merged_state.dns, false)?;
+            }
             if !self.no_verify {
                 with_retry(
                     VERIFY_RETRY_INTERVAL_MILLISECONDS,
@@ -289,6 +333,8 @@ impl NetworkState {
                         nm_checkpoint_timeout_extend(checkpoint, timeout)?;
                         let mut new_cur_net_state = cur_net_state.clone();
                         new_cur_net_state.set_include_secrets(true);
+                        // Include 802.1X authentication state
+                        new_cur_net_state.set_include_status_data(true);
                         new_cur_net_state.retrieve_async().await?;
                         merged_state.verify(&new_cur_net_state)
                     },
@@ -315,11 +361,6 @@ impl NetworkState {
         )?;
 
         nispor_apply(&merged_state).await?;
-        if let Some(running_hostname) =
-            self.hostname.as_ref().and_then(|c| c.running.as_ref())
-        {
-            set_running_hostname(running_hostname)?;
-        }
         if !self.no_verify {
             with_retry(
                 VERIFY_RETRY_INTERVAL_MILLISECONDS,
@@ -344,6 +385,9 @@ impl NetworkState {
                 self.hostname.clone_from(&other.hostname);
             }
         }
+        if other.ip_forwarding.is_some() {
+            self.ip_forwarding.clone_from(&other.ip_forwarding);
+        }
         self.interfaces.update(&other.interfaces);
         if other.dns.is_some() {
             self.dns.clone_from(&other.dns);
@@ -375,8 +419,14 @@ impl NetworkState {
             ret.hostname.clone_from(&self.hostname);
         }
 
+        if merged_state.ip_forwarding.is_changed() {
+            ret.ip_forwarding.clone_from(&self.ip_forwarding);
+        }
+
         ret.routes = merged_state.routes.gen_diff();
         ret.rules = merged_state.rules.gen_diff();
+        ret.nexthops = merged_state.nexthops.gen_diff();
+        ret.neighbors = merged_state.neighbors.gen_diff();
         if self.description != current.description {
             ret.description.clone_from(&self.description);
         }
@@ -461,6 +511,7 @@ where
 impl MergedNetworkState {
     fn verify(&self, current: &NetworkState) -> Result<(), NmstateError> {
         self.hostname.verify(current.hostname.as_ref())?;
+        self.ip_forwarding.verify(current.ip_forwarding.as_ref())?;
         self.interfaces.verify(&current.interfaces)?;
         let ignored_kernel_ifaces: Vec<&str> = self
             .interfaces
@@ -477,6 +528,8 @@ impl MergedNetworkState {
         )?;
         self.rules
             .verify(&current.rules, ignored_kernel_ifaces.as_slice())?;
+        self.nexthops.verify(&current.nexthops)?;
+        self.neighbors.verify(&current.neighbors)?;
         self.dns.verify(current.dns.clone().unwrap_or_default())?;
         self.ovsdb
             .verify(current.ovsdb.clone().unwrap_or_default())?;
@@ -0,0 +1,65 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    ip::is_ipv6_addr, AddressFamily, ErrorKind, MergedNexthops, NexthopEntry,
+    Nexthops, NmstateError,
+};
+
+impl MergedNexthops {
+    pub(crate) fn gen_diff(&self) -> Nexthops {
+        let cur_nhs = self.current.config.as_deref().unwrap_or_default();
+
+        let changed_nhs: Vec<NexthopEntry> = self
+            .for_apply
+            .iter()
+            .filter(|nh| {
+                nh.is_absent()
+                    || !cur_nhs.iter().any(|cur_nh| nh.is_match(cur_nh))
+            })
+            .cloned()
+            .collect();
+
+        Nexthops {
+            config: if changed_nhs.is_empty() {
+                None
+            } else {
+                Some(changed_nhs)
+            },
+        }
+    }
+
+    pub(crate) fn verify(
+        &self,
+        current: &Nexthops,
+    ) -> Result<(), NmstateError> {
+        let cur_nhs = current.config.as_deref().unwrap_or_default();
+        for nh in self.for_apply.as_slice() {
+            if nh.is_absent() {
+                if let Some(cur_nh) =
+                    cur_nhs.iter().find(|cur_nh| cur_nh.id == nh.id)
+                {
+                    return Err(NmstateError::new(
+                        ErrorKind::VerificationError,
+                        format!(
+                            "Desired absent nexthop {nh} still found \
+                            after apply: {cur_nh}"
+                        ),
+                    ));
+                }
+            } else if !cur_nhs.iter().any(|cur_nh| nh.is_match(cur_nh)) {
+                return Err(NmstateError::new(
+                    ErrorKind::VerificationError,
+                    format!("Desired nexthop {nh} not found after apply"),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl NexthopEntry {
+    pub(crate) fn is_ipv6(&self) -> bool {
+        self.family == Some(AddressFamily::IPv6)
+            || self.next_hop_addr.as_deref().map(is_ipv6_addr) == Some(true)
+    }
+}
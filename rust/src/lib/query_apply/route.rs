@@ -20,7 +20,12 @@ impl MergedRoutes {
 
         for rts in self.merged.values() {
             for rt in rts {
-                if rt.is_absent() || !current_routes.contains(rt) {
+                if rt.is_absent()
+                    || current_routes
+                        .get(rt)
+                        .map(|cur_rt| !rt.is_metrics_match(cur_rt))
+                        .unwrap_or(true)
+                {
                     changed_routes.push(rt.clone());
                 }
             }
@@ -142,6 +147,21 @@ impl MergedRoutes {
     }
 }
 
+impl RouteEntry {
+    // Whether the route has metrics or flags defined
+    pub(crate) fn has_metrics(&self) -> bool {
+        self.cwnd.is_some()
+            || self.mtu.is_some()
+            || self.advmss.is_some()
+            || self.initcwnd.is_some()
+            || self.initrwnd.is_some()
+            || self.quickack == Some(true)
+            || self.rto_min.is_some()
+            || self.onlink == Some(true)
+            || self.lock.as_ref().map(|l| !l.is_empty()) == Some(true)
+    }
+}
+
 /// NetworkManager doesn't add routes with ipvx.method auto or dhcp until the
 /// interface has at least an IP.
 /// This function checks if that's the case. Note that it doesn't check if the
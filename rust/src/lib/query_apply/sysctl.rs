@@ -0,0 +1,87 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    ErrorKind, IpForwarding, Ipv4Sysctl, Ipv6Sysctl, MergedIpForwarding,
+    NmstateError,
+};
+
+impl MergedIpForwarding {
+    pub(crate) fn is_changed(&self) -> bool {
+        if let Some(desired) = self.desired.as_ref() {
+            let current = self.current.clone().unwrap_or_default();
+            (desired.ipv4.is_some() && desired.ipv4 != current.ipv4)
+                || (desired.ipv6.is_some() && desired.ipv6 != current.ipv6)
+        } else {
+            false
+        }
+    }
+
+    pub(crate) fn verify(
+        &self,
+        current: Option<&IpForwarding>,
+    ) -> Result<(), NmstateError> {
+        let desired = if let Some(d) = &self.desired {
+            d
+        } else {
+            return Ok(());
+        };
+        let current = current.cloned().unwrap_or_default();
+
+        for (family, des_value, cur_value) in [
+            ("ipv4", desired.ipv4, current.ipv4),
+            ("ipv6", desired.ipv6, current.ipv6),
+        ] {
+            if des_value.is_some() && des_value != cur_value {
+                let e = NmstateError::new(
+                    ErrorKind::VerificationError,
+                    format!(
+                        "Verification fail, desire ip-forwarding.{family}: \
+                        {des_value:?}, current: {cur_value:?}"
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Ipv4Sysctl {
+    // Fill unset properties from `default`
+    pub(crate) fn fill_default(&mut self, default: &Self) {
+        if self.forwarding.is_none() {
+            self.forwarding = default.forwarding;
+        }
+        if self.rp_filter.is_none() {
+            self.rp_filter = default.rp_filter;
+        }
+        if self.accept_local.is_none() {
+            self.accept_local = default.accept_local;
+        }
+        if self.proxy_arp.is_none() {
+            self.proxy_arp = default.proxy_arp;
+        }
+        if self.arp_ignore.is_none() {
+            self.arp_ignore = default.arp_ignore;
+        }
+        if self.arp_announce.is_none() {
+            self.arp_announce = default.arp_announce;
+        }
+    }
+}
+
+impl Ipv6Sysctl {
+    // Fill unset properties from `default`
+    pub(crate) fn fill_default(&mut self, default: &Self) {
+        if self.forwarding.is_none() {
+            self.forwarding = default.forwarding;
+        }
+        if self.accept_ra.is_none() {
+            self.accept_ra = default.accept_ra;
+        }
+        if self.use_tempaddr.is_none() {
+            self.use_tempaddr = default.use_tempaddr;
+        }
+    }
+}
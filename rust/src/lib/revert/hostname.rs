@@ -4,10 +4,12 @@ use crate::{HostNameState, MergedHostNameState};
 
 impl MergedHostNameState {
     pub(crate) fn generate_revert(&self) -> Option<HostNameState> {
-        if self.desired.is_some() {
-            self.current.clone()
-        } else {
-            None
+        let desired = self.desired.as_ref()?;
+        let mut revert = self.current.clone()?;
+        // Query omits unset pretty hostname, use empty string to remove it
+        if desired.pretty.is_some() && revert.pretty.is_none() {
+            revert.pretty = Some(String::new());
         }
+        Some(revert)
     }
 }
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::BaseInterface;
+use crate::{BaseInterface, InterfaceIpv4, InterfaceIpv6};
 
 impl BaseInterface {
     pub(crate) fn generate_revert_extra(
@@ -27,6 +27,24 @@ impl BaseInterface {
         {
             self.ipv6.clone_from(&current.ipv6);
         }
+        // Query only shows sysctl holding non-default value, hence use
+        // effective value for sysctl mentioned in desired.
+        if let (Some(des_sysctl), Some(mut sysctl)) = (
+            desired.ipv4.as_ref().and_then(|i| i.sysctl.as_ref()),
+            current.ipv4.as_ref().and_then(|i| i.effective_sysctl()),
+        ) {
+            sysctl.retain_defined(des_sysctl);
+            self.ipv4.get_or_insert_with(InterfaceIpv4::new).sysctl =
+                Some(sysctl);
+        }
+        if let (Some(des_sysctl), Some(mut sysctl)) = (
+            desired.ipv6.as_ref().and_then(|i| i.sysctl.as_ref()),
+            current.ipv6.as_ref().and_then(|i| i.effective_sysctl()),
+        ) {
+            sysctl.retain_defined(des_sysctl);
+            self.ipv6.get_or_insert_with(InterfaceIpv6::new).sysctl =
+                Some(sysctl);
+        }
         self.ipv4.as_mut().and_then(|i| i.sanitize(false).ok());
         self.ipv6.as_mut().and_then(|i| i.sanitize(false).ok());
     }
@@ -3,9 +3,12 @@
 mod dns;
 mod hostname;
 mod ifaces;
+mod neighbor;
 mod net_state;
+mod nexthop;
 mod ovn;
 mod ovsdb;
 mod route;
 mod route_rule;
 mod state;
+mod sysctl;
@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{MergedNeighbors, NeighborEntry, NeighborState, Neighbors};
+
+impl MergedNeighbors {
+    pub(crate) fn generate_revert(&self) -> Neighbors {
+        let mut revert_neighs: Vec<NeighborEntry> = Vec::new();
+        let cur_neighs = self.current.config.as_deref().unwrap_or_default();
+
+        for des_neigh in self.desired.config.as_deref().unwrap_or_default() {
+            if des_neigh.is_absent() {
+                for cur_neigh in
+                    cur_neighs.iter().filter(|n| des_neigh.is_match(n))
+                {
+                    revert_neighs.push(cur_neigh.clone());
+                }
+            } else if let Some(cur_neigh) =
+                cur_neighs.iter().find(|n| des_neigh.is_same_key(n))
+            {
+                revert_neighs.push(cur_neigh.clone());
+            } else {
+                revert_neighs.push(NeighborEntry {
+                    state: Some(NeighborState::Absent),
+                    ip: des_neigh.ip.clone(),
+                    interface: des_neigh.interface.clone(),
+                    proxy: des_neigh.proxy,
+                    ..Default::default()
+                });
+            }
+        }
+
+        if revert_neighs.is_empty() {
+            Neighbors::default()
+        } else {
+            Neighbors {
+                config: Some(revert_neighs),
+                ..Default::default()
+            }
+        }
+    }
+}
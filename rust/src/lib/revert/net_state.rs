@@ -17,10 +17,13 @@ impl NetworkState {
             interfaces: merged_state.interfaces.generate_revert()?,
             routes: merged_state.routes.generate_revert(),
             rules: merged_state.rules.generate_revert(),
+            nexthops: merged_state.nexthops.generate_revert(),
+            neighbors: merged_state.neighbors.generate_revert(),
             dns: merged_state.dns.generate_revert(),
             ovsdb: merged_state.ovsdb.generate_revert(),
             ovn: merged_state.ovn.generate_revert(),
             hostname: merged_state.hostname.generate_revert(),
+            ip_forwarding: merged_state.ip_forwarding.generate_revert(),
             ..Default::default()
         })
     }
@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{MergedNexthops, NexthopEntry, NexthopState, Nexthops};
+
+impl MergedNexthops {
+    pub(crate) fn generate_revert(&self) -> Nexthops {
+        let mut revert_nhs: Vec<NexthopEntry> = Vec::new();
+        let cur_nhs = self.current.config.as_deref().unwrap_or_default();
+
+        for des_nh in self.desired.config.as_deref().unwrap_or_default() {
+            if des_nh.is_absent() {
+                for cur_nh in cur_nhs.iter().filter(|n| des_nh.is_match(n)) {
+                    revert_nhs.push(cur_nh.clone());
+                }
+            } else if let Some(cur_nh) =
+                cur_nhs.iter().find(|n| n.id.is_some() && n.id == des_nh.id)
+            {
+                revert_nhs.push(cur_nh.clone());
+            } else {
+                revert_nhs.push(NexthopEntry {
+                    state: Some(NexthopState::Absent),
+                    id: des_nh.id,
+                    ..Default::default()
+                });
+            }
+        }
+
+        if revert_nhs.is_empty() {
+            Nexthops::default()
+        } else {
+            Nexthops {
+                config: Some(revert_nhs),
+            }
+        }
+    }
+}
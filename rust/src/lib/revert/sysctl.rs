@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{IpForwarding, Ipv4Sysctl, Ipv6Sysctl, MergedIpForwarding};
+
+impl MergedIpForwarding {
+    pub(crate) fn generate_revert(&self) -> Option<IpForwarding> {
+        let desired = self.desired.as_ref()?;
+        let current = self.current.clone().unwrap_or_default();
+        let revert = IpForwarding {
+            ipv4: desired.ipv4.and(current.ipv4),
+            ipv6: desired.ipv6.and(current.ipv6),
+        };
+        if revert == IpForwarding::default() {
+            None
+        } else {
+            Some(revert)
+        }
+    }
+}
+
+impl Ipv4Sysctl {
+    // Only keep properties set in `desired`
+    pub(crate) fn retain_defined(&mut self, desired: &Self) {
+        if desired.forwarding.is_none() {
+            self.forwarding = None;
+        }
+        if desired.rp_filter.is_none() {
+            self.rp_filter = None;
+        }
+        if desired.accept_local.is_none() {
+            self.accept_local = None;
+        }
+        if desired.proxy_arp.is_none() {
+            self.proxy_arp = None;
+        }
+        if desired.arp_ignore.is_none() {
+            self.arp_ignore = None;
+        }
+        if desired.arp_announce.is_none() {
+            self.arp_announce = None;
+        }
+    }
+}
+
+impl Ipv6Sysctl {
+    // Only keep properties set in `desired`
+    pub(crate) fn retain_defined(&mut self, desired: &Self) {
+        if desired.forwarding.is_none() {
+            self.forwarding = None;
+        }
+        if desired.accept_ra.is_none() {
+            self.accept_ra = None;
+        }
+        if desired.use_tempaddr.is_none() {
+            self.use_tempaddr = None;
+        }
+    }
+}
@@ -51,6 +51,11 @@ pub struct Routes {
     ///   - next-hop-interface: eth1
     ///     state: absent
     /// ```
+    /// Set [RouteEntry.protocol] of absent route to only remove routes
+    /// installed by specified protocol.
+    /// Routes installed by routing daemons(e.g. `protocol: bgp`) are not
+    /// included, hence never removed by absent route, the example above
+    /// purges all static and boot routes of eth1 but keeps the `bgp` ones.
     ///
     /// To change a route entry, you need to delete old one and add new one(can
     /// be in single transaction).
@@ -69,11 +74,27 @@ impl Routes {
 
     pub fn validate(&self) -> Result<(), NmstateError> {
         // All desire non-absent route should have next hop interface except
-        // for route with route type `Blackhole`, `Unreachable`, `Prohibit`.
+        // for route with route type `Blackhole`, `Unreachable`, `Prohibit`
+        // or route using nexthop object.
         if let Some(config_routes) = self.config.as_ref() {
             for route in config_routes.iter() {
                 if !route.is_absent() {
-                    if !route.is_unicast()
+                    if route.nexthop_id.is_some() {
+                        if route.next_hop_iface.is_some()
+                            || route.next_hop_addr.is_some()
+                            || route.weight.is_some()
+                            || route.route_type.is_some()
+                        {
+                            return Err(NmstateError::new(
+                                ErrorKind::InvalidArgument,
+                                format!(
+                                    "Route using nexthop-id cannot have \
+                                    next-hop-interface, next-hop-address, \
+                                    weight or route-type: {route}"
+                                ),
+                            ));
+                        }
+                    } else if !route.is_unicast()
                         && (route.next_hop_iface.is_some()
                             && route.next_hop_iface
                                 != Some(LOOPBACK_IFACE_NAME.to_string())
@@ -140,7 +161,8 @@ pub struct RouteEntry {
     /// Route next hop interface name.
     /// Serialize and deserialize to/from `next-hop-interface`.
     /// Mandatory for every non-absent routes except for route with
-    /// route type `Blackhole`, `Unreachable`, `Prohibit`.
+    /// route type `Blackhole`, `Unreachable`, `Prohibit` or route using
+    /// `nexthop-id`.
     pub next_hop_iface: Option<String>,
     #[serde(
         skip_serializing_if = "Option::is_none",
@@ -187,6 +209,237 @@ pub struct RouteEntry {
     /// Route source defines which IP address should be used as the source
     /// for packets routed via a specific route
     pub source: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    /// MTU along the path to the destination
+    pub mtu: Option<u32>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    /// Maximal TCP segment size advertised to peer when establishing TCP
+    /// connections to this destination
+    pub advmss: Option<u32>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    /// Initial TCP congestion window size
+    pub initcwnd: Option<u32>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    /// Initial TCP receive window size
+    pub initrwnd: Option<u32>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_bool_or_string"
+    )]
+    /// Enable quick ack for connections to this destination
+    pub quickack: Option<bool>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    /// Minimum TCP retransmission timeout in milliseconds
+    /// Serialize and deserialize to/from `rto-min`.
+    pub rto_min: Option<u32>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_bool_or_string"
+    )]
+    /// Pretend that the next hop is directly attached to this link, even if
+    /// it does not match any interface prefix
+    pub onlink: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Route metrics locked to specified value, prevent them from being
+    /// updated by TCP path MTU discovery or congestion control.
+    /// The metric been locked should also be defined in this route entry.
+    pub lock: Option<Vec<RouteMetricLock>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Route scope. When querying, only shown when not
+    /// [RouteScope::Global]. IPv6 route only support [RouteScope::Global].
+    pub scope: Option<RouteScope>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Routing protocol which installed this route.
+    /// Desired route can only use [RouteProtocol::Static], or
+    /// [RouteProtocol::Boot] in kernel only mode as NetworkManager always
+    /// installs route using [RouteProtocol::Static]. Routes installed by other
+    /// protocols are managed by their routing daemons. For absent route, this
+    /// could be used to only remove routes from specified protocol.
+    pub protocol: Option<RouteProtocol>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// IPv6 router preference of this route. When querying, only shown when
+    /// not [RoutePreference::Medium].
+    pub pref: Option<RoutePreference>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    /// ID of the kernel nexthop or nexthop group defined in
+    /// [crate::Nexthops] to forward the traffic to.
+    /// Route using nexthop cannot have `next-hop-interface`,
+    /// `next-hop-address`, `weight` or `route-type` defined.
+    /// Serialize and deserialize to/from `nexthop-id`.
+    pub nexthop_id: Option<u32>,
+}
+
+#[derive(
+    Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default,
+)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+/// Route scope
+pub enum RouteScope {
+    /// Global route, valid everywhere.
+    /// Deserialize from `global` or `universe`.
+    #[default]
+    #[serde(alias = "universe")]
+    Global,
+    /// Interior route in local autonomous system
+    Site,
+    /// Route on this link, destination is directly attached
+    Link,
+    /// Route on this host
+    Host,
+}
+
+impl std::fmt::Display for RouteScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Global => "global",
+                Self::Site => "site",
+                Self::Link => "link",
+                Self::Host => "host",
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+/// Routing protocol which installed the route
+pub enum RouteProtocol {
+    /// Route installed by kernel
+    Kernel,
+    /// Route installed during boot, often used by `iproute` command
+    Boot,
+    /// Route installed by administrator, used by NetworkManager and nmstate
+    Static,
+    /// Route learned from IPv6 router advertisement
+    Ra,
+    /// Route learned from DHCP
+    Dhcp,
+    Mrouted,
+    Keepalived,
+    Babel,
+    Zebra,
+    Bird,
+    Bgp,
+    Isis,
+    Ospf,
+    Rip,
+    Eigrp,
+}
+
+impl std::fmt::Display for RouteProtocol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Kernel => "kernel",
+                Self::Boot => "boot",
+                Self::Static => "static",
+                Self::Ra => "ra",
+                Self::Dhcp => "dhcp",
+                Self::Mrouted => "mrouted",
+                Self::Keepalived => "keepalived",
+                Self::Babel => "babel",
+                Self::Zebra => "zebra",
+                Self::Bird => "bird",
+                Self::Bgp => "bgp",
+                Self::Isis => "isis",
+                Self::Ospf => "ospf",
+                Self::Rip => "rip",
+                Self::Eigrp => "eigrp",
+            }
+        )
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default,
+)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+/// IPv6 router preference defined in RFC 4191
+pub enum RoutePreference {
+    Low,
+    #[default]
+    Medium,
+    High,
+}
+
+impl std::fmt::Display for RoutePreference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Low => "low",
+                Self::Medium => "medium",
+                Self::High => "high",
+            }
+        )
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord,
+)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+/// Route metric which could be locked
+pub enum RouteMetricLock {
+    /// Lock [RouteEntry.mtu]
+    Mtu,
+    /// Lock [RouteEntry.advmss]
+    Advmss,
+    /// Lock [RouteEntry.initcwnd]
+    Initcwnd,
+    /// Lock [RouteEntry.initrwnd]
+    Initrwnd,
+}
+
+impl std::fmt::Display for RouteMetricLock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Mtu => "mtu",
+                Self::Advmss => "advmss",
+                Self::Initcwnd => "initcwnd",
+                Self::Initrwnd => "initrwnd",
+            }
+        )
+    }
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
@@ -278,9 +531,68 @@ impl RouteEntry {
         if self.source.as_ref().is_some() && self.source != other.source {
             return false;
         }
+        self.is_metrics_match(other)
+    }
+
+    // Whether the route metrics, flags, scope, protocol, preference and
+    // nexthop ID defined in desired route (self) matches with another
+    pub(crate) fn is_metrics_match(&self, other: &Self) -> bool {
+        for (des, cur) in [
+            (self.mtu, other.mtu),
+            (self.advmss, other.advmss),
+            (self.initcwnd, other.initcwnd),
+            (self.initrwnd, other.initrwnd),
+            (self.rto_min, other.rto_min),
+        ] {
+            if des.is_some() && des != cur {
+                return false;
+            }
+        }
+        for (des, cur) in
+            [(self.quickack, other.quickack), (self.onlink, other.onlink)]
+        {
+            if des.is_some()
+                && des.unwrap_or_default() != cur.unwrap_or_default()
+            {
+                return false;
+            }
+        }
+        if let Some(des_lock) = self.lock.as_deref() {
+            let mut des_lock = des_lock.to_vec();
+            let mut cur_lock = other.lock.clone().unwrap_or_default();
+            des_lock.sort_unstable();
+            des_lock.dedup();
+            cur_lock.sort_unstable();
+            cur_lock.dedup();
+            if des_lock != cur_lock {
+                return false;
+            }
+        }
+        if self.scope.is_some()
+            && self.scope.unwrap_or_default() != other.scope.unwrap_or_default()
+        {
+            return false;
+        }
+        if self.protocol.is_some() && self.protocol != other.protocol {
+            return false;
+        }
+        if self.pref.is_some()
+            && self.pref.unwrap_or_default() != other.pref.unwrap_or_default()
+        {
+            return false;
+        }
+        if self.nexthop_id.is_some() && self.nexthop_id != other.nexthop_id {
+            return false;
+        }
         true
     }
 
+    // Whether the desired route (self) is holding the same route key with
+    // another but different metrics, hence should replace it.
+    fn is_replacing(&self, other: &Self) -> bool {
+        !self.is_absent() && self == other && !self.is_metrics_match(other)
+    }
+
     // Return tuple of (no_absent, is_ipv4, table_id, next_hop_iface,
     // destination, next_hop_addr, source, weight, cwnd)
     // Metric is ignored
@@ -378,6 +690,80 @@ impl RouteEntry {
                 ));
             }
         }
+        for (name, value) in [
+            ("mtu", self.mtu),
+            ("advmss", self.advmss),
+            ("initcwnd", self.initcwnd),
+            ("initrwnd", self.initrwnd),
+            ("rto-min", self.rto_min),
+        ] {
+            if value == Some(0) {
+                return Err(NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!("The value of '{name}' cannot be 0"),
+                ));
+            }
+        }
+        if let Some(locks) = self.lock.as_mut() {
+            locks.sort_unstable();
+            locks.dedup();
+        }
+        if self.is_ipv6() {
+            if let Some(scope) = self.scope {
+                if scope != RouteScope::Global {
+                    return Err(NmstateError::new(
+                        ErrorKind::InvalidArgument,
+                        format!(
+                            "IPv6 route only support global scope, \
+                            but got {scope} in route {self}"
+                        ),
+                    ));
+                }
+            }
+        } else if self.pref.is_some() && self.destination.is_some() {
+            return Err(NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "Route preference is only valid for IPv6 route, \
+                    but got {self}"
+                ),
+            ));
+        }
+        if let Some(protocol) = self.protocol {
+            if !self.is_absent()
+                && ![RouteProtocol::Static, RouteProtocol::Boot]
+                    .contains(&protocol)
+            {
+                return Err(NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "Only static and boot route protocol is allowed \
+                        for desired route, routes of protocol {protocol} \
+                        should be managed by its routing daemon, \
+                        but got {self}"
+                    ),
+                ));
+            }
+        }
+        if let Some(locks) = self.lock.as_ref() {
+            for lock in locks {
+                let value = match lock {
+                    RouteMetricLock::Mtu => self.mtu,
+                    RouteMetricLock::Advmss => self.advmss,
+                    RouteMetricLock::Initcwnd => self.initcwnd,
+                    RouteMetricLock::Initrwnd => self.initrwnd,
+                };
+                if value.is_none() {
+                    return Err(NmstateError::new(
+                        ErrorKind::InvalidArgument,
+                        format!(
+                            "Route metric '{lock}' is locked but not \
+                            defined in route {self}"
+                        ),
+                    ));
+                }
+            }
+        }
         Ok(())
     }
 
@@ -452,6 +838,48 @@ impl std::fmt::Display for RouteEntry {
         if let Some(v) = self.cwnd {
             props.push(format!("cwnd: {v}"));
         }
+        if let Some(v) = self.mtu {
+            props.push(format!("mtu: {v}"));
+        }
+        if let Some(v) = self.advmss {
+            props.push(format!("advmss: {v}"));
+        }
+        if let Some(v) = self.initcwnd {
+            props.push(format!("initcwnd: {v}"));
+        }
+        if let Some(v) = self.initrwnd {
+            props.push(format!("initrwnd: {v}"));
+        }
+        if let Some(v) = self.quickack {
+            props.push(format!("quickack: {v}"));
+        }
+        if let Some(v) = self.rto_min {
+            props.push(format!("rto-min: {v}"));
+        }
+        if let Some(v) = self.onlink {
+            props.push(format!("onlink: {v}"));
+        }
+        if let Some(v) = self.lock.as_ref() {
+            props.push(format!(
+                "lock: [{}]",
+                v.iter()
+                    .map(|l| l.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ));
+        }
+        if let Some(v) = self.scope {
+            props.push(format!("scope: {v}"));
+        }
+        if let Some(v) = self.protocol {
+            props.push(format!("protocol: {v}"));
+        }
+        if let Some(v) = self.pref {
+            props.push(format!("pref: {v}"));
+        }
+        if let Some(v) = self.nexthop_id {
+            props.push(format!("nexthop-id: {v}"));
+        }
 
         write!(f, "{}", props.join(" "))
     }
@@ -552,7 +980,7 @@ impl MergedRoutes {
                     ));
                 }
                 changed_ifaces.insert(via.as_str());
-            } else if rt.route_type.is_some() {
+            } else if rt.route_type.is_some() || rt.nexthop_id.is_some() {
                 changed_ifaces.insert(LOOPBACK_IFACE_NAME);
             }
         }
@@ -598,7 +1026,28 @@ impl MergedRoutes {
                         let mut new_rt = rt.clone();
                         new_rt.state = Some(RouteState::Absent);
                         changed_routes.insert(new_rt);
-                    } else {
+                    } else if !desired_routes
+                        .as_slice()
+                        .iter()
+                        .any(|des_rt| des_rt.is_replacing(rt))
+                    {
+                        merged_routes.push(rt.clone());
+                    }
+                } else if rt.nexthop_id.is_some() {
+                    if desired_routes
+                        .as_slice()
+                        .iter()
+                        .filter(|r| r.is_absent())
+                        .any(|absent_rt| absent_rt.is_match(rt))
+                    {
+                        let mut new_rt = rt.clone();
+                        new_rt.state = Some(RouteState::Absent);
+                        changed_routes.insert(new_rt);
+                    } else if !desired_routes
+                        .as_slice()
+                        .iter()
+                        .any(|des_rt| des_rt.is_replacing(rt))
+                    {
                         merged_routes.push(rt.clone());
                     }
                 }
@@ -612,7 +1061,7 @@ impl MergedRoutes {
             .filter(|rt| !rt.is_absent())
         {
             if let Some(cur_rts) = current.config.as_ref() {
-                if !cur_rts.as_slice().iter().any(|cur_rt| cur_rt.is_match(rt))
+                if !cur_rts.as_slice().iter().any(|cur_rt| rt.is_match(cur_rt))
                 {
                     changed_routes.insert(rt.clone());
                 }
@@ -633,7 +1082,7 @@ impl MergedRoutes {
                         Entry::Vacant(v) => v.insert(Vec::new()),
                     };
                 rts.push(rt);
-            } else if rt.route_type.is_some() {
+            } else if rt.route_type.is_some() || rt.nexthop_id.is_some() {
                 let rts: &mut Vec<RouteEntry> =
                     match merged.entry(LOOPBACK_IFACE_NAME.to_string()) {
                         Entry::Occupied(o) => o.into_mut(),
@@ -10,6 +10,7 @@ use crate::{
 };
 
 const ROUTE_RULE_DEFAULT_PRIORIRY: i64 = 30000;
+const ROUTE_RULE_MAX_DSCP: u8 = 63;
 
 #[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
 #[non_exhaustive]
@@ -129,6 +130,68 @@ pub struct RouteRuleEntry {
         alias = "suppress_prefixlength"
     )]
     pub suppress_prefix_length: Option<u32>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u8_or_string"
+    )]
+    /// IP protocol number to match, for example 6 for TCP and 17 for UDP.
+    pub ipproto: Option<u8>,
+    /// Source port range to match.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sport: Option<RouteRulePortRange>,
+    /// Destination port range to match.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dport: Option<RouteRulePortRange>,
+    /// User ID range of the socket owner to match.
+    /// Can deserialize from `uid-range` or `uidrange`.
+    /// Serialize into `uid-range`.
+    #[serde(skip_serializing_if = "Option::is_none", alias = "uidrange")]
+    pub uid_range: Option<RouteRuleUidRange>,
+    /// Outgoing interface.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub oif: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u8_or_string"
+    )]
+    /// Type of Service(TOS) value to match. Cannot be used with `dscp`.
+    pub tos: Option<u8>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u8_or_string"
+    )]
+    /// DSCP value(0 to 63) to match. Cannot be used with `tos`.
+    pub dscp: Option<u8>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_bool_or_string"
+    )]
+    /// Lookup the route table of the VRF(L3 master device) holding the
+    /// incoming or outgoing interface. Cannot be used with `route-table`.
+    pub l3mdev: Option<bool>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    /// Jump to the route rule with specified priority. The target priority
+    /// should be bigger than the priority of this route rule.
+    /// Cannot be used with `route-table` or `action`.
+    pub goto: Option<u32>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        alias = "not",
+        deserialize_with = "crate::deserializer::option_bool_or_string"
+    )]
+    /// Invert the selector result of this route rule.
+    /// Can deserialize from `invert` or `not`.
+    /// Serialize into `invert`.
+    pub invert: Option<bool>,
 }
 
 impl RouteRuleEntry {
@@ -206,6 +269,106 @@ impl RouteRuleEntry {
         Ok(())
     }
 
+    fn validate_selectors(&self) -> Result<(), NmstateError> {
+        for (name, range) in [("sport", self.sport), ("dport", self.dport)] {
+            if let Some(range) = range {
+                if range.start > range.end {
+                    let e = NmstateError::new(
+                        ErrorKind::InvalidArgument,
+                        format!(
+                            "The {name} start {} is bigger than end {} in \
+                            route rule '{self}'",
+                            range.start, range.end
+                        ),
+                    );
+                    log::error!("{}", e);
+                    return Err(e);
+                }
+            }
+        }
+        if let Some(range) = self.uid_range {
+            if range.start > range.end {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "The uid-range start {} is bigger than end {} in \
+                        route rule '{self}'",
+                        range.start, range.end
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
+        if self.tos.is_some() && self.dscp.is_some() {
+            let e = NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "The tos and dscp cannot be used together in \
+                    route rule '{self}'"
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+        if let Some(dscp) = self.dscp {
+            if dscp > ROUTE_RULE_MAX_DSCP {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "The dscp {dscp} is out of range(0 to \
+                        {ROUTE_RULE_MAX_DSCP}) in route rule '{self}'"
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
+        if self.l3mdev == Some(true) && self.table_id.is_some() {
+            let e = NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "The l3mdev and route-table cannot be used together \
+                    in route rule '{self}'"
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+        if let Some(goto) = self.goto {
+            if self.table_id.is_some()
+                || self.action.is_some()
+                || self.l3mdev == Some(true)
+            {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "The goto cannot be used together with \
+                        route-table, action or l3mdev in route rule '{self}'"
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+            if let Some(priority) = self.priority {
+                if priority != RouteRuleEntry::USE_DEFAULT_PRIORITY
+                    && i64::from(goto) <= priority
+                {
+                    let e = NmstateError::new(
+                        ErrorKind::InvalidArgument,
+                        format!(
+                            "The goto target {goto} should be bigger than \
+                            the priority of route rule '{self}'"
+                        ),
+                    );
+                    log::error!("{}", e);
+                    return Err(e);
+                }
+            }
+        }
+        Ok(())
+    }
+
     pub(crate) fn is_absent(&self) -> bool {
         matches!(self.state, Some(RouteRuleState::Absent))
     }
@@ -308,14 +471,68 @@ impl RouteRuleEntry {
         {
             return false;
         }
+        if self.ipproto.is_some()
+            && self.ipproto.unwrap_or(0) != other.ipproto.unwrap_or(0)
+        {
+            return false;
+        }
+        if self.sport.is_some() && self.sport != other.sport {
+            return false;
+        }
+        if self.dport.is_some() && self.dport != other.dport {
+            return false;
+        }
+        if self.uid_range.is_some() && self.uid_range != other.uid_range {
+            return false;
+        }
+        if self.oif.is_some() && self.oif != other.oif {
+            return false;
+        }
+        if self.tos.is_some() && self.tos.unwrap_or(0) != other.tos.unwrap_or(0)
+        {
+            return false;
+        }
+        if self.dscp.is_some()
+            && self.dscp.unwrap_or(0) != other.dscp.unwrap_or(0)
+        {
+            return false;
+        }
+        if self.l3mdev.is_some()
+            && self.l3mdev.unwrap_or_default()
+                != other.l3mdev.unwrap_or_default()
+        {
+            return false;
+        }
+        if self.goto.is_some() && self.goto != other.goto {
+            return false;
+        }
+        if self.invert.is_some()
+            && self.invert.unwrap_or_default()
+                != other.invert.unwrap_or_default()
+        {
+            return false;
+        }
         true
     }
 
     // Return tuple of (no_absent, is_ipv4, table_id, ip_from,
-    // ip_to, priority, fwmark, fwmask, action, suppress_prefix_length)
+    // ip_to, priority, fwmark, fwmask, action, suppress_prefix_length,
+    // selectors)
     fn sort_key(
         &self,
-    ) -> (bool, bool, u32, &str, &str, i64, u32, u32, u8, u32) {
+    ) -> (
+        bool,
+        bool,
+        u32,
+        &str,
+        &str,
+        i64,
+        u32,
+        u32,
+        u8,
+        u32,
+        RouteRuleSelectorsSortKey,
+    ) {
         (
             !matches!(self.state, Some(RouteRuleState::Absent)),
             {
@@ -343,6 +560,24 @@ impl RouteRuleEntry {
             self.fwmask.unwrap_or(0),
             self.action.map(u8::from).unwrap_or(0),
             self.suppress_prefix_length.unwrap_or_default(),
+            self.selectors_sort_key(),
+        )
+    }
+
+    // Return tuple of (ipproto, sport, dport, uid_range, oif, tos, dscp,
+    // l3mdev, goto, invert)
+    fn selectors_sort_key(&self) -> RouteRuleSelectorsSortKey {
+        (
+            self.ipproto.unwrap_or_default(),
+            self.sport.map(|r| (r.start, r.end)),
+            self.dport.map(|r| (r.start, r.end)),
+            self.uid_range.map(|r| (r.start, r.end)),
+            self.oif.clone().unwrap_or_default(),
+            self.tos.unwrap_or_default(),
+            self.dscp.unwrap_or_default(),
+            self.l3mdev.unwrap_or_default(),
+            self.goto.unwrap_or_default(),
+            self.invert.unwrap_or_default(),
         )
     }
 
@@ -391,11 +626,16 @@ impl RouteRuleEntry {
         }
         self.validate_ip_from_to()?;
         self.validate_fwmark_and_fwmask()?;
+        self.validate_selectors()?;
 
-        if self.action.is_none() && self.table_id.is_none() {
+        if self.action.is_none()
+            && self.table_id.is_none()
+            && self.goto.is_none()
+            && self.l3mdev != Some(true)
+        {
             log::info!(
-                "Route rule {self} has no action or route-table \
-                defined, using default route table 254"
+                "Route rule {self} has no action, route-table, goto or \
+                l3mdev defined, using default route table 254"
             );
             self.table_id = Some(RouteRuleEntry::DEFAULR_ROUTE_TABLE_ID);
         }
@@ -470,10 +710,107 @@ impl std::fmt::Display for RouteRuleEntry {
         if let Some(v) = self.suppress_prefix_length.as_ref() {
             props.push(format!("suppress-prefix-length: {v}"));
         }
+        if let Some(v) = self.ipproto.as_ref() {
+            props.push(format!("ipproto: {v}"));
+        }
+        if let Some(v) = self.sport.as_ref() {
+            props.push(format!("sport: {v}"));
+        }
+        if let Some(v) = self.dport.as_ref() {
+            props.push(format!("dport: {v}"));
+        }
+        if let Some(v) = self.uid_range.as_ref() {
+            props.push(format!("uid-range: {v}"));
+        }
+        if let Some(v) = self.oif.as_ref() {
+            props.push(format!("oif: {v}"));
+        }
+        if let Some(v) = self.tos.as_ref() {
+            props.push(format!("tos: {v}"));
+        }
+        if let Some(v) = self.dscp.as_ref() {
+            props.push(format!("dscp: {v}"));
+        }
+        if let Some(v) = self.l3mdev.as_ref() {
+            props.push(format!("l3mdev: {v}"));
+        }
+        if let Some(v) = self.goto.as_ref() {
+            props.push(format!("goto: {v}"));
+        }
+        if let Some(v) = self.invert.as_ref() {
+            props.push(format!("invert: {v}"));
+        }
         write!(f, "{}", props.join(" "))
     }
 }
 
+type RouteRuleSelectorsSortKey = (
+    u8,
+    Option<(u16, u16)>,
+    Option<(u16, u16)>,
+    Option<(u32, u32)>,
+    String,
+    u8,
+    u8,
+    bool,
+    u32,
+    bool,
+);
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize,
+)]
+#[non_exhaustive]
+#[serde(deny_unknown_fields)]
+/// Port range of route rule selector
+pub struct RouteRulePortRange {
+    #[serde(deserialize_with = "crate::deserializer::u16_or_string")]
+    /// Minimum port number(included).
+    pub start: u16,
+    #[serde(deserialize_with = "crate::deserializer::u16_or_string")]
+    /// Maximum port number(included).
+    pub end: u16,
+}
+
+impl RouteRulePortRange {
+    pub fn new(start: u16, end: u16) -> Self {
+        Self { start, end }
+    }
+}
+
+impl std::fmt::Display for RouteRulePortRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize,
+)]
+#[non_exhaustive]
+#[serde(deny_unknown_fields)]
+/// User ID range of route rule selector
+pub struct RouteRuleUidRange {
+    #[serde(deserialize_with = "crate::deserializer::u32_or_string")]
+    /// Minimum user ID(included).
+    pub start: u32,
+    #[serde(deserialize_with = "crate::deserializer::u32_or_string")]
+    /// Maximum user ID(included).
+    pub end: u32,
+}
+
+impl RouteRuleUidRange {
+    pub fn new(start: u32, end: u32) -> Self {
+        Self { start, end }
+    }
+}
+
+impl std::fmt::Display for RouteRuleUidRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "kebab-case")]
 #[non_exhaustive]
@@ -589,11 +926,10 @@ impl MergedRouteRules {
             .collect();
 
         self.for_apply.retain(|rule| {
-            if let Some(iif) = rule.iif.as_ref() {
-                !ignored_ifaces.contains(&iif.as_str())
-            } else {
-                true
-            }
+            [rule.iif.as_ref(), rule.oif.as_ref()]
+                .iter()
+                .flatten()
+                .all(|i| !ignored_ifaces.contains(&i.as_str()))
         })
     }
 
@@ -10,8 +10,21 @@ use serde::Serialize;
 // Please sort this list
 pub enum NmstateFeature {
     Dhcpv4CustomHostname,
+    Dhcpv4Fqdn,
+    Dhcpv4Iaid,
+    Dhcpv4RejectServers,
+    Dhcpv4RequestOptions,
+    Dhcpv4Timeout,
+    Dhcpv4UserClass,
+    Dhcpv4VendorClassIdentifier,
     Dhcpv6CustomHostname,
+    Dhcpv6Iaid,
+    Dhcpv6SendRelease,
+    Dhcpv6Timeout,
     IfaceNameReferedBySriovVfId,
+    IpForwarding,
+    Ipv4Sysctl,
+    Ipv6Sysctl,
     Lldp,
     MacBasedIdentifier,
     Mptcp,
@@ -26,6 +39,8 @@ pub enum NmstateFeature {
     StaticDnsOption,
     StaticDnsSearch,
     StaticHostname,
+    StaticNeighbor,
+    StaticNexthop,
     StaticRoute,
     StaticRouteRule,
     StaticRouteRuleSuppressPrefixLength,
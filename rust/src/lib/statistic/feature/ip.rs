@@ -4,20 +4,56 @@ use crate::{InterfaceIpv4, InterfaceIpv6, NmstateFeature};
 
 impl InterfaceIpv4 {
     pub(crate) fn get_features(&self) -> Vec<NmstateFeature> {
+        let mut ret = Vec::new();
         if self.dhcp_custom_hostname.is_some() {
-            vec![NmstateFeature::Dhcpv4CustomHostname]
-        } else {
-            Vec::new()
+            ret.push(NmstateFeature::Dhcpv4CustomHostname);
         }
+        if self.dhcp_fqdn.is_some() {
+            ret.push(NmstateFeature::Dhcpv4Fqdn);
+        }
+        if self.dhcp_iaid.is_some() {
+            ret.push(NmstateFeature::Dhcpv4Iaid);
+        }
+        if self.dhcp_reject_servers.is_some() {
+            ret.push(NmstateFeature::Dhcpv4RejectServers);
+        }
+        if self.dhcp_request_options.is_some() {
+            ret.push(NmstateFeature::Dhcpv4RequestOptions);
+        }
+        if self.dhcp_timeout.is_some() {
+            ret.push(NmstateFeature::Dhcpv4Timeout);
+        }
+        if self.dhcp_user_class.is_some() {
+            ret.push(NmstateFeature::Dhcpv4UserClass);
+        }
+        if self.dhcp_vendor_class_identifier.is_some() {
+            ret.push(NmstateFeature::Dhcpv4VendorClassIdentifier);
+        }
+        if self.sysctl.as_ref().map(|s| !s.is_empty()) == Some(true) {
+            ret.push(NmstateFeature::Ipv4Sysctl);
+        }
+        ret
     }
 }
 
 impl InterfaceIpv6 {
     pub(crate) fn get_features(&self) -> Vec<NmstateFeature> {
+        let mut ret = Vec::new();
         if self.dhcp_custom_hostname.is_some() {
-            vec![NmstateFeature::Dhcpv6CustomHostname]
-        } else {
-            Vec::new()
+            ret.push(NmstateFeature::Dhcpv6CustomHostname);
+        }
+        if self.dhcp_iaid.is_some() {
+            ret.push(NmstateFeature::Dhcpv6Iaid);
+        }
+        if self.dhcp_send_release.is_some() {
+            ret.push(NmstateFeature::Dhcpv6SendRelease);
+        }
+        if self.dhcp_timeout.is_some() {
+            ret.push(NmstateFeature::Dhcpv6Timeout);
+        }
+        if self.sysctl.as_ref().map(|s| !s.is_empty()) == Some(true) {
+            ret.push(NmstateFeature::Ipv6Sysctl);
         }
+        ret
     }
 }
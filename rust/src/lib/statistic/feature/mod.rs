@@ -7,8 +7,11 @@ mod hostname;
 mod iface;
 mod inter_ifaces;
 mod ip;
+mod neighbor;
+mod nexthop;
 mod ovs;
 mod route;
 mod route_rule;
+mod sysctl;
 
 pub use self::features::NmstateFeature;
@@ -0,0 +1,19 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{MergedNeighbors, NmstateFeature};
+
+impl MergedNeighbors {
+    pub(crate) fn get_features(&self) -> Vec<NmstateFeature> {
+        if self
+            .desired
+            .config
+            .as_ref()
+            .map(|neighs| !neighs.is_empty())
+            .unwrap_or_default()
+        {
+            vec![NmstateFeature::StaticNeighbor]
+        } else {
+            Vec::new()
+        }
+    }
+}
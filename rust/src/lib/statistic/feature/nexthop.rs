@@ -0,0 +1,19 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{MergedNexthops, NmstateFeature};
+
+impl MergedNexthops {
+    pub(crate) fn get_features(&self) -> Vec<NmstateFeature> {
+        if self
+            .desired
+            .config
+            .as_ref()
+            .map(|nhs| !nhs.is_empty())
+            .unwrap_or_default()
+        {
+            vec![NmstateFeature::StaticNexthop]
+        } else {
+            Vec::new()
+        }
+    }
+}
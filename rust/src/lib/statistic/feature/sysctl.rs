@@ -0,0 +1,13 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{MergedIpForwarding, NmstateFeature};
+
+impl MergedIpForwarding {
+    pub(crate) fn get_features(&self) -> Vec<NmstateFeature> {
+        if self.desired.is_some() {
+            vec![NmstateFeature::IpForwarding]
+        } else {
+            Vec::new()
+        }
+    }
+}
@@ -43,9 +43,12 @@ impl NetworkState {
         features.append(&mut merged_state.dns.get_features());
         features.append(&mut merged_state.routes.get_features());
         features.append(&mut merged_state.rules.get_features());
+        features.append(&mut merged_state.nexthops.get_features());
+        features.append(&mut merged_state.neighbors.get_features());
         features.append(&mut merged_state.ovsdb.get_features());
         features.append(&mut merged_state.ovn.get_features());
         features.append(&mut merged_state.hostname.get_features());
+        features.append(&mut merged_state.ip_forwarding.get_features());
 
         features.sort_unstable();
 
@@ -0,0 +1,338 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use crate::{ErrorKind, NmstateError};
+
+const ARP_IGNORE_VALID_VALUES: [u8; 5] = [0, 1, 2, 3, 8];
+const ARP_ANNOUNCE_MAX: u8 = 2;
+const ACCEPT_RA_MAX: u8 = 2;
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+/// Per-interface IPv4 kernel parameters stored in
+/// `/proc/sys/net/ipv4/conf/<iface_name>/`.
+/// When querying, only parameters holding different value from kernel
+/// default(`/proc/sys/net/ipv4/conf/default/`) are shown.
+/// When applying, `None` means preserve current value.
+/// In NetworkManager mode, only `forwarding` is supported, changing other
+/// properties will fail with [crate::ErrorKind::NotSupportedError].
+///
+/// Example yaml output:
+/// ```yaml
+/// interfaces:
+/// - name: eth1
+///   type: ethernet
+///   ipv4:
+///     enabled: true
+///     sysctl:
+///       forwarding: true
+///       rp-filter: loose
+///       arp-ignore: 1
+/// ```
+pub struct Ipv4Sysctl {
+    /// Forwarding IPv4 packets received on this interface.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_bool_or_string"
+    )]
+    pub forwarding: Option<bool>,
+    /// Reverse path filtering.
+    /// Serialize and deserialize to/from `rp-filter`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rp_filter: Option<RpFilter>,
+    /// Accept packets with local source addresses.
+    /// Serialize and deserialize to/from `accept-local`.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_bool_or_string"
+    )]
+    pub accept_local: Option<bool>,
+    /// Reply ARP request on behalf of other hosts reachable via this host.
+    /// Serialize and deserialize to/from `proxy-arp`.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_bool_or_string"
+    )]
+    pub proxy_arp: Option<bool>,
+    /// Mode for sending replies in response to received ARP requests.
+    /// Valid values are 0, 1, 2, 3 and 8, please refer to kernel document
+    /// `ip-sysctl.rst` for detail.
+    /// Serialize and deserialize to/from `arp-ignore`.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u8_or_string"
+    )]
+    pub arp_ignore: Option<u8>,
+    /// Restriction level for announcing the local source IP address in ARP
+    /// requests. Valid values are 0, 1 and 2, please refer to kernel
+    /// document `ip-sysctl.rst` for detail.
+    /// Serialize and deserialize to/from `arp-announce`.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u8_or_string"
+    )]
+    pub arp_announce: Option<u8>,
+}
+
+impl Ipv4Sysctl {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    pub(crate) fn sanitize(&self) -> Result<(), NmstateError> {
+        if let Some(v) = self.arp_ignore {
+            if !ARP_IGNORE_VALID_VALUES.contains(&v) {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "Invalid IPv4 sysctl arp-ignore value {v}, \
+                        should be one of {ARP_IGNORE_VALID_VALUES:?}"
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
+        if let Some(v) = self.arp_announce {
+            if v > ARP_ANNOUNCE_MAX {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "Invalid IPv4 sysctl arp-announce value {v}, \
+                        should be in the range of 0 to {ARP_ANNOUNCE_MAX}"
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+/// Per-interface IPv6 kernel parameters stored in
+/// `/proc/sys/net/ipv6/conf/<iface_name>/`.
+/// When querying, only parameters holding different value from kernel
+/// default(`/proc/sys/net/ipv6/conf/default/`) are shown.
+/// When applying, `None` means preserve current value.
+/// In NetworkManager mode, only `use-tempaddr` is supported, changing other
+/// properties will fail with [crate::ErrorKind::NotSupportedError].
+/// To disable IPv6, please use `enabled: false` of [crate::InterfaceIpv6].
+///
+/// Example yaml output:
+/// ```yaml
+/// interfaces:
+/// - name: eth1
+///   type: ethernet
+///   ipv6:
+///     enabled: true
+///     sysctl:
+///       forwarding: true
+///       accept-ra: 2
+///       use-tempaddr: prefer-public
+/// ```
+pub struct Ipv6Sysctl {
+    /// Forwarding IPv6 packets received on this interface.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_bool_or_string"
+    )]
+    pub forwarding: Option<bool>,
+    /// Accept IPv6 router advertisements.
+    /// 0 for never, 1 for only when forwarding is disabled, 2 for always.
+    /// Serialize and deserialize to/from `accept-ra`.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u8_or_string"
+    )]
+    pub accept_ra: Option<u8>,
+    /// IPv6 privacy extensions defined in RFC 8981.
+    /// Serialize and deserialize to/from `use-tempaddr`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_tempaddr: Option<Ipv6TempAddr>,
+}
+
+impl Ipv6Sysctl {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    pub(crate) fn sanitize(&self) -> Result<(), NmstateError> {
+        if let Some(v) = self.accept_ra {
+            if v > ACCEPT_RA_MAX {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "Invalid IPv6 sysctl accept-ra value {v}, \
+                        should be in the range of 0 to {ACCEPT_RA_MAX}"
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+/// Reverse path filtering mode defined in RFC 3704.
+pub enum RpFilter {
+    /// No source validation.
+    #[default]
+    Disabled,
+    /// Strict mode, each incoming packet is tested against the FIB and
+    /// dropped if the interface is not the best reverse path.
+    Strict,
+    /// Loose mode, each incoming packet's source address is tested against
+    /// the FIB and dropped if not reachable via any interface.
+    Loose,
+}
+
+impl From<RpFilter> for u8 {
+    fn from(v: RpFilter) -> Self {
+        match v {
+            RpFilter::Disabled => 0,
+            RpFilter::Strict => 1,
+            RpFilter::Loose => 2,
+        }
+    }
+}
+
+impl From<u8> for RpFilter {
+    fn from(d: u8) -> Self {
+        match d {
+            0 => Self::Disabled,
+            1 => Self::Strict,
+            _ => Self::Loose,
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+/// Whether to generate temporary IPv6 addresses defined in RFC 8981.
+pub enum Ipv6TempAddr {
+    /// Do not generate temporary addresses.
+    #[default]
+    Disabled,
+    /// Generate temporary addresses but prefer public addresses.
+    PreferPublic,
+    /// Generate temporary addresses and prefer them over public addresses.
+    PreferTemporary,
+}
+
+impl std::fmt::Display for Ipv6TempAddr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Disabled => "disabled",
+                Self::PreferPublic => "prefer-public",
+                Self::PreferTemporary => "prefer-temporary",
+            }
+        )
+    }
+}
+
+impl From<Ipv6TempAddr> for i32 {
+    fn from(v: Ipv6TempAddr) -> Self {
+        match v {
+            Ipv6TempAddr::Disabled => 0,
+            Ipv6TempAddr::PreferPublic => 1,
+            Ipv6TempAddr::PreferTemporary => 2,
+        }
+    }
+}
+
+impl From<i32> for Ipv6TempAddr {
+    fn from(d: i32) -> Self {
+        match d {
+            i32::MIN..=0 => Self::Disabled,
+            1 => Self::PreferPublic,
+            _ => Self::PreferTemporary,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[non_exhaustive]
+/// Global IP forwarding switches.
+///
+/// Setting IPv4 global forwarding will also change the `forwarding` sysctl
+/// of all interfaces. These settings are applied to kernel directly and not
+/// persistent across reboot, hence changing them is only supported in kernel
+/// mode.
+///
+/// Example yaml output of [crate::NetworkState] with IP forwarding:
+/// ```yaml
+/// ip-forwarding:
+///   ipv4: true
+///   ipv6: false
+/// ```
+pub struct IpForwarding {
+    /// Stored in `/proc/sys/net/ipv4/ip_forward`.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_bool_or_string"
+    )]
+    pub ipv4: Option<bool>,
+    /// Stored in `/proc/sys/net/ipv6/conf/all/forwarding`.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_bool_or_string"
+    )]
+    pub ipv6: Option<bool>,
+}
+
+impl IpForwarding {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub(crate) struct MergedIpForwarding {
+    pub(crate) desired: Option<IpForwarding>,
+    pub(crate) current: Option<IpForwarding>,
+}
+
+impl MergedIpForwarding {
+    pub(crate) fn new(
+        desired: Option<IpForwarding>,
+        current: Option<IpForwarding>,
+    ) -> Self {
+        Self { desired, current }
+    }
+}
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{DnsState, ErrorKind, MergedDnsState};
+use crate::{DnsOverTls, DnsState, Dnssec, ErrorKind, MergedDnsState};
 
 #[test]
 fn test_dns_verify_uncompressed_srvs() {
@@ -182,3 +182,188 @@ fn test_purge_with_empty_server_and_search() {
     let merged = MergedDnsState::new(Some(desired), current).unwrap();
     assert!(merged.is_purge());
 }
+
+#[test]
+fn test_dns_over_tls_srv_sanitize() {
+    let mut desired: DnsState = serde_yaml::from_str(
+        r"---
+        config:
+          dns-over-tls: yes
+          dnssec: allow-downgrade
+          server:
+          - dns+tls://192.0.2.1:853#dns.example.org
+          - dns+tls://[2001:DB8:0::1]:853#dns.example.org
+          - dns+tls://2001:db8::2
+          - 192.0.2.2
+        ",
+    )
+    .unwrap();
+    desired.sanitize().unwrap();
+    let config = desired.config.as_ref().unwrap();
+
+    assert_eq!(config.dns_over_tls, Some(DnsOverTls::Yes));
+    assert_eq!(config.dnssec, Some(Dnssec::AllowDowngrade));
+    assert_eq!(
+        config.server.as_deref(),
+        Some(
+            [
+                "dns+tls://192.0.2.1:853#dns.example.org".to_string(),
+                "dns+tls://[2001:db8::1]:853#dns.example.org".to_string(),
+                "dns+tls://2001:db8::2".to_string(),
+                "192.0.2.2".to_string(),
+            ]
+            .as_slice()
+        )
+    );
+}
+
+#[test]
+fn test_dns_invalid_dns_over_tls_srv() {
+    for srv in [
+        "dns+tls://[2001:db8::1]853#dns.example.org",
+        "dns+tls://192.0.2.1:0",
+        "dns+tls://192.0.2.1#",
+        "dns+tls://dns.example.org",
+        "https://192.0.2.1/dns-query",
+    ] {
+        let mut desired = DnsState::new();
+        desired.config = Some(crate::DnsClientState {
+            server: Some(vec![srv.to_string()]),
+            ..Default::default()
+        });
+        let result = desired.sanitize();
+        assert!(result.is_err(), "{srv} should be invalid");
+        if let Err(e) = result {
+            assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+        }
+    }
+}
+
+#[test]
+fn test_dns_policy_preserved_when_not_mentioned() {
+    let desired: DnsState = serde_yaml::from_str(
+        r"---
+        config:
+          server:
+          - 192.0.2.1
+        ",
+    )
+    .unwrap();
+    let current: DnsState = serde_yaml::from_str(
+        r"---
+        config:
+          dns-over-tls: opportunistic
+          dnssec: yes
+          server:
+          - 192.0.2.251
+        ",
+    )
+    .unwrap();
+    let merged = MergedDnsState::new(Some(desired), current).unwrap();
+
+    assert!(merged.is_changed());
+    assert_eq!(merged.dns_over_tls, Some(DnsOverTls::Opportunistic));
+    assert_eq!(merged.dnssec, Some(Dnssec::Yes));
+}
+
+#[test]
+fn test_dns_policy_change_only() {
+    let desired: DnsState = serde_yaml::from_str(
+        r"---
+        config:
+          dnssec: no
+        ",
+    )
+    .unwrap();
+    let current: DnsState = serde_yaml::from_str(
+        r"---
+        config:
+          dnssec: yes
+          server:
+          - 192.0.2.251
+        ",
+    )
+    .unwrap();
+    let merged = MergedDnsState::new(Some(desired), current).unwrap();
+
+    assert!(merged.is_changed());
+    assert!(merged.is_dns_policy_changed());
+    assert_eq!(merged.servers, vec!["192.0.2.251".to_string()]);
+    assert_eq!(merged.dnssec, Some(Dnssec::No));
+}
+
+#[test]
+fn test_dns_policy_unchanged_on_server_change() {
+    let desired: DnsState = serde_yaml::from_str(
+        r"---
+        config:
+          server:
+          - 192.0.2.252
+        ",
+    )
+    .unwrap();
+    let current: DnsState = serde_yaml::from_str(
+        r"---
+        config:
+          dnssec: yes
+          server:
+          - 192.0.2.251
+        ",
+    )
+    .unwrap();
+    let merged = MergedDnsState::new(Some(desired), current).unwrap();
+
+    assert!(merged.is_changed());
+    assert!(!merged.is_dns_policy_changed());
+    merged.validate_memory_only().unwrap();
+}
+
+#[test]
+fn test_dns_policy_dnssec_change_memory_only() {
+    let desired: DnsState = serde_yaml::from_str(
+        r"---
+        config:
+          dnssec: no
+        ",
+    )
+    .unwrap();
+    let current: DnsState = serde_yaml::from_str(
+        r"---
+        config:
+          dnssec: yes
+        ",
+    )
+    .unwrap();
+    let merged = MergedDnsState::new(Some(desired), current).unwrap();
+
+    let result = merged.validate_memory_only();
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::NotSupportedError);
+    }
+}
+
+#[test]
+fn test_dns_policy_purge() {
+    let desired: DnsState = serde_yaml::from_str(
+        r"---
+        config: {}
+        ",
+    )
+    .unwrap();
+    let current: DnsState = serde_yaml::from_str(
+        r"---
+        config:
+          dns-over-tls: yes
+          dnssec: yes
+          server:
+          - dns+tls://192.0.2.251
+        ",
+    )
+    .unwrap();
+    let merged = MergedDnsState::new(Some(desired), current).unwrap();
+
+    assert!(merged.is_purge());
+    assert_eq!(merged.dns_over_tls, None);
+    assert_eq!(merged.dnssec, None);
+}
@@ -1,8 +1,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    ErrorKind, EthernetInterface, InterfaceIdentifier, InterfaceType,
-    Interfaces, MergedInterfaces,
+    ErrorKind, EthernetInterface, EthernetWakeOnLanMode, InterfaceIdentifier,
+    InterfaceType, Interfaces, MergedInterfaces, NetworkState,
 };
 
 #[test]
@@ -355,3 +355,86 @@ fn test_mac_identifer_check_iface_type_also() {
     );
     assert_eq!(des_iface.base_iface().profile_name.as_deref(), Some("wan0"))
 }
+
+#[test]
+fn test_ethernet_wol_hide_secrets() {
+    let mut state: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  ethernet:
+    wake-on-lan:
+      modes:
+      - secureon
+      secureon-password: 0a:1b:2c:3d:4e:5f
+",
+    )
+    .unwrap();
+
+    state.hide_secrets();
+    let output = serde_yaml::to_string(&state).unwrap();
+    assert!(!output.to_lowercase().contains("0a:1b:2c:3d:4e:5f"));
+    assert!(output.contains(NetworkState::PASSWORD_HID_BY_NMSTATE));
+}
+
+#[test]
+fn test_ethernet_wol_secureon_implies_magic() {
+    let mut iface: EthernetInterface = serde_yaml::from_str(
+        r"---
+name: eth1
+type: ethernet
+state: up
+ethernet:
+  wake-on-lan:
+    modes:
+    - secureon
+    - phy
+    secureon-password: 0a:1b:2c:3d:4e:5f
+",
+    )
+    .unwrap();
+    iface.sanitize().unwrap();
+
+    let wol_conf = iface
+        .ethernet
+        .as_ref()
+        .and_then(|e| e.wake_on_lan.as_ref())
+        .unwrap();
+    assert_eq!(
+        wol_conf.modes,
+        Some(vec![
+            EthernetWakeOnLanMode::Phy,
+            EthernetWakeOnLanMode::Magic,
+            EthernetWakeOnLanMode::Secureon,
+        ])
+    );
+    assert_eq!(
+        wol_conf.secureon_password.as_deref(),
+        Some("0A:1B:2C:3D:4E:5F")
+    );
+}
+
+#[test]
+fn test_ethernet_wol_password_without_secureon() {
+    let mut iface: EthernetInterface = serde_yaml::from_str(
+        r"---
+name: eth1
+type: ethernet
+state: up
+ethernet:
+  wake-on-lan:
+    modes:
+    - magic
+    secureon-password: 0a:1b:2c:3d:4e:5f
+",
+    )
+    .unwrap();
+    let result = iface.sanitize();
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
@@ -1,6 +1,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{EthernetInterface, EthtoolFeatureConfig};
+use crate::{
+    ErrorKind, EthernetInterface, EthtoolFeatureConfig, EthtoolRssHashFunction,
+    Interfaces, MergedInterfaces,
+};
 
 #[test]
 fn test_ethtool_stringlized_attributes() {
@@ -57,6 +60,17 @@ ethtool:
     rx-mini-max: "205"
     tx: "206"
     tx-max: "207"
+  channels:
+    rx: "300"
+    rx-max: "301"
+    tx: "302"
+    tx-max: "303"
+    other: "304"
+    other-max: "305"
+    combined: "306"
+    combined-max: "307"
+  rss:
+    equal: "4"
 
 "#,
     )
@@ -67,6 +81,8 @@ ethtool:
     let pause = ethtool_conf.pause.as_ref().unwrap();
     let coalesce = ethtool_conf.coalesce.as_ref().unwrap();
     let ring = ethtool_conf.ring.as_ref().unwrap();
+    let channels = ethtool_conf.channels.as_ref().unwrap();
+    let rss = ethtool_conf.rss.as_ref().unwrap();
 
     assert_eq!(features.get("rx-checksum"), Some(&true));
     assert_eq!(features.get("rx-gro"), Some(&true));
@@ -112,6 +128,15 @@ ethtool:
     assert_eq!(ring.rx_mini_max, Some(205));
     assert_eq!(ring.tx, Some(206));
     assert_eq!(ring.tx_max, Some(207));
+    assert_eq!(channels.rx, Some(300));
+    assert_eq!(channels.rx_max, Some(301));
+    assert_eq!(channels.tx, Some(302));
+    assert_eq!(channels.tx_max, Some(303));
+    assert_eq!(channels.other, Some(304));
+    assert_eq!(channels.other_max, Some(305));
+    assert_eq!(channels.combined, Some(306));
+    assert_eq!(channels.combined_max, Some(307));
+    assert_eq!(rss.equal, Some(4));
 }
 
 #[test]
@@ -127,3 +152,249 @@ fn test_ethtool_sort_features_when_serialize() {
     let yml_out = serde_yaml::to_string(&features).unwrap();
     assert_eq!(yml_out, "a: true\nb: true\nc: true\n");
 }
+
+#[test]
+fn test_ethtool_rss_equal_with_indirection_table() {
+    let des_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: eth1
+  type: ethernet
+  ethtool:
+    rss:
+      equal: 2
+      indirection-table: [0, 1, 0, 1]
+",
+    )
+    .unwrap();
+
+    let result =
+        MergedInterfaces::new(des_ifaces, Interfaces::new(), false, false);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_ethtool_rss_invalid_hash_key() {
+    let des_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: eth1
+  type: ethernet
+  ethtool:
+    rss:
+      hash-key: 6d:5a:5
+",
+    )
+    .unwrap();
+
+    let result =
+        MergedInterfaces::new(des_ifaces, Interfaces::new(), false, false);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_ethtool_rss_verify_equal() {
+    let des_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: eth1
+  type: ethernet
+  ethtool:
+    rss:
+      hash-function: toeplitz
+      hash-key: 6D:5A:56:DA
+      equal: 2
+",
+    )
+    .unwrap();
+    let cur_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: eth1
+  type: ethernet
+  ethtool:
+    rss:
+      hash-function: toeplitz
+      hash-key: 6d:5a:56:da
+      indirection-table: [0, 1, 2, 3, 0, 1, 2, 3]
+",
+    )
+    .unwrap();
+
+    let merged_ifaces =
+        MergedInterfaces::new(des_ifaces, cur_ifaces, false, false).unwrap();
+
+    let rss = merged_ifaces
+        .kernel_ifaces
+        .get("eth1")
+        .and_then(|i| i.for_verify.as_ref())
+        .and_then(|i| i.base_iface().ethtool.as_ref())
+        .and_then(|e| e.rss.as_ref())
+        .unwrap();
+    assert_eq!(rss.hash_function, Some(EthtoolRssHashFunction::Toeplitz));
+    assert_eq!(rss.equal, None);
+    assert_eq!(rss.indirection_table, Some(vec![0, 1, 0, 1, 0, 1, 0, 1]));
+
+    let expected_cur_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: eth1
+  type: ethernet
+  ethtool:
+    rss:
+      hash-function: toeplitz
+      hash-key: 6d:5a:56:da
+      indirection-table: [0, 1, 0, 1, 0, 1, 0, 1]
+",
+    )
+    .unwrap();
+    merged_ifaces.verify(&expected_cur_ifaces).unwrap();
+}
+
+#[test]
+fn test_ethtool_link_modes_fec_eee_verify() {
+    let des_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: eth1
+  type: ethernet
+  ethtool:
+    link-modes:
+      advertised:
+      - 25000baseSR/Full
+      - 25000baseCR/Full
+    fec:
+      mode: rs
+    eee:
+      enabled: true
+      tx-lpi-timer: '100'
+",
+    )
+    .unwrap();
+    let cur_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: eth1
+  type: ethernet
+  ethtool:
+    link-modes:
+      supported:
+      - 10000baseT/Full
+      - 25000baseCR/Full
+      - 25000baseSR/Full
+      advertised:
+      - 10000baseT/Full
+    fec:
+      mode: auto
+      active: baser
+    eee:
+      enabled: false
+      active: false
+      tx-lpi-enabled: false
+      tx-lpi-timer: 0
+",
+    )
+    .unwrap();
+
+    let merged_ifaces =
+        MergedInterfaces::new(des_ifaces, cur_ifaces, false, false).unwrap();
+
+    let expected_cur_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: eth1
+  type: ethernet
+  ethtool:
+    link-modes:
+      supported:
+      - 10000baseT/Full
+      - 25000baseCR/Full
+      - 25000baseSR/Full
+      advertised:
+      - 25000baseCR/Full
+      - 25000baseSR/Full
+    fec:
+      mode: rs
+      active: rs
+    eee:
+      enabled: true
+      active: true
+      tx-lpi-enabled: false
+      tx-lpi-timer: 100
+",
+    )
+    .unwrap();
+    merged_ifaces.verify(&expected_cur_ifaces).unwrap();
+}
+
+#[test]
+fn test_ethtool_private_flags() {
+    let des_ifaces: Interfaces = serde_yaml::from_str(
+        r#"---
+- name: eth1
+  type: ethernet
+  ethtool:
+    private-flags:
+      disable-fw-lldp: "true"
+"#,
+    )
+    .unwrap();
+    let cur_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: eth1
+  type: ethernet
+  ethtool:
+    private-flags:
+      disable-fw-lldp: false
+      link-down-on-close: false
+",
+    )
+    .unwrap();
+
+    let merged_ifaces =
+        MergedInterfaces::new(des_ifaces, cur_ifaces, false, false).unwrap();
+
+    let private_flags = merged_ifaces
+        .kernel_ifaces
+        .get("eth1")
+        .and_then(|i| i.for_apply.as_ref())
+        .and_then(|i| i.base_iface().ethtool.as_ref())
+        .and_then(|e| e.private_flags.as_ref())
+        .unwrap();
+    assert_eq!(private_flags.get("disable-fw-lldp"), Some(&true));
+
+    let expected_cur_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: eth1
+  type: ethernet
+  ethtool:
+    private-flags:
+      disable-fw-lldp: true
+      link-down-on-close: false
+",
+    )
+    .unwrap();
+    merged_ifaces.verify(&expected_cur_ifaces).unwrap();
+}
+
+#[test]
+fn test_ethtool_netlink_message_ids() {
+    use crate::nispor::ethtool_netlink::*;
+
+    // Should be identical to `ETHTOOL_MSG_*` values defined in kernel
+    // `include/uapi/linux/ethtool_netlink.h`
+    assert_eq!(ETHTOOL_MSG_LINKMODES_GET, 4);
+    assert_eq!(ETHTOOL_MSG_LINKMODES_SET, 5);
+    assert_eq!(ETHTOOL_MSG_WOL_GET, 9);
+    assert_eq!(ETHTOOL_MSG_WOL_SET, 10);
+    assert_eq!(ETHTOOL_MSG_PRIVFLAGS_GET, 13);
+    assert_eq!(ETHTOOL_MSG_PRIVFLAGS_SET, 14);
+    assert_eq!(ETHTOOL_MSG_CHANNELS_GET, 17);
+    assert_eq!(ETHTOOL_MSG_CHANNELS_SET, 18);
+    assert_eq!(ETHTOOL_MSG_EEE_GET, 23);
+    assert_eq!(ETHTOOL_MSG_EEE_SET, 24);
+    assert_eq!(ETHTOOL_MSG_FEC_GET, 29);
+    assert_eq!(ETHTOOL_MSG_FEC_SET, 30);
+    assert_eq!(ETHTOOL_MSG_RSS_GET, 38);
+    assert_eq!(ETHTOOL_MSG_RSS_SET, 48);
+}
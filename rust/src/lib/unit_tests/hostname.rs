@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{ErrorKind, HostNameState, MergedHostNameState};
+
+#[test]
+fn test_hostname_pretty_change_memory_only() {
+    let desired: HostNameState = serde_yaml::from_str(
+        r"---
+        pretty: Test Laptop
+        ",
+    )
+    .unwrap();
+    let current: HostNameState = serde_yaml::from_str(
+        r"---
+        running: host-a.example.org
+        ",
+    )
+    .unwrap();
+    let merged = MergedHostNameState::new(Some(desired), Some(current));
+
+    let result = merged.validate_memory_only();
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::NotSupportedError);
+    }
+}
+
+#[test]
+fn test_hostname_pretty_unchanged_memory_only() {
+    let desired: HostNameState = serde_yaml::from_str(
+        r"---
+        running: host-b.example.org
+        pretty: Test Laptop
+        ",
+    )
+    .unwrap();
+    let current: HostNameState = serde_yaml::from_str(
+        r"---
+        running: host-a.example.org
+        pretty: Test Laptop
+        ",
+    )
+    .unwrap();
+    let merged = MergedHostNameState::new(Some(desired), Some(current));
+
+    merged.validate_memory_only().unwrap();
+}
+
+#[test]
+fn test_hostname_pretty_remove_unset_memory_only() {
+    let desired: HostNameState = serde_yaml::from_str(
+        r#"---
+        pretty: ""
+        "#,
+    )
+    .unwrap();
+    let merged =
+        MergedHostNameState::new(Some(desired), Some(HostNameState::default()));
+
+    merged.validate_memory_only().unwrap();
+}
@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{ErrorKind, Interface, Interfaces};
+
+#[test]
+fn test_hsr_interlink() {
+    let mut ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: hsr0
+  type: hsr
+  state: up
+  hsr:
+    port1: eth1
+    port2: eth2
+    interlink: eth3
+    multicast-spec: 40
+    protocol: hsr
+",
+    )
+    .unwrap();
+    let iface = ifaces.kernel_ifaces.get_mut("hsr0").unwrap();
+    iface.sanitize(true).unwrap();
+
+    if let Interface::Hsr(hsr_iface) = iface {
+        assert_eq!(
+            hsr_iface.hsr.as_ref().unwrap().interlink.as_deref(),
+            Some("eth3")
+        );
+    } else {
+        panic!("Should be HSR interface");
+    }
+}
+
+#[test]
+fn test_hsr_prp_with_interlink() {
+    let mut ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: hsr0
+  type: hsr
+  state: up
+  hsr:
+    port1: eth1
+    port2: eth2
+    interlink: eth3
+    multicast-spec: 40
+    protocol: prp
+",
+    )
+    .unwrap();
+    let result = ifaces.kernel_ifaces.get_mut("hsr0").unwrap().sanitize(true);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_hsr_interlink_same_as_port() {
+    let mut ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: hsr0
+  type: hsr
+  state: up
+  hsr:
+    port1: eth1
+    port2: eth2
+    interlink: eth2
+    multicast-spec: 40
+    protocol: hsr
+",
+    )
+    .unwrap();
+    let result = ifaces.kernel_ifaces.get_mut("hsr0").unwrap().sanitize(true);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_hsr_same_port1_and_port2() {
+    let mut ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: hsr0
+  type: hsr
+  state: up
+  hsr:
+    port1: eth1
+    port2: eth1
+    multicast-spec: 40
+    protocol: prp
+",
+    )
+    .unwrap();
+    let result = ifaces.kernel_ifaces.get_mut("hsr0").unwrap().sanitize(true);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
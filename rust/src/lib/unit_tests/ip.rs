@@ -2,7 +2,8 @@
 
 use crate::{
     ip::sanitize_ip_network, unit_tests::testlib::new_eth_iface, BaseInterface,
-    ErrorKind, Interface, InterfaceState, Interfaces, MergedInterfaces,
+    ErrorKind, Interface, InterfaceIpAddrFlag, InterfaceState, Interfaces,
+    Ipv6TempAddr, MergedInterfaces,
 };
 
 fn gen_test_eth_ifaces() -> Interfaces {
@@ -566,3 +567,635 @@ fn test_ip_serlize_allow_extra_address() {
 
     assert_eq!(desired, new);
 }
+
+#[test]
+fn test_ipv6_privacy_dad_and_timeouts() {
+    let mut iface: BaseInterface = serde_yaml::from_str(
+        r#"---
+name: eth1
+type: ethernet
+state: up
+ipv6:
+  enabled: true
+  dhcp: true
+  autoconf: true
+  ip6-privacy: prefer-temporary
+  dad-transmits: "0"
+  ra-timeout: 30
+  dhcp-timeout: "60"
+  mtu-from-ra: false
+"#,
+    )
+    .unwrap();
+
+    iface.sanitize(true).unwrap();
+
+    let ipv6 = iface.ipv6.as_ref().unwrap();
+    assert_eq!(ipv6.ip6_privacy, Some(Ipv6TempAddr::PreferTemporary));
+    assert_eq!(ipv6.dad_transmits, Some(0));
+    assert_eq!(ipv6.ra_timeout, Some(30));
+    assert_eq!(ipv6.dhcp_timeout, Some(60));
+    assert_eq!(ipv6.mtu_from_ra, Some(false));
+
+    let new_iface: BaseInterface =
+        serde_yaml::from_str(&serde_yaml::to_string(&iface).unwrap()).unwrap();
+    assert_eq!(iface, new_iface);
+}
+
+#[test]
+fn test_ipv6_timeouts_ignored_when_auto_disabled() {
+    let mut iface: BaseInterface = serde_yaml::from_str(
+        r#"---
+name: eth1
+type: ethernet
+state: up
+ipv6:
+  enabled: true
+  dhcp: false
+  autoconf: false
+  ra-timeout: 30
+  dhcp-timeout: 60
+"#,
+    )
+    .unwrap();
+
+    iface.sanitize(true).unwrap();
+
+    let ipv6 = iface.ipv6.as_ref().unwrap();
+    assert_eq!(ipv6.ra_timeout, None);
+    assert_eq!(ipv6.dhcp_timeout, None);
+}
+
+#[test]
+fn test_ipv6_invalid_ra_timeout() {
+    let mut iface: BaseInterface = serde_yaml::from_str(
+        r#"---
+name: eth1
+type: ethernet
+state: up
+ipv6:
+  enabled: true
+  dhcp: true
+  autoconf: true
+  ra-timeout: 0
+"#,
+    )
+    .unwrap();
+
+    let result = iface.sanitize(true);
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+        assert!(e.msg().contains("ra-timeout"));
+    }
+}
+
+#[test]
+fn test_ipv6_privacy_conflict_with_use_tempaddr() {
+    let mut iface: BaseInterface = serde_yaml::from_str(
+        r#"---
+name: eth1
+type: ethernet
+state: up
+ipv6:
+  enabled: true
+  ip6-privacy: prefer-public
+  sysctl:
+    use-tempaddr: disabled
+"#,
+    )
+    .unwrap();
+
+    let result = iface.sanitize(true);
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+        assert!(e.msg().contains("ip6-privacy"));
+    }
+}
+
+#[test]
+fn test_ipv4_does_not_support_ip6_privacy() {
+    let result = serde_yaml::from_str::<BaseInterface>(
+        r#"---
+name: eth1
+type: ethernet
+ipv4:
+  enabled: true
+  ip6-privacy: disabled
+"#,
+    );
+
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("ip6-privacy"));
+}
+
+#[test]
+fn test_ipv4_dhcp_extended_options() {
+    let mut iface: BaseInterface = serde_yaml::from_str(
+        r#"---
+name: eth1
+type: ethernet
+state: up
+ipv4:
+  enabled: true
+  dhcp: true
+  dhcp-vendor-class-identifier: PXEClient
+  dhcp-user-class:
+  - ipam
+  dhcp-iaid: "1234"
+  dhcp-fqdn: c9.example.org
+  dhcp-timeout: "90"
+  dhcp-reject-servers:
+  - 192.0.2.1
+  - 198.51.100.0/24
+  dhcp-request-options:
+  - 66
+  - 67
+"#,
+    )
+    .unwrap();
+
+    iface.sanitize(true).unwrap();
+
+    let ipv4 = iface.ipv4.as_ref().unwrap();
+    assert_eq!(
+        ipv4.dhcp_vendor_class_identifier.as_deref(),
+        Some("PXEClient")
+    );
+    assert_eq!(ipv4.dhcp_user_class, Some(vec!["ipam".to_string()]));
+    assert_eq!(ipv4.dhcp_iaid.as_deref(), Some("1234"));
+    assert_eq!(ipv4.dhcp_fqdn.as_deref(), Some("c9.example.org"));
+    assert_eq!(ipv4.dhcp_timeout, Some(90));
+    assert_eq!(
+        ipv4.dhcp_reject_servers,
+        Some(vec![
+            "192.0.2.1/32".to_string(),
+            "198.51.100.0/24".to_string()
+        ])
+    );
+    assert_eq!(ipv4.dhcp_request_options, Some(vec![66, 67]));
+
+    let new_iface: BaseInterface =
+        serde_yaml::from_str(&serde_yaml::to_string(&iface).unwrap()).unwrap();
+    assert_eq!(iface, new_iface);
+}
+
+#[test]
+fn test_ipv4_dhcp_options_ignored_when_dhcp_disabled() {
+    let mut iface: BaseInterface = serde_yaml::from_str(
+        r#"---
+name: eth1
+type: ethernet
+state: up
+ipv4:
+  enabled: true
+  dhcp: false
+  dhcp-vendor-class-identifier: PXEClient
+  dhcp-timeout: 90
+"#,
+    )
+    .unwrap();
+
+    iface.sanitize(true).unwrap();
+
+    let ipv4 = iface.ipv4.as_ref().unwrap();
+    assert_eq!(ipv4.dhcp_vendor_class_identifier, None);
+    assert_eq!(ipv4.dhcp_timeout, None);
+}
+
+#[test]
+fn test_ipv4_dhcp_fqdn_conflict_with_custom_hostname() {
+    let mut iface: BaseInterface = serde_yaml::from_str(
+        r#"---
+name: eth1
+type: ethernet
+state: up
+ipv4:
+  enabled: true
+  dhcp: true
+  dhcp-custom-hostname: c8.example.org
+  dhcp-fqdn: c9.example.org
+"#,
+    )
+    .unwrap();
+
+    let result = iface.sanitize(true);
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_ipv4_dhcp_reject_servers_ipv6() {
+    let mut iface: BaseInterface = serde_yaml::from_str(
+        r#"---
+name: eth1
+type: ethernet
+state: up
+ipv4:
+  enabled: true
+  dhcp: true
+  dhcp-reject-servers:
+  - 2001:db8::1
+"#,
+    )
+    .unwrap();
+
+    let result = iface.sanitize(true);
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_ipv6_dhcp_iaid_and_send_release() {
+    let mut iface: BaseInterface = serde_yaml::from_str(
+        r#"---
+name: eth1
+type: ethernet
+state: up
+ipv6:
+  enabled: true
+  dhcp: true
+  autoconf: true
+  dhcp-iaid: stable
+  dhcp-send-release: "true"
+"#,
+    )
+    .unwrap();
+
+    iface.sanitize(true).unwrap();
+
+    let ipv6 = iface.ipv6.as_ref().unwrap();
+    assert_eq!(ipv6.dhcp_iaid.as_deref(), Some("stable"));
+    assert_eq!(ipv6.dhcp_send_release, Some(true));
+}
+
+#[test]
+fn test_ipv6_invalid_dhcp_iaid() {
+    let mut iface: BaseInterface = serde_yaml::from_str(
+        r#"---
+name: eth1
+type: ethernet
+state: up
+ipv6:
+  enabled: true
+  dhcp: true
+  autoconf: true
+  dhcp-iaid: foo
+"#,
+    )
+    .unwrap();
+
+    let result = iface.sanitize(true);
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+        assert!(e.msg().contains("dhcp-iaid"));
+    }
+}
+
+#[test]
+fn test_ipv6_does_not_support_dhcp_vendor_class_identifier() {
+    let result = serde_yaml::from_str::<BaseInterface>(
+        r#"---
+name: eth1
+type: ethernet
+ipv6:
+  enabled: true
+  dhcp-vendor-class-identifier: PXEClient
+"#,
+    );
+
+    assert!(result.is_err());
+    assert!(result
+        .unwrap_err()
+        .to_string()
+        .contains("dhcp-vendor-class-identifier"));
+}
+
+#[test]
+fn test_ip_remove_dhcp_lease_status_data() {
+    let mut ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: eth1
+  type: ethernet
+  state: up
+  ipv4:
+    enabled: true
+    dhcp: true
+    dhcp-lease:
+      server: 192.0.2.1
+      lease-time: 3600
+      expiry: 1700000000
+      routers:
+      - 192.0.2.1
+      dns-servers:
+      - 192.0.2.53
+      domain-name: example.org
+      ntp-servers:
+      - 192.0.2.123
+      mtu: 1500
+  ipv6:
+    enabled: true
+    dhcp: true
+    autoconf: true
+    dhcp-lease:
+      server: 000100012c8f1e5a525400123456
+      dns-servers:
+      - 2001:db8::53
+      prefix-delegation:
+      - 2001:db8:a::/56
+",
+    )
+    .unwrap();
+    let iface = ifaces.kernel_ifaces.get("eth1").unwrap();
+    let lease = iface
+        .base_iface()
+        .ipv4
+        .as_ref()
+        .and_then(|i| i.dhcp_lease.as_ref())
+        .unwrap();
+    assert_eq!(lease.server.as_deref(), Some("192.0.2.1"));
+    assert_eq!(lease.lease_time, Some(3600));
+    assert_eq!(lease.mtu, Some(1500));
+    let lease = iface
+        .base_iface()
+        .ipv6
+        .as_ref()
+        .and_then(|i| i.dhcp_lease.as_ref())
+        .unwrap();
+    assert_eq!(
+        lease.prefix_delegation,
+        Some(vec!["2001:db8:a::/56".to_string()])
+    );
+
+    ifaces.remove_status_data();
+
+    let iface = ifaces.kernel_ifaces.get("eth1").unwrap();
+    assert_eq!(iface.base_iface().ipv4.as_ref().unwrap().dhcp_lease, None);
+    assert_eq!(iface.base_iface().ipv6.as_ref().unwrap().dhcp_lease, None);
+}
+
+#[test]
+fn test_ip_verify_ignore_dhcp_lease() {
+    let des_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: eth1
+  type: ethernet
+  state: up
+  ipv4:
+    enabled: true
+    dhcp: true
+    dhcp-lease:
+      server: 192.0.2.2
+      lease-time: 600
+",
+    )
+    .unwrap();
+    let cur_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: eth1
+  type: ethernet
+  state: up
+  ipv4:
+    enabled: true
+    dhcp: true
+    dhcp-lease:
+      server: 192.0.2.1
+      lease-time: 3600
+",
+    )
+    .unwrap();
+
+    let merged_ifaces =
+        MergedInterfaces::new(des_ifaces, gen_test_eth_ifaces(), false, false)
+            .unwrap();
+    let merged_iface = merged_ifaces.kernel_ifaces.get("eth1").unwrap();
+    let apply_iface = merged_iface.for_apply.as_ref().unwrap();
+    assert_eq!(
+        apply_iface.base_iface().ipv4.as_ref().unwrap().dhcp_lease,
+        None
+    );
+
+    merged_ifaces.verify(&cur_ifaces).unwrap();
+}
+
+#[test]
+fn test_ipv4_does_not_support_unknown_dhcp_lease_key() {
+    let result = serde_yaml::from_str::<BaseInterface>(
+        r"---
+name: eth1
+type: ethernet
+ipv4:
+  enabled: true
+  dhcp: true
+  dhcp-lease:
+    renew-time: 1800
+",
+    );
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_ip_addr_peer_and_flags() {
+    let des_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: eth1
+  type: ethernet
+  state: up
+  ipv4:
+    enabled: true
+    address:
+    - ip: 192.0.2.1
+      prefix-length: 32
+      peer: 192.0.2.2
+      flags:
+      - noprefixroute
+  ipv6:
+    enabled: true
+    address:
+    - ip: 2001:db8:1::1
+      prefix-length: 64
+      flags:
+      - optimistic
+      - nodad
+      - nodad
+",
+    )
+    .unwrap();
+
+    let merged_ifaces =
+        MergedInterfaces::new(des_ifaces, gen_test_eth_ifaces(), false, false)
+            .unwrap();
+    let apply_iface = merged_ifaces
+        .kernel_ifaces
+        .get("eth1")
+        .and_then(|i| i.for_apply.as_ref())
+        .unwrap();
+
+    let ipv4_addr = &apply_iface
+        .base_iface()
+        .ipv4
+        .as_ref()
+        .unwrap()
+        .addresses
+        .as_ref()
+        .unwrap()[0];
+    assert_eq!(ipv4_addr.peer.unwrap().to_string(), "192.0.2.2");
+    assert_eq!(
+        ipv4_addr.flags.as_deref(),
+        Some([InterfaceIpAddrFlag::Noprefixroute].as_slice())
+    );
+    let ipv6_addr = &apply_iface
+        .base_iface()
+        .ipv6
+        .as_ref()
+        .unwrap()
+        .addresses
+        .as_ref()
+        .unwrap()[0];
+    assert_eq!(ipv6_addr.peer, None);
+    assert_eq!(
+        ipv6_addr.flags.as_deref(),
+        Some(
+            [InterfaceIpAddrFlag::Nodad, InterfaceIpAddrFlag::Optimistic]
+                .as_slice()
+        )
+    );
+}
+
+#[test]
+fn test_ipv4_addr_with_ipv6_only_flag() {
+    let des_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: eth1
+  type: ethernet
+  state: up
+  ipv4:
+    enabled: true
+    address:
+    - ip: 192.0.2.1
+      prefix-length: 24
+      flags:
+      - nodad
+",
+    )
+    .unwrap();
+
+    let result =
+        MergedInterfaces::new(des_ifaces, gen_test_eth_ifaces(), false, false);
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_ip_addr_peer_in_different_family() {
+    let des_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: eth1
+  type: ethernet
+  state: up
+  ipv6:
+    enabled: true
+    address:
+    - ip: 2001:db8:1::1
+      prefix-length: 128
+      peer: 192.0.2.2
+",
+    )
+    .unwrap();
+
+    let result =
+        MergedInterfaces::new(des_ifaces, gen_test_eth_ifaces(), false, false);
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_ip_verify_ignore_undesired_addr_flags() {
+    let des_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: eth1
+  type: ethernet
+  state: up
+  ipv4:
+    enabled: true
+    address:
+    - ip: 192.0.2.1
+      prefix-length: 24
+",
+    )
+    .unwrap();
+    let cur_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: eth1
+  type: ethernet
+  state: up
+  ipv4:
+    enabled: true
+    address:
+    - ip: 192.0.2.1
+      prefix-length: 24
+      flags:
+      - noprefixroute
+",
+    )
+    .unwrap();
+
+    let merged_ifaces =
+        MergedInterfaces::new(des_ifaces, gen_test_eth_ifaces(), false, false)
+            .unwrap();
+
+    merged_ifaces.verify(&cur_ifaces).unwrap();
+}
+
+#[test]
+fn test_ip_verify_addr_flags_mismatch() {
+    let des_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: eth1
+  type: ethernet
+  state: up
+  ipv6:
+    enabled: true
+    address:
+    - ip: 2001:db8:1::1
+      prefix-length: 64
+      flags:
+      - nodad
+",
+    )
+    .unwrap();
+    let cur_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: eth1
+  type: ethernet
+  state: up
+  ipv6:
+    enabled: true
+    address:
+    - ip: 2001:db8:1::1
+      prefix-length: 64
+",
+    )
+    .unwrap();
+
+    let merged_ifaces =
+        MergedInterfaces::new(des_ifaces, gen_test_eth_ifaces(), false, false)
+            .unwrap();
+
+    let result = merged_ifaces.verify(&cur_ifaces);
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::VerificationError);
+    }
+}
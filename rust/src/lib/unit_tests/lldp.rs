@@ -1,6 +1,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::LldpConfig;
+use crate::{
+    query_apply::{get_lldp_frame_ttl, lldp_frame_to_nmstate},
+    LldpConfig, LldpMedAppType, LldpMedNetworkPolicies, LldpNeighborTlv,
+};
 
 #[test]
 fn test_lldp_stringlized_attributes() {
@@ -33,3 +36,139 @@ fn test_lldp_stringlized_attributes() {
         assert!(!conf.enabled);
     }
 }
+
+#[test]
+fn test_lldp_med_network_policy_parse() {
+    let frame: Vec<u8> = vec![
+        0x01, 0x80, 0xc2, 0x00, 0x00, 0x0e, // Destination MAC
+        0x00, 0x23, 0x45, 0x67, 0x89, 0x1b, // Source MAC
+        0x88, 0xcc, // Ether type
+        0x02, 0x07, 0x04, 0x00, 0x23, 0x45, 0x67, 0x89,
+        0x1b, // Chassis ID
+        0x04, 0x04, 0x05, b'g', b'i', b'1', // Port ID
+        0x06, 0x02, 0x00, 0x78, // TTL
+        // LLDP-MED network policy: voice, tagged, VLAN 200, priority 5,
+        // DSCP 46
+        0xfe, 0x08, 0x00, 0x12, 0xbb, 0x02, 0x01, 0x41, 0x91, 0x6e,
+        // LLDP-MED network policy: voice signaling, unknown policy
+        0xfe, 0x08, 0x00, 0x12, 0xbb, 0x02, 0x02, 0x80, 0x00, 0x00,
+        // LLDP-MED capabilities, ignored
+        0xfe, 0x07, 0x00, 0x12, 0xbb, 0x01, 0x00, 0x33, 0x03, // End
+        0x00, 0x00,
+    ];
+
+    let policies = LldpMedNetworkPolicies::from_lldp_frame(&frame).unwrap();
+
+    assert_eq!(policies.lldp_med_network_policies.len(), 2);
+    let voice = &policies.lldp_med_network_policies[0];
+    assert_eq!(voice.application_type, LldpMedAppType::Voice);
+    assert!(!voice.unknown_policy);
+    assert!(voice.tagged);
+    assert_eq!(voice.vid, 200);
+    assert_eq!(voice.priority, 5);
+    assert_eq!(voice.dscp, 46);
+    let signaling = &policies.lldp_med_network_policies[1];
+    assert_eq!(signaling.application_type, LldpMedAppType::VoiceSignaling);
+    assert!(signaling.unknown_policy);
+}
+
+#[test]
+fn test_lldp_med_network_policy_parse_truncated_frame() {
+    let frame: Vec<u8> = vec![
+        0x01, 0x80, 0xc2, 0x00, 0x00, 0x0e, 0x00, 0x23, 0x45, 0x67, 0x89, 0x1b,
+        0x88, 0xcc, 0xfe, 0x08, 0x00, 0x12, 0xbb, 0x02,
+    ];
+
+    assert_eq!(LldpMedNetworkPolicies::from_lldp_frame(&frame), None);
+}
+
+// Frame captured from Extreme Summit300-48 switch
+const SUMMIT300_LLDP_FRAME: &str = "\
+    0180c200000e000130f9ada088cc020704000130f9ada0040405312f3106020078\
+    081753756d6d69743330302d34382d506f72742031303031000a0d53756d6d6974\
+    3330302d3438000c4c53756d6d69743330302d3438202d2056657273696f6e2037\
+    2e34652e3120284275696c642035292062792052656c656173655f4d6173746572\
+    2030352f32372f30352030343a35333a3131000e0400140014100e0706000130f9\
+    ada002000003e900fe0700120f02070100fe0900120f01036c000010fe0900120f\
+    030100000000fe0600120f0405f2fe060080c20101e8fe070080c202010000fe17\
+    0080c20301e81076322d303438382d30332d3035303500fe050080c20400000000\
+    00";
+
+fn hex_to_bytes(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_lldp_frame_to_nmstate() {
+    let frame = hex_to_bytes(SUMMIT300_LLDP_FRAME);
+
+    let tlvs = lldp_frame_to_nmstate(&frame);
+
+    let expected: serde_yaml::Value = serde_yaml::from_str(
+        r#"
+- system-name: Summit300-48
+  type: 5
+- system-description: Summit300-48 - Version 7.4e.1 (Build 5) by Release_Master
+    05/27/05 04:53:11
+  type: 6
+- system-capabilities:
+  - MAC Bridge component
+  - Router
+  type: 7
+- _description: MAC address
+  chassis-id: 00:01:30:F9:AD:A0
+  chassis-id-type: 4
+  type: 1
+- _description: Interface name
+  port-id: 1/1
+  port-id-type: 5
+  type: 2
+- ieee-802-1-vlans:
+  - name: v2-0488-03-0505
+    vid: 488
+  oui: 00:80:c2
+  subtype: 3
+  type: 127
+- ieee-802-3-mac-phy-conf:
+    autoneg: true
+    operational-mau-type: 16
+    pmd-autoneg-cap: 27648
+  oui: 00:12:0f
+  subtype: 1
+  type: 127
+- ieee-802-1-ppvids:
+  - 0
+  oui: 00:80:c2
+  subtype: 2
+  type: 127
+- management-addresses:
+  - address: 00:01:30:F9:AD:A0
+    address-subtype: MAC
+    interface-number: 1001
+    interface-number-subtype: 2
+  type: 8
+- ieee-802-3-max-frame-size: 1522
+  oui: 00:12:0f
+  subtype: 4
+  type: 127
+"#,
+    )
+    .unwrap();
+    assert_eq!(serde_yaml::to_value(&tlvs).unwrap(), expected);
+    assert_eq!(get_lldp_frame_ttl(&frame), Some(120));
+}
+
+#[test]
+fn test_lldp_frame_to_nmstate_truncated() {
+    let frame = hex_to_bytes(SUMMIT300_LLDP_FRAME);
+
+    // Cut in the middle of system name TLV
+    let tlvs = lldp_frame_to_nmstate(&frame[..60]);
+
+    assert_eq!(tlvs.len(), 2);
+    assert!(matches!(tlvs[0], LldpNeighborTlv::ChassisId(_)));
+    assert!(matches!(tlvs[1], LldpNeighborTlv::PortId(_)));
+}
@@ -0,0 +1,219 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    unit_tests::testlib::new_eth_iface, ErrorKind, Interface, Interfaces,
+    MacSecSaConfig, MergedInterfaces, NetworkState,
+};
+
+const STATIC_SAK_YAML: &str = r"---
+- name: macsec0
+  type: macsec
+  state: up
+  macsec:
+    encrypt: true
+    base-iface: eth1
+    port: 1
+    validation: strict
+    send-sci: true
+    static-sak:
+      tx-sa:
+        an: 1
+        pn: 1
+        key-id: 01
+        key: 81A6BD5B5E0BBC4E63DA2CFD1B9B3EDA
+      rx-sc:
+      - address: 52:54:00:aa:bb:cc
+        port: 1
+        sa:
+        - an: 0
+          pn: 1
+          key-id: '02'
+          key: 3dd7a7e2b6c1c2b97c3a1e1e1d2f7f40
+";
+
+#[test]
+fn test_macsec_static_sak_sanitize() {
+    let mut ifaces: Interfaces = serde_yaml::from_str(STATIC_SAK_YAML).unwrap();
+    let iface = ifaces.kernel_ifaces.get_mut("macsec0").unwrap();
+    iface.sanitize(true).unwrap();
+
+    if let Interface::MacSec(macsec_iface) = iface {
+        let static_sak = macsec_iface
+            .macsec
+            .as_ref()
+            .and_then(|c| c.static_sak.as_ref())
+            .unwrap();
+        let tx_sa = static_sak.tx_sa.as_ref().unwrap();
+        assert_eq!(tx_sa.key_id, "01000000000000000000000000000000");
+        assert_eq!(
+            tx_sa.key.as_deref(),
+            Some("81a6bd5b5e0bbc4e63da2cfd1b9b3eda")
+        );
+        let rx_sc = &static_sak.rx_sc.as_ref().unwrap()[0];
+        assert_eq!(rx_sc.address, "52:54:00:AA:BB:CC");
+        assert_eq!(
+            rx_sc.sa.as_ref().unwrap()[0].key_id,
+            "02000000000000000000000000000000"
+        );
+    } else {
+        panic!("Should be MACsec interface");
+    }
+}
+
+#[test]
+fn test_macsec_static_sak_conflict_with_mka() {
+    let mut ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+        - name: macsec0
+          type: macsec
+          state: up
+          macsec:
+            encrypt: true
+            base-iface: eth1
+            mka-cak: 50b71a8ef0bd5751ea76de6d6c98c03a
+            mka-ckn: f2b4297d39da7330910a74abc0449feb
+            port: 1
+            validation: strict
+            send-sci: true
+            static-sak:
+              tx-sa:
+                an: 0
+                key-id: 01
+                key: 81a6bd5b5e0bbc4e63da2cfd1b9b3eda
+        ",
+    )
+    .unwrap();
+    let result = ifaces
+        .kernel_ifaces
+        .get_mut("macsec0")
+        .unwrap()
+        .sanitize(true);
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_macsec_static_sak_invalid_key() {
+    let mut ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+        - name: macsec0
+          type: macsec
+          state: up
+          macsec:
+            encrypt: true
+            base-iface: eth1
+            port: 1
+            validation: strict
+            send-sci: true
+            static-sak:
+              tx-sa:
+                an: 0
+                key-id: 01
+                key: 81a6bd5b5e0bbc4e
+        ",
+    )
+    .unwrap();
+    let result = ifaces
+        .kernel_ifaces
+        .get_mut("macsec0")
+        .unwrap()
+        .sanitize(true);
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_macsec_static_sak_hide_secrets() {
+    let mut state = NetworkState::new();
+    state.interfaces = serde_yaml::from_str(STATIC_SAK_YAML).unwrap();
+    state.hide_secrets();
+    let output = serde_yaml::to_string(&state).unwrap();
+    assert!(!output.contains("81A6BD5B5E0BBC4E63DA2CFD1B9B3EDA"));
+    assert!(!output.contains("3dd7a7e2b6c1c2b97c3a1e1e1d2f7f40"));
+    assert!(!format!("{state:?}").contains("3dd7a7e2b6c1c2b97c3a1e1e1d2f7f40"));
+}
+
+#[test]
+fn test_macsec_static_sak_verify_ignore_pn_and_key() {
+    let mut pre_apply_cur_ifaces = Interfaces::new();
+    pre_apply_cur_ifaces.push(new_eth_iface("eth1"));
+    let desired: Interfaces = serde_yaml::from_str(STATIC_SAK_YAML).unwrap();
+    let mut current: Interfaces = serde_yaml::from_str(
+        r"---
+        - name: macsec0
+          type: macsec
+          state: up
+          macsec:
+            encrypt: true
+            base-iface: eth1
+            port: 1
+            validation: strict
+            send-sci: true
+            static-sak:
+              tx-sa:
+                an: 1
+                pn: 1024
+                key-id: 01000000000000000000000000000000
+                key: <_password_hid_by_nmstate>
+              rx-sc:
+              - address: 52:54:00:AA:BB:CC
+                port: 1
+                sa:
+                - an: 0
+                  pn: 998
+                  key-id: 02000000000000000000000000000000
+                  key: <_password_hid_by_nmstate>
+        ",
+    )
+    .unwrap();
+    current.push(new_eth_iface("eth1"));
+
+    let merged_ifaces =
+        MergedInterfaces::new(desired, pre_apply_cur_ifaces, false, false)
+            .unwrap();
+
+    merged_ifaces.verify(&current).unwrap();
+}
+
+#[test]
+fn test_macsec_static_sak_gcm_aes_256_key_not_supported() {
+    let mut ifaces: Interfaces = serde_yaml::from_str(
+        r#"---
+        - name: macsec0
+          type: macsec
+          state: up
+          macsec:
+            encrypt: true
+            base-iface: eth1
+            port: 1
+            validation: strict
+            send-sci: true
+            static-sak:
+              tx-sa:
+                an: "2"
+                key-id: 01
+                key: 81a6bd5b5e0bbc4e63da2cfd1b9b3eda3dd7a7e2b6c1c2b97c3a1e1e1d2f7f40
+        "#,
+    )
+    .unwrap();
+    let result = ifaces
+        .kernel_ifaces
+        .get_mut("macsec0")
+        .unwrap()
+        .sanitize(true);
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_macsec_sa_debug_without_key() {
+    let sa = MacSecSaConfig::new();
+
+    assert!(format!("{sa:?}").contains("key: None"));
+}
@@ -19,6 +19,12 @@ mod gen_diff;
 #[cfg(test)]
 mod gen_revert;
 #[cfg(test)]
+mod hostname;
+#[cfg(test)]
+mod hsr;
+#[cfg(test)]
+mod ieee8021x;
+#[cfg(test)]
 mod ifaces;
 #[cfg(test)]
 mod ifaces_ctrller;
@@ -35,10 +41,16 @@ mod mac_vlan;
 #[cfg(test)]
 mod mac_vtap;
 #[cfg(test)]
+mod macsec;
+#[cfg(test)]
 mod mptcp;
 #[cfg(test)]
+mod neighbor;
+#[cfg(test)]
 mod net_state;
 #[cfg(test)]
+mod nexthop;
+#[cfg(test)]
 mod nm;
 #[cfg(test)]
 mod ovn;
@@ -57,6 +69,8 @@ mod sriov;
 #[cfg(test)]
 mod statistic;
 #[cfg(test)]
+mod sysctl;
+#[cfg(test)]
 mod testlib;
 #[cfg(test)]
 mod vlan;
@@ -0,0 +1,209 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    ErrorKind, MergedNeighbors, NeighborEntry, NeighborState, Neighbors,
+    NetworkState,
+};
+
+fn gen_current_neighbors() -> Neighbors {
+    serde_yaml::from_str(
+        r"
+        config:
+        - ip: 192.0.2.1
+          interface: eth1
+          link-layer-address: 00:23:45:67:89:1A
+        - ip: 2001:db8:1::1
+          interface: eth1
+          link-layer-address: 00:23:45:67:89:1B
+        - ip: 192.0.2.2
+          interface: eth2
+          proxy: true
+        ",
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_neighbor_sanitize() {
+    let mut neigh: NeighborEntry = serde_yaml::from_str(
+        r"
+        ip: 2001:db8:1:0::1
+        interface: eth1
+        link-layer-address: 0:23:45:67:89:1a
+        ",
+    )
+    .unwrap();
+    neigh.sanitize().unwrap();
+
+    assert_eq!(neigh.ip.as_deref(), Some("2001:db8:1::1"));
+    assert_eq!(
+        neigh.link_layer_address.as_deref(),
+        Some("00:23:45:67:89:1A")
+    );
+}
+
+#[test]
+fn test_neighbor_invalid_link_layer_address() {
+    let mut neigh: NeighborEntry = serde_yaml::from_str(
+        r"
+        ip: 192.0.2.1
+        interface: eth1
+        link-layer-address: 00:23:45:67:89:1G
+        ",
+    )
+    .unwrap();
+    let result = neigh.sanitize();
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_neighbor_without_link_layer_address() {
+    let mut neigh: NeighborEntry = serde_yaml::from_str(
+        r"
+        ip: 192.0.2.1
+        interface: eth1
+        ",
+    )
+    .unwrap();
+    let result = neigh.sanitize();
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_neighbor_without_interface() {
+    let mut neigh: NeighborEntry = serde_yaml::from_str(
+        r"
+        ip: 192.0.2.1
+        link-layer-address: 00:23:45:67:89:1A
+        ",
+    )
+    .unwrap();
+    let result = neigh.sanitize();
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_neighbor_proxy_with_link_layer_address() {
+    let mut neigh: NeighborEntry = serde_yaml::from_str(
+        r"
+        ip: 192.0.2.1
+        interface: eth1
+        proxy: true
+        link-layer-address: 00:23:45:67:89:1A
+        ",
+    )
+    .unwrap();
+    let result = neigh.sanitize();
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_neighbor_duplicate() {
+    let desired: Neighbors = serde_yaml::from_str(
+        r"
+        config:
+        - ip: 192.0.2.9
+          interface: eth1
+          link-layer-address: 00:23:45:67:89:1A
+        - ip: 192.0.2.9
+          interface: eth1
+          link-layer-address: 00:23:45:67:89:1B
+        ",
+    )
+    .unwrap();
+    let result = MergedNeighbors::new(desired, gen_current_neighbors());
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_neighbor_absent_wildcard() {
+    let desired: Neighbors = serde_yaml::from_str(
+        r"
+        config:
+        - interface: eth1
+          state: absent
+        - ip: 192.0.2.1
+          interface: eth1
+          link-layer-address: 00:23:45:67:89:2A
+        ",
+    )
+    .unwrap();
+    let merged =
+        MergedNeighbors::new(desired, gen_current_neighbors()).unwrap();
+
+    assert_eq!(merged.for_apply.len(), 2);
+    assert!(merged.for_apply[0].is_absent());
+    assert_eq!(merged.for_apply[0].ip.as_deref(), Some("2001:db8:1::1"));
+    assert!(!merged.for_apply[1].is_absent());
+    assert_eq!(
+        merged.for_apply[1].link_layer_address.as_deref(),
+        Some("00:23:45:67:89:2A")
+    );
+    assert!(merged.is_changed());
+}
+
+#[test]
+fn test_neighbor_no_change() {
+    let desired: Neighbors = serde_yaml::from_str(
+        r"
+        config:
+        - ip: 192.0.2.1
+          interface: eth1
+          link-layer-address: 00:23:45:67:89:1a
+        ",
+    )
+    .unwrap();
+    let merged =
+        MergedNeighbors::new(desired, gen_current_neighbors()).unwrap();
+
+    assert!(!merged.is_changed());
+    assert!(merged.gen_diff().is_empty());
+}
+
+#[test]
+fn test_neighbor_revert() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"
+        neighbors:
+          config:
+          - ip: 192.0.2.1
+            interface: eth1
+            link-layer-address: 00:23:45:67:89:2A
+          - ip: 192.0.2.10
+            interface: eth1
+            link-layer-address: 00:23:45:67:89:2B
+          - ip: 192.0.2.2
+            interface: eth2
+            proxy: true
+            state: absent
+        ",
+    )
+    .unwrap();
+    let current = NetworkState {
+        neighbors: gen_current_neighbors(),
+        ..Default::default()
+    };
+
+    let revert = desired.generate_revert(&current).unwrap();
+    let revert_neighs = revert.neighbors.config.unwrap();
+
+    assert_eq!(revert_neighs.len(), 3);
+    assert_eq!(
+        revert_neighs[0].link_layer_address.as_deref(),
+        Some("00:23:45:67:89:1A")
+    );
+    assert_eq!(revert_neighs[1].state, Some(NeighborState::Absent));
+    assert_eq!(revert_neighs[1].ip.as_deref(), Some("192.0.2.10"));
+    assert!(!revert_neighs[2].is_absent());
+    assert!(revert_neighs[2].is_proxy());
+}
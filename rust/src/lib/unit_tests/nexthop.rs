@@ -0,0 +1,336 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    ErrorKind, MergedNetworkState, MergedNexthops, NetworkState, NexthopEntry,
+    NexthopGroupType, NexthopState, Nexthops,
+};
+
+fn gen_current_nexthops() -> Nexthops {
+    serde_yaml::from_str(
+        r"
+        config:
+        - id: 1
+          family: ipv4
+          next-hop-interface: eth1
+          next-hop-address: 192.0.2.1
+        - id: 2
+          family: ipv4
+          next-hop-interface: eth2
+          next-hop-address: 198.51.100.1
+        - id: 10
+          group:
+          - id: 1
+            weight: 1
+          - id: 2
+            weight: 3
+          group-type: mpath
+        ",
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_nexthop_deserialize_group() {
+    let nh: NexthopEntry = serde_yaml::from_str(
+        r#"
+        id: "20"
+        group:
+        - id: 1
+          weight: "10"
+        - id: 2
+        group-type: resilient
+        resilient:
+          buckets: 64
+          idle-timer: 60
+          unbalanced-timer: 300
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(nh.id, Some(20));
+    assert_eq!(nh.group_type, Some(NexthopGroupType::Resilient));
+    let members = nh.group.as_deref().unwrap();
+    assert_eq!(members[0].weight, Some(10));
+    assert_eq!(members[1].weight, None);
+    let res_conf = nh.resilient.as_ref().unwrap();
+    assert_eq!(res_conf.buckets, Some(64));
+    assert_eq!(res_conf.idle_timer, Some(60));
+    assert_eq!(res_conf.unbalanced_timer, Some(300));
+}
+
+#[test]
+fn test_nexthop_group_match_ignore_order_and_default_weight() {
+    let mut des_nh: NexthopEntry = serde_yaml::from_str(
+        r"
+        id: 10
+        group:
+        - id: 2
+          weight: 3
+        - id: 1
+        ",
+    )
+    .unwrap();
+    des_nh.sanitize().unwrap();
+    let cur_nhs = gen_current_nexthops();
+    let cur_nh = &cur_nhs.config.as_ref().unwrap()[2];
+
+    assert!(des_nh.is_match(cur_nh));
+}
+
+#[test]
+fn test_nexthop_without_id() {
+    let mut nh: NexthopEntry = serde_yaml::from_str(
+        r"
+        next-hop-interface: eth1
+        next-hop-address: 192.0.2.1
+        ",
+    )
+    .unwrap();
+
+    let result = nh.sanitize();
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_nexthop_without_next_hop_iface() {
+    let mut nh: NexthopEntry = serde_yaml::from_str(
+        r"
+        id: 1
+        next-hop-address: 192.0.2.1
+        ",
+    )
+    .unwrap();
+
+    let result = nh.sanitize();
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_nexthop_group_with_next_hop_iface() {
+    let mut nh: NexthopEntry = serde_yaml::from_str(
+        r"
+        id: 10
+        next-hop-interface: eth1
+        group:
+        - id: 1
+        ",
+    )
+    .unwrap();
+
+    let result = nh.sanitize();
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_nexthop_group_invalid_weight() {
+    let mut nh: NexthopEntry = serde_yaml::from_str(
+        r"
+        id: 10
+        group:
+        - id: 1
+          weight: 257
+        ",
+    )
+    .unwrap();
+
+    let result = nh.sanitize();
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_nexthop_resilient_without_resilient_group_type() {
+    let mut nh: NexthopEntry = serde_yaml::from_str(
+        r"
+        id: 10
+        group:
+        - id: 1
+        resilient:
+          buckets: 32
+        ",
+    )
+    .unwrap();
+
+    let result = nh.sanitize();
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_nexthop_family_mismatch() {
+    let mut nh: NexthopEntry = serde_yaml::from_str(
+        r"
+        id: 1
+        family: ipv6
+        next-hop-interface: eth1
+        next-hop-address: 192.0.2.1
+        ",
+    )
+    .unwrap();
+
+    let result = nh.sanitize();
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_merged_nexthops_group_member_not_exist() {
+    let desired: Nexthops = serde_yaml::from_str(
+        r"
+        config:
+        - id: 20
+          group:
+          - id: 1
+          - id: 3
+        ",
+    )
+    .unwrap();
+
+    let result = MergedNexthops::new(desired, gen_current_nexthops());
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_merged_nexthops_nested_group() {
+    let desired: Nexthops = serde_yaml::from_str(
+        r"
+        config:
+        - id: 20
+          group:
+          - id: 1
+          - id: 10
+        ",
+    )
+    .unwrap();
+
+    let result = MergedNexthops::new(desired, gen_current_nexthops());
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_merged_nexthops_duplicate_id() {
+    let desired: Nexthops = serde_yaml::from_str(
+        r"
+        config:
+        - id: 3
+          blackhole: true
+        - id: 3
+          next-hop-interface: eth1
+        ",
+    )
+    .unwrap();
+
+    let result = MergedNexthops::new(desired, Nexthops::new());
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_merged_nexthops_absent() {
+    let desired: Nexthops = serde_yaml::from_str(
+        r"
+        config:
+        - id: 10
+          state: absent
+        ",
+    )
+    .unwrap();
+
+    let merged = MergedNexthops::new(desired, gen_current_nexthops()).unwrap();
+
+    assert!(merged.is_changed());
+    assert_eq!(merged.for_apply.len(), 1);
+    assert_eq!(merged.for_apply[0].id, Some(10));
+    assert_eq!(merged.for_apply[0].state, Some(NexthopState::Absent));
+    assert!(merged.for_apply[0].is_group());
+}
+
+#[test]
+fn test_merged_nexthops_no_change() {
+    let desired: Nexthops = serde_yaml::from_str(
+        r"
+        config:
+        - id: 1
+          next-hop-interface: eth1
+          next-hop-address: 192.0.2.1
+        ",
+    )
+    .unwrap();
+
+    let merged = MergedNexthops::new(desired, gen_current_nexthops()).unwrap();
+
+    assert!(!merged.is_changed());
+    assert!(merged.gen_diff().is_empty());
+}
+
+#[test]
+fn test_merged_nexthops_replace_revert() {
+    let desired: Nexthops = serde_yaml::from_str(
+        r"
+        config:
+        - id: 1
+          next-hop-interface: eth1
+          next-hop-address: 192.0.2.254
+        - id: 3
+          blackhole: true
+        ",
+    )
+    .unwrap();
+
+    let merged = MergedNexthops::new(desired, gen_current_nexthops()).unwrap();
+    let revert = merged.generate_revert();
+    let revert_nhs = revert.config.as_deref().unwrap();
+
+    assert!(merged.is_changed());
+    assert_eq!(revert_nhs.len(), 2);
+    assert_eq!(revert_nhs[0].next_hop_addr.as_deref(), Some("192.0.2.1"));
+    assert_eq!(revert_nhs[1].id, Some(3));
+    assert!(revert_nhs[1].is_absent());
+}
+
+#[test]
+fn test_route_with_nexthop_id_and_next_hop_iface() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"
+        routes:
+          config:
+          - destination: 203.0.113.0/24
+            next-hop-interface: eth1
+            nexthop-id: 10
+        ",
+    )
+    .unwrap();
+
+    let result =
+        MergedNetworkState::new(desired, NetworkState::new(), false, false);
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_route_with_nexthop_id_without_next_hop_iface() {
+    let mut current = NetworkState::new();
+    current.nexthops = gen_current_nexthops();
+    current.routes.config = Some(Vec::new());
+    let desired: NetworkState = serde_yaml::from_str(
+        r"
+        routes:
+          config:
+          - destination: 203.0.113.0/24
+            nexthop-id: 10
+        ",
+    )
+    .unwrap();
+
+    let merged_state =
+        MergedNetworkState::new(desired, current, false, false).unwrap();
+
+    assert!(merged_state.routes.is_changed());
+    assert_eq!(merged_state.routes.changed_routes.len(), 1);
+    assert_eq!(merged_state.routes.changed_routes[0].nexthop_id, Some(10));
+}
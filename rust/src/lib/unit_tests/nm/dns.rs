@@ -386,3 +386,206 @@ fn test_copy_ip_stack_if_marked_for_dns() {
     assert!(iface.base_iface().ipv6.is_some());
     assert!(iface.base_iface().ipv4.is_some());
 }
+
+#[test]
+fn test_dns_skip_iface_holding_iface_dns() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+        dns-resolver:
+          config:
+            server:
+            - 192.0.2.250
+        interfaces:
+          - name: eth1
+            type: ethernet
+            state: up
+            ipv4:
+              enabled: true
+              address:
+              - ip: 192.0.2.251
+                prefix-length: 24
+            dns:
+              server:
+              - 192.0.2.53
+              search:
+              - ~corp.example.com
+          - name: eth2
+            type: ethernet
+            state: up
+            ipv4:
+              enabled: true
+              address:
+              - ip: 198.51.100.1
+                prefix-length: 24",
+    )
+    .unwrap();
+    let current: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+          - name: eth1
+            type: ethernet
+            state: up
+          - name: eth2
+            type: ethernet
+            state: up",
+    )
+    .unwrap();
+
+    let mut merged_state =
+        MergedNetworkState::new(desired, current, false, false).unwrap();
+
+    store_dns_config_to_iface(&mut merged_state, &[], &[]).unwrap();
+
+    let eth1_iface = merged_state
+        .interfaces
+        .get_iface("eth1", InterfaceType::Ethernet)
+        .unwrap()
+        .for_apply
+        .as_ref()
+        .unwrap();
+    let eth2_iface = merged_state
+        .interfaces
+        .get_iface("eth2", InterfaceType::Ethernet)
+        .unwrap()
+        .for_apply
+        .as_ref()
+        .unwrap();
+
+    assert_eq!(eth1_iface.base_iface().ipv4.as_ref().unwrap().dns, None);
+    assert_eq!(
+        eth1_iface.base_iface().dns.as_ref().unwrap().server,
+        Some(vec!["192.0.2.53".to_string()])
+    );
+    assert_eq!(
+        eth2_iface
+            .base_iface()
+            .ipv4
+            .as_ref()
+            .unwrap()
+            .dns
+            .as_ref()
+            .unwrap()
+            .server,
+        Some(vec!["192.0.2.250".to_string()])
+    );
+}
+
+#[test]
+fn test_iface_dns_srv_with_ip_disabled() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+          - name: eth1
+            type: ethernet
+            state: up
+            ipv4:
+              enabled: true
+              address:
+              - ip: 192.0.2.251
+                prefix-length: 24
+            ipv6:
+              enabled: false
+            dns:
+              server:
+              - 2001:db8::53",
+    )
+    .unwrap();
+
+    let result =
+        MergedNetworkState::new(desired, NetworkState::new(), false, false);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_iface_dns_link_local_srv_pointing_to_other_iface() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+          - name: eth1
+            type: ethernet
+            state: up
+            dns:
+              server:
+              - fe80::1%eth2",
+    )
+    .unwrap();
+
+    let result =
+        MergedNetworkState::new(desired, NetworkState::new(), false, false);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_iface_dns_gen_conf() {
+    let net_state: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+          - name: eth1
+            type: ethernet
+            state: up
+            ipv4:
+              enabled: true
+              address:
+              - ip: 192.0.2.251
+                prefix-length: 24
+            ipv6:
+              enabled: true
+              address:
+              - ip: 2001:db8::1
+                prefix-length: 64
+            dns:
+              server:
+              - 2001:db8::53
+              - 192.0.2.53
+              - fe80::53
+              search:
+              - ~corp.example.com
+              priority: 50",
+    )
+    .unwrap();
+
+    let conf = net_state.gen_conf().unwrap();
+    let keyfile = &conf["NetworkManager"][0].1;
+
+    assert!(keyfile.contains("dns=192.0.2.53\n"));
+    assert!(keyfile.contains("dns=2001:db8::53;fe80::53\n"));
+    assert!(keyfile.contains("dns-search=~corp.example.com\n"));
+    assert!(keyfile.contains("dns-priority=50\n"));
+    assert!(keyfile.contains("nmstate.interface.dns=yes\n"));
+}
+
+#[test]
+fn test_dns_over_tls_gen_conf() {
+    let net_state: NetworkState = serde_yaml::from_str(
+        r"---
+        dns-resolver:
+          config:
+            dns-over-tls: yes
+            server:
+            - dns+tls://192.0.2.53:853#dns.example.org
+        interfaces:
+          - name: eth1
+            type: ethernet
+            state: up
+            ipv4:
+              enabled: true
+              address:
+              - ip: 192.0.2.251
+                prefix-length: 24",
+    )
+    .unwrap();
+
+    let conf = net_state.gen_conf().unwrap();
+    let keyfile = &conf["NetworkManager"][0].1;
+
+    assert!(keyfile.contains("dns-over-tls=2\n"));
+    assert!(keyfile.contains("dns=dns+tls://192.0.2.53:853#dns.example.org\n"));
+}
@@ -0,0 +1,109 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{ErrorKind, NetworkState};
+
+#[test]
+fn test_ethtool_rss_gen_conf_not_supported() {
+    let net_state: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+          - name: eth1
+            type: ethernet
+            state: up
+            ethtool:
+              rss:
+                equal: 2",
+    )
+    .unwrap();
+
+    let result = net_state.gen_conf();
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::NotSupportedError);
+    }
+}
+
+#[test]
+fn test_ethtool_link_modes_gen_conf_not_supported() {
+    let net_state: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+          - name: eth1
+            type: ethernet
+            state: up
+            ethtool:
+              link-modes:
+                advertised:
+                - 1000baseT/Full",
+    )
+    .unwrap();
+
+    let result = net_state.gen_conf();
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::NotSupportedError);
+    }
+}
+
+#[test]
+fn test_ethtool_eee_gen_conf() {
+    let net_state: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+          - name: eth1
+            type: ethernet
+            state: up
+            ethtool:
+              eee:
+                enabled: true",
+    )
+    .unwrap();
+
+    let conf = net_state.gen_conf().unwrap();
+    let keyfile = &conf["NetworkManager"][0].1;
+
+    assert!(keyfile.contains("eee-enabled=true\n"));
+}
+
+#[test]
+fn test_ethtool_eee_lpi_gen_conf_not_supported() {
+    let net_state: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+          - name: eth1
+            type: ethernet
+            state: up
+            ethtool:
+              eee:
+                enabled: true
+                tx-lpi-timer: 100",
+    )
+    .unwrap();
+
+    let result = net_state.gen_conf();
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::NotSupportedError);
+    }
+}
+
+#[test]
+fn test_ethtool_private_flags_gen_conf_not_supported() {
+    let net_state: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+          - name: eth1
+            type: ethernet
+            state: up
+            ethtool:
+              private-flags:
+                disable-fw-lldp: true",
+    )
+    .unwrap();
+
+    let result = net_state.gen_conf();
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::NotSupportedError);
+    }
+}
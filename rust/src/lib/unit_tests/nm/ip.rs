@@ -0,0 +1,155 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{nm::nm_gen_conf, ErrorKind, MergedNetworkState, NetworkState};
+
+#[test]
+fn test_nm_dhcp_timeout_preserve_current() {
+    let des_net_state: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+          - name: eth1
+            type: ethernet
+            state: up
+            mtu: 1500",
+    )
+    .unwrap();
+    let cur_net_state: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+          - name: eth1
+            type: ethernet
+            state: up
+            ipv4:
+              enabled: true
+              dhcp: true
+              dhcp-timeout: 90
+            ipv6:
+              enabled: true
+              dhcp: true
+              autoconf: true
+              dhcp-timeout: 60",
+    )
+    .unwrap();
+
+    let merged_state =
+        MergedNetworkState::new(des_net_state, cur_net_state, false, false)
+            .unwrap();
+
+    let conf = nm_gen_conf(&merged_state).unwrap();
+    let keyfile = &conf[0].1;
+
+    assert!(keyfile.contains("dhcp-timeout=90\n"));
+    assert!(keyfile.contains("dhcp-timeout=60\n"));
+    assert!(!keyfile.contains("dhcp-timeout=2147483647\n"));
+}
+
+#[test]
+fn test_nm_ip_sysctl_gen_conf() {
+    let net_state: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+          - name: eth1
+            type: ethernet
+            state: up
+            ipv4:
+              enabled: false
+              sysctl:
+                forwarding: true
+            ipv6:
+              enabled: false
+              sysctl:
+                use-tempaddr: prefer-public",
+    )
+    .unwrap();
+
+    let conf = net_state.gen_conf().unwrap();
+    let keyfile = &conf["NetworkManager"][0].1;
+
+    assert!(keyfile.contains("forwarding=1\n"));
+    assert!(keyfile.contains("ip6-privacy=1\n"));
+}
+
+#[test]
+fn test_nm_ip_sysctl_not_supported() {
+    let net_state: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+          - name: eth1
+            type: ethernet
+            state: up
+            ipv4:
+              enabled: false
+              sysctl:
+                rp-filter: loose",
+    )
+    .unwrap();
+
+    let result = net_state.gen_conf();
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::NotSupportedError);
+    }
+}
+
+#[test]
+fn test_nm_ip_sysctl_allow_current_value() {
+    let des_net_state: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+          - name: eth1
+            type: ethernet
+            state: up
+            ipv4:
+              enabled: false
+              sysctl:
+                rp-filter: loose
+                arp-ignore: 0",
+    )
+    .unwrap();
+    let mut cur_net_state: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+          - name: eth1
+            type: ethernet
+            state: up
+            ipv4:
+              enabled: false
+              sysctl:
+                rp-filter: loose",
+    )
+    .unwrap();
+    if let Some(ip) = cur_net_state
+        .interfaces
+        .kernel_ifaces
+        .get_mut("eth1")
+        .and_then(|i| i.base_iface_mut().ipv4.as_mut())
+    {
+        let mut default = crate::Ipv4Sysctl::new();
+        default.arp_ignore = Some(0);
+        ip.sysctl_default = Some(default);
+    }
+
+    let merged_state =
+        MergedNetworkState::new(des_net_state, cur_net_state, false, false)
+            .unwrap();
+
+    nm_gen_conf(&merged_state).unwrap();
+}
+
+#[test]
+fn test_nm_ip_forwarding_not_supported() {
+    let net_state: NetworkState = serde_yaml::from_str(
+        r"---
+        ip-forwarding:
+          ipv4: true",
+    )
+    .unwrap();
+
+    let result = net_state.gen_conf();
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::NotSupportedError);
+    }
+}
@@ -3,6 +3,10 @@
 #[cfg(test)]
 mod dns;
 #[cfg(test)]
+mod ethtool;
+#[cfg(test)]
+mod ip;
+#[cfg(test)]
 mod route;
 #[cfg(test)]
 mod route_rule;
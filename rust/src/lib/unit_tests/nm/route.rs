@@ -7,8 +7,8 @@ use crate::{
         TEST_IPV4_ADDR1, TEST_IPV4_NET1, TEST_IPV6_ADDR1, TEST_IPV6_NET1,
         TEST_NIC,
     },
-    InterfaceType, Interfaces, MergedNetworkState, NetworkState, RouteEntry,
-    RouteState,
+    ErrorKind, InterfaceType, Interfaces, MergedNetworkState, NetworkState,
+    RouteEntry, RouteState,
 };
 
 #[test]
@@ -212,3 +212,131 @@ routes:
     assert!(eth1_iface.is_up());
     assert!(br0_iface.is_absent());
 }
+
+#[test]
+fn test_route_metrics_gen_conf() {
+    let net_state: NetworkState = serde_yaml::from_str(
+        r"
+        interfaces:
+        - name: eth1
+          type: ethernet
+          state: up
+          ipv4:
+            enabled: true
+            address:
+            - ip: 192.0.2.251
+              prefix-length: 24
+        routes:
+          config:
+          - destination: 198.51.100.0/24
+            next-hop-interface: eth1
+            next-hop-address: 192.0.2.1
+            mtu: 1400
+            advmss: 1360
+            initcwnd: 20
+            initrwnd: 30
+            quickack: true
+            rto-min: 50
+            onlink: true
+            lock:
+            - mtu
+            - initcwnd
+        ",
+    )
+    .unwrap();
+
+    let conf = net_state.gen_conf().unwrap();
+    let keyfile = &conf["NetworkManager"][0].1;
+
+    assert!(keyfile.contains(
+        "route0_options=table=254,mtu=1400,lock-mtu=true,advmss=1360,\
+        initcwnd=20,lock-initcwnd=true,initrwnd=30,quickack=true,\
+        rto_min=50,onlink=true\n"
+    ));
+}
+
+#[test]
+fn test_route_scope_gen_conf() {
+    let net_state: NetworkState = serde_yaml::from_str(
+        r"
+        interfaces:
+        - name: eth1
+          type: ethernet
+          state: up
+          ipv4:
+            enabled: true
+            address:
+            - ip: 192.0.2.251
+              prefix-length: 24
+        routes:
+          config:
+          - destination: 198.51.100.0/24
+            next-hop-interface: eth1
+            scope: link
+            protocol: static
+        ",
+    )
+    .unwrap();
+
+    let conf = net_state.gen_conf().unwrap();
+    let keyfile = &conf["NetworkManager"][0].1;
+
+    assert!(keyfile.contains("route0_options=table=254,scope=253\n"));
+}
+
+#[test]
+fn test_route_pref_not_supported_by_nm() {
+    let mut cur_net_state = NetworkState::new();
+    cur_net_state.interfaces.push(new_eth_iface("eth1"));
+    let des_net_state: NetworkState = serde_yaml::from_str(
+        r"
+        interfaces:
+        - name: eth1
+          type: ethernet
+          state: up
+          ipv6:
+            enabled: true
+            address:
+            - ip: 2001:db8:1::1
+              prefix-length: 64
+        routes:
+          config:
+          - destination: 2001:db8:a::/64
+            next-hop-interface: eth1
+            pref: high
+        ",
+    )
+    .unwrap();
+
+    let mut merged_state =
+        MergedNetworkState::new(des_net_state, cur_net_state, false, false)
+            .unwrap();
+
+    let result = store_route_config(&mut merged_state);
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::NotSupportedError);
+}
+
+#[test]
+fn test_nexthop_not_supported_by_nm() {
+    let mut cur_net_state = NetworkState::new();
+    cur_net_state.interfaces.push(new_eth_iface("eth1"));
+    let des_net_state: NetworkState = serde_yaml::from_str(
+        r"
+        nexthops:
+          config:
+          - id: 1
+            next-hop-interface: eth1
+            next-hop-address: 192.0.2.1
+        ",
+    )
+    .unwrap();
+
+    let mut merged_state =
+        MergedNetworkState::new(des_net_state, cur_net_state, false, false)
+            .unwrap();
+
+    let result = store_route_config(&mut merged_state);
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::NotSupportedError);
+}
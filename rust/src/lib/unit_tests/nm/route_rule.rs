@@ -9,7 +9,7 @@ use crate::{
         TEST_RULE_PRIORITY1, TEST_RULE_PRIORITY2, TEST_TABLE_ID1,
         TEST_TABLE_ID2,
     },
-    InterfaceType, Interfaces, MergedNetworkState, NetworkState,
+    ErrorKind, InterfaceType, Interfaces, MergedNetworkState, NetworkState,
     RouteRuleEntry,
 };
 
@@ -377,3 +377,74 @@ fn test_route_rule_use_loopback() {
         Some(expected_ipv6_rules)
     );
 }
+
+#[test]
+fn test_route_rule_selectors_gen_conf() {
+    let net_state: NetworkState = serde_yaml::from_str(
+        r"
+        interfaces:
+        - name: eth1
+          type: ethernet
+          state: up
+          ipv4:
+            enabled: true
+            address:
+            - ip: 192.0.2.251
+              prefix-length: 24
+        route-rules:
+          config:
+          - ip-from: 198.51.100.0/24
+            priority: 1000
+            route-table: 100
+            iif: eth1
+            ipproto: 6
+            sport:
+              start: 1000
+              end: 2000
+            dport:
+              start: 443
+              end: 443
+            uid-range:
+              start: 1000
+              end: 1999
+            invert: true
+        ",
+    )
+    .unwrap();
+
+    let conf = net_state.gen_conf().unwrap();
+    let keyfile = &conf["NetworkManager"][0].1;
+
+    assert!(keyfile.contains(
+        "priority 1000 not from 198.51.100.0/24 iif eth1 ipproto 6 \
+        sport 1000-2000 dport 443 uidrange 1000-1999 table 100\n"
+    ));
+}
+
+#[test]
+fn test_route_rule_goto_not_supported_by_nm() {
+    let net_state: NetworkState = serde_yaml::from_str(
+        r"
+        interfaces:
+        - name: eth1
+          type: ethernet
+          state: up
+          ipv4:
+            enabled: true
+            address:
+            - ip: 192.0.2.251
+              prefix-length: 24
+        route-rules:
+          config:
+          - ip-from: 198.51.100.0/24
+            priority: 1000
+            goto: 2000
+            iif: eth1
+        ",
+    )
+    .unwrap();
+
+    let result = net_state.gen_conf();
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::NotSupportedError);
+}
@@ -8,7 +8,8 @@ use crate::{
         TEST_IPV4_NET1, TEST_IPV6_ADDR1, TEST_IPV6_ADDR2, TEST_IPV6_NET1,
         TEST_IPV6_NET2, TEST_NIC, TEST_ROUTE_METRIC,
     },
-    ErrorKind, InterfaceType, Interfaces, MergedRoutes, RouteEntry, RouteState,
+    ErrorKind, InterfaceType, Interfaces, MergedRoutes, RouteEntry,
+    RouteMetricLock, RoutePreference, RouteProtocol, RouteScope, RouteState,
     Routes,
 };
 
@@ -617,3 +618,324 @@ fn test_route_without_options_is_match_with_any() {
     assert!(!desired_route.is_match(&not_match_route));
     assert!(desired_route.is_match(&match_route));
 }
+
+#[test]
+fn test_route_metrics_is_match() {
+    let mut desired_route: RouteEntry = serde_yaml::from_str(
+        r"
+        destination: 192.0.2.0/24
+        mtu: 1400
+        advmss: 1360
+        quickack: false
+        lock:
+        - advmss
+        - mtu
+        ",
+    )
+    .unwrap();
+    desired_route.sanitize().unwrap();
+
+    let match_route: RouteEntry = serde_yaml::from_str(
+        r"
+        destination: 192.0.2.0/24
+        mtu: 1400
+        advmss: 1360
+        initcwnd: 20
+        lock:
+        - mtu
+        - advmss
+        ",
+    )
+    .unwrap();
+    let not_match_route: RouteEntry = serde_yaml::from_str(
+        r"
+        destination: 192.0.2.0/24
+        mtu: 1500
+        advmss: 1360
+        lock:
+        - mtu
+        - advmss
+        ",
+    )
+    .unwrap();
+    let not_match_route_2: RouteEntry = serde_yaml::from_str(
+        r"
+        destination: 192.0.2.0/24
+        mtu: 1400
+        advmss: 1360
+        lock:
+        - mtu
+        ",
+    )
+    .unwrap();
+
+    assert_eq!(
+        desired_route.lock,
+        Some(vec![RouteMetricLock::Mtu, RouteMetricLock::Advmss])
+    );
+    assert!(desired_route.is_match(&match_route));
+    assert!(!desired_route.is_match(&not_match_route));
+    assert!(!desired_route.is_match(&not_match_route_2));
+}
+
+#[test]
+fn test_route_lock_undefined_metric() {
+    let mut route: RouteEntry = serde_yaml::from_str(
+        r"
+        destination: 192.0.2.0/24
+        mtu: 1400
+        lock:
+        - initcwnd
+        ",
+    )
+    .unwrap();
+
+    let result = route.sanitize();
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_route_metric_zero_invalid() {
+    let mut route: RouteEntry = serde_yaml::from_str(
+        r"
+        destination: 192.0.2.0/24
+        rto-min: 0
+        ",
+    )
+    .unwrap();
+
+    let result = route.sanitize();
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_route_metrics_change_replace_current() {
+    let (merged_ifaces, _) = gen_merged_ifaces_for_route_test();
+    let current: Routes = serde_yaml::from_str(
+        r"
+        config:
+        - destination: 198.51.100.0/24
+          next-hop-interface: eth1
+          next-hop-address: 192.0.2.1
+          metric: 100
+          table-id: 254
+          mtu: 1500
+        ",
+    )
+    .unwrap();
+    let desired: Routes = serde_yaml::from_str(
+        r"
+        config:
+        - destination: 198.51.100.0/24
+          next-hop-interface: eth1
+          next-hop-address: 192.0.2.1
+          metric: 100
+          table-id: 254
+          mtu: 1400
+          onlink: true
+        ",
+    )
+    .unwrap();
+
+    let merged_routes =
+        MergedRoutes::new(desired, current, &merged_ifaces).unwrap();
+
+    let routes = &merged_routes.merged["eth1"];
+    assert_eq!(routes.len(), 1);
+    assert_eq!(routes[0].mtu, Some(1400));
+    assert_eq!(routes[0].onlink, Some(true));
+    assert_eq!(merged_routes.changed_routes.len(), 1);
+    assert!(!merged_routes.changed_routes[0].is_absent());
+    assert_eq!(merged_routes.changed_routes[0].mtu, Some(1400));
+
+    let diff = merged_routes.gen_diff();
+    assert_eq!(diff.config.as_ref().map(|r| r.len()), Some(1));
+}
+
+#[test]
+fn test_route_scope_protocol_pref_is_match() {
+    let desired_route: RouteEntry = serde_yaml::from_str(
+        r"
+        destination: 2001:db8:a::/64
+        scope: universe
+        protocol: static
+        pref: high
+        ",
+    )
+    .unwrap();
+    let match_route: RouteEntry = serde_yaml::from_str(
+        r"
+        destination: 2001:db8:a::/64
+        protocol: static
+        pref: high
+        ",
+    )
+    .unwrap();
+    let not_match_route: RouteEntry = serde_yaml::from_str(
+        r"
+        destination: 2001:db8:a::/64
+        protocol: static
+        ",
+    )
+    .unwrap();
+    let not_match_route_2: RouteEntry = serde_yaml::from_str(
+        r"
+        destination: 2001:db8:a::/64
+        protocol: ra
+        pref: high
+        ",
+    )
+    .unwrap();
+
+    assert_eq!(desired_route.scope, Some(RouteScope::Global));
+    assert_eq!(desired_route.protocol, Some(RouteProtocol::Static));
+    assert_eq!(desired_route.pref, Some(RoutePreference::High));
+    assert!(desired_route.is_match(&match_route));
+    assert!(!desired_route.is_match(&not_match_route));
+    assert!(!desired_route.is_match(&not_match_route_2));
+}
+
+// Current route always has protocol and scope queried, desired route without
+// them should not be treated as changed.
+#[test]
+fn test_route_unchanged_when_current_has_protocol_and_scope() {
+    let (merged_ifaces, _) = gen_merged_ifaces_for_route_test();
+    let current: Routes = serde_yaml::from_str(
+        r"
+        config:
+        - destination: 198.51.100.0/24
+          next-hop-interface: eth1
+          next-hop-address: 192.0.2.1
+          metric: 100
+          table-id: 254
+          scope: link
+          protocol: static
+        ",
+    )
+    .unwrap();
+    let desired: Routes = serde_yaml::from_str(
+        r"
+        config:
+        - destination: 198.51.100.0/24
+          next-hop-interface: eth1
+          next-hop-address: 192.0.2.1
+          metric: 100
+          table-id: 254
+        ",
+    )
+    .unwrap();
+
+    let merged_routes =
+        MergedRoutes::new(desired, current, &merged_ifaces).unwrap();
+
+    assert!(merged_routes.changed_routes.is_empty());
+}
+
+#[test]
+fn test_route_ipv6_non_global_scope_invalid() {
+    let mut route: RouteEntry = serde_yaml::from_str(
+        r"
+        destination: 2001:db8:a::/64
+        next-hop-interface: eth1
+        scope: link
+        ",
+    )
+    .unwrap();
+
+    let result = route.sanitize();
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_route_ipv4_pref_invalid() {
+    let mut route: RouteEntry = serde_yaml::from_str(
+        r"
+        destination: 192.0.2.0/24
+        next-hop-interface: eth1
+        pref: low
+        ",
+    )
+    .unwrap();
+
+    let result = route.sanitize();
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_route_desired_non_static_protocol_invalid() {
+    let mut route: RouteEntry = serde_yaml::from_str(
+        r"
+        destination: 192.0.2.0/24
+        next-hop-interface: eth1
+        protocol: bgp
+        ",
+    )
+    .unwrap();
+
+    let result = route.sanitize();
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidArgument);
+
+    let mut absent_route: RouteEntry = serde_yaml::from_str(
+        r"
+        state: absent
+        protocol: bgp
+        ",
+    )
+    .unwrap();
+    absent_route.sanitize().unwrap();
+}
+
+#[test]
+fn test_absent_route_filter_by_protocol() {
+    let (merged_ifaces, _) = gen_merged_ifaces_for_route_test();
+    let current: Routes = serde_yaml::from_str(
+        r"
+        config:
+        - destination: 198.51.100.0/24
+          next-hop-interface: eth1
+          next-hop-address: 192.0.2.1
+          table-id: 254
+          protocol: static
+        - destination: 203.0.113.0/24
+          next-hop-interface: eth1
+          next-hop-address: 192.0.2.1
+          table-id: 254
+          protocol: boot
+        - destination: 192.0.2.0/24
+          next-hop-interface: eth1
+          next-hop-address: 0.0.0.0
+          table-id: 254
+          scope: link
+          protocol: boot
+        ",
+    )
+    .unwrap();
+    let desired: Routes = serde_yaml::from_str(
+        r"
+        config:
+        - state: absent
+          protocol: boot
+          scope: global
+        ",
+    )
+    .unwrap();
+
+    let merged_routes =
+        MergedRoutes::new(desired, current, &merged_ifaces).unwrap();
+
+    let routes = &merged_routes.merged["eth1"];
+    assert_eq!(routes.len(), 2);
+    assert_eq!(routes[0].destination.as_deref(), Some("192.0.2.0/24"));
+    assert_eq!(routes[1].destination.as_deref(), Some("198.51.100.0/24"));
+    assert_eq!(merged_routes.changed_routes.len(), 1);
+    assert!(merged_routes.changed_routes[0].is_absent());
+    assert_eq!(
+        merged_routes.changed_routes[0].destination.as_deref(),
+        Some("203.0.113.0/24")
+    );
+}
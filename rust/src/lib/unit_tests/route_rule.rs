@@ -1,8 +1,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    unit_tests::testlib::gen_test_rule_entries, MergedRouteRules,
-    RouteRuleEntry, RouteRules,
+    unit_tests::testlib::gen_test_rule_entries, ErrorKind, MergedRouteRules,
+    RouteRuleEntry, RouteRulePortRange, RouteRuleUidRange, RouteRules,
 };
 
 #[test]
@@ -320,3 +320,243 @@ fn test_route_rule_auto_priority_increasing_from_empty() {
     );
     assert_eq!(rules[2].priority, Some(30002));
 }
+
+#[test]
+fn test_route_rule_selectors() {
+    let mut rule: RouteRuleEntry = serde_yaml::from_str(
+        r"
+        ip-from: 192.0.2.0/24
+        priority: 1000
+        route-table: 100
+        ipproto: 6
+        sport:
+          start: 1000
+          end: 2000
+        dport:
+          start: '443'
+          end: '443'
+        uidrange:
+          start: 1000
+          end: 1999
+        oif: eth1
+        tos: 8
+        not: true
+        ",
+    )
+    .unwrap();
+    rule.sanitize().unwrap();
+
+    assert_eq!(rule.ipproto, Some(6));
+    assert_eq!(rule.sport, Some(RouteRulePortRange::new(1000, 2000)));
+    assert_eq!(rule.dport, Some(RouteRulePortRange::new(443, 443)));
+    assert_eq!(rule.uid_range, Some(RouteRuleUidRange::new(1000, 1999)));
+    assert_eq!(rule.oif.as_deref(), Some("eth1"));
+    assert_eq!(rule.tos, Some(8));
+    assert_eq!(rule.invert, Some(true));
+
+    let yaml = serde_yaml::to_string(&rule).unwrap();
+    assert!(yaml.contains("uid-range:"));
+    assert!(yaml.contains("invert: true"));
+}
+
+#[test]
+fn test_route_rule_selectors_not_match() {
+    let rule: RouteRuleEntry = serde_yaml::from_str(
+        r"
+        ip-from: 192.0.2.0/24
+        priority: 1000
+        route-table: 100
+        ipproto: 17
+        dport:
+          start: 53
+          end: 53
+        ",
+    )
+    .unwrap();
+    let mut other = rule.clone();
+    other.dport = Some(RouteRulePortRange::new(5353, 5353));
+
+    assert!(!rule.is_match(&other));
+    assert_ne!(rule, other);
+
+    other.dport = rule.dport;
+    other.invert = Some(false);
+    assert!(rule.is_match(&other));
+}
+
+#[test]
+fn test_route_rule_invalid_port_range() {
+    let mut rule: RouteRuleEntry = serde_yaml::from_str(
+        r"
+        ip-from: 192.0.2.0/24
+        sport:
+          start: 2000
+          end: 1000
+        ",
+    )
+    .unwrap();
+    let result = rule.sanitize();
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_route_rule_tos_and_dscp() {
+    let mut rule: RouteRuleEntry = serde_yaml::from_str(
+        r"
+        ip-from: 192.0.2.0/24
+        tos: 8
+        dscp: 2
+        ",
+    )
+    .unwrap();
+    let result = rule.sanitize();
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_route_rule_invalid_dscp() {
+    let mut rule: RouteRuleEntry = serde_yaml::from_str(
+        r"
+        ip-from: 192.0.2.0/24
+        dscp: 64
+        ",
+    )
+    .unwrap();
+    let result = rule.sanitize();
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_route_rule_goto_with_table() {
+    let mut rule: RouteRuleEntry = serde_yaml::from_str(
+        r"
+        ip-from: 192.0.2.0/24
+        priority: 1000
+        route-table: 100
+        goto: 2000
+        ",
+    )
+    .unwrap();
+    let result = rule.sanitize();
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_route_rule_goto_backward() {
+    let mut rule: RouteRuleEntry = serde_yaml::from_str(
+        r"
+        ip-from: 192.0.2.0/24
+        priority: 1000
+        goto: 500
+        ",
+    )
+    .unwrap();
+    let result = rule.sanitize();
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_route_rule_goto_and_l3mdev_no_default_table() {
+    let mut rule: RouteRuleEntry = serde_yaml::from_str(
+        r"
+        ip-from: 192.0.2.0/24
+        priority: 1000
+        goto: 2000
+        ",
+    )
+    .unwrap();
+    rule.sanitize().unwrap();
+    assert_eq!(rule.table_id, None);
+
+    let mut rule: RouteRuleEntry = serde_yaml::from_str(
+        r"
+        family: ipv4
+        priority: 1000
+        l3mdev: true
+        ",
+    )
+    .unwrap();
+    rule.sanitize().unwrap();
+    assert_eq!(rule.table_id, None);
+}
+
+#[test]
+fn test_route_rule_l3mdev_with_table() {
+    let mut rule: RouteRuleEntry = serde_yaml::from_str(
+        r"
+        family: ipv4
+        l3mdev: true
+        route-table: 100
+        ",
+    )
+    .unwrap();
+    let result = rule.sanitize();
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_nl_rule_to_nmstate() {
+    use rtnetlink::{
+        packet_core::DefaultNla,
+        packet_route::{
+            route::RouteProtocol,
+            rule::{
+                RuleAction, RuleAttribute, RuleFlags, RuleMessage,
+                RulePortRange,
+            },
+            AddressFamily as NlAddressFamily, IpProtocol,
+        },
+    };
+
+    let mut rule_msg = RuleMessage::default();
+    rule_msg.header.family = NlAddressFamily::Inet;
+    rule_msg.header.dst_len = 24;
+    rule_msg.header.action = RuleAction::ToTable;
+    rule_msg.header.flags = RuleFlags::Invert;
+    rule_msg.attributes = vec![
+        RuleAttribute::Table(100),
+        RuleAttribute::Priority(1000),
+        RuleAttribute::Destination("192.0.2.0".parse().unwrap()),
+        RuleAttribute::Protocol(RouteProtocol::Static),
+        RuleAttribute::Oifname("eth1".to_string()),
+        RuleAttribute::IpProtocol(IpProtocol::Tcp),
+        RuleAttribute::DestinationPortRange(RulePortRange {
+            start: 80,
+            end: 8080,
+        }),
+        RuleAttribute::Other(DefaultNla::new(25, vec![10])),
+    ];
+
+    let rule =
+        crate::nispor::nl_rule_to_nmstate(&rule_msg, &[RouteProtocol::Static])
+            .unwrap();
+
+    assert_eq!(rule.family, Some(crate::AddressFamily::IPv4));
+    assert_eq!(rule.table_id, Some(100));
+    assert_eq!(rule.priority, Some(1000));
+    assert_eq!(rule.ip_to.as_deref(), Some("192.0.2.0/24"));
+    assert_eq!(rule.oif.as_deref(), Some("eth1"));
+    assert_eq!(rule.ipproto, Some(6));
+    assert_eq!(rule.dport, Some(RouteRulePortRange::new(80, 8080)));
+    assert_eq!(rule.dscp, Some(10));
+    assert_eq!(rule.invert, Some(true));
+    assert_eq!(rule.action, None);
+
+    assert!(crate::nispor::nl_rule_to_nmstate(
+        &rule_msg,
+        &[RouteProtocol::Boot]
+    )
+    .is_none());
+}
@@ -869,3 +869,184 @@ fn test_sriov_not_allow_802_1ad_vlan_protocol_for_vlan_0_and_qos_0() {
         assert_eq!(e.kind(), ErrorKind::InvalidArgument);
     }
 }
+
+#[test]
+fn test_resolve_sriov_representor_port_name_ovs_bridge() {
+    let current = serde_yaml::from_str::<Interfaces>(
+        r"---
+        - name: eth1
+          type: ethernet
+          state: up
+          ethernet:
+            sr-iov:
+              total-vfs: 2
+              eswitch:
+                mode: switchdev
+              vfs:
+              - id: 0
+                iface-name: eth1v0
+                representor: eth1_0
+              - id: 1
+                iface-name: eth1v1
+                representor: eth1_1
+        ",
+    )
+    .unwrap();
+    let mut desired = serde_yaml::from_str::<Interfaces>(
+        r"---
+        - name: ovs-br0
+          type: ovs-bridge
+          state: up
+          bridge:
+            port:
+            - name: sriov-rep:eth1:0
+            - name: sriov:eth1:1
+        ",
+    )
+    .unwrap();
+    desired.resolve_sriov_reference(&current).unwrap();
+    let br_iface = desired
+        .get_iface("ovs-br0", InterfaceType::OvsBridge)
+        .unwrap();
+    let ports = br_iface.ports().unwrap();
+    assert_eq!(ports, vec!["eth1_0", "eth1v1"]);
+}
+
+#[test]
+fn test_failed_to_resolve_sriov_representor_in_legacy_mode() {
+    let current = gen_sriov_current_ifaces();
+    let mut desired = serde_yaml::from_str::<Interfaces>(
+        r"---
+        - name: sriov-rep:eth1:0
+          type: ethernet
+          state: up
+        ",
+    )
+    .unwrap();
+    let result = desired.resolve_sriov_reference(&current);
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_sriov_vf_link_state_ignore_stats() {
+    let mut pre_apply_cur_ifaces = Interfaces::new();
+    pre_apply_cur_ifaces.push(new_eth_iface("eth1"));
+    let desired = serde_yaml::from_str::<Interfaces>(
+        r"---
+        - name: eth1
+          type: ethernet
+          state: up
+          ethernet:
+            sr-iov:
+              total-vfs: 1
+              vfs:
+              - id: 0
+                link-state: enable
+                query-rss-en: true
+                stats:
+                  rx-packets: 100
+                  tx-packets: 100
+                  rx-bytes: 10000
+                  tx-bytes: 10000
+                  broadcast: 0
+                  multicast: 0
+                  rx-dropped: 0
+                  tx-dropped: 0
+        ",
+    )
+    .unwrap();
+    let mut current = serde_yaml::from_str::<Interfaces>(
+        r"---
+        - name: eth1
+          type: ethernet
+          state: up
+          ethernet:
+            sr-iov:
+              total-vfs: 1
+              vfs:
+              - id: 0
+                iface-name: eth1v0
+                link-state: enable
+                query-rss-en: true
+                stats:
+                  rx-packets: 200
+                  tx-packets: 200
+                  rx-bytes: 20000
+                  tx-bytes: 20000
+                  broadcast: 1
+                  multicast: 1
+                  rx-dropped: 0
+                  tx-dropped: 0
+        ",
+    )
+    .unwrap();
+    current.push(new_eth_iface("eth1v0"));
+    current.remove_status_data();
+    if let Some(Interface::Ethernet(eth_iface)) =
+        current.get_iface("eth1", InterfaceType::Ethernet)
+    {
+        let vfs = eth_iface
+            .ethernet
+            .as_ref()
+            .and_then(|e| e.sr_iov.as_ref())
+            .and_then(|s| s.vfs.as_ref())
+            .unwrap();
+        assert_eq!(vfs[0].stats, None);
+    } else {
+        panic!("Should be ethernet interface");
+    }
+
+    let merged_ifaces =
+        MergedInterfaces::new(desired, pre_apply_cur_ifaces, false, false)
+            .unwrap();
+
+    merged_ifaces.verify(&current).unwrap();
+}
+
+#[test]
+fn test_sriov_vf_link_state_verify_failure() {
+    let mut pre_apply_cur_ifaces = Interfaces::new();
+    pre_apply_cur_ifaces.push(new_eth_iface("eth1"));
+    let desired = serde_yaml::from_str::<Interfaces>(
+        r"---
+        - name: eth1
+          type: ethernet
+          state: up
+          ethernet:
+            sr-iov:
+              total-vfs: 1
+              vfs:
+              - id: 0
+                link-state: enable
+        ",
+    )
+    .unwrap();
+    let current = serde_yaml::from_str::<Interfaces>(
+        r"---
+        - name: eth1
+          type: ethernet
+          state: up
+          ethernet:
+            sr-iov:
+              total-vfs: 1
+              vfs:
+              - id: 0
+                link-state: auto
+                query-rss-en: false
+        ",
+    )
+    .unwrap();
+
+    let merged_ifaces =
+        MergedInterfaces::new(desired, pre_apply_cur_ifaces, false, false)
+            .unwrap();
+
+    let result = merged_ifaces.verify(&current);
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::VerificationError);
+    }
+}
@@ -379,6 +379,59 @@ fn test_statistic_feature_dhcp_hostname() {
     )
 }
 
+#[test]
+fn test_statistic_feature_dhcp_options() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r#"---
+        interfaces:
+          - name: eth1
+            type: ethernet
+            state: up
+            ipv4:
+              dhcp: true
+              enabled: true
+              dhcp-vendor-class-identifier: PXEClient
+              dhcp-user-class:
+              - ipam
+              dhcp-iaid: ifname
+              dhcp-fqdn: c9.example.org
+              dhcp-timeout: 60
+              dhcp-reject-servers:
+              - 192.0.2.1
+              dhcp-request-options:
+              - 66
+              - 67
+            ipv6:
+              dhcp: true
+              autoconf: true
+              enabled: true
+              dhcp-iaid: "1234"
+              dhcp-send-release: true
+              dhcp-timeout: 60
+        "#,
+    )
+    .unwrap();
+    let current: NetworkState = serde_yaml::from_str(CUR_STATE_STR).unwrap();
+
+    let stat = desired.statistic(&current).unwrap();
+
+    assert_eq!(
+        stat.features.as_slice(),
+        [
+            NmstateFeature::Dhcpv4Fqdn,
+            NmstateFeature::Dhcpv4Iaid,
+            NmstateFeature::Dhcpv4RejectServers,
+            NmstateFeature::Dhcpv4RequestOptions,
+            NmstateFeature::Dhcpv4Timeout,
+            NmstateFeature::Dhcpv4UserClass,
+            NmstateFeature::Dhcpv4VendorClassIdentifier,
+            NmstateFeature::Dhcpv6Iaid,
+            NmstateFeature::Dhcpv6SendRelease,
+            NmstateFeature::Dhcpv6Timeout,
+        ]
+    )
+}
+
 #[test]
 fn test_statistic_feature_lldp() {
     let desired: NetworkState = serde_yaml::from_str(
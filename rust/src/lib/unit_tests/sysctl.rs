@@ -0,0 +1,269 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    unit_tests::testlib::new_eth_iface, ErrorKind, Interface, Interfaces,
+    Ipv4Sysctl, Ipv6Sysctl, Ipv6TempAddr, MergedInterfaces, NetworkState,
+    RpFilter,
+};
+
+fn gen_test_eth_ifaces() -> Interfaces {
+    let mut ifaces = Interfaces::new();
+    ifaces.push(new_eth_iface("eth1"));
+    ifaces
+}
+
+// Simulate query result which only shows non-default sysctl
+fn gen_current_ifaces() -> Interfaces {
+    let mut ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+        - name: eth1
+          type: ethernet
+          state: up
+          ipv4:
+            enabled: false
+            sysctl:
+              rp-filter: strict
+          ipv6:
+            enabled: false
+        ",
+    )
+    .unwrap();
+    let iface = ifaces.kernel_ifaces.get_mut("eth1").unwrap();
+    let base_iface = iface.base_iface_mut();
+    base_iface.ipv4.as_mut().unwrap().sysctl_default = Some(Ipv4Sysctl {
+        forwarding: Some(false),
+        rp_filter: Some(RpFilter::Loose),
+        accept_local: Some(false),
+        proxy_arp: Some(false),
+        arp_ignore: Some(0),
+        arp_announce: Some(0),
+    });
+    base_iface.ipv6.as_mut().unwrap().sysctl_default = Some(Ipv6Sysctl {
+        forwarding: Some(false),
+        accept_ra: Some(1),
+        use_tempaddr: Some(Ipv6TempAddr::Disabled),
+    });
+    ifaces
+}
+
+#[test]
+fn test_ip_sysctl_serialize() {
+    let ifaces: Interfaces = serde_yaml::from_str(
+        r#"---
+        - name: eth1
+          type: ethernet
+          ipv4:
+            sysctl:
+              forwarding: "true"
+              rp-filter: loose
+              accept-local: true
+              proxy-arp: false
+              arp-ignore: "1"
+              arp-announce: 2
+          ipv6:
+            sysctl:
+              forwarding: true
+              accept-ra: 2
+              use-tempaddr: prefer-temporary
+        "#,
+    )
+    .unwrap();
+    let iface = ifaces.kernel_ifaces.get("eth1").unwrap().base_iface();
+    let ipv4_sysctl = iface.ipv4.as_ref().unwrap().sysctl.as_ref().unwrap();
+    let ipv6_sysctl = iface.ipv6.as_ref().unwrap().sysctl.as_ref().unwrap();
+
+    assert_eq!(ipv4_sysctl.forwarding, Some(true));
+    assert_eq!(ipv4_sysctl.rp_filter, Some(RpFilter::Loose));
+    assert_eq!(ipv4_sysctl.arp_ignore, Some(1));
+    assert_eq!(ipv6_sysctl.accept_ra, Some(2));
+    assert_eq!(
+        ipv6_sysctl.use_tempaddr,
+        Some(Ipv6TempAddr::PreferTemporary)
+    );
+
+    let new_ifaces: Interfaces =
+        serde_yaml::from_str(&serde_yaml::to_string(&ifaces).unwrap()).unwrap();
+    assert_eq!(ifaces, new_ifaces);
+}
+
+#[test]
+fn test_ipv6_sysctl_does_not_support_ipv4_option() {
+    let result = serde_yaml::from_str::<Interface>(
+        r"---
+        name: eth1
+        type: ethernet
+        ipv6:
+          sysctl:
+            rp-filter: loose
+        ",
+    );
+
+    assert!(result.is_err());
+    assert!(result.unwrap_err().to_string().contains("rp-filter"));
+}
+
+#[test]
+fn test_ipv4_sysctl_invalid_arp_ignore() {
+    let desired: Interfaces = serde_yaml::from_str(
+        r"---
+        - name: eth1
+          type: ethernet
+          state: up
+          ipv4:
+            sysctl:
+              arp-ignore: 4
+        ",
+    )
+    .unwrap();
+
+    let result =
+        MergedInterfaces::new(desired, gen_test_eth_ifaces(), false, false);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_ipv6_sysctl_invalid_accept_ra() {
+    let desired: Interfaces = serde_yaml::from_str(
+        r"---
+        - name: eth1
+          type: ethernet
+          state: up
+          ipv6:
+            sysctl:
+              accept-ra: 3
+        ",
+    )
+    .unwrap();
+
+    let result =
+        MergedInterfaces::new(desired, gen_test_eth_ifaces(), false, false);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_ip_sysctl_verify_default_value() {
+    let desired: Interfaces = serde_yaml::from_str(
+        r"---
+        - name: eth1
+          type: ethernet
+          state: up
+          ipv4:
+            sysctl:
+              rp-filter: strict
+              forwarding: false
+          ipv6:
+            sysctl:
+              accept-ra: 1
+        ",
+    )
+    .unwrap();
+
+    let merged_ifaces =
+        MergedInterfaces::new(desired, gen_current_ifaces(), false, false)
+            .unwrap();
+
+    merged_ifaces.verify(&gen_current_ifaces()).unwrap();
+}
+
+#[test]
+fn test_ip_sysctl_verify_failure() {
+    let desired: Interfaces = serde_yaml::from_str(
+        r"---
+        - name: eth1
+          type: ethernet
+          state: up
+          ipv4:
+            sysctl:
+              rp-filter: disabled
+        ",
+    )
+    .unwrap();
+
+    let merged_ifaces =
+        MergedInterfaces::new(desired, gen_current_ifaces(), false, false)
+            .unwrap();
+
+    let result = merged_ifaces.verify(&gen_current_ifaces());
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::VerificationError);
+    }
+}
+
+#[test]
+fn test_ip_sysctl_revert() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+        - name: eth1
+          type: ethernet
+          state: up
+          ipv4:
+            sysctl:
+              rp-filter: disabled
+              proxy-arp: true
+          ipv6:
+            sysctl:
+              forwarding: true
+        ",
+    )
+    .unwrap();
+    let current = NetworkState {
+        interfaces: gen_current_ifaces(),
+        ..Default::default()
+    };
+
+    let revert = desired.generate_revert(&current).unwrap();
+
+    let iface = revert.interfaces.kernel_ifaces.get("eth1").unwrap();
+    let base_iface = iface.base_iface();
+    assert_eq!(
+        base_iface.ipv4.as_ref().unwrap().sysctl,
+        Some(Ipv4Sysctl {
+            rp_filter: Some(RpFilter::Strict),
+            proxy_arp: Some(false),
+            ..Default::default()
+        })
+    );
+    assert_eq!(
+        base_iface.ipv6.as_ref().unwrap().sysctl,
+        Some(Ipv6Sysctl {
+            forwarding: Some(false),
+            ..Default::default()
+        })
+    );
+}
+
+#[test]
+fn test_ip_forwarding_gen_diff() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+        ip-forwarding:
+          ipv4: true
+          ipv6: false
+        ",
+    )
+    .unwrap();
+    let current: NetworkState = serde_yaml::from_str(
+        r"---
+        ip-forwarding:
+          ipv4: false
+          ipv6: false
+        ",
+    )
+    .unwrap();
+
+    let diff = desired.gen_diff(&current).unwrap();
+    assert_eq!(diff.ip_forwarding, desired.ip_forwarding);
+
+    let diff = current.gen_diff(&current).unwrap();
+    assert!(diff.ip_forwarding.is_none());
+}
@@ -48,6 +48,65 @@ class Route:
     USE_DEFAULT_METRIC = -1
     USE_DEFAULT_ROUTE_TABLE = 0
     CWND = "cwnd"
+    MTU = "mtu"
+    ADVMSS = "advmss"
+    INITCWND = "initcwnd"
+    INITRWND = "initrwnd"
+    QUICKACK = "quickack"
+    RTO_MIN = "rto-min"
+    ONLINK = "onlink"
+    LOCK = "lock"
+    SCOPE = "scope"
+    SCOPE_GLOBAL = "global"
+    SCOPE_SITE = "site"
+    SCOPE_LINK = "link"
+    SCOPE_HOST = "host"
+    PROTOCOL = "protocol"
+    PROTOCOL_BOOT = "boot"
+    PROTOCOL_STATIC = "static"
+    PROTOCOL_RA = "ra"
+    PROTOCOL_DHCP = "dhcp"
+    PROTOCOL_BGP = "bgp"
+    PREF = "pref"
+    PREF_LOW = "low"
+    PREF_MEDIUM = "medium"
+    PREF_HIGH = "high"
+    NEXTHOP_ID = "nexthop-id"
+
+
+class Nexthop:
+    KEY = "nexthops"
+    CONFIG = "config"
+    STATE = "state"
+    STATE_ABSENT = "absent"
+    ID = "id"
+    FAMILY = "family"
+    NEXT_HOP_INTERFACE = "next-hop-interface"
+    NEXT_HOP_ADDRESS = "next-hop-address"
+    ONLINK = "onlink"
+    BLACKHOLE = "blackhole"
+    GROUP = "group"
+    GROUP_MEMBER_ID = "id"
+    GROUP_MEMBER_WEIGHT = "weight"
+    GROUP_TYPE = "group-type"
+    GROUP_TYPE_MPATH = "mpath"
+    GROUP_TYPE_RESILIENT = "resilient"
+    RESILIENT = "resilient"
+    RESILIENT_BUCKETS = "buckets"
+    RESILIENT_IDLE_TIMER = "idle-timer"
+    RESILIENT_UNBALANCED_TIMER = "unbalanced-timer"
+
+
+class Neighbor:
+    KEY = "neighbors"
+    RUNNING = "running"
+    CONFIG = "config"
+    STATE = "state"
+    STATE_ABSENT = "absent"
+    IP = "ip"
+    INTERFACE = "interface"
+    LINK_LAYER_ADDRESS = "link-layer-address"
+    PROXY = "proxy"
 
 
 class RouteRule:
@@ -72,6 +131,20 @@ class RouteRule:
     ACTION_UNREACHABLE = "unreachable"
     ACTION_PROHIBIT = "prohibit"
     SUPPRESS_PREFIX_LENGTH = "suppress-prefix-length"
+    IPPROTO = "ipproto"
+    SPORT = "sport"
+    DPORT = "dport"
+    PORT_RANGE_START = "start"
+    PORT_RANGE_END = "end"
+    UID_RANGE = "uid-range"
+    UID_RANGE_START = "start"
+    UID_RANGE_END = "end"
+    OIF = "oif"
+    TOS = "tos"
+    DSCP = "dscp"
+    L3MDEV = "l3mdev"
+    GOTO = "goto"
+    INVERT = "invert"
 
 
 class DNS:
@@ -81,6 +154,21 @@ class DNS:
     SERVER = "server"
     SEARCH = "search"
     OPTIONS = "options"
+    DNS_OVER_TLS = "dns-over-tls"
+    DNS_OVER_TLS_NO = "no"
+    DNS_OVER_TLS_OPPORTUNISTIC = "opportunistic"
+    DNS_OVER_TLS_YES = "yes"
+    DNSSEC = "dnssec"
+    DNSSEC_NO = "no"
+    DNSSEC_ALLOW_DOWNGRADE = "allow-downgrade"
+    DNSSEC_YES = "yes"
+
+
+class InterfaceDNS:
+    CONFIG_SUBTREE = "dns"
+    SERVER = "server"
+    SEARCH = "search"
+    PRIORITY = "priority"
 
 
 class Constants:
@@ -150,6 +238,12 @@ class InterfaceIP:
     ADDRESS_PREFERRED_LEFT = "preferred-life-time"
     ADDRESS_PREFERRED_LIFE_TIME = "preferred-life-time"
     ADDRESS_LIFETIME_FOREVER = "forever"
+    ADDRESS_PEER = "peer"
+    ADDRESS_FLAGS = "flags"
+    ADDRESS_FLAG_NOPREFIXROUTE = "noprefixroute"
+    ADDRESS_FLAG_NODAD = "nodad"
+    ADDRESS_FLAG_HOME = "home"
+    ADDRESS_FLAG_OPTIMISTIC = "optimistic"
     DHCP = "dhcp"
     AUTO_DNS = "auto-dns"
     AUTO_GATEWAY = "auto-gateway"
@@ -160,10 +254,37 @@ class InterfaceIP:
     ALLOW_EXTRA_ADDRESS = "allow-extra-address"
     DHCP_SEND_HOSTNAME = "dhcp-send-hostname"
     DHCP_CUSTOM_HOSTNAME = "dhcp-custom-hostname"
+    DHCP_IAID = "dhcp-iaid"
+    DHCP_TIMEOUT = "dhcp-timeout"
+    DHCP_LEASE = "dhcp-lease"
+    DHCP_LEASE_SERVER = "server"
+    DHCP_LEASE_LEASE_TIME = "lease-time"
+    DHCP_LEASE_EXPIRY = "expiry"
+    DHCP_LEASE_DNS_SERVERS = "dns-servers"
+    DHCP_LEASE_DOMAIN_SEARCH = "domain-search"
+    DHCP_LEASE_NTP_SERVERS = "ntp-servers"
+    SYSCTL = "sysctl"
+    SYSCTL_FORWARDING = "forwarding"
 
 
 class InterfaceIPv4(InterfaceIP):
     DHCP_CLIENT_ID = "dhcp-client-id"
+    DHCP_VENDOR_CLASS_IDENTIFIER = "dhcp-vendor-class-identifier"
+    DHCP_USER_CLASS = "dhcp-user-class"
+    DHCP_FQDN = "dhcp-fqdn"
+    DHCP_REJECT_SERVERS = "dhcp-reject-servers"
+    DHCP_REQUEST_OPTIONS = "dhcp-request-options"
+    DHCP_LEASE_ROUTERS = "routers"
+    DHCP_LEASE_DOMAIN_NAME = "domain-name"
+    DHCP_LEASE_MTU = "mtu"
+    SYSCTL_RP_FILTER = "rp-filter"
+    SYSCTL_RP_FILTER_DISABLED = "disabled"
+    SYSCTL_RP_FILTER_STRICT = "strict"
+    SYSCTL_RP_FILTER_LOOSE = "loose"
+    SYSCTL_ACCEPT_LOCAL = "accept-local"
+    SYSCTL_PROXY_ARP = "proxy-arp"
+    SYSCTL_ARP_IGNORE = "arp-ignore"
+    SYSCTL_ARP_ANNOUNCE = "arp-announce"
 
 
 class InterfaceIPv6(InterfaceIP):
@@ -173,6 +294,20 @@ class InterfaceIPv6(InterfaceIP):
     ADDR_GEN_MODE_EUI64 = "eui64"
     ADDR_GEN_MODE_STABLE_PRIVACY = "stable-privacy"
     TOKEN = "token"
+    IP6_PRIVACY = "ip6-privacy"
+    IP6_PRIVACY_DISABLED = "disabled"
+    IP6_PRIVACY_PREFER_PUBLIC = "prefer-public"
+    IP6_PRIVACY_PREFER_TEMPORARY = "prefer-temporary"
+    DAD_TRANSMITS = "dad-transmits"
+    RA_TIMEOUT = "ra-timeout"
+    DHCP_SEND_RELEASE = "dhcp-send-release"
+    DHCP_LEASE_PREFIX_DELEGATION = "prefix-delegation"
+    MTU_FROM_RA = "mtu-from-ra"
+    SYSCTL_ACCEPT_RA = "accept-ra"
+    SYSCTL_USE_TEMPADDR = "use-tempaddr"
+    SYSCTL_USE_TEMPADDR_DISABLED = "disabled"
+    SYSCTL_USE_TEMPADDR_PREFER_PUBLIC = "prefer-public"
+    SYSCTL_USE_TEMPADDR_PREFER_TEMPORARY = "prefer-temporary"
 
 
 class Bond:
@@ -291,6 +426,14 @@ class Ethernet:
         DRIVERS_AUTOPROBE = "drivers-autoprobe"
         TOTAL_VFS = "total-vfs"
         VFS_SUBTREE = "vfs"
+        ESWITCH_SUBTREE = "eswitch"
+
+        class Eswitch:
+            MODE = "mode"
+            MODE_LEGACY = "legacy"
+            MODE_SWITCHDEV = "switchdev"
+            INLINE_MODE = "inline-mode"
+            ENCAP_MODE = "encap-mode"
 
         class VFS:
             ID = "id"
@@ -302,6 +445,37 @@ class Ethernet:
             VLAN_ID = "vlan-id"
             QOS = "qos"
             VLAN_PROTO = "vlan-proto"
+            REPRESENTOR = "representor"
+            LINK_STATE = "link-state"
+            LINK_STATE_AUTO = "auto"
+            LINK_STATE_ENABLE = "enable"
+            LINK_STATE_DISABLE = "disable"
+            QUERY_RSS_EN = "query-rss-en"
+            STATS_SUBTREE = "stats"
+
+            class Stats:
+                RX_PACKETS = "rx-packets"
+                TX_PACKETS = "tx-packets"
+                RX_BYTES = "rx-bytes"
+                TX_BYTES = "tx-bytes"
+                BROADCAST = "broadcast"
+                MULTICAST = "multicast"
+                RX_DROPPED = "rx-dropped"
+                TX_DROPPED = "tx-dropped"
+
+    class WakeOnLan:
+        CONFIG_SUBTREE = "wake-on-lan"
+        MODES = "modes"
+        SECUREON_PASSWORD = "secureon-password"
+
+        class Mode:
+            PHY = "phy"
+            UNICAST = "unicast"
+            MULTICAST = "multicast"
+            BROADCAST = "broadcast"
+            ARP = "arp"
+            MAGIC = "magic"
+            SECUREON = "secureon"
 
 
 class Veth:
@@ -421,6 +595,7 @@ class LLDP:
         TLV_TYPE = "type"
         TLV_SUBTYPE = "subtype"
         ORGANIZATION_CODE = "oui"
+        MED_NETWORK_POLICIES = "lldp-med-network-policies"
 
 
 class VRF:
@@ -478,6 +653,22 @@ class MacSec:
     OFFLOAD_OFF = "off"
     OFFLOAD_PHY = "phy"
     OFFLOAD_MAC = "mac"
+    STATIC_SAK_SUBTREE = "static-sak"
+
+    class StaticSak:
+        TX_SA_SUBTREE = "tx-sa"
+        RX_SC_SUBTREE = "rx-sc"
+
+        class RxSc:
+            ADDRESS = "address"
+            PORT = "port"
+            SA_SUBTREE = "sa"
+
+        class Sa:
+            AN = "an"
+            PN = "pn"
+            KEY_ID = "key-id"
+            KEY = "key"
 
 
 class Hsr:
@@ -487,6 +678,7 @@ class Hsr:
     SUPERVISION_ADDRESS = "supervision-address"
     MULTICAST_SPEC = "multicast-spec"
     PROTOCOL = "protocol"
+    INTERLINK = "interlink"
 
 
 class IpVlan:
@@ -512,6 +704,19 @@ class Ieee8021X:
     CA_CERT = "ca-cert"
     PHASE2_AUTH = "phase2-auth"
     PASSWORD = "password"
+    ANONYMOUS_IDENTITY = "anonymous-identity"
+    DOMAIN_SUFFIX_MATCH = "domain-suffix-match"
+    ALTSUBJECT_MATCHES = "altsubject-matches"
+    PHASE1_AUTH_FLAGS = "phase1-auth-flags"
+    PHASE2_CA_CERT = "phase2-ca-cert"
+    PHASE2_CLIENT_CERT = "phase2-client-cert"
+    PHASE2_PRIVATE_KEY = "phase2-private-key"
+    PHASE2_PRIVATE_KEY_PASSWORD = "phase2-private-key-password"
+    AUTH_STATE = "auth-state"
+    AUTH_STATE_AUTHENTICATED = "authenticated"
+    AUTH_STATE_CONNECTING = "connecting"
+    AUTH_STATE_FAILED = "failed"
+    AUTH_FAILURE_REASON = "auth-failure-reason"
 
 
 class Ethtool:
@@ -533,6 +738,52 @@ class Ethtool:
         RX_MINI = "rx-mini"
         TX = "tx"
 
+    class Channels:
+        CONFIG_SUBTREE = "channels"
+        RX = "rx"
+        TX = "tx"
+        OTHER = "other"
+        COMBINED = "combined"
+
+    class Rss:
+        CONFIG_SUBTREE = "rss"
+        HASH_FUNCTION = "hash-function"
+        HASH_KEY = "hash-key"
+        INDIRECTION_TABLE = "indirection-table"
+        EQUAL = "equal"
+
+        class HashFunction:
+            TOEPLITZ = "toeplitz"
+            XOR = "xor"
+            CRC32 = "crc32"
+
+    class LinkModes:
+        CONFIG_SUBTREE = "link-modes"
+        SUPPORTED = "supported"
+        ADVERTISED = "advertised"
+
+    class Fec:
+        CONFIG_SUBTREE = "fec"
+        MODE = "mode"
+        ACTIVE = "active"
+
+        class Mode:
+            OFF = "off"
+            RS = "rs"
+            BASER = "baser"
+            LLRS = "llrs"
+            AUTO = "auto"
+
+    class Eee:
+        CONFIG_SUBTREE = "eee"
+        ENABLED = "enabled"
+        ACTIVE = "active"
+        TX_LPI_ENABLED = "tx-lpi-enabled"
+        TX_LPI_TIMER = "tx-lpi-timer"
+
+    class PrivateFlags:
+        CONFIG_SUBTREE = "private-flags"
+
     class Coalesce:
         CONFIG_SUBTREE = "coalesce"
         ADAPTIVE_RX = "adaptive-rx"
@@ -563,6 +814,13 @@ class HostNameState:
     KEY = "hostname"
     CONFIG = "config"
     RUNNING = "running"
+    PRETTY = "pretty"
+
+
+class IpForwarding:
+    KEY = "ip-forwarding"
+    IPV4 = "ipv4"
+    IPV6 = "ipv6"
 
 
 class Mptcp:
@@ -9,6 +9,7 @@ import libnmstate
 from libnmstate.error import NmstateValueError
 from libnmstate.schema import DNS
 from libnmstate.schema import Interface
+from libnmstate.schema import InterfaceDNS
 from libnmstate.schema import InterfaceIPv4
 from libnmstate.schema import InterfaceIPv6
 from libnmstate.schema import InterfaceState
@@ -17,6 +18,7 @@ from libnmstate.schema import Route
 
 from .testlib import assertlib
 from .testlib import cmdlib
+from .testlib import statelib
 from .testlib.bondlib import bond_interface
 from .testlib.genconf import gen_conf_apply
 from .testlib.servicelib import disable_service
@@ -669,3 +671,82 @@ def test_kernel_mode_dns_and_purge():
         libnmstate.apply(desired_state, kernel_only=True)
         cur_state = libnmstate.show(kernel_only=True)
         assert not cur_state[DNS.KEY][DNS.CONFIG]
+
+
+@pytest.fixture
+def eth1_with_iface_dns():
+    iface_states = _get_test_iface_states()
+    iface_states[0][InterfaceDNS.CONFIG_SUBTREE] = {
+        InterfaceDNS.SERVER: [
+            IPV6_DNS_NAMESERVERS[0],
+            IPV4_DNS_NAMESERVERS[0],
+        ],
+        InterfaceDNS.SEARCH: ["~corp.example.com", "example.org"],
+        InterfaceDNS.PRIORITY: 50,
+    }
+    libnmstate.apply({Interface.KEY: iface_states})
+    yield
+    iface_states[0][InterfaceDNS.CONFIG_SUBTREE] = {}
+    libnmstate.apply({Interface.KEY: iface_states})
+
+
+def test_iface_dns(eth1_with_iface_dns):
+    iface_state = statelib.show_only(("eth1",))[Interface.KEY][0]
+    assert iface_state[InterfaceDNS.CONFIG_SUBTREE] == {
+        InterfaceDNS.SERVER: [
+            IPV4_DNS_NAMESERVERS[0],
+            IPV6_DNS_NAMESERVERS[0],
+        ],
+        InterfaceDNS.SEARCH: ["~corp.example.com", "example.org"],
+        InterfaceDNS.PRIORITY: 50,
+    }
+    assert not libnmstate.show()[DNS.KEY][DNS.CONFIG]
+
+
+def test_global_dns_not_stored_to_iface_with_iface_dns(eth1_with_iface_dns):
+    dns_config = {
+        DNS.SERVER: [IPV4_DNS_NAMESERVERS[1]],
+        DNS.SEARCH: EXAMPLE_SEARCHES,
+    }
+    libnmstate.apply({DNS.KEY: {DNS.CONFIG: dns_config}})
+
+    current_state = libnmstate.show()
+    assert current_state[DNS.KEY][DNS.CONFIG] == dns_config
+    eth1_state = statelib.show_only(("eth1",))[Interface.KEY][0]
+    assert eth1_state[InterfaceDNS.CONFIG_SUBTREE][InterfaceDNS.SERVER] == [
+        IPV4_DNS_NAMESERVERS[0],
+        IPV6_DNS_NAMESERVERS[0],
+    ]
+    eth2_state = statelib.show_only(("eth2",))[Interface.KEY][0]
+    assert InterfaceDNS.CONFIG_SUBTREE not in eth2_state
+
+
+def test_purge_iface_dns(eth1_with_iface_dns):
+    libnmstate.apply(
+        {
+            Interface.KEY: [
+                {
+                    Interface.NAME: "eth1",
+                    InterfaceDNS.CONFIG_SUBTREE: {},
+                }
+            ]
+        }
+    )
+    eth1_state = statelib.show_only(("eth1",))[Interface.KEY][0]
+    assert InterfaceDNS.CONFIG_SUBTREE not in eth1_state
+
+
+def test_dns_over_tls_and_dnssec():
+    dns_config = {
+        DNS.SERVER: [
+            f"dns+tls://{IPV4_DNS_NAMESERVERS[0]}:853#dns.google",
+            f"dns+tls://[{IPV6_DNS_NAMESERVERS[0]}]:853#dns.google",
+        ],
+        DNS.SEARCH: EXAMPLE_SEARCHES,
+        DNS.DNS_OVER_TLS: DNS.DNS_OVER_TLS_OPPORTUNISTIC,
+        DNS.DNSSEC: DNS.DNSSEC_ALLOW_DOWNGRADE,
+    }
+    libnmstate.apply({DNS.KEY: {DNS.CONFIG: dns_config}})
+
+    current_state = libnmstate.show()
+    assert current_state[DNS.KEY][DNS.CONFIG] == dns_config
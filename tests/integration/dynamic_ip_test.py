@@ -202,6 +202,57 @@ def test_ipv4_dhcp(dhcpcli_up):
     assert _poll(_has_ipv4_classless_route)
 
 
+def test_ipv4_dhcp_extended_options(dhcpcli_up):
+    desired_state = dhcpcli_up
+    dhcp_cli_desired_state = desired_state[Interface.KEY][0]
+    dhcp_cli_desired_state[Interface.STATE] = InterfaceState.UP
+    dhcp_cli_desired_state[Interface.IPV4] = _create_ipv4_state(
+        enabled=True, dhcp=True
+    )
+    dhcp_cli_desired_state[Interface.IPV4].update(
+        {
+            InterfaceIPv4.DHCP_VENDOR_CLASS_IDENTIFIER: "PXEClient",
+            InterfaceIPv4.DHCP_IAID: "ifname",
+            InterfaceIPv4.DHCP_FQDN: "c9.example.org",
+            InterfaceIPv4.DHCP_TIMEOUT: 60,
+            InterfaceIPv4.DHCP_REJECT_SERVERS: ["198.51.100.0/24"],
+        }
+    )
+
+    libnmstate.apply(desired_state)
+    assertlib.assert_state(desired_state)
+
+    assert _poll(_has_ipv4_dhcp_gateway)
+
+
+def test_ipv4_dhcp_lease_in_status_data(dhcpcli_up):
+    desired_state = dhcpcli_up
+    dhcp_cli_desired_state = desired_state[Interface.KEY][0]
+    dhcp_cli_desired_state[Interface.STATE] = InterfaceState.UP
+    dhcp_cli_desired_state[Interface.IPV4] = _create_ipv4_state(
+        enabled=True, dhcp=True
+    )
+
+    libnmstate.apply(desired_state)
+    assertlib.assert_state(desired_state)
+    assert _poll(_has_ipv4_dhcp_gateway)
+
+    state = statelib.show_only((DHCP_CLI_NIC,), include_status_data=True)
+    iface_state = state[Interface.KEY][0]
+    lease = iface_state[Interface.IPV4][InterfaceIPv4.DHCP_LEASE]
+    assert lease[InterfaceIPv4.DHCP_LEASE_SERVER] == DHCP_SRV_IP4
+    assert lease[InterfaceIPv4.DHCP_LEASE_LEASE_TIME] == 48 * 3600
+    assert lease[InterfaceIPv4.DHCP_LEASE_DNS_SERVERS] == [DHCP_SRV_IP4]
+
+    state = statelib.show_only((DHCP_CLI_NIC,))
+    iface_state = state[Interface.KEY][0]
+    assert InterfaceIPv4.DHCP_LEASE not in iface_state[Interface.IPV4]
+
+    # The lease is query only, applying it back should be ignored
+    iface_state[Interface.IPV4][InterfaceIPv4.DHCP_LEASE] = lease
+    libnmstate.apply({Interface.KEY: [iface_state]})
+
+
 def test_ipv6_dhcp_only(dhcpcli_up):
     desired_state = dhcpcli_up
     dhcp_cli_desired_state = desired_state[Interface.KEY][0]
@@ -237,6 +288,21 @@ def test_ipv6_dhcp_and_autoconf(dhcpcli_up):
     assert _poll(_has_ipv6_auto_nameserver)
 
 
+def test_ipv6_dhcp_and_ra_timeout(dhcpcli_up):
+    desired_state = dhcpcli_up
+    dhcp_cli_desired_state = desired_state[Interface.KEY][0]
+    dhcp_cli_desired_state[Interface.STATE] = InterfaceState.UP
+    dhcp_cli_desired_state[Interface.IPV6] = _create_ipv6_state(
+        enabled=True, dhcp=True, autoconf=True
+    )
+    dhcp_cli_desired_state[Interface.IPV6][InterfaceIPv6.DHCP_TIMEOUT] = 60
+    dhcp_cli_desired_state[Interface.IPV6][InterfaceIPv6.RA_TIMEOUT] = 30
+
+    libnmstate.apply(desired_state)
+
+    assertlib.assert_state(desired_state)
+
+
 @pytest.mark.tier1
 def test_static_ip_with_auto_ip_enabled(dhcpcli_up):
     ipv4_state = _create_ipv4_state(enabled=True, dhcp=True)
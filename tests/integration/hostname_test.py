@@ -22,6 +22,7 @@ import os
 import pytest
 
 import libnmstate
+from libnmstate.error import NmstateNotSupportedError
 from libnmstate.error import NmstateVerificationError
 from libnmstate.schema import HostNameState
 
@@ -29,6 +30,7 @@ from .testlib import cmdlib
 
 TEST_HOSTNAME1 = "nmstate-test1.example.org"
 TEST_HOSTNAME2 = "nmstate-test2.example.org"
+TEST_PRETTY_HOSTNAME = "Nmstate Test Host"
 
 
 @pytest.fixture
@@ -119,3 +121,62 @@ def test_hostname_set_different_running_and_config(restore_hostname):
         cmdlib.exec_cmd(["cat", "/etc/hostname"], check=True)[1].strip()
         == TEST_HOSTNAME2
     )
+
+
+@pytest.mark.skipif(
+    os.environ.get("CI") == "true",
+    reason="NM cannot change hostname in container",
+)
+def test_hostname_set_pretty(restore_hostname):
+    libnmstate.apply(
+        {
+            HostNameState.KEY: {
+                HostNameState.PRETTY: TEST_PRETTY_HOSTNAME,
+            }
+        },
+    )
+    assert (
+        libnmstate.show()[HostNameState.KEY][HostNameState.PRETTY]
+        == TEST_PRETTY_HOSTNAME
+    )
+    libnmstate.apply({HostNameState.KEY: {HostNameState.PRETTY: ""}})
+    assert HostNameState.PRETTY not in libnmstate.show()[HostNameState.KEY]
+
+
+def test_hostname_set_pretty_in_memory_only(restore_hostname):
+    with pytest.raises(NmstateNotSupportedError):
+        libnmstate.apply(
+            {
+                HostNameState.KEY: {
+                    HostNameState.PRETTY: TEST_PRETTY_HOSTNAME,
+                }
+            },
+            save_to_disk=False,
+        )
+
+
+@pytest.mark.skipif(
+    os.environ.get("CI") == "true",
+    reason="Cannot change hostname in container",
+)
+def test_hostname_set_all_in_kernel_mode(restore_hostname):
+    desired_state = {
+        HostNameState.KEY: {
+            HostNameState.RUNNING: TEST_HOSTNAME1,
+            HostNameState.CONFIG: TEST_HOSTNAME2,
+            HostNameState.PRETTY: TEST_PRETTY_HOSTNAME,
+        }
+    }
+    libnmstate.apply(desired_state, kernel_only=True)
+    cur_state = libnmstate.show(kernel_only=True)[HostNameState.KEY]
+    assert cur_state == desired_state[HostNameState.KEY]
+    libnmstate.apply(
+        {HostNameState.KEY: {HostNameState.PRETTY: ""}}, kernel_only=True
+    )
+
+
+def test_gen_conf_static_hostname():
+    confs = libnmstate.generate_configurations(
+        {HostNameState.KEY: {HostNameState.CONFIG: TEST_HOSTNAME1}}
+    )
+    assert confs["hostname"] == [["/etc/hostname", f"{TEST_HOSTNAME1}\n"]]
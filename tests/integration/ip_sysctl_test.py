@@ -0,0 +1,197 @@
+# SPDX-License-Identifier: LGPL-2.1-or-later
+
+import pytest
+
+import libnmstate
+from libnmstate.error import NmstateNotSupportedError
+from libnmstate.error import NmstateValueError
+from libnmstate.schema import Interface
+from libnmstate.schema import InterfaceIPv4
+from libnmstate.schema import InterfaceIPv6
+from libnmstate.schema import InterfaceState
+from libnmstate.schema import InterfaceType
+from libnmstate.schema import IpForwarding
+
+from .testlib import assertlib
+from .testlib import cmdlib
+
+
+def _get_sysctl(key):
+    return cmdlib.exec_cmd(f"sysctl -n {key}".split(), check=True)[1].strip()
+
+
+@pytest.fixture
+def restore_ip_forwarding():
+    ipv4_value = _get_sysctl("net.ipv4.ip_forward")
+    ipv6_value = _get_sysctl("net.ipv6.conf.all.forwarding")
+    yield
+    cmdlib.exec_cmd(
+        f"sysctl -w net.ipv4.ip_forward={ipv4_value}".split(), check=True
+    )
+    cmdlib.exec_cmd(
+        f"sysctl -w net.ipv6.conf.all.forwarding={ipv6_value}".split(),
+        check=True,
+    )
+
+
+@pytest.mark.tier1
+def test_set_ip_sysctl_kernel_mode(eth1_up):
+    desired_state = {
+        Interface.KEY: [
+            {
+                Interface.NAME: "eth1",
+                Interface.IPV4: {
+                    InterfaceIPv4.SYSCTL: {
+                        InterfaceIPv4.SYSCTL_FORWARDING: True,
+                        InterfaceIPv4.SYSCTL_RP_FILTER: (
+                            InterfaceIPv4.SYSCTL_RP_FILTER_LOOSE
+                        ),
+                        InterfaceIPv4.SYSCTL_ACCEPT_LOCAL: True,
+                        InterfaceIPv4.SYSCTL_PROXY_ARP: True,
+                        InterfaceIPv4.SYSCTL_ARP_IGNORE: 1,
+                        InterfaceIPv4.SYSCTL_ARP_ANNOUNCE: 2,
+                    },
+                },
+                Interface.IPV6: {
+                    InterfaceIPv6.SYSCTL: {
+                        InterfaceIPv6.SYSCTL_FORWARDING: True,
+                        InterfaceIPv6.SYSCTL_ACCEPT_RA: 2,
+                        InterfaceIPv6.SYSCTL_USE_TEMPADDR: (
+                            InterfaceIPv6.SYSCTL_USE_TEMPADDR_PREFER_PUBLIC
+                        ),
+                    },
+                },
+            }
+        ]
+    }
+    libnmstate.apply(desired_state, kernel_only=True)
+
+    assert _get_sysctl("net.ipv4.conf.eth1.rp_filter") == "2"
+    assert _get_sysctl("net.ipv4.conf.eth1.arp_ignore") == "1"
+    assert _get_sysctl("net.ipv6.conf.eth1.accept_ra") == "2"
+    assert _get_sysctl("net.ipv6.conf.eth1.use_tempaddr") == "1"
+
+
+@pytest.mark.tier1
+def test_set_nm_supported_ip_sysctl(eth1_up):
+    desired_state = {
+        Interface.KEY: [
+            {
+                Interface.NAME: "eth1",
+                Interface.TYPE: InterfaceType.ETHERNET,
+                Interface.STATE: InterfaceState.UP,
+                Interface.IPV4: {
+                    InterfaceIPv4.ENABLED: False,
+                    InterfaceIPv4.SYSCTL: {
+                        InterfaceIPv4.SYSCTL_FORWARDING: True,
+                    },
+                },
+                Interface.IPV6: {
+                    InterfaceIPv6.ENABLED: True,
+                    InterfaceIPv6.SYSCTL: {
+                        InterfaceIPv6.SYSCTL_USE_TEMPADDR: (
+                            InterfaceIPv6.SYSCTL_USE_TEMPADDR_PREFER_PUBLIC
+                        ),
+                    },
+                },
+            }
+        ]
+    }
+    libnmstate.apply(desired_state)
+    assertlib.assert_state_match(desired_state)
+
+    assert _get_sysctl("net.ipv4.conf.eth1.forwarding") == "1"
+    assert _get_sysctl("net.ipv6.conf.eth1.use_tempaddr") == "1"
+
+
+@pytest.mark.tier1
+def test_nm_not_supported_ip_sysctl(eth1_up):
+    with pytest.raises(NmstateNotSupportedError):
+        libnmstate.apply(
+            {
+                Interface.KEY: [
+                    {
+                        Interface.NAME: "eth1",
+                        Interface.IPV4: {
+                            InterfaceIPv4.SYSCTL: {
+                                InterfaceIPv4.SYSCTL_ARP_IGNORE: 2,
+                            },
+                        },
+                    }
+                ]
+            }
+        )
+
+
+@pytest.mark.tier1
+def test_invalid_arp_ignore(eth1_up):
+    with pytest.raises(NmstateValueError):
+        libnmstate.apply(
+            {
+                Interface.KEY: [
+                    {
+                        Interface.NAME: "eth1",
+                        Interface.IPV4: {
+                            InterfaceIPv4.SYSCTL: {
+                                InterfaceIPv4.SYSCTL_ARP_IGNORE: 5,
+                            },
+                        },
+                    }
+                ]
+            }
+        )
+
+
+@pytest.mark.tier1
+def test_set_ip_forwarding_kernel_mode(restore_ip_forwarding):
+    desired_state = {
+        IpForwarding.KEY: {
+            IpForwarding.IPV4: True,
+            IpForwarding.IPV6: True,
+        }
+    }
+    libnmstate.apply(desired_state, kernel_only=True)
+
+    current_state = libnmstate.show(kernel_only=True)
+    assert current_state[IpForwarding.KEY] == desired_state[IpForwarding.KEY]
+    assert _get_sysctl("net.ipv4.ip_forward") == "1"
+    assert _get_sysctl("net.ipv6.conf.all.forwarding") == "1"
+
+    desired_state[IpForwarding.KEY][IpForwarding.IPV4] = False
+    libnmstate.apply(desired_state, kernel_only=True)
+
+    assert _get_sysctl("net.ipv4.ip_forward") == "0"
+
+
+@pytest.mark.tier1
+def test_nm_not_supported_ip_forwarding(restore_ip_forwarding):
+    desired = _get_sysctl("net.ipv4.ip_forward") != "1"
+    with pytest.raises(NmstateNotSupportedError):
+        libnmstate.apply({IpForwarding.KEY: {IpForwarding.IPV4: desired}})
+
+
+@pytest.mark.tier1
+def test_set_ipv6_privacy_dad_and_mtu_from_ra(eth1_up):
+    desired_state = {
+        Interface.KEY: [
+            {
+                Interface.NAME: "eth1",
+                Interface.TYPE: InterfaceType.ETHERNET,
+                Interface.STATE: InterfaceState.UP,
+                Interface.IPV6: {
+                    InterfaceIPv6.ENABLED: True,
+                    InterfaceIPv6.IP6_PRIVACY: (
+                        InterfaceIPv6.IP6_PRIVACY_PREFER_TEMPORARY
+                    ),
+                    InterfaceIPv6.DAD_TRANSMITS: 0,
+                    InterfaceIPv6.MTU_FROM_RA: False,
+                },
+            }
+        ]
+    }
+    libnmstate.apply(desired_state)
+    assertlib.assert_state_match(desired_state)
+
+    assert _get_sysctl("net.ipv6.conf.eth1.use_tempaddr") == "2"
+    assert _get_sysctl("net.ipv6.conf.eth1.dad_transmits") == "0"
+    assert _get_sysctl("net.ipv6.conf.eth1.accept_ra_mtu") == "0"
@@ -1006,6 +1006,9 @@ def test_linux_bridge_multicast_router(bridge0_with_port0, mcast_router_value):
 @pytest.mark.tier1
 def test_linux_bridge_show_port_ip_as_disabled(bridge0_with_port0):
     state = show_only(("eth1",))
+    # Kernel sysctl might be changed by NetworkManager on port
+    state[Interface.KEY][0][Interface.IPV4].pop(InterfaceIP.SYSCTL, None)
+    state[Interface.KEY][0][Interface.IPV6].pop(InterfaceIP.SYSCTL, None)
 
     assert state[Interface.KEY][0][Interface.IPV4] == {
         InterfaceIPv4.ENABLED: False
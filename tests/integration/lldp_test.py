@@ -18,6 +18,7 @@
 
 from contextlib import contextmanager
 import os
+import subprocess
 import time
 import yaml
 
@@ -336,6 +337,32 @@ def test_show_running_config_has_no_lldp_neighbor(lldptest_up):
         )
 
 
+def test_kernel_mode_lldp_neighbors(lldptest_up):
+    with subprocess.Popen(
+        f"nmstatectl show --kernel --lldp-listen-time 5 {LLDPTEST}".split(),
+        stdout=subprocess.PIPE,
+        stderr=subprocess.PIPE,
+    ) as proc:
+        # Wait the LLDP listener to start
+        time.sleep(1)
+        _send_lldp_packet()
+        out, _ = proc.communicate()
+    dstate = yaml.safe_load(out)
+    lldp_config = dstate[Interface.KEY][0][LLDP.CONFIG_SUBTREE]
+    assert lldp_config[LLDP.ENABLED]
+    assert lldp_config[LLDP.NEIGHBORS_SUBTREE] == [
+        yaml.safe_load(EXPECTED_LLDP_NEIGHBOR)
+    ]
+
+
+def test_kernel_mode_lldp_disabled_without_listening(lldptest_up):
+    _, out, _ = cmdlib.exec_cmd(
+        f"nmstatectl show --kernel {LLDPTEST}".split(), check=True
+    )
+    dstate = yaml.safe_load(out)
+    assert LLDP.CONFIG_SUBTREE not in dstate[Interface.KEY][0]
+
+
 @contextmanager
 def lldp_enabled(ifstate):
     lldp_config = ifstate[Interface.KEY][0][LLDP.CONFIG_SUBTREE]
@@ -5,6 +5,7 @@ import copy
 import pytest
 
 import libnmstate
+from libnmstate.error import NmstateNotSupportedError
 from libnmstate.error import NmstateValueError
 from libnmstate.schema import DNS
 from libnmstate.schema import Interface
@@ -12,6 +13,8 @@ from libnmstate.schema import InterfaceIPv4
 from libnmstate.schema import InterfaceIPv6
 from libnmstate.schema import InterfaceState
 from libnmstate.schema import InterfaceType
+from libnmstate.schema import Neighbor
+from libnmstate.schema import Nexthop
 from libnmstate.schema import Route
 from libnmstate.schema import RouteRule
 
@@ -938,6 +941,85 @@ def test_remove_and_add_route_with_cwnd(eth1_up):
     assert_routes(expected_routes, cur_state)
 
 
+@pytest.mark.tier1
+def test_add_route_with_metrics_and_lock(eth1_up):
+    routes = [
+        {
+            Route.NEXT_HOP_INTERFACE: "eth1",
+            Route.DESTINATION: IPV4_TEST_NET1,
+            Route.NEXT_HOP_ADDRESS: IPV4_ADDRESS1,
+            Route.MTU: 1400,
+            Route.ADVMSS: 1360,
+            Route.INITCWND: 20,
+            Route.INITRWND: 30,
+            Route.QUICKACK: True,
+            Route.RTO_MIN: 50,
+            Route.LOCK: [Route.MTU, Route.INITCWND],
+        },
+        {
+            Route.NEXT_HOP_INTERFACE: "eth1",
+            Route.DESTINATION: IPV6_TEST_NET1,
+            Route.NEXT_HOP_ADDRESS: IPV6_GATEWAY1,
+            Route.MTU: 1400,
+            Route.LOCK: [Route.MTU],
+        },
+    ]
+    libnmstate.apply(
+        {
+            Interface.KEY: [ETH1_INTERFACE_STATE],
+            Route.KEY: {Route.CONFIG: routes},
+        }
+    )
+    cur_state = libnmstate.show()
+    assert_routes(routes, cur_state)
+
+
+@pytest.mark.tier1
+def test_change_route_mtu_in_place(eth1_up):
+    route = {
+        Route.NEXT_HOP_INTERFACE: "eth1",
+        Route.DESTINATION: IPV4_TEST_NET1,
+        Route.NEXT_HOP_ADDRESS: IPV4_ADDRESS1,
+        Route.MTU: 1400,
+    }
+    libnmstate.apply(
+        {
+            Interface.KEY: [ETH1_INTERFACE_STATE],
+            Route.KEY: {Route.CONFIG: [route]},
+        }
+    )
+    route[Route.MTU] = 1300
+    libnmstate.apply({Route.KEY: {Route.CONFIG: [route]}})
+
+    cur_state = libnmstate.show()
+    assert_routes([route], cur_state)
+    routes_output = cmdlib.exec_cmd(
+        f"ip route show {IPV4_TEST_NET1} dev eth1".split(), check=True
+    )[1]
+    assert "mtu 1300" in routes_output
+    assert "mtu 1400" not in routes_output
+
+
+@pytest.mark.tier1
+def test_add_onlink_route(eth1_up):
+    routes = [
+        {
+            Route.NEXT_HOP_INTERFACE: "eth1",
+            Route.DESTINATION: IPV4_TEST_NET1,
+            Route.NEXT_HOP_ADDRESS: "198.51.100.1",
+            Route.ONLINK: True,
+        },
+    ]
+    libnmstate.apply(
+        {
+            Interface.KEY: [ETH1_INTERFACE_STATE],
+            Route.KEY: {Route.CONFIG: routes},
+        }
+    )
+    cur_state = libnmstate.show()
+    assert_routes(routes, cur_state)
+
+
 @pytest.mark.tier1
 def test_route_cwnd_without_lock_means_cwnd_none(eth1_up):
     libnmstate.apply({Interface.KEY: [ETH1_INTERFACE_STATE]})
@@ -1646,6 +1728,70 @@ def test_do_not_show_bgp_route(static_route_with_additional_bgp_route):
         assert route[Route.DESTINATION] != BGP_ROUTE_DST_V6
 
 
+def test_remove_static_routes_by_protocol_keep_bgp(
+    static_route_with_additional_bgp_route,
+):
+    libnmstate.apply(
+        {
+            Route.KEY: {
+                Route.CONFIG: [
+                    {
+                        Route.STATE: Route.STATE_ABSENT,
+                        Route.PROTOCOL: Route.PROTOCOL_STATIC,
+                    }
+                ]
+            }
+        }
+    )
+
+    for route in libnmstate.show()[Route.KEY][Route.CONFIG]:
+        assert route.get(Route.PROTOCOL) != Route.PROTOCOL_STATIC
+    for dst in (BGP_ROUTE_DST_V4, BGP_ROUTE_DST_V6):
+        output = cmdlib.exec_cmd(f"ip route show {dst}".split(), check=True)[
+            1
+        ]
+        assert f"proto {BGP_PROTOCOL_ID}" in output or "proto bgp" in output
+
+
+@pytest.mark.tier1
+def test_show_route_protocol(eth1_up):
+    routes = [
+        {
+            Route.NEXT_HOP_INTERFACE: "eth1",
+            Route.DESTINATION: IPV4_TEST_NET1,
+            Route.NEXT_HOP_ADDRESS: IPV4_ADDRESS1,
+        },
+    ]
+    libnmstate.apply(
+        {
+            Interface.KEY: [ETH1_INTERFACE_STATE],
+            Route.KEY: {Route.CONFIG: routes},
+        }
+    )
+    routes[0][Route.PROTOCOL] = Route.PROTOCOL_STATIC
+    cur_state = libnmstate.show()
+    assert_routes(routes, cur_state)
+
+
+@pytest.mark.tier1
+def test_add_link_scope_route(eth1_up):
+    routes = [
+        {
+            Route.NEXT_HOP_INTERFACE: "eth1",
+            Route.DESTINATION: IPV4_TEST_NET1,
+            Route.SCOPE: Route.SCOPE_LINK,
+        },
+    ]
+    libnmstate.apply(
+        {
+            Interface.KEY: [ETH1_INTERFACE_STATE],
+            Route.KEY: {Route.CONFIG: routes},
+        }
+    )
+    cur_state = libnmstate.show()
+    assert_routes(routes, cur_state)
+
+
 @pytest.mark.tier1
 def test_route_rule_iif(route_rule_test_env):
     desired_rules = [
@@ -2078,6 +2224,54 @@ def test_route_rule_suppress_prefix_length(route_rule_test_env):
     _check_ip_rules(desired_state[RouteRule.KEY][RouteRule.CONFIG])
 
 
+def test_route_rule_selectors(route_rule_test_env):
+    rule = {
+        RouteRule.IP_FROM: "192.0.2.0/24",
+        RouteRule.PRIORITY: 1000,
+        RouteRule.ROUTE_TABLE: IPV4_ROUTE_TABLE_ID1,
+        RouteRule.IPPROTO: 6,
+        RouteRule.SPORT: {
+            RouteRule.PORT_RANGE_START: 1000,
+            RouteRule.PORT_RANGE_END: 2000,
+        },
+        RouteRule.DPORT: {
+            RouteRule.PORT_RANGE_START: 443,
+            RouteRule.PORT_RANGE_END: 443,
+        },
+        RouteRule.UID_RANGE: {
+            RouteRule.UID_RANGE_START: 1000,
+            RouteRule.UID_RANGE_END: 1999,
+        },
+        RouteRule.OIF: "eth1",
+        RouteRule.TOS: 8,
+        RouteRule.INVERT: True,
+    }
+    libnmstate.apply({RouteRule.KEY: {RouteRule.CONFIG: [rule]}})
+
+    cur_rules = libnmstate.show()[RouteRule.KEY][RouteRule.CONFIG]
+    assert any(
+        all(cur_rule.get(key) == value for key, value in rule.items())
+        for cur_rule in cur_rules
+    )
+
+
+def test_route_rule_goto_not_supported_in_nm_mode(route_rule_test_env):
+    with pytest.raises(NmstateNotSupportedError):
+        libnmstate.apply(
+            {
+                RouteRule.KEY: {
+                    RouteRule.CONFIG: [
+                        {
+                            RouteRule.IP_FROM: "192.0.2.0/24",
+                            RouteRule.PRIORITY: 1000,
+                            RouteRule.GOTO: 2000,
+                        }
+                    ]
+                }
+            }
+        )
+
+
 def test_append_route_rule(route_rule_test_env):
     desired_state = {
         RouteRule.KEY: {
@@ -2189,3 +2383,259 @@ def test_kernel_mode_static_route_and_remove(cleanup_veth1_kernel_mode):
     assert_routes_missing(
         desired_state[Route.KEY][Route.CONFIG], cur_state, nic="veth1"
     )
+
+
+def _get_nexthops(state):
+    return {
+        nh[Nexthop.ID]: nh
+        for nh in state.get(Nexthop.KEY, {}).get(Nexthop.CONFIG, [])
+    }
+
+
+def test_kernel_mode_nexthop_group_route(cleanup_veth1_kernel_mode):
+    desired_state = load_yaml(
+        """---
+        interfaces:
+        - name: veth1
+          type: veth
+          state: up
+          veth:
+            peer: veth1_peer
+          ipv4:
+            address:
+            - ip: 192.0.2.251
+              prefix-length: 24
+            dhcp: false
+            enabled: true
+        - name: veth1_peer
+          type: veth
+          state: up
+          veth:
+            peer: veth1
+        nexthops:
+          config:
+          - id: 101
+            next-hop-interface: veth1
+            next-hop-address: 192.0.2.1
+          - id: 102
+            next-hop-interface: veth1
+            next-hop-address: 192.0.2.2
+          - id: 110
+            group:
+            - id: 101
+              weight: 2
+            - id: 102
+        routes:
+          config:
+          - destination: 203.0.113.0/24
+            nexthop-id: 110
+        """
+    )
+    libnmstate.apply(desired_state, kernel_only=True)
+
+    cur_state = libnmstate.show(kernel_only=True)
+    nhs = _get_nexthops(cur_state)
+    assert nhs[101][Nexthop.NEXT_HOP_INTERFACE] == "veth1"
+    assert nhs[101][Nexthop.NEXT_HOP_ADDRESS] == "192.0.2.1"
+    assert nhs[110][Nexthop.GROUP] == [
+        {Nexthop.GROUP_MEMBER_ID: 101, Nexthop.GROUP_MEMBER_WEIGHT: 2},
+        {Nexthop.GROUP_MEMBER_ID: 102, Nexthop.GROUP_MEMBER_WEIGHT: 1},
+    ]
+    assert any(
+        rt.get(Route.NEXTHOP_ID) == 110
+        and rt[Route.DESTINATION] == IPV4_TEST_NET1
+        for rt in cur_state[Route.KEY][Route.CONFIG]
+    )
+
+    libnmstate.apply(
+        load_yaml(
+            """---
+            routes:
+              config:
+              - destination: 203.0.113.0/24
+                nexthop-id: 110
+                state: absent
+            nexthops:
+              config:
+              - id: 110
+                state: absent
+              - id: 101
+                state: absent
+              - id: 102
+                state: absent
+            """
+        ),
+        kernel_only=True,
+    )
+
+    cur_state = libnmstate.show(kernel_only=True)
+    nhs = _get_nexthops(cur_state)
+    assert 101 not in nhs
+    assert 102 not in nhs
+    assert 110 not in nhs
+    assert not any(
+        rt.get(Route.NEXTHOP_ID) == 110
+        for rt in cur_state[Route.KEY][Route.CONFIG]
+    )
+
+
+def test_kernel_mode_resilient_nexthop_group(cleanup_veth1_kernel_mode):
+    desired_state = load_yaml(
+        """---
+        interfaces:
+        - name: veth1
+          type: veth
+          state: up
+          veth:
+            peer: veth1_peer
+          ipv4:
+            address:
+            - ip: 192.0.2.251
+              prefix-length: 24
+            dhcp: false
+            enabled: true
+        - name: veth1_peer
+          type: veth
+          state: up
+          veth:
+            peer: veth1
+        nexthops:
+          config:
+          - id: 101
+            next-hop-interface: veth1
+            next-hop-address: 192.0.2.1
+          - id: 102
+            next-hop-interface: veth1
+            next-hop-address: 192.0.2.2
+          - id: 120
+            group:
+            - id: 101
+            - id: 102
+            group-type: resilient
+            resilient:
+              buckets: 32
+              idle-timer: 60
+        """
+    )
+    libnmstate.apply(desired_state, kernel_only=True)
+
+    nh = _get_nexthops(libnmstate.show(kernel_only=True))[120]
+    assert nh[Nexthop.GROUP_TYPE] == Nexthop.GROUP_TYPE_RESILIENT
+    assert nh[Nexthop.RESILIENT][Nexthop.RESILIENT_BUCKETS] == 32
+    assert nh[Nexthop.RESILIENT][Nexthop.RESILIENT_IDLE_TIMER] == 60
+
+
+def test_nexthop_not_supported_in_nm_mode(eth1_up):
+    with pytest.raises(NmstateNotSupportedError):
+        libnmstate.apply(
+            {
+                Nexthop.KEY: {
+                    Nexthop.CONFIG: [
+                        {
+                            Nexthop.ID: 101,
+                            Nexthop.NEXT_HOP_INTERFACE: "eth1",
+                            Nexthop.NEXT_HOP_ADDRESS: "192.0.2.1",
+                        }
+                    ]
+                }
+            }
+        )
+
+
+def _get_neighbors(state):
+    return [
+        neigh
+        for neigh in state.get(Neighbor.KEY, {}).get(Neighbor.CONFIG, [])
+        if neigh.get(Neighbor.INTERFACE) == "veth1"
+    ]
+
+
+def test_kernel_mode_static_neighbor(cleanup_veth1_kernel_mode):
+    desired_state = load_yaml(
+        """---
+        interfaces:
+        - name: veth1
+          type: veth
+          state: up
+          veth:
+            peer: veth1_peer
+          ipv4:
+            address:
+            - ip: 192.0.2.251
+              prefix-length: 24
+            dhcp: false
+            enabled: true
+          ipv6:
+            enabled: true
+            autoconf: false
+            dhcp: false
+            address:
+              - ip: 2001:db8:1::1
+                prefix-length: 64
+        - name: veth1_peer
+          type: veth
+          state: up
+          veth:
+            peer: veth1
+        neighbors:
+          config:
+          - ip: 192.0.2.1
+            interface: veth1
+            link-layer-address: 00:23:45:67:89:1a
+          - ip: 2001:db8:1::2
+            interface: veth1
+            link-layer-address: 00:23:45:67:89:1b
+          - ip: 192.0.2.2
+            interface: veth1
+            proxy: true
+        """
+    )
+    libnmstate.apply(desired_state, kernel_only=True)
+
+    neighs = _get_neighbors(libnmstate.show(kernel_only=True))
+    assert {
+        Neighbor.IP: "192.0.2.1",
+        Neighbor.INTERFACE: "veth1",
+        Neighbor.LINK_LAYER_ADDRESS: "00:23:45:67:89:1A",
+    } in neighs
+    assert {
+        Neighbor.IP: "2001:db8:1::2",
+        Neighbor.INTERFACE: "veth1",
+        Neighbor.LINK_LAYER_ADDRESS: "00:23:45:67:89:1B",
+    } in neighs
+    assert {
+        Neighbor.IP: "192.0.2.2",
+        Neighbor.INTERFACE: "veth1",
+        Neighbor.PROXY: True,
+    } in neighs
+
+    libnmstate.apply(
+        load_yaml(
+            """---
+            neighbors:
+              config:
+              - interface: veth1
+                state: absent
+            """
+        ),
+        kernel_only=True,
+    )
+
+    assert not _get_neighbors(libnmstate.show(kernel_only=True))
+
+
+def test_neighbor_not_supported_in_nm_mode(eth1_up):
+    with pytest.raises(NmstateNotSupportedError):
+        libnmstate.apply(
+            {
+                Neighbor.KEY: {
+                    Neighbor.CONFIG: [
+                        {
+                            Neighbor.IP: "192.0.2.1",
+                            Neighbor.INTERFACE: "eth1",
+                            Neighbor.LINK_LAYER_ADDRESS: "00:23:45:67:89:1A",
+                        }
+                    ]
+                }
+            }
+        )
@@ -734,6 +734,14 @@ def test_get_ip_address_from_unmanaged_dummy():
             check=True,
         )
         iface_state = statelib.show_only((DUMMY1,))[Interface.KEY][0]
+        iface_state[Interface.IPV4].pop(InterfaceIPv4.SYSCTL, None)
+        for key in (
+            InterfaceIPv6.SYSCTL,
+            InterfaceIPv6.IP6_PRIVACY,
+            InterfaceIPv6.DAD_TRANSMITS,
+            InterfaceIPv6.MTU_FROM_RA,
+        ):
+            iface_state[Interface.IPV6].pop(key, None)
         # Remove IPv6 link local address
         iface_state[Interface.IPV6][InterfaceIPv6.ADDRESS] = [
             addr
@@ -1110,3 +1118,43 @@ def test_kernel_mode_static_ip(cleanup_veth1_kernel_mode):
         kernel_only=True,
     )
     assertlib.assert_state_match(desired_state, kernel_only=True)
+
+
+def test_kernel_mode_static_ip_with_peer_and_flags(cleanup_veth1_kernel_mode):
+    desired_state = load_yaml(
+        """---
+        interfaces:
+        - name: veth1
+          type: veth
+          state: up
+          veth:
+            peer: veth1_peer
+          ipv4:
+            address:
+            - ip: 192.0.2.251
+              prefix-length: 32
+              peer: 192.0.2.252
+              flags:
+              - noprefixroute
+            dhcp: false
+            enabled: true
+          ipv6:
+            enabled: true
+            autoconf: false
+            dhcp: false
+            address:
+              - ip: 2001:db8:1::1
+                prefix-length: 64
+                flags:
+                - nodad
+                - noprefixroute
+        """
+    )
+    apply_with_description(
+        "Configure the veth device veth1 with the peer veth1_peer, "
+        "address 192.0.2.251/32 with peer 192.0.2.252 and noprefixroute flag "
+        "and 2001:db8:1::1/64 with nodad and noprefixroute flags",
+        desired_state,
+        kernel_only=True,
+    )
+    assertlib.assert_state_match(desired_state, kernel_only=True)
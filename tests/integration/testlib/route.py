@@ -17,8 +17,10 @@ def assert_routes(routes, state, nic="eth1"):
 
     # The kernel contains more route entries than desired config
     for route in routes:
-        assert any(route == cur_rt for cur_rt in config_routes)
-        assert any(route == run_rt for run_rt in running_routes)
+        assert any(_is_route_equal(route, cur_rt) for cur_rt in config_routes)
+        assert any(
+            _is_route_equal(route, run_rt) for run_rt in running_routes
+        )
 
 
 def assert_routes_missing(routes, state, nic="eth1"):
@@ -30,8 +32,22 @@ def assert_routes_missing(routes, state, nic="eth1"):
 
     # The kernel contains more route entries than desired config
     for route in routes:
-        assert all(route != cur_rt for cur_rt in config_routes)
-        assert all(route != run_rt for run_rt in running_routes)
+        assert all(
+            not _is_route_equal(route, cur_rt) for cur_rt in config_routes
+        )
+        assert all(
+            not _is_route_equal(route, run_rt) for run_rt in running_routes
+        )
+
+
+# The kernel reports route protocol and non-global scope for every route,
+# only compare them when desired route has them defined.
+def _is_route_equal(route, cur_route):
+    cur_route = copy.copy(cur_route)
+    for key in (Route.PROTOCOL, Route.SCOPE):
+        if key not in route:
+            cur_route.pop(key, None)
+    return route == cur_route
 
 
 def _clone_prepare_routes(routes, nic=None):
@@ -20,17 +20,19 @@ from libnmstate.schema import Mptcp
 from libnmstate.schema import OVSBridge
 
 
-def show_only(ifnames, include_secrets=False):
+def show_only(ifnames, include_secrets=False, include_status_data=False):
     """
     Report the current state, filtering based on the given interface names.
     """
     base_filter_state = {
         Interface.KEY: [{Interface.NAME: ifname} for ifname in ifnames]
     }
-    if include_secrets:
-        current_state = State(libnmstate.show(include_secrets=True))
-    else:
-        current_state = State(libnmstate.show())
+    current_state = State(
+        libnmstate.show(
+            include_secrets=include_secrets,
+            include_status_data=include_status_data,
+        )
+    )
     current_state.filter(base_filter_state)
     return current_state.state
 